@@ -1,6 +1,6 @@
 ///! tests that various assets integration scenarios work well
 use crate::{helpers::*, kusama_test_net::This, prelude::*};
-use composable_traits::xcm::assets::XcmAssetLocation;
+use composable_traits::{assets::AssetInfo, xcm::assets::XcmAssetLocation};
 
 use frame_system::RawOrigin;
 use primitives::currency::*;
@@ -14,17 +14,15 @@ fn updated_assets_registry_works_well_for_ratios() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(42),
-			XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))),
 			Rational64::from(10, 1),
-			None,
+			AssetInfo::foreign(XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))), None),
 		)
 		.unwrap();
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(123),
-			XcmAssetLocation(MultiLocation::new(1, X1(Parachain(4321)))),
 			Rational64::from(10, 100),
-			None,
+			AssetInfo::foreign(XcmAssetLocation(MultiLocation::new(1, X1(Parachain(4321)))), None),
 		)
 		.unwrap();
 		assert_eq!(
@@ -45,9 +43,8 @@ fn registered_assets_with_smaller_than_native_price() {
 		use this_runtime::*;
 		AssetsRegistry::register_asset(
 			RawOrigin::Root.into(),
-			XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))),
 			Rational64::from(10, 1),
-			None,
+			AssetInfo::foreign(XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))), None),
 		)
 		.unwrap();
 		let asset_id = System::events()
@@ -75,9 +72,8 @@ fn registered_assets_with_larger_than_native_price() {
 		use this_runtime::*;
 		AssetsRegistry::register_asset(
 			RawOrigin::Root.into(),
-			XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))),
 			Rational64::from(10, 100),
-			None,
+			AssetInfo::foreign(XcmAssetLocation(MultiLocation::new(1, X1(Parachain(666)))), None),
 		)
 		.unwrap();
 		let asset_id = System::events()
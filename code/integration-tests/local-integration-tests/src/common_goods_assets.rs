@@ -10,7 +10,7 @@ pub const FEE_NATIVE_KUSAMA: Balance = 106_666_660;
 
 use crate::{assert_lt_by, helpers::simtest, kusama_test_net::*, prelude::*};
 use common::Balance;
-use composable_traits::{currency::Rational64, xcm::assets::XcmAssetLocation};
+use composable_traits::{assets::AssetInfo, currency::Rational64, xcm::assets::XcmAssetLocation};
 use cumulus_primitives_core::ParaId;
 use frame_support::{
 	assert_ok, log,
@@ -207,9 +207,8 @@ fn rockmine_shib_to_dali_transfer() {
 		));
 		AssetsRegistry::register_asset(
 			root.into(),
-			location.clone(),
 			Rational64::from(15, 1000),
-			Some(4),
+			AssetInfo::foreign(location.clone(), Some(4)),
 		)
 		.unwrap();
 		System::events()
@@ -309,9 +308,8 @@ fn rockmine_stable_to_dali_transfer() {
 
 		AssetsRegistry::register_asset(
 			root.into(),
-			location.clone(),
 			ratio,
-			Some(STABLE::EXPONENT),
+			AssetInfo::foreign(location.clone(), Some(STABLE::EXPONENT)),
 		)
 		.unwrap();
 		System::events()
@@ -614,9 +612,8 @@ fn register_statemine_asset(remote_asset_id: CommonAssetId, ratio: Rational64) -
 		let location = state_mine_asset(remote_asset_id);
 		AssetsRegistry::register_asset(
 			frame_system::RawOrigin::Root.into(),
-			location.clone(),
 			ratio,
-			None,
+			AssetInfo::foreign(location.clone(), None),
 		)
 		.unwrap();
 
@@ -670,9 +667,8 @@ fn cannot_reserve_transfer_from_two_consensuses_in_one_message() {
 		AssetsRegistry::update_asset(
 			root.into(),
 			sibling_asset_id,
-			location,
 			rational!(15 / 1_000_000_000),
-			Some(STABLE::EXPONENT),
+			AssetInfo::foreign(location, Some(STABLE::EXPONENT)),
 		)
 		.expect("Asset already in Currency Factory; QED");
 
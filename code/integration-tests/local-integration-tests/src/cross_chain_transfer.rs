@@ -6,7 +6,7 @@ use crate::{
 };
 use codec::Encode;
 use common::{AccountId, Balance};
-use composable_traits::{currency::RangeId, rational};
+use composable_traits::{assets::AssetInfo, currency::RangeId, rational};
 
 use frame_system::RawOrigin;
 
@@ -88,12 +88,14 @@ fn transfer_this_native_to_sibling_overridden() {
 		assert_ok!(AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId::PICA,
-			composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
-				1,
-				X1(Parachain(THIS_PARA_ID),)
-			)),
 			rational!(1 / 1),
-			None,
+			AssetInfo::foreign(
+				composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
+					1,
+					X1(Parachain(THIS_PARA_ID),)
+				)),
+				None,
+			),
 		));
 	});
 
@@ -138,12 +140,14 @@ fn transfer_non_native_reserve_asset_from_this_to_sibling() {
 		assert_ok!(this_runtime::AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId::PBLO,
-			composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(CurrencyId::PBLO.into()),)
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(CurrencyId::PBLO.into()),)
+				)),
+				None,
+			),
 		));
 	});
 
@@ -183,12 +187,14 @@ fn transfer_non_native_reserve_asset_from_this_to_sibling_by_local_id_overridden
 		assert_ok!(this_runtime::AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId::PBLO,
-			composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(CurrencyId::PBLO.into()),)
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				composable_traits::xcm::assets::XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(CurrencyId::PBLO.into()),)
+				)),
+				None,
+			),
 		));
 	});
 
@@ -236,8 +242,12 @@ fn this_native_transferred_from_sibling_to_native_is_not_enough() {
 		let root = frame_system::RawOrigin::Root;
 		let location =
 			XcmAssetLocation::new(MultiLocation::new(1, X1(Parachain(THIS_PARA_ID))).into());
-		AssetsRegistry::register_asset(root.into(), location.clone(), Rational64::one(), None)
-			.unwrap();
+		AssetsRegistry::register_asset(
+			root.into(),
+			Rational64::one(),
+			AssetInfo::foreign(location.clone(), None),
+		)
+		.unwrap();
 		System::events()
 			.iter()
 			.find_map(|x| match x.event {
@@ -931,9 +941,8 @@ fn sibling_trap_assets_works() {
 		assert_ok!(AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			any_asset,
-			remote,
 			Rational64::one(),
-			None
+			AssetInfo::foreign(remote, None)
 		));
 		(balance, sibling_non_native_amount)
 	});
@@ -1002,9 +1011,8 @@ fn sibling_shib_to_transfer() {
 		AssetsRegistry::update_asset(
 			root.into(),
 			sibling_asset_id,
-			location,
 			Rational64::one(),
-			Some(SHIB::EXPONENT),
+			AssetInfo::foreign(location, Some(SHIB::EXPONENT)),
 		)
 		.expect("Asset already in Currency Factory; QED");
 
@@ -1031,9 +1039,8 @@ fn sibling_shib_to_transfer() {
 		));
 		AssetsRegistry::register_asset(
 			root.into(),
-			location,
 			Rational64::one(),
-			Some(SHIB::EXPONENT),
+			AssetInfo::foreign(location, Some(SHIB::EXPONENT)),
 		)
 		.expect("Asset details are valid; QED");
 		System::events()
@@ -1098,9 +1105,8 @@ fn transfer_unknown_token_from_known_origin_ends_up_in_unknown_tokens() {
 		AssetsRegistry::update_asset(
 			root.into(),
 			sibling_asset_id,
-			location,
 			Rational64::one(),
-			Some(SHIB::EXPONENT),
+			AssetInfo::foreign(location, Some(SHIB::EXPONENT)),
 		)
 		.expect("Asset already in Currency Factory; QED");
 
@@ -1129,9 +1135,8 @@ fn transfer_unknown_token_from_known_origin_ends_up_in_unknown_tokens() {
 		AssetsRegistry::update_asset(
 			root.into(),
 			sibling_asset_id,
-			location,
 			Rational64::one(),
-			Some(SHIB::EXPONENT),
+			AssetInfo::foreign(location, Some(SHIB::EXPONENT)),
 		)
 		.expect("Asset already in Currency Factory; QED");
 
@@ -1156,9 +1161,8 @@ fn transfer_unknown_token_from_known_origin_ends_up_in_unknown_tokens() {
 		));
 		AssetsRegistry::register_asset(
 			root.into(),
-			location,
 			Rational64::one(),
-			Some(SHIB::EXPONENT),
+			AssetInfo::foreign(location, Some(SHIB::EXPONENT)),
 		)
 		.expect("Asset details are valid; QED");
 		System::events()
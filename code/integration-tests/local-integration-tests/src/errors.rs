@@ -5,7 +5,7 @@ use crate::{
 };
 
 use common::Balance;
-use composable_traits::currency::AssetRatioInspect;
+use composable_traits::{assets::AssetInfo, currency::AssetRatioInspect};
 use orml_traits::MultiCurrency;
 use xcm::VersionedMultiAsset;
 
@@ -136,12 +136,14 @@ fn cannot_reserver_transfer_assets_when_fee_and_non_fee_has_different_origin() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(100500),
-			XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
+				)),
+				None,
+			),
 		)
 		.unwrap();
 		assert_ok!(Tokens::deposit(CurrencyId(100500), &alice().into(), 2 * transfer_amount));
@@ -149,12 +151,14 @@ fn cannot_reserver_transfer_assets_when_fee_and_non_fee_has_different_origin() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(123_666),
-			XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(123_666)),
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(123_666)),
+				)),
+				None,
+			),
 		)
 		.unwrap();
 		assert_ok!(Tokens::deposit(CurrencyId(123_666), &alice().into(), 2 * transfer_amount));
@@ -207,12 +211,14 @@ fn transfer_existing_asset_but_with_relevant_outgoing_fee_by_local_id() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(100500),
-			XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
+				)),
+				None,
+			),
 		)
 		.unwrap();
 
@@ -254,12 +260,14 @@ fn cannot_transfer_away_if_min_fee_is_not_defined() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(100500),
-			XcmAssetLocation(MultiLocation::new(
-				1,
-				X2(Parachain(SIBLING_PARA_ID), GeneralIndex(100500)),
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				XcmAssetLocation(MultiLocation::new(
+					1,
+					X2(Parachain(SIBLING_PARA_ID), GeneralIndex(100500)),
+				)),
+				None,
+			),
 		)
 		.unwrap();
 		assert_ok!(Tokens::deposit(CurrencyId(100500), &alice().into(), 2 * transfer_amount));
@@ -316,12 +324,14 @@ fn cannot_reserve_transfer_assets_from_self() {
 		AssetsRegistry::update_asset(
 			RawOrigin::Root.into(),
 			CurrencyId(100500),
-			XcmAssetLocation(MultiLocation::new(
-				0,
-				X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
-			)),
 			Rational64::one(),
-			None,
+			AssetInfo::foreign(
+				XcmAssetLocation(MultiLocation::new(
+					0,
+					X2(Parachain(THIS_PARA_ID), GeneralIndex(100500)),
+				)),
+				None,
+			),
 		)
 		.unwrap();
 
@@ -34,11 +34,13 @@ export default {
   types: {
     Asset: {
       name: "Vec<u8>",
+      symbol: "Option<Vec<u8>>",
       id: "u128",
       decimals: "u32",
       ratio: "Option<ComposableTraitsCurrencyRational64>",
       foreignId: "Option<XcmV1MultiLocation>",
       existentialDeposit: "CustomRpcBalance",
+      payableAsFee: "bool",
     }
   }
 };
@@ -3,16 +3,18 @@
 
 import type { CustomRpcBalance } from '@composable/types/interfaces/common';
 import type { ComposableTraitsCurrencyRational64, XcmV1MultiLocation } from '@composable/types/interfaces/crowdloanRewards';
-import type { Bytes, Option, Struct, u128, u32 } from '@polkadot/types-codec';
+import type { Bytes, Option, Struct, bool, u128, u32 } from '@polkadot/types-codec';
 
 /** @name Asset */
 export interface Asset extends Struct {
   readonly name: Bytes;
+  readonly symbol: Option<Bytes>;
   readonly id: u128;
   readonly decimals: u32;
   readonly ratio: Option<ComposableTraitsCurrencyRational64>;
   readonly foreignId: Option<XcmV1MultiLocation>;
   readonly existentialDeposit: CustomRpcBalance;
+  readonly payableAsFee: bool;
 }
 
 export type PHANTOM_ASSETS = 'assets';
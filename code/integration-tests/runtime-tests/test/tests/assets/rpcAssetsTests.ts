@@ -57,7 +57,18 @@ describe("[SHORT] rpc.assets Tests", function () {
   it("rpc.assets.listAssets Tests", async function () {
     if (!testConfiguration.enabledTests.rpc.listAssets__success) this.skip();
     const result = await RpcAssetsTests.rpcListAssetsTest(api);
-    result.every(i => expect(i).to.have.all.keys("id", "name", "decimals", "foreignId", "ratio", "existentialDeposit"));
+    result.every(i =>
+      expect(i).to.have.all.keys(
+        "id",
+        "name",
+        "symbol",
+        "decimals",
+        "foreignId",
+        "ratio",
+        "existentialDeposit",
+        "payableAsFee"
+      )
+    );
     expect(result.map(e => e.id.toNumber())).to.include.members([
       // These are the assets to be included on the first release
       1, 4, 5, 129, 130, 131
@@ -93,7 +104,11 @@ describe("[SHORT] rpc.assets Tests", function () {
       sudoKey,
       api.events.sudo.Sudid.is,
       api.tx.sudo.sudo(
-        api.tx.assetsRegistry.updateAsset(4, newKsmInfo.foreignId, newKsmInfo.ratio, newKsmInfo.decimals)
+        api.tx.assetsRegistry.updateAsset(
+          4,
+          newKsmInfo.ratio,
+          foreignAssetInfo(newKsmInfo.foreignId, newKsmInfo.decimals)
+        )
       )
     );
 
@@ -116,7 +131,11 @@ describe("[SHORT] rpc.assets Tests", function () {
       sudoKey,
       api.events.sudo.Sudid.is,
       api.tx.sudo.sudo(
-        api.tx.assetsRegistry.updateAsset(4, newKsmInfo.foreignId, newKsmInfo.ratio, newKsmInfo.decimals)
+        api.tx.assetsRegistry.updateAsset(
+          4,
+          newKsmInfo.ratio,
+          foreignAssetInfo(newKsmInfo.foreignId, newKsmInfo.decimals)
+        )
       )
     );
 
@@ -138,7 +157,10 @@ describe("[SHORT] rpc.assets Tests", function () {
       sudoKey,
       api.events.sudo.Sudid.is,
       api.tx.sudo.sudo(
-        api.tx.assetsRegistry.registerAsset(newAssetInfo.foreignId, newAssetInfo.ratio, newAssetInfo.decimals)
+        api.tx.assetsRegistry.registerAsset(
+          newAssetInfo.ratio,
+          foreignAssetInfo(newAssetInfo.foreignId, newAssetInfo.decimals)
+        )
       )
     );
     const resultAfterRegister = await RpcAssetsTests.rpcListAssetsTest(api);
@@ -161,6 +183,17 @@ class RpcAssetsTests {
   }
 }
 
+function foreignAssetInfo(location: object, decimals: number) {
+  return {
+    name: null,
+    symbol: null,
+    decimals,
+    existentialDeposit: null,
+    location,
+    payableAsFee: true
+  };
+}
+
 function hex_to_ascii(str1: string) {
   const hex = str1.toString();
   let str = "";
@@ -2,7 +2,9 @@
 
 use super::*;
 use crate::{
-	models::Proof, AccountIdOf, Call, Config, IdentityOf, Pallet as Airdrop, Pallet, ProofOf,
+	merkle::{self, MerkleHash},
+	models::{MerkleClaim, Proof},
+	AccountIdOf, Call, Config, IdentityOf, Pallet as Airdrop, Pallet, ProofOf,
 };
 use composable_support::{
 	signature_verification,
@@ -238,7 +240,28 @@ benchmarks! {
 		<Airdrop<T> as Airdropper>::add_recipient(creator, airdrop_id, remote_accounts)?;
 		let reward_account = accounts[0].0.clone();
 		System::<T>::set_block_number(VESTING_PERIOD.into());
-	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()))
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), None)
+
+	create_merkle_airdrop_benchmark {
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+	}: create_merkle_airdrop(RawOrigin::Signed(creator), None, VESTING_STEP.into(), [0; 32], T::Balance::from(1_000_000_000_000), 1)
+
+	claim_with_merkle_proof_benchmark {
+		let x in 1..T::MaxMerkleProofLength::get();
+		let accounts = generate_accounts::<T>(2);
+		let reward_account = accounts[0].0.clone();
+		let remote_account = accounts[0].1.as_remote_public::<T>();
+		let amount = T::Balance::from(1_000_000_000_000);
+		let vesting_period: MomentOf<T> = VESTING_PERIOD.into();
+		let proof: Vec<MerkleHash> = (0..x).map(|i| [i as u8; 32]).collect();
+		let leaf = merkle::leaf_hash(0, &remote_account, &amount, &vesting_period);
+		let merkle_root = merkle::root_from_proof(leaf, &proof);
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_merkle_airdrop(creator.clone(), None, VESTING_STEP.into(), merkle_root, amount, 1)?;
+		<Airdrop<T> as Airdropper>::enable_airdrop(creator, airdrop_id)?;
+		let merkle_claim = MerkleClaim { index: 0, amount, vesting_period, proof };
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), Some(merkle_claim))
 }
 
 impl_benchmark_test_suite!(
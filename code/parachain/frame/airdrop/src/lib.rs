@@ -3,6 +3,8 @@
 
 pub use pallet::*;
 
+pub mod merkle;
+pub mod migrations;
 pub mod models;
 pub mod weights;
 
@@ -15,7 +17,8 @@ mod tests;
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{
-		models::{Airdrop, AirdropState, Identity, Proof, RecipientFund},
+		merkle::{self, MerkleHash},
+		models::{Airdrop, AirdropState, Identity, MerkleClaim, Proof, RecipientFund},
 		weights::WeightInfo,
 	};
 	use codec::{Codec, FullCodec, MaxEncodedLen};
@@ -70,6 +73,8 @@ pub mod pallet {
 	/// ['Proof'](crate::models::Proof) as configured by the pallet
 	pub type ProofOf<T> = Proof<<T as Config>::RelayChainAccountId>;
 	pub type IdentityOf<T> = Identity<<T as Config>::RelayChainAccountId>;
+	/// [`MerkleClaim`](crate::models::MerkleClaim) as configured by the pallet.
+	pub type MerkleClaimOf<T> = MerkleClaim<<T as Config>::Balance, <T as Config>::Moment>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -117,6 +122,9 @@ pub mod pallet {
 		RecipientNotFound,
 		InvalidProof,
 		UnclaimedFundsRemaining,
+		InvalidMerkleProof,
+		MerkleProofTooLong,
+		RecipientsFixedByMerkleRoot,
 	}
 
 	#[pallet::config]
@@ -182,11 +190,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type Stake: Get<BalanceOf<Self>>;
 
+		/// The maximum number of sibling hashes in a Merkle proof, i.e. the depth of the deepest
+		/// supported Merkle tree of recipients.
+		#[pallet::constant]
+		type MaxMerkleProofLength: Get<u32>;
+
 		/// The implementation of extrinsic weights.
 		type WeightInfo: WeightInfo;
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The counter used to identify Airdrops.
@@ -234,6 +250,21 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Bitmap of claimed leaves of Airdrops committed to by a Merkle root. Each word tracks 128
+	/// consecutive leaf indices.
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_leaves)]
+	#[allow(clippy::disallowed_types)] // Allow `frame_support::pallet_prelude::ValueQuery` because default of 0 is correct
+	pub type ClaimedLeaves<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AirdropId, Twox64Concat, u32, u128, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new Airdrop. This requires that the user puts down a stake in PICA.
@@ -268,6 +299,54 @@ pub mod pallet {
 			<Self as Airdropper>::create_airdrop(creator, start_at, vesting_schedule)
 		}
 
+		/// Create a new Airdrop whose recipients are committed to by a Merkle root instead of being
+		/// added to storage. This requires that the user puts down a stake in PICA and funds the
+		/// Airdrop with `total_funds` up front.
+		///
+		/// Each leaf of the tree commits to the `index` of the leaf, the recipient identity, the
+		/// amount of funds and the vesting period of the recipient (see [`merkle`](crate::merkle)).
+		/// Recipients are materialized in storage by their first `claim`.
+		///
+		/// Can be called by any signed origin.
+		///
+		/// # Parameter Sources
+		/// * `start_at` - user provided, optional
+		/// * `vesting_schedule` - user provided
+		/// * `merkle_root` - user provided
+		/// * `total_funds` - user provided, sum of the amounts of all leaves
+		/// * `total_recipients` - user provided, number of leaves
+		///
+		/// # Emits
+		/// * `AirdropCreated`
+		/// * `RecipientsAdded`
+		/// * `AirdropStarted`
+		///
+		/// # Errors
+		/// * `AirdropAlreadyStarted` - The Airdrop has already started or has been scheduled to
+		/// start
+		/// * `BackToTheFuture` - The provided `start` has already passed
+		#[pallet::weight(<T as Config>::WeightInfo::create_merkle_airdrop())]
+		#[transactional]
+		pub fn create_merkle_airdrop(
+			origin: OriginFor<T>,
+			start_at: Option<MomentOf<T>>,
+			vesting_schedule: MomentOf<T>,
+			merkle_root: MerkleHash,
+			total_funds: BalanceOf<T>,
+			total_recipients: u32,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+
+			<Self as Airdropper>::create_merkle_airdrop(
+				creator,
+				start_at,
+				vesting_schedule,
+				merkle_root,
+				total_funds,
+				total_recipients,
+			)
+		}
+
 		/// Add one or more recipients to the Airdrop, specifying the token amount that each
 		/// provided address will receive.
 		///
@@ -283,6 +362,8 @@ pub mod pallet {
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
+		/// * `RecipientsFixedByMerkleRoot` - Recipients of the Airdrop are committed to by a Merkle
+		/// root
 		#[pallet::weight(<T as Config>::WeightInfo::add_recipient(recipients.len() as u32))]
 		#[transactional]
 		pub fn add_recipient(
@@ -312,6 +393,8 @@ pub mod pallet {
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
 		/// * `RecipientAlreadyClaimed` - The recipient has already began claiming their funds.
 		/// * `RecipientNotFound` - No recipient associated with the `identity` could be found.
+		/// * `RecipientsFixedByMerkleRoot` - Recipients of the Airdrop are committed to by a Merkle
+		/// root
 		#[pallet::weight(<T as Config>::WeightInfo::remove_recipient())]
 		#[transactional]
 		pub fn remove_recipient(
@@ -374,12 +457,16 @@ pub mod pallet {
 		///
 		/// If no more funds are left to claim, the Airdrop will be removed.
 		///
+		/// For Airdrops committed to by a Merkle root, the first claim of a recipient must provide
+		/// the `merkle_claim` of their leaf. Later claims may omit it.
+		///
 		/// Callable by any unsigned origin.
 		///
 		/// # Parameter Sources
 		/// * `airdrop_id` - user selected, provided by the system
 		/// * `reward_account` - user provided
 		/// * `proof` - calculated by the system (requires applicable signing)
+		/// * `merkle_claim` - user provided, optional
 		///
 		/// # Emits
 		/// * `AirdropEnded`
@@ -390,18 +477,29 @@ pub mod pallet {
 		/// * `AssociatedWithAnotherAccount` - Associated with a different account
 		/// * `ArithmeticError` - Overflow while totaling claimed funds
 		/// * `InvalidProof`
+		/// * `InvalidMerkleProof` - The `merkle_claim` is not part of the Merkle root
+		/// * `MerkleProofTooLong` - The `merkle_claim` exceeds `MaxMerkleProofLength`
+		/// * `RecipientAlreadyClaimed` - The leaf of the `merkle_claim` has already been claimed
 		/// * `RecipientNotFound` - No recipient associated with the `identity` could be found.
-		#[pallet::weight(<T as Config>::WeightInfo::claim(TotalAirdropRecipients::<T>::get(airdrop_id)))]
+		#[pallet::weight(match merkle_claim {
+			Some(merkle_claim) => <T as Config>::WeightInfo::claim_with_merkle_proof(merkle_claim.proof.len() as u32),
+			None => <T as Config>::WeightInfo::claim(TotalAirdropRecipients::<T>::get(airdrop_id)),
+		})]
 		#[transactional]
 		pub fn claim(
 			origin: OriginFor<T>,
 			airdrop_id: T::AirdropId,
 			reward_account: T::AccountId,
 			proof: ProofOf<T>,
+			merkle_claim: Option<MerkleClaimOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
 			let identity = Self::get_identity(proof, &reward_account, T::Prefix::get())?;
 
+			if let Some(merkle_claim) = merkle_claim {
+				Self::insert_merkle_recipient(airdrop_id, identity.clone(), merkle_claim)?;
+			}
+
 			match Associations::<T>::get(airdrop_id, reward_account.clone()) {
 				// Confirm association matches
 				Some(associated_account) => {
@@ -477,6 +575,80 @@ pub mod pallet {
 				.map_err(|_| Error::<T>::RecipientNotFound)
 		}
 
+		/// Computes the [`RecipientFund`](crate::models::RecipientFund) committed to by a leaf of
+		/// the Merkle root of an Airdrop.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `InvalidMerkleProof` - The leaf is not part of the Merkle root
+		/// * `MerkleProofTooLong` - The proof exceeds `MaxMerkleProofLength`
+		/// * `RecipientAlreadyClaimed` - The leaf has already been claimed
+		pub(crate) fn merkle_recipient_fund(
+			airdrop_id: T::AirdropId,
+			identity: &IdentityOf<T>,
+			merkle_claim: &MerkleClaimOf<T>,
+		) -> Result<RecipientFundOf<T>, Error<T>> {
+			let airdrop = Self::get_airdrop(&airdrop_id)?;
+			let merkle_root = airdrop.merkle_root.ok_or(Error::<T>::InvalidMerkleProof)?;
+			ensure!(
+				merkle_claim.proof.len() <= T::MaxMerkleProofLength::get() as usize,
+				Error::<T>::MerkleProofTooLong
+			);
+			ensure!(
+				!Self::is_leaf_claimed(airdrop_id, merkle_claim.index),
+				Error::<T>::RecipientAlreadyClaimed
+			);
+
+			let leaf = merkle::leaf_hash(
+				merkle_claim.index,
+				identity,
+				&merkle_claim.amount,
+				&merkle_claim.vesting_period,
+			);
+			ensure!(
+				merkle::root_from_proof(leaf, &merkle_claim.proof) == merkle_root,
+				Error::<T>::InvalidMerkleProof
+			);
+
+			Ok(RecipientFundOf::<T> {
+				total: merkle_claim.amount,
+				claimed: T::Balance::zero(),
+				vesting_period: merkle_claim.vesting_period,
+				funded_claim: false,
+			})
+		}
+
+		/// Stores the recipient committed to by a leaf of the Merkle root of an Airdrop and marks
+		/// the leaf as claimed. Does nothing if the recipient is already stored.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `InvalidMerkleProof` - The leaf is not part of the Merkle root
+		/// * `MerkleProofTooLong` - The proof exceeds `MaxMerkleProofLength`
+		/// * `RecipientAlreadyClaimed` - The leaf has already been claimed
+		pub(crate) fn insert_merkle_recipient(
+			airdrop_id: T::AirdropId,
+			identity: IdentityOf<T>,
+			merkle_claim: MerkleClaimOf<T>,
+		) -> DispatchResult {
+			if RecipientFunds::<T>::contains_key(airdrop_id, &identity) {
+				return Ok(())
+			}
+
+			let fund = Self::merkle_recipient_fund(airdrop_id, &identity, &merkle_claim)?;
+			ClaimedLeaves::<T>::mutate(airdrop_id, merkle_claim.index / 128, |word| {
+				*word |= 1_u128 << (merkle_claim.index % 128)
+			});
+			RecipientFunds::<T>::insert(airdrop_id, identity, fund);
+
+			Ok(())
+		}
+
+		/// Checks whether the leaf at `index` of the Merkle root of an Airdrop has been claimed.
+		pub fn is_leaf_claimed(airdrop_id: T::AirdropId, index: u32) -> bool {
+			ClaimedLeaves::<T>::get(airdrop_id, index / 128) & (1_u128 << (index % 128)) != 0
+		}
+
 		/// Gets the remote account address from the `Proof`.
 		///
 		/// # Errors
@@ -522,6 +694,29 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::InvalidProof)?;
 					Result::<_, DispatchError>::Ok(Identity::Cosmos(cosmos_address))
 				},
+				Proof::Solana(solana_address, solana_proof) => {
+					let reward_account_encoded =
+						reward_account.using_encoded(signature_verification::get_encoded_vec);
+					let solana_address = signature_verification::solana_verify(
+						prefix,
+						&reward_account_encoded,
+						solana_address,
+						&solana_proof,
+					)
+					.map_err(|_| Error::<T>::InvalidProof)?;
+					Result::<_, DispatchError>::Ok(Identity::Solana(solana_address))
+				},
+				Proof::Bitcoin(bitcoin_proof) => {
+					let reward_account_encoded =
+						reward_account.using_encoded(signature_verification::get_encoded_vec);
+					let bitcoin_address = signature_verification::bitcoin_recover(
+						prefix,
+						&reward_account_encoded,
+						&bitcoin_proof,
+					)
+					.map_err(|_| Error::<T>::InvalidProof)?;
+					Result::<_, DispatchError>::Ok(Identity::Bitcoin(bitcoin_address))
+				},
 			}?;
 			Ok(identity)
 		}
@@ -626,6 +821,8 @@ pub mod pallet {
 			RecipientFunds::<T>::remove_prefix(airdrop_id, None);
 			#[allow(deprecated)]
 			Associations::<T>::remove_prefix(airdrop_id, None);
+			#[allow(deprecated)]
+			ClaimedLeaves::<T>::remove_prefix(airdrop_id, None);
 			Airdrops::<T>::remove(airdrop_id);
 
 			Ok(true)
@@ -637,6 +834,7 @@ pub mod pallet {
 		type AirdropId = AirdropIdOf<T>;
 		type AirdropStart = MomentOf<T>;
 		type Balance = BalanceOf<T>;
+		type MerkleRoot = MerkleHash;
 		type Proof = ProofOf<T>;
 		type Recipient = IdentityOf<T>;
 		type RecipientCollection = Vec<(Self::Recipient, BalanceOf<T>, MomentOf<T>, bool)>;
@@ -671,6 +869,7 @@ pub mod pallet {
 					start: None,
 					schedule,
 					disabled: false,
+					merkle_root: None,
 				},
 			);
 
@@ -686,6 +885,63 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Create a new Airdrop whose recipients are committed to by a Merkle root.
+		///
+		/// The creator funds the Airdrop with `total_funds` on top of the stake.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `AirdropAlreadyStarted` - The Airdrop has already started or has been scheduled to
+		/// start
+		/// * `BackToTheFuture` - The provided `start` has already passed
+		fn create_merkle_airdrop(
+			creator_id: Self::AccountId,
+			start: Option<Self::AirdropStart>,
+			schedule: Self::VestingSchedule,
+			merkle_root: Self::MerkleRoot,
+			total_funds: Self::Balance,
+			total_recipients: u32,
+		) -> DispatchResult {
+			let airdrop_id = AirdropCount::<T>::increment()?;
+			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
+
+			Airdrops::<T>::insert(
+				airdrop_id,
+				Airdrop {
+					creator: creator_id.clone(),
+					total_funds,
+					total_recipients,
+					claimed_funds: T::Balance::zero(),
+					start: None,
+					schedule,
+					disabled: false,
+					merkle_root: Some(merkle_root),
+				},
+			);
+			TotalAirdropRecipients::<T>::insert(airdrop_id, total_recipients);
+
+			// Transfer stake and recipient funds into airdrop specific account.
+			T::RecipientFundAsset::transfer(
+				&creator_id,
+				&airdrop_account,
+				T::Stake::get().safe_add(&total_funds)?,
+				false,
+			)?;
+
+			Self::deposit_event(Event::AirdropCreated { airdrop_id, by: creator_id });
+			Self::deposit_event(Event::RecipientsAdded {
+				airdrop_id,
+				number: total_recipients,
+				unclaimed_funds: total_funds,
+			});
+
+			if let Some(moment) = start {
+				Self::start_airdrop_at(airdrop_id, moment)?;
+			}
+
+			Ok(())
+		}
+
 		/// Add one or more recipients to an Airdrop.
 		///
 		/// Airdrop creator is expected to be able to fund the Airdrop. If the Airdrops current
@@ -697,6 +953,8 @@ pub mod pallet {
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
+		/// * `RecipientsFixedByMerkleRoot` - Recipients of the Airdrop are committed to by a Merkle
+		/// root
 		fn add_recipient(
 			origin_id: Self::AccountId,
 			airdrop_id: Self::AirdropId,
@@ -704,6 +962,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let airdrop = Self::get_airdrop(&airdrop_id)?;
 			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(airdrop.merkle_root.is_none(), Error::<T>::RecipientsFixedByMerkleRoot);
 
 			// Calculate total funds and recipients local to this transaction
 			let (transaction_funds, transaction_recipients) = recipients.iter().try_fold(
@@ -782,6 +1041,8 @@ pub mod pallet {
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
 		/// * `RecipientAlreadyClaimed` - The recipient has already began claiming their funds.
 		/// * `RecipientNotFound` - No recipient associated with the `identity` could be found.
+		/// * `RecipientsFixedByMerkleRoot` - Recipients of the Airdrop are committed to by a Merkle
+		/// root
 		fn remove_recipient(
 			origin_id: Self::AccountId,
 			airdrop_id: Self::AirdropId,
@@ -789,6 +1050,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let airdrop = Self::get_airdrop(&airdrop_id)?;
 			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(airdrop.merkle_root.is_none(), Error::<T>::RecipientsFixedByMerkleRoot);
 
 			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
 			let recipient_fund = Self::get_recipient_fund(airdrop_id, recipient.clone())?;
@@ -897,6 +1159,9 @@ pub mod pallet {
 
 		/// Claim a recipient reward from an Airdrop.
 		///
+		/// Recipients of Airdrops committed to by a Merkle root are removed from storage once they
+		/// have claimed all of their funds. Their leaf stays marked as claimed.
+		///
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `AirdropIsNotEnabled` - The Airdrop has not been enabled
@@ -908,9 +1173,10 @@ pub mod pallet {
 			reward_account: Self::AccountId,
 		) -> DispatchResultWithPostInfo {
 			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
+			let is_merkle_airdrop = Self::get_airdrop(&airdrop_id)?.merkle_root.is_some();
 			let (available_to_claim, recipient_fund) =
-				RecipientFunds::<T>::try_mutate(airdrop_id, identity, |fund| {
-					match fund.as_mut() {
+				RecipientFunds::<T>::try_mutate_exists(airdrop_id, identity, |maybe_fund| {
+					match maybe_fund.as_mut() {
 						Some(fund) => {
 							let claimable = Self::claimable(airdrop_id, fund)?;
 							let available_to_claim = claimable.saturating_sub(fund.claimed);
@@ -922,8 +1188,13 @@ pub mod pallet {
 
 							// Update Airdrop and fund status
 							fund.claimed = fund.claimed.saturating_add(available_to_claim);
+							let fund = *fund;
+
+							if is_merkle_airdrop && fund.claimed >= fund.total {
+								*maybe_fund = None;
+							}
 
-							Ok((available_to_claim, *fund))
+							Ok((available_to_claim, fund))
 						},
 						None => Err(Error::<T>::RecipientNotFound),
 					}
@@ -971,7 +1242,7 @@ pub mod pallet {
 		type Call = Call<T>;
 
 		fn validate_unsigned(_: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			if let Call::claim { airdrop_id, reward_account, proof } = call {
+			if let Call::claim { airdrop_id, reward_account, proof, merkle_claim } = call {
 				// Validity Error if the airdrop does not exist
 				let airdrop_state = Self::get_airdrop_state(*airdrop_id).map_err(|_| {
 					Into::<TransactionValidityError>::into(InvalidTransaction::Custom(
@@ -1002,7 +1273,12 @@ pub mod pallet {
 				}
 
 				// Validity Error if there are no funds for this recipient
-				match RecipientFunds::<T>::get(airdrop_id, identity.clone()) {
+				let fund = RecipientFunds::<T>::get(airdrop_id, identity.clone()).or_else(|| {
+					merkle_claim.as_ref().and_then(|merkle_claim| {
+						Self::merkle_recipient_fund(*airdrop_id, &identity, merkle_claim).ok()
+					})
+				});
+				match fund {
 					None => InvalidTransaction::Custom(ValidityError::NoFunds as u8).into(),
 					Some(fund) if fund.total.is_zero() =>
 						InvalidTransaction::Custom(ValidityError::NoFunds as u8).into(),
@@ -0,0 +1,74 @@
+//! Merkle tree commitments to the recipients of an Airdrop.
+//!
+//! Leaves commit to `(index, identity, amount, vesting_period)`. Nodes hash their children in
+//! sorted order, so proofs are a plain list of sibling hashes from the leaf up to the root.
+//! Leaves and nodes are hashed with distinct prefixes to prevent second preimage attacks.
+
+use codec::Encode;
+use sp_io::hashing::blake2_256;
+use sp_std::vec::Vec;
+
+/// Hash used for the leaves and nodes of the tree.
+pub type MerkleHash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Hash of the leaf committing to the recipient at position `index`.
+pub fn leaf_hash<Identity: Encode, Balance: Encode, Period: Encode>(
+	index: u32,
+	identity: &Identity,
+	amount: &Balance,
+	vesting_period: &Period,
+) -> MerkleHash {
+	blake2_256(&(LEAF_PREFIX, index, identity, amount, vesting_period).encode())
+}
+
+/// Hash of the parent of two sibling nodes.
+pub fn node_hash(a: &MerkleHash, b: &MerkleHash) -> MerkleHash {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	blake2_256(&(NODE_PREFIX, left, right).encode())
+}
+
+/// Root of the tree implied by `leaf` and its `proof`.
+pub fn root_from_proof(leaf: MerkleHash, proof: &[MerkleHash]) -> MerkleHash {
+	proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling))
+}
+
+/// Builds the root of a tree along with the proof of each leaf.
+///
+/// A node without a sibling is promoted to the next level unchanged. Returns `None` if there are
+/// no leaves.
+pub fn build_tree(leaves: &[MerkleHash]) -> Option<(MerkleHash, Vec<Vec<MerkleHash>>)> {
+	if leaves.is_empty() {
+		return None
+	}
+
+	let mut proofs = leaves.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+	// leaf indices covered by each node of the current level
+	let mut covered = (0..leaves.len()).map(|i| sp_std::vec![i]).collect::<Vec<_>>();
+	let mut level = leaves.to_vec();
+
+	while level.len() > 1 {
+		let mut next_level = Vec::with_capacity((level.len() + 1) / 2);
+		let mut next_covered = Vec::with_capacity((level.len() + 1) / 2);
+		for (nodes, indices) in level.chunks(2).zip(covered.chunks(2)) {
+			match (nodes, indices) {
+				([left, right], [left_indices, right_indices]) => {
+					left_indices.iter().for_each(|&i| proofs[i].push(*right));
+					right_indices.iter().for_each(|&i| proofs[i].push(*left));
+					next_level.push(node_hash(left, right));
+					next_covered.push([left_indices.as_slice(), right_indices.as_slice()].concat());
+				},
+				_ => {
+					next_level.push(nodes[0]);
+					next_covered.push(indices[0].clone());
+				},
+			}
+		}
+		level = next_level;
+		covered = next_covered;
+	}
+
+	Some((level[0], proofs))
+}
@@ -0,0 +1,58 @@
+//! Storage migrations of the airdrop pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds a `merkle_root` to stored airdrops, see [`Pallet::create_merkle_airdrop`].
+
+	use super::*;
+	use crate::models::Airdrop;
+	use codec::Decode;
+	use frame_support::{
+		traits::{Get, GetStorageVersion, StorageVersion},
+		weights::Weight,
+	};
+
+	/// Airdrop as stored before the introduction of Merkle airdrops.
+	#[derive(Decode)]
+	struct OldAirdrop<AccountId, Balance, Moment> {
+		creator: AccountId,
+		total_funds: Balance,
+		total_recipients: u32,
+		claimed_funds: Balance,
+		start: Option<Moment>,
+		schedule: Moment,
+		disabled: bool,
+	}
+
+	type OldAirdropOf<T> = OldAirdrop<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		<T as Config>::Moment,
+	>;
+
+	/// Recipients of stored airdrops were all added to storage, so none of them has a Merkle root.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		Airdrops::<T>::translate::<OldAirdropOf<T>, _>(|_, airdrop| {
+			translated += 1;
+			Some(Airdrop {
+				creator: airdrop.creator,
+				total_funds: airdrop.total_funds,
+				total_recipients: airdrop.total_recipients,
+				claimed_funds: airdrop.claimed_funds,
+				start: airdrop.start,
+				schedule: airdrop.schedule,
+				disabled: airdrop.disabled,
+				merkle_root: None,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -1,5 +1,8 @@
 #![cfg(test)]
-use crate::{self as pallet_airdrop, models::Proof};
+use crate::{
+	self as pallet_airdrop,
+	models::{MerkleClaim, Proof},
+};
 use codec::Encode;
 use composable_support::{
 	signature_verification,
@@ -83,6 +86,7 @@ parameter_types! {
 	pub const AirdropPalletId: PalletId = PalletId(*b"pal_aird");
 	pub const Prefix: &'static [u8] = PROOF_PREFIX;
 	pub const Stake: Balance = STAKE;
+	pub const MaxMerkleProofLength: u32 = 32;
 }
 
 impl pallet_airdrop::Config for MockRuntime {
@@ -97,6 +101,7 @@ impl pallet_airdrop::Config for MockRuntime {
 	type PalletId = AirdropPalletId;
 	type Prefix = Prefix;
 	type Stake = Stake;
+	type MaxMerkleProofLength = MaxMerkleProofLength;
 	type WeightInfo = ();
 }
 
@@ -169,13 +174,18 @@ impl Identity {
 		airdrop_id: AirdropId,
 		reward_account: AccountId,
 	) -> DispatchResultWithPostInfo {
-		let proof = match self {
-			Identity::Relay(relay_account) => relay_proof(relay_account, reward_account.clone()),
-			Identity::Eth(ethereum_account) =>
-				ethereum_proof(ethereum_account, reward_account.clone()),
-		};
+		self.claim_with_merkle_proof(airdrop_id, reward_account, None)
+	}
+
+	pub fn claim_with_merkle_proof(
+		&self,
+		airdrop_id: AirdropId,
+		reward_account: AccountId,
+		merkle_claim: Option<MerkleClaim<Balance, Moment>>,
+	) -> DispatchResultWithPostInfo {
+		let proof = self.clone().proof(reward_account.clone());
 
-		Airdrop::claim(RuntimeOrigin::none(), airdrop_id, reward_account, proof)
+		Airdrop::claim(RuntimeOrigin::none(), airdrop_id, reward_account, proof, merkle_claim)
 	}
 }
 
@@ -1,9 +1,12 @@
+use crate::merkle::MerkleHash;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::types::{
-	CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress,
+	BitcoinAddress, BitcoinSignature, CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature,
+	EthereumAddress, SolanaPublicKey, SolanaSignature,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{MultiSignature, RuntimeDebug};
+use sp_std::vec::Vec;
 
 /// A single Airdrop.
 #[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, TypeInfo, MaxEncodedLen)]
@@ -22,6 +25,9 @@ pub struct Airdrop<AccountId, Balance, Moment> {
 	pub schedule: Moment,
 	/// Set `true` if an airdrop has been explicitly disabled.
 	pub disabled: bool,
+	/// Root of the Merkle tree of recipients, if recipients are committed to by a Merkle root
+	/// instead of being added to storage.
+	pub merkle_root: Option<MerkleHash>,
 }
 
 /// Funds, and related information, to be claimed by an Airdrop recipient.
@@ -37,6 +43,19 @@ pub struct RecipientFund<Balance, Period> {
 	pub funded_claim: bool,
 }
 
+/// Recipient leaf, and the proof of its inclusion, of an Airdrop committed to by a Merkle root.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MerkleClaim<Balance, Period> {
+	/// Position of the leaf in the tree. Used to track claimed leaves.
+	pub index: u32,
+	/// Total funds committed for this recipient.
+	pub amount: Balance,
+	/// The minimum time, in blocks, between recipient claims.
+	pub vesting_period: Period,
+	/// Sibling hashes from the leaf up to the root.
+	pub proof: Vec<MerkleHash>,
+}
+
 /// Current State of an [`Airdrop`](Airdrop).
 #[derive(Debug, Encode, Decode, PartialEq, Eq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub enum AirdropState {
@@ -54,6 +73,8 @@ pub enum Proof<AccountId> {
 	RelayChain(AccountId, MultiSignature),
 	Ethereum(EcdsaSignature),
 	Cosmos(CosmosPublicKey, CosmosEcdsaSignature),
+	Solana(SolanaPublicKey, SolanaSignature),
+	Bitcoin(BitcoinSignature),
 }
 
 /// Remote account that is associated with a local account.
@@ -62,4 +83,6 @@ pub enum Identity<AccountId> {
 	RelayChain(AccountId),
 	Ethereum(EthereumAddress),
 	Cosmos(CosmosPublicKey),
+	Solana(SolanaPublicKey),
+	Bitcoin(BitcoinAddress),
 }
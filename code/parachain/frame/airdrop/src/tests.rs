@@ -272,7 +272,8 @@ mod remove_recipient {
 				RuntimeOrigin::none(),
 				1,
 				accounts[0].clone().0,
-				accounts[0].clone().1.proof(accounts[0].clone().0)
+				accounts[0].clone().1.proof(accounts[0].clone().0),
+				None
 			));
 			assert_noop!(
 				Airdrop::remove_recipient(creator, 1, accounts[0].1.as_remote_public()),
@@ -451,6 +452,186 @@ mod claim {
 	}
 }
 
+#[cfg(test)]
+mod merkle_airdrop {
+	use super::*;
+	use crate::{merkle, models::MerkleClaim};
+
+	fn with_merkle_recipients<R>(
+		count: u128,
+		execute: impl FnOnce(
+			&dyn Fn(Moment),
+			Vec<(AccountId, Identity, MerkleClaim<Balance, Moment>)>,
+		) -> R,
+	) -> R {
+		let accounts = generate_accounts(count as _);
+		let leaves = accounts
+			.iter()
+			.enumerate()
+			.map(|(index, (_, account))| {
+				merkle::leaf_hash(
+					index as u32,
+					&account.as_remote_public(),
+					&DEFAULT_REWARD,
+					&DEFAULT_VESTING_PERIOD,
+				)
+			})
+			.collect::<Vec<_>>();
+		let (merkle_root, proofs) = merkle::build_tree(&leaves).expect("has leaves; qed");
+		let recipients = accounts
+			.into_iter()
+			.zip(proofs)
+			.enumerate()
+			.map(|(index, ((local_account, remote_account), proof))| {
+				let merkle_claim = MerkleClaim {
+					index: index as u32,
+					amount: DEFAULT_REWARD,
+					vesting_period: DEFAULT_VESTING_PERIOD,
+					proof,
+				};
+				(local_account, remote_account, merkle_claim)
+			})
+			.collect();
+
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(0xDEADC0DE);
+			let creator = RuntimeOrigin::signed(CREATOR);
+			let start_moment = 0xCAFEBABE;
+			let set_moment = |x: Moment| Timestamp::set_timestamp(start_moment + x);
+
+			Balances::make_free_balance_be(&CREATOR, STAKE + DEFAULT_REWARD * count);
+
+			assert_ok!(Airdrop::create_merkle_airdrop(
+				creator,
+				Some(start_moment),
+				DEFAULT_VESTING_SCHEDULE,
+				merkle_root,
+				DEFAULT_REWARD * count,
+				count as u32,
+			));
+
+			execute(&set_moment, recipients)
+		})
+	}
+
+	#[test]
+	fn should_fund_merkle_airdrop_on_creation() {
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |_, _| {
+			assert_eq!(Balances::balance(&CREATOR), 0);
+			assert_eq!(
+				Balances::balance(&Airdrop::get_airdrop_account_id(1)),
+				STAKE + DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_add_recipients_to_merkle_airdrop() {
+		let creator = RuntimeOrigin::signed(CREATOR);
+
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |_, recipients| {
+			assert_noop!(
+				Airdrop::add_recipient(
+					creator,
+					1,
+					vec![(
+						recipients[0].1.as_remote_public(),
+						DEFAULT_REWARD,
+						DEFAULT_VESTING_PERIOD,
+						DEFAULT_FUNDED_CLAIM
+					)]
+				),
+				Error::<MockRuntime>::RecipientsFixedByMerkleRoot
+			);
+		})
+	}
+
+	#[test]
+	fn should_give_full_fund_to_merkle_recipients_at_end_of_vesting_period() {
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |set_moment, recipients| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			for (local_account, remote_account, merkle_claim) in recipients {
+				assert_ok!(remote_account.claim_with_merkle_proof(
+					1,
+					local_account.clone(),
+					Some(merkle_claim)
+				));
+				assert_eq!(DEFAULT_REWARD, Balances::balance(&local_account));
+			}
+
+			assert!(Airdrop::airdrops(1).is_none());
+		})
+	}
+
+	#[test]
+	fn should_claim_vested_funds_without_merkle_proof_once_recipient_is_stored() {
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |set_moment, recipients| {
+			let (local_account, remote_account, merkle_claim) = recipients[0].clone();
+			set_moment(DEFAULT_VESTING_PERIOD / 2);
+			assert_ok!(remote_account.claim_with_merkle_proof(
+				1,
+				local_account.clone(),
+				Some(merkle_claim)
+			));
+			assert_eq!(DEFAULT_REWARD / 2, Balances::balance(&local_account));
+
+			set_moment(DEFAULT_VESTING_PERIOD);
+			assert_ok!(remote_account.claim(1, local_account.clone()));
+			assert_eq!(DEFAULT_REWARD, Balances::balance(&local_account));
+			assert!(Airdrop::recipient_funds(1, remote_account.as_remote_public()).is_none());
+		})
+	}
+
+	#[test]
+	fn should_fail_to_claim_merkle_leaf_twice() {
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |set_moment, recipients| {
+			let (local_account, remote_account, merkle_claim) = recipients[0].clone();
+			set_moment(DEFAULT_VESTING_PERIOD);
+			assert_ok!(remote_account.claim_with_merkle_proof(
+				1,
+				local_account.clone(),
+				Some(merkle_claim.clone())
+			));
+			assert!(Airdrop::is_leaf_claimed(1, merkle_claim.index));
+
+			assert_noop!(
+				remote_account.claim_with_merkle_proof(1, local_account, Some(merkle_claim)),
+				Error::<MockRuntime>::RecipientAlreadyClaimed
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_claim_with_invalid_merkle_proof() {
+		with_merkle_recipients(DEFAULT_NB_OF_CONTRIBUTORS, |set_moment, recipients| {
+			let (local_account, remote_account, merkle_claim) = recipients[0].clone();
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			assert_noop!(
+				remote_account.claim_with_merkle_proof(
+					1,
+					local_account.clone(),
+					Some(MerkleClaim { amount: DEFAULT_REWARD * 2, ..merkle_claim.clone() })
+				),
+				Error::<MockRuntime>::InvalidMerkleProof
+			);
+			assert_noop!(
+				remote_account.claim_with_merkle_proof(
+					1,
+					local_account.clone(),
+					Some(MerkleClaim { index: 1, ..merkle_claim })
+				),
+				Error::<MockRuntime>::InvalidMerkleProof
+			);
+			assert_noop!(
+				remote_account.claim(1, local_account),
+				Error::<MockRuntime>::RecipientNotFound
+			);
+		})
+	}
+}
+
 #[cfg(test)]
 mod ethereum_recover {
 	use super::*;
@@ -507,3 +688,105 @@ mod cosmos_recover {
 		assert_eq!(Ok(CosmosPublicKey::Secp256r1(pub_key)), verified);
 	}
 }
+
+#[cfg(test)]
+mod solana_verify {
+	use super::*;
+	use composable_support::types::{SolanaPublicKey, SolanaSignature};
+	use sp_core::{ed25519, Pair};
+
+	#[test]
+	fn should_verify_ed25519_sig_and_pub_key() {
+		let sign_key = ed25519::Pair::from_seed(&[42; 32]);
+		let public_key = SolanaPublicKey(sign_key.public().0);
+		let msg = CREATOR.using_encoded(signature_verification::get_encoded_vec);
+		let sig = SolanaSignature(
+			sign_key
+				.sign(&signature_verification::solana_signable_message(PROOF_PREFIX, &msg))
+				.0,
+		);
+
+		assert_eq!(
+			Ok(public_key),
+			signature_verification::solana_verify(PROOF_PREFIX, &msg, public_key, &sig)
+		);
+		assert_eq!(
+			Err(signature_verification::SignatureVerificationError::FailedVerification),
+			signature_verification::solana_verify(
+				PROOF_PREFIX,
+				&OTHER.using_encoded(signature_verification::get_encoded_vec),
+				public_key,
+				&sig
+			)
+		);
+	}
+}
+
+#[cfg(test)]
+mod bitcoin_recover {
+	use super::*;
+	use composable_support::types::{BitcoinAddress, BitcoinSignature};
+
+	fn bitcoin_proof(header: u8) -> ([u8; 33], BitcoinSignature) {
+		let sign_key = EthereumKey::parse(&[42; 32]).expect("valid key; QED");
+		let msg = sha2_256(&sha2_256(&signature_verification::bitcoin_signable_message(
+			PROOF_PREFIX,
+			&CREATOR.using_encoded(signature_verification::get_encoded_vec),
+		)));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), &sign_key);
+		let mut proof = [0_u8; 65];
+		proof[0] = header + recovery_id.serialize();
+		proof[1..].copy_from_slice(&sig.serialize());
+
+		(
+			libsecp256k1::PublicKey::from_secret_key(&sign_key).serialize_compressed(),
+			BitcoinSignature(proof),
+		)
+	}
+
+	fn recover(proof: &BitcoinSignature) -> Result<BitcoinAddress, ()> {
+		signature_verification::bitcoin_recover(
+			PROOF_PREFIX,
+			&CREATOR.using_encoded(signature_verification::get_encoded_vec),
+			proof,
+		)
+		.map_err(|_| ())
+	}
+
+	#[test]
+	fn should_recover_address_kind_from_header() {
+		let (public_key, p2pkh_proof) = bitcoin_proof(31);
+		let public_key_hash = signature_verification::hash160(&public_key);
+		assert_eq!(Ok(BitcoinAddress::P2pkh(public_key_hash)), recover(&p2pkh_proof));
+
+		let (_, p2wpkh_proof) = bitcoin_proof(39);
+		assert_eq!(Ok(BitcoinAddress::P2wpkh(public_key_hash)), recover(&p2wpkh_proof));
+
+		let (_, p2sh_proof) = bitcoin_proof(35);
+		let script = [&[0x00, 0x14][..], &public_key_hash[..]].concat();
+		assert_eq!(
+			Ok(BitcoinAddress::P2shP2wpkh(signature_verification::hash160(&script))),
+			recover(&p2sh_proof)
+		);
+	}
+
+	#[test]
+	fn should_fail_on_invalid_header() {
+		let (_, BitcoinSignature(mut proof)) = bitcoin_proof(31);
+		proof[0] = 43;
+		assert_eq!(Err(()), recover(&BitcoinSignature(proof)));
+	}
+
+	#[test]
+	fn should_encode_bitcoin_signable_message() {
+		let mut expected = vec![24];
+		expected.extend_from_slice(b"Bitcoin Signed Message:\n");
+		expected.push(11);
+		expected.extend_from_slice(b"picasso-abc");
+
+		assert_eq!(
+			expected,
+			signature_verification::bitcoin_signable_message(PROOF_PREFIX, b"abc")
+		);
+	}
+}
@@ -2,11 +2,13 @@ use frame_support::weights::Weight;
 
 pub trait WeightInfo {
 	fn create_airdrop() -> Weight;
+	fn create_merkle_airdrop() -> Weight;
 	fn add_recipient(x: u32) -> Weight;
 	fn remove_recipient() -> Weight;
 	fn enable_airdrop() -> Weight;
 	fn disable_airdrop() -> Weight;
 	fn claim(x: u32) -> Weight;
+	fn claim_with_merkle_proof(x: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -14,6 +16,10 @@ impl WeightInfo for () {
 		Weight::from_ref_time(10_000)
 	}
 
+	fn create_merkle_airdrop() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
 	fn add_recipient(_x: u32) -> Weight {
 		Weight::from_ref_time(10_000)
 	}
@@ -33,4 +39,8 @@ impl WeightInfo for () {
 	fn claim(_x: u32) -> Weight {
 		Weight::from_ref_time(10_000)
 	}
+
+	fn claim_with_merkle_proof(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
 }
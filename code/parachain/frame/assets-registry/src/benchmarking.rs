@@ -6,7 +6,9 @@ use crate::{self as pallet_assets_registry, prelude::*};
 
 #[allow(unused_imports)]
 use crate::Pallet as AssetsRegistry;
-use composable_traits::{currency::Rational64, rational, xcm::assets::XcmAssetLocation};
+use composable_traits::{
+	assets::AssetInfo, currency::Rational64, rational, xcm::assets::XcmAssetLocation,
+};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
@@ -23,18 +25,18 @@ benchmarks! {
 		let ratio = rational!(42 / 123);
 		let decimals = 3;
 
-	}: _(RawOrigin::Root, location, ratio, Some(decimals))
+	}: _(RawOrigin::Root, ratio, AssetInfo::foreign(location, Some(decimals)))
 
 	update_asset {
 		let location = T::ForeignAssetId::decode(&mut &XcmAssetLocation::RELAY_NATIVE.encode()[..]).unwrap();
 		let ratio = rational!(42 / 123);
 		let decimals = 3;
 
-		AssetsRegistry::<T>::register_asset(RawOrigin::Root.into(), location.clone(), ratio, Some(decimals)).unwrap();
+		AssetsRegistry::<T>::register_asset(RawOrigin::Root.into(), ratio, AssetInfo::foreign(location.clone(), Some(decimals))).unwrap();
 
 		let local_asset_id = AssetsRegistry::<T>::from_foreign_asset(location.clone()).unwrap();
 
-	}: _(RawOrigin::Root, local_asset_id, location, rational!(42 / 123), Some(3))
+	}: _(RawOrigin::Root, local_asset_id, rational!(42 / 123), AssetInfo::foreign(location, Some(3)))
 
 	set_min_fee {
 		let target_parachain_id = 100_u32.into();
@@ -25,6 +25,7 @@ mod tests;
 
 mod prelude;
 
+pub mod migrations;
 pub mod weights;
 
 #[frame_support::pallet]
@@ -33,8 +34,8 @@ pub mod pallet {
 	pub use crate::weights::WeightInfo;
 	use codec::FullCodec;
 	use composable_traits::{
-		assets::Asset,
-		currency::{BalanceLike, CurrencyFactory, Exponent, ForeignByNative, RangeId},
+		assets::{Asset, AssetInfo, DEFAULT_DECIMALS},
+		currency::{BalanceLike, CurrencyFactory, Exponent, ForeignByNative, LocalAssets, RangeId},
 		xcm::assets::{ForeignMetadata, RemoteAssetRegistryInspect, RemoteAssetRegistryMutate},
 	};
 	use cumulus_primitives_core::ParaId;
@@ -43,8 +44,17 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use scale_info::TypeInfo;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{UniqueSaturatedFrom, UniqueSaturatedInto},
+		ArithmeticError, Rounding,
+	};
 	use sp_std::{fmt::Debug, str, vec::Vec};
 
+	pub type AssetInfoOf<T> = AssetInfo<<T as Config>::Balance, <T as Config>::ForeignAssetId>;
+
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	/// The module configuration trait.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -81,18 +91,32 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		type Balance: BalanceLike;
 		type CurrencyFactory: CurrencyFactory<AssetId = Self::LocalAssetId, Balance = Self::Balance>;
+		/// Converted at the ratio of assets registered without existential deposit.
+		#[pallet::constant]
+		type NativeExistentialDeposit: Get<Self::Balance>;
+		/// Metadata of the well known assets, registered by the migration to
+		/// [`STORAGE_VERSION`] if missing.
+		type WellKnownAssets: Get<Vec<(Self::LocalAssetId, AssetInfoOf<Self>)>>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
-	/// Mapping local asset to foreign asset.
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+	}
+
+	/// Metadata of registered assets, including the foreign location of foreign assets.
 	#[pallet::storage]
-	#[pallet::getter(fn from_local_asset)]
-	pub type LocalToForeign<T: Config> =
-		StorageMap<_, Twox128, T::LocalAssetId, ForeignMetadata<T::ForeignAssetId>, OptionQuery>;
+	#[pallet::getter(fn asset_info)]
+	pub type Assets<T: Config> =
+		StorageMap<_, Twox128, T::LocalAssetId, AssetInfoOf<T>, OptionQuery>;
 
 	/// Mapping foreign asset to local asset.
 	#[pallet::storage]
@@ -118,12 +142,15 @@ pub mod pallet {
 	pub type AssetRatio<T: Config> = StorageMap<_, Twox128, T::LocalAssetId, Rational, OptionQuery>;
 
 	#[pallet::genesis_config]
-	pub struct GenesisConfig<T: Config>(sp_std::marker::PhantomData<T>);
+	pub struct GenesisConfig<T: Config> {
+		/// Metadata of well known assets.
+		pub assets: Vec<(T::LocalAssetId, AssetInfoOf<T>)>,
+	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self(<_>::default())
+			Self { assets: Default::default() }
 		}
 	}
 
@@ -133,7 +160,12 @@ pub mod pallet {
 		composable_traits::xcm::assets::XcmAssetLocation:
 			codec::EncodeLike<<T as Config>::ForeignAssetId>,
 	{
-		fn build(&self) {}
+		fn build(&self) {
+			for (asset_id, info) in &self.assets {
+				Pallet::<T>::do_set_asset_info(*asset_id, info.clone())
+					.expect("foreign locations of genesis assets are unique");
+			}
+		}
 	}
 
 	#[pallet::event]
@@ -141,13 +173,13 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		AssetRegistered {
 			asset_id: T::LocalAssetId,
-			location: T::ForeignAssetId,
-			decimals: Option<Exponent>,
+			location: Option<T::ForeignAssetId>,
+			decimals: Exponent,
 		},
 		AssetUpdated {
 			asset_id: T::LocalAssetId,
-			location: T::ForeignAssetId,
-			decimals: Option<Exponent>,
+			location: Option<T::ForeignAssetId>,
+			decimals: Exponent,
 		},
 		MinFeeUpdated {
 			target_parachain_id: ParaId,
@@ -167,56 +199,59 @@ pub mod pallet {
 		/// Creates asset using `CurrencyFactory`.
 		/// Raises `AssetRegistered` event
 		///
-		/// Sets only required fields by `CurrencyFactory`, to upsert metadata use referenced
-		/// pallet.
+		/// Assets with a foreign `location` are created in the range of foreign assets, others in
+		/// the range of local tokens.
 		///
 		/// # Parameters:
 		///
 		/// `ratio` -  
-		/// Allows `bring you own gas` fees.
-		/// Set to `None` to prevent payment in this asset, only transferring.
-		/// Setting to some will NOT start minting tokens with specified ratio.
+		/// Allows `bring you own gas` fees, if `info.payable_as_fee`.
+		/// Setting it will NOT start minting tokens with specified ratio.
 		///
 		/// ```python
 		///  ratio = foreign_token / native_token
 		///  amount_of_foreign_asset = amount_of_native_asset * ratio
 		/// ```
 		///
-		/// `decimals` - `human` number of decimals
-		///
-		/// `ed` - same meaning as in for foreign asset account (if None, then asset is not
-		/// sufficient)
+		/// `info` - name, symbol, `human` number of decimals, existential deposit, foreign location
+		/// and fee payment flag of the asset
 		#[pallet::weight(<T as Config>::WeightInfo::register_asset())]
 		pub fn register_asset(
 			origin: OriginFor<T>,
-			location: T::ForeignAssetId,
 			ratio: Rational,
-			decimals: Option<Exponent>,
+			info: AssetInfoOf<T>,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateAssetRegistryOrigin::ensure_origin(origin)?;
-			ensure!(
-				!ForeignToLocal::<T>::contains_key(&location),
-				Error::<T>::ForeignAssetAlreadyRegistered
-			);
-			let asset_id = T::CurrencyFactory::create(RangeId::FOREIGN_ASSETS)?;
-			Self::set_reserve_location(asset_id, location.clone(), ratio, decimals)?;
+			let range = match &info.location {
+				Some(location) => {
+					ensure!(
+						!ForeignToLocal::<T>::contains_key(location),
+						Error::<T>::ForeignAssetAlreadyRegistered
+					);
+					RangeId::FOREIGN_ASSETS
+				},
+				None => RangeId::TOKENS,
+			};
+			let asset_id = T::CurrencyFactory::create(range)?;
+			let (location, decimals) = (info.location.clone(), info.decimals);
+			Self::set_asset_info(asset_id, ratio, info)?;
 			Self::deposit_event(Event::<T>::AssetRegistered { asset_id, location, decimals });
 			Ok(().into())
 		}
 
-		/// Given well existing asset, update its remote information.
+		/// Given well existing asset, update its metadata.
 		/// Use with caution as it allow reroute assets location.
 		/// See `register_asset` for parameters meaning.
 		#[pallet::weight(<T as Config>::WeightInfo::update_asset())]
 		pub fn update_asset(
 			origin: OriginFor<T>,
 			asset_id: T::LocalAssetId,
-			location: T::ForeignAssetId,
 			ratio: Rational,
-			decimals: Option<Exponent>,
+			info: AssetInfoOf<T>,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateAssetRegistryOrigin::ensure_origin(origin)?;
-			Self::set_reserve_location(asset_id, location.clone(), ratio, decimals)?;
+			let (location, decimals) = (info.location.clone(), info.decimals);
+			Self::set_asset_info(asset_id, ratio, info)?;
 			Self::deposit_event(Event::<T>::AssetUpdated { asset_id, location, decimals });
 			Ok(().into())
 		}
@@ -251,6 +286,33 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Pallet<T> {
+		/// Replaces the metadata of `asset_id`, moving its foreign location mapping if changed.
+		pub(crate) fn do_set_asset_info(
+			asset_id: T::LocalAssetId,
+			info: AssetInfoOf<T>,
+		) -> DispatchResult {
+			if let Some(location) = &info.location {
+				ensure!(
+					ForeignToLocal::<T>::get(location).map_or(true, |owner| owner == asset_id),
+					Error::<T>::ForeignAssetAlreadyRegistered
+				);
+			}
+			if let Some(previous) =
+				Assets::<T>::get(asset_id).and_then(|previous| previous.location)
+			{
+				if info.location.as_ref() != Some(&previous) {
+					ForeignToLocal::<T>::remove(previous);
+				}
+			}
+			if let Some(location) = &info.location {
+				ForeignToLocal::<T>::insert(location, asset_id);
+			}
+			Assets::<T>::insert(asset_id, info);
+			Ok(())
+		}
+	}
+
 	impl<T: Config> RemoteAssetRegistryMutate for Pallet<T> {
 		type AssetId = T::LocalAssetId;
 
@@ -258,15 +320,13 @@ pub mod pallet {
 
 		type Balance = T::Balance;
 
-		fn set_reserve_location(
+		fn set_asset_info(
 			asset_id: Self::AssetId,
-			location: Self::AssetNativeLocation,
 			ratio: Rational,
-			decimals: Option<Exponent>,
+			info: AssetInfoOf<T>,
 		) -> DispatchResult {
-			ForeignToLocal::<T>::insert(&location, asset_id);
-			LocalToForeign::<T>::insert(asset_id, ForeignMetadata { decimals, location });
-			AssetRatio::<T>::mutate_exists(asset_id, |x| *x = Some(ratio));
+			Self::do_set_asset_info(asset_id, info)?;
+			AssetRatio::<T>::insert(asset_id, ratio);
 			Ok(())
 		}
 
@@ -289,7 +349,8 @@ pub mod pallet {
 		fn asset_to_remote(
 			asset_id: Self::AssetId,
 		) -> Option<composable_traits::xcm::assets::ForeignMetadata<Self::AssetNativeLocation>> {
-			LocalToForeign::<T>::get(asset_id)
+			let info = Assets::<T>::get(asset_id)?;
+			Some(ForeignMetadata { decimals: Some(info.decimals), location: info.location? })
 		}
 
 		fn location_to_asset(location: Self::AssetNativeLocation) -> Option<Self::AssetId> {
@@ -304,32 +365,56 @@ pub mod pallet {
 		}
 
 		fn get_foreign_assets_list() -> Vec<Asset<T::Balance, Self::AssetNativeLocation>> {
-			ForeignToLocal::<T>::iter()
-				.map(|(_, asset_id)| {
-					let foreign_metadata = LocalToForeign::<T>::get(asset_id)
-						.expect("Must exist, as it does in ForeignToLocal");
-					let decimals = match foreign_metadata.decimals {
-						Some(exponent) => exponent,
-						_ => 12_u8,
-					};
-					let ratio = AssetRatio::<T>::get(asset_id);
-
-					Asset {
-						name: None,
-						id: asset_id.into(),
-						decimals,
-						ratio,
-						foreign_id: Some(foreign_metadata.location),
-						existential_deposit: T::Balance::default(),
-					}
+			Assets::<T>::iter()
+				.map(|(asset_id, info)| Asset {
+					name: info.name.map(Into::into),
+					symbol: info.symbol.map(Into::into),
+					id: asset_id.into(),
+					decimals: info.decimals,
+					ratio: AssetRatio::<T>::get(asset_id),
+					foreign_id: info.location,
+					existential_deposit: Self::existential_deposit(asset_id).unwrap_or_default(),
+					payable_as_fee: info.payable_as_fee,
 				})
 				.collect::<Vec<_>>()
 		}
 	}
 
+	impl<T: Config> AssetExistentialDepositInspect for Pallet<T> {
+		type AssetId = T::LocalAssetId;
+		type Balance = T::Balance;
+
+		fn existential_deposit(asset_id: Self::AssetId) -> Result<Self::Balance, DispatchError> {
+			let info = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+			if let Some(existential_deposit) = info.existential_deposit {
+				return Ok(existential_deposit)
+			}
+			let ratio = AssetRatio::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+			multiply_by_rational_with_rounding(
+				T::NativeExistentialDeposit::get().unique_saturated_into(),
+				ratio.n.into(),
+				ratio.d.into(),
+				Rounding::Down,
+			)
+			.map(T::Balance::unique_saturated_from)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+		}
+	}
+
+	impl<T: Config> LocalAssets<T::LocalAssetId> for Pallet<T> {
+		/// Decimals of registered assets, [`DEFAULT_DECIMALS`] for others.
+		fn decimals(asset_id: T::LocalAssetId) -> Result<Exponent, DispatchError> {
+			Ok(Assets::<T>::get(asset_id).map_or(DEFAULT_DECIMALS, |info| info.decimals))
+		}
+	}
+
 	impl<T: Config> AssetRatioInspect for Pallet<T> {
 		type AssetId = T::LocalAssetId;
+		/// Only assets payable as fee have a ratio, `None` prevents payment in the asset.
 		fn get_ratio(asset_id: Self::AssetId) -> Option<ForeignByNative> {
+			if Assets::<T>::get(asset_id).map_or(false, |info| !info.payable_as_fee) {
+				return None
+			}
 			AssetRatio::<T>::get(asset_id).map(Into::into)
 		}
 	}
@@ -0,0 +1,52 @@
+//! Storage migrations of the assets registry pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Moves the foreign metadata of registered assets into [`Assets`], and registers the
+	//! metadata of the well known assets, which were only known to the runtime.
+
+	use super::*;
+	use composable_traits::{assets::AssetInfo, xcm::assets::ForeignMetadata};
+	use frame_support::{pallet_prelude::*, traits::GetStorageVersion};
+
+	/// Mapping of local assets to foreign metadata, replaced by [`Assets`].
+	#[frame_support::storage_alias]
+	type LocalToForeign<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox128,
+		<T as Config>::LocalAssetId,
+		ForeignMetadata<<T as Config>::ForeignAssetId>,
+	>;
+
+	/// Assets were only registered with a ratio, so they all stay payable as fee, with the
+	/// existential deposit derived from the ratio.
+	/// The registered metadata of a well known asset takes precedence over its
+	/// [`Config::WellKnownAssets`] metadata.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut migrated = 0_u64;
+		for (asset_id, metadata) in LocalToForeign::<T>::drain() {
+			migrated += 1;
+			Assets::<T>::insert(asset_id, AssetInfo::foreign(metadata.location, metadata.decimals));
+		}
+		let (mut checked, mut seeded) = (0_u64, 0_u64);
+		for (asset_id, info) in T::WellKnownAssets::get() {
+			checked += 1;
+			if Assets::<T>::contains_key(asset_id) {
+				continue
+			}
+			// A location already registered for another asset stays with it.
+			if Pallet::<T>::do_set_asset_info(asset_id, info).is_ok() {
+				seeded += 1;
+			}
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get()
+			.reads_writes(migrated + checked + 2 * seeded + 1, 2 * migrated + 2 * seeded + 1)
+	}
+}
@@ -2,7 +2,7 @@ use crate::{self as pallet_assets_registry, weights::SubstrateWeight};
 use composable_traits::xcm::assets::XcmAssetLocation;
 use frame_support::{
 	ord_parameter_types, parameter_types,
-	traits::{EnsureOneOf, Everything},
+	traits::{ConstU64, EnsureOneOf, Everything},
 };
 use frame_system::{self as system, EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
@@ -17,6 +17,7 @@ type Block = frame_system::mocking::MockBlock<Runtime>;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 
 pub const ROOT: AccountId = 0_u32;
+pub const NATIVE_EXISTENTIAL_DEPOSIT: Balance = 1_000;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -95,6 +96,8 @@ impl pallet_assets_registry::Config for Runtime {
 		EnsureSignedBy<RootAccount, AccountId>, // for tests
 		EnsureRoot<AccountId>,                  // for benchmarks
 	>;
+	type NativeExistentialDeposit = ConstU64<NATIVE_EXISTENTIAL_DEPOSIT>;
+	type WellKnownAssets = ();
 	type WeightInfo = SubstrateWeight<Self>;
 }
 
@@ -1,8 +1,8 @@
 use crate::{prelude::*, runtime::*, Error};
 use codec::{Decode, Encode};
 use composable_traits::{
-	assets::Asset,
-	currency::Rational64,
+	assets::{Asset, AssetInfo, BasicAssetMetadata},
+	currency::{LocalAssets, Rational64},
 	rational,
 	xcm::assets::{ForeignMetadata, RemoteAssetRegistryInspect, XcmAssetLocation},
 };
@@ -24,9 +24,8 @@ fn set_metadata() {
 		System::set_block_number(1);
 		assert_ok!(AssetsRegistry::register_asset(
 			RawOrigin::Root.into(),
-			XcmAssetLocation::RELAY_NATIVE,
 			rational!(42 / 123),
-			Some(4)
+			AssetInfo::foreign(XcmAssetLocation::RELAY_NATIVE, Some(4))
 		));
 		let asset_id = System::events()
 			.iter()
@@ -72,18 +71,21 @@ fn register_asset() {
 
 		assert_ok!(AssetsRegistry::register_asset(
 			RuntimeOrigin::root(),
-			location.clone(),
 			ratio,
-			Some(decimals)
+			AssetInfo::foreign(location.clone(), Some(decimals))
 		));
 		let local_asset_id = AssetsRegistry::from_foreign_asset(location.clone()).unwrap();
 		assert_eq!(
-			AssetsRegistry::from_local_asset(local_asset_id),
-			Some(ForeignMetadata { decimals: Some(decimals), location: location.clone() })
+			AssetsRegistry::asset_info(local_asset_id),
+			Some(AssetInfo::foreign(location.clone(), Some(decimals)))
 		);
 
 		assert_noop!(
-			AssetsRegistry::register_asset(RuntimeOrigin::root(), location, ratio, Some(decimals)),
+			AssetsRegistry::register_asset(
+				RuntimeOrigin::root(),
+				ratio,
+				AssetInfo::foreign(location, Some(decimals))
+			),
 			Error::<Runtime>::ForeignAssetAlreadyRegistered
 		);
 	})
@@ -101,15 +103,14 @@ fn update_asset() {
 
 		assert_ok!(AssetsRegistry::register_asset(
 			RuntimeOrigin::root(),
-			location.clone(),
 			ratio,
-			Some(decimals)
+			AssetInfo::foreign(location.clone(), Some(decimals))
 		));
 
 		let local_asset_id = AssetsRegistry::from_foreign_asset(location.clone()).unwrap();
 		assert_eq!(
-			AssetsRegistry::from_local_asset(local_asset_id),
-			Some(ForeignMetadata { decimals: Some(decimals), location: location.clone() })
+			AssetsRegistry::asset_info(local_asset_id),
+			Some(AssetInfo::foreign(location.clone(), Some(decimals)))
 		);
 		assert_eq!(AssetsRegistry::asset_ratio(local_asset_id), Some(ratio));
 
@@ -118,13 +119,12 @@ fn update_asset() {
 		assert_ok!(AssetsRegistry::update_asset(
 			RuntimeOrigin::root(),
 			local_asset_id,
-			location.clone(),
 			new_ratio,
-			Some(new_decimals)
+			AssetInfo::foreign(location.clone(), Some(new_decimals))
 		));
 		assert_eq!(
-			AssetsRegistry::from_local_asset(local_asset_id),
-			Some(ForeignMetadata { decimals: Some(new_decimals), location })
+			AssetsRegistry::asset_info(local_asset_id),
+			Some(AssetInfo::foreign(location, Some(new_decimals)))
 		);
 		assert_eq!(AssetsRegistry::asset_ratio(local_asset_id), Some(new_ratio));
 	})
@@ -172,9 +172,8 @@ fn get_foreign_assets_list_should_work() {
 
 		assert_ok!(AssetsRegistry::register_asset(
 			RuntimeOrigin::root(),
-			location.clone(),
 			ratio,
-			Some(decimals)
+			AssetInfo::foreign(location, Some(decimals))
 		));
 
 		let foreign_assets = AssetsRegistry::get_foreign_assets_list();
@@ -183,6 +182,7 @@ fn get_foreign_assets_list_should_work() {
 			foreign_assets,
 			vec![Asset {
 				name: None,
+				symbol: None,
 				id: 12884901886,
 				decimals: 3,
 				ratio: Some(ratio),
@@ -190,8 +190,101 @@ fn get_foreign_assets_list_should_work() {
 					parents: 1,
 					interior: Junctions::Here
 				})),
-				existential_deposit: 0,
+				// derived from the native existential deposit
+				existential_deposit: NATIVE_EXISTENTIAL_DEPOSIT * 42 / 123,
+				payable_as_fee: true,
 			}]
 		);
 	})
 }
+
+#[test]
+fn register_local_asset_with_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let metadata = BasicAssetMetadata::try_from(b"LOCAL", b"Local Token").unwrap();
+		let info = AssetInfo {
+			name: Some(metadata.name),
+			symbol: Some(metadata.symbol),
+			decimals: 6,
+			existential_deposit: Some(5),
+			location: None,
+			payable_as_fee: false,
+		};
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			rational!(1 / 1),
+			info.clone()
+		));
+		let asset_id = System::events()
+			.iter()
+			.find_map(|x| match x.event {
+				RuntimeEvent::AssetsRegistry(crate::Event::<Runtime>::AssetRegistered {
+					asset_id,
+					location: None,
+					decimals: 6,
+				}) => Some(asset_id),
+				_ => None,
+			})
+			.unwrap();
+
+		assert_eq!(AssetsRegistry::asset_info(asset_id), Some(info));
+		assert_eq!(<AssetsRegistry as RemoteAssetRegistryInspect>::asset_to_remote(asset_id), None);
+		assert_eq!(<AssetsRegistry as LocalAssets<_>>::decimals(asset_id), Ok(6));
+		assert_eq!(<AssetsRegistry as LocalAssets<_>>::decimals(42), Ok(12));
+		assert_eq!(AssetsRegistry::existential_deposit(asset_id), Ok(5));
+		// not payable as fee
+		assert_eq!(AssetsRegistry::asset_ratio(asset_id), Some(rational!(1 / 1)));
+		assert_eq!(<AssetsRegistry as AssetRatioInspect>::get_ratio(asset_id), None);
+
+		let assets = AssetsRegistry::get_foreign_assets_list();
+		assert_eq!(assets.len(), 1);
+		assert_eq!(assets[0].name, Some(b"Local Token".to_vec()));
+		assert_eq!(assets[0].symbol, Some(b"LOCAL".to_vec()));
+		assert_eq!(assets[0].existential_deposit, 5);
+		assert!(!assets[0].payable_as_fee);
+	})
+}
+
+#[test]
+fn update_asset_moves_location() {
+	new_test_ext().execute_with(|| {
+		let location = XcmAssetLocation::RELAY_NATIVE;
+		let new_location = XcmAssetLocation::LOCAL_NATIVE;
+		let ratio = rational!(42 / 123);
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			ratio,
+			AssetInfo::foreign(location.clone(), None)
+		));
+		let asset_id = AssetsRegistry::from_foreign_asset(location.clone()).unwrap();
+
+		assert_ok!(AssetsRegistry::update_asset(
+			RuntimeOrigin::root(),
+			asset_id,
+			ratio,
+			AssetInfo::foreign(new_location.clone(), None)
+		));
+		assert_eq!(AssetsRegistry::from_foreign_asset(location.clone()), None);
+		assert_eq!(AssetsRegistry::from_foreign_asset(new_location.clone()), Some(asset_id));
+		assert_eq!(
+			<AssetsRegistry as RemoteAssetRegistryInspect>::asset_to_remote(asset_id),
+			Some(ForeignMetadata { decimals: Some(12), location: new_location.clone() })
+		);
+
+		assert_noop!(
+			AssetsRegistry::update_asset(
+				RuntimeOrigin::root(),
+				asset_id + 1,
+				ratio,
+				AssetInfo::foreign(new_location, None)
+			),
+			Error::<Runtime>::ForeignAssetAlreadyRegistered
+		);
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			ratio,
+			AssetInfo::foreign(location, None)
+		));
+	})
+}
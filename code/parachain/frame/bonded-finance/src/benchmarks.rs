@@ -2,17 +2,21 @@
 
 #[cfg(test)]
 use crate::Pallet as BondedFinance;
-use crate::{AssetIdOf, BalanceOf, BlockNumberOf, BondOfferOf, Call, Config, Pallet};
+use crate::{
+	AssetIdOf, BalanceOf, BlockNumberOf, BondOfferOf, BondPositions, Call, Config, Pallet,
+};
 use codec::Decode;
 use composable_support::validation::Validated;
-use composable_traits::bonded_finance::{BondDuration, BondOffer, BondOfferReward};
+use composable_traits::bonded_finance::{
+	BondDuration, BondOffer, BondOfferReward, EarlyRedemptionPenalty,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
 	traits::{fungible::Mutate as _, fungibles::Mutate as _},
 };
 use frame_system::RawOrigin;
-use sp_runtime::traits::One;
+use sp_runtime::{traits::One, Perbill};
 
 const MIN_VESTED_TRANSFER: u128 = 1000 * 1_000_000_000_000;
 const BALANCE: u128 = 1_000_000 * 1_000_000_000_000;
@@ -42,6 +46,10 @@ where
 			asset: reward_asset,
 			maturity: BlockNumberOf::<T>::from(96u32),
 		},
+		early_redemption: Some(EarlyRedemptionPenalty::Linear {
+			start: Perbill::from_percent(50),
+			end: Perbill::zero(),
+		}),
 	}
 }
 
@@ -112,6 +120,42 @@ benchmarks! {
 		let offer_id = T::BondOfferId::one();
 		call_bond::<T>(&caller, nb_of_bonds, offer_id);
 	}: _(RawOrigin::Signed(caller), offer_id)
+
+	cancel_partially {
+		let [bond_asset, reward_asset] = assets::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		initial_mint::<T>(bond_asset, &caller, reward_asset);
+		let mut bond_offer = bond_offer::<T>(bond_asset, reward_asset);
+		bond_offer.nb_of_bonds = BalanceOf::<T>::from(2u128);
+		bond_offer.reward.amount = BalanceOf::<T>::from(2 * MIN_VESTED_TRANSFER);
+		call_offer::<T>(bond_offer, &caller);
+		let offer_id = T::BondOfferId::one();
+	}: _(RawOrigin::Signed(caller), offer_id, BalanceOf::<T>::from(1u128))
+
+	redeem {
+		let [bond_asset, reward_asset] = assets::<T>();
+		let caller: T::AccountId = account("caller", 0, 0xCAFEBABE);
+		initial_mint::<T>(bond_asset, &caller, reward_asset);
+		let bond_offer = bond_offer::<T>(bond_asset, reward_asset);
+		let nb_of_bonds = bond_offer.nb_of_bonds;
+		call_offer::<T>(bond_offer, &caller);
+		let offer_id = T::BondOfferId::one();
+		call_bond::<T>(&caller, nb_of_bonds, offer_id);
+		let fnft_instance_id = BondPositions::<T>::iter_keys().next().unwrap();
+		frame_system::Pallet::<T>::set_block_number(BlockNumberOf::<T>::from(100u32));
+	}: _(RawOrigin::Signed(caller), fnft_instance_id)
+
+	redeem_early {
+		let [bond_asset, reward_asset] = assets::<T>();
+		let caller: T::AccountId = account("caller", 0, 0xCAFEBABE);
+		initial_mint::<T>(bond_asset, &caller, reward_asset);
+		let bond_offer = bond_offer::<T>(bond_asset, reward_asset);
+		let nb_of_bonds = bond_offer.nb_of_bonds;
+		call_offer::<T>(bond_offer, &caller);
+		let offer_id = T::BondOfferId::one();
+		call_bond::<T>(&caller, nb_of_bonds, offer_id);
+		let fnft_instance_id = BondPositions::<T>::iter_keys().next().unwrap();
+	}: _(RawOrigin::Signed(caller), fnft_instance_id)
 }
 
 impl_benchmark_test_suite!(BondedFinance, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
@@ -33,6 +33,7 @@
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -56,21 +57,32 @@ pub mod pallet {
 		validation::Validated,
 	};
 	use composable_traits::{
-		bonded_finance::{BondDuration, BondOffer, BondedFinance, ValidBondOffer},
-		vesting::{VestedTransfer, VestingScheduleInfo, VestingWindow::BlockNumberBased},
+		bonded_finance::{
+			BondDuration, BondOffer, BondPosition, BondedAmount, BondedFinance,
+			EarlyRedemptionPenalty, ValidBondOffer,
+		},
+		fnft::{FinancialNft, FinancialNftProtocol},
+		vesting::VestedTransfer,
 	};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
 			fungible::{self, Inspect as FungibleInspect, Transfer as FungibleTransfer},
 			fungibles::{self, Inspect as FungiblesInspect, Transfer as FungiblesTransfer},
+			tokens::nonfungibles::{
+				Create as NonFungiblesCreate, Inspect as NonFungiblesInspect,
+				Mutate as NonFungiblesMutate,
+			},
 		},
 		transactional, PalletId,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 	use scale_info::TypeInfo;
-	use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Convert, One, Zero};
-	use sp_std::fmt::Debug;
+	use sp_runtime::{
+		traits::{AccountIdConversion, BlockNumberProvider, Convert, One, Saturating, Zero},
+		Perbill,
+	};
+	use sp_std::{fmt::Debug, vec, vec::Vec};
 
 	use crate::weights::WeightInfo;
 
@@ -84,18 +96,44 @@ pub mod pallet {
 		<<T as Config>::NativeCurrency as FungibleInspect<AccountIdOf<T>>>::Balance;
 	pub(crate) type BondOfferOf<T> =
 		BondOffer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+	pub(crate) type BondPositionOf<T> = BondPosition<
+		<T as Config>::BondOfferId,
+		AccountIdOf<T>,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberOf<T>,
+	>;
+	pub(crate) type BondedAmountOf<T> = BondedAmount<AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new offer has been created.
 		NewOffer { offer_id: T::BondOfferId, beneficiary: AccountIdOf<T> },
-		/// A new bond has been registered.
-		NewBond { offer_id: T::BondOfferId, who: AccountIdOf<T>, nb_of_bonds: BalanceOf<T> },
+		/// A new bond has been registered, represented by a financial NFT owned by `who`.
+		NewBond {
+			offer_id: T::BondOfferId,
+			who: AccountIdOf<T>,
+			nb_of_bonds: BalanceOf<T>,
+			fnft_collection_id: AssetIdOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		},
 		/// An offer has been cancelled by the `AdminOrigin`.
 		OfferCancelled { offer_id: T::BondOfferId },
 		/// An offer has been completed.
 		OfferCompleted { offer_id: T::BondOfferId },
+		/// Part of the unsold bonds of an offer have been cancelled by its beneficiary.
+		OfferPartiallyCancelled { offer_id: T::BondOfferId, nb_of_bonds: BalanceOf<T> },
+		/// Amounts of a bond have been redeemed by the owner of its financial NFT. The penalties
+		/// are zero unless redeemed early.
+		BondRedeemed {
+			fnft_instance_id: T::FinancialNftInstanceId,
+			owner: AccountIdOf<T>,
+			bond_penalty: BalanceOf<T>,
+			reward_penalty: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -108,6 +146,14 @@ pub mod pallet {
 		OfferCompleted,
 		/// Someone tried to bond with an invalid number of nb_of_bonds.
 		InvalidNumberOfBonds,
+		/// The bond position could not be found.
+		BondPositionNotFound,
+		/// Only the owner of the financial NFT of a bond can redeem it.
+		OnlyBondOwnerCanRedeem,
+		/// None of the amounts of the bond are mature yet.
+		BondNotMatured,
+		/// The offer of the bond does not allow early redemption.
+		EarlyRedemptionNotAllowed,
 	}
 
 	#[pallet::config]
@@ -122,7 +168,7 @@ pub mod pallet {
 		/// The multi currency system offers are based on.
 		type Currency: fungibles::Mutate<AccountIdOf<Self>> + FungiblesTransfer<AccountIdOf<Self>>;
 
-		/// The dependency managing vesting transfer of rewards.
+		/// The dependency whose minimum vested transfer bounds bond prices and rewards.
 		type Vesting: VestedTransfer<
 			AssetId = AssetIdOf<Self>,
 			AccountId = AccountIdOf<Self>,
@@ -130,6 +176,25 @@ pub mod pallet {
 			Balance = BalanceOf<Self>,
 		>;
 
+		/// The financial NFTs representing bond positions.
+		type FinancialNft: NonFungiblesMutate<AccountIdOf<Self>>
+			+ NonFungiblesCreate<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			> + FinancialNft<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			>;
+
+		/// The ID of a financial NFT within its collection.
+		type FinancialNftInstanceId: Parameter + Member + MaxEncodedLen + Copy;
+
+		/// The financial NFT collection of bond positions, created by the pallet.
+		#[pallet::constant]
+		type FinancialNftCollectionId: Get<AssetIdOf<Self>>;
+
 		/// The ID of a bond offer.
 		type BondOfferId: Copy
 			+ Clone
@@ -171,9 +236,37 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberOf<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub _phantom: sp_std::marker::PhantomData<T>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			GenesisConfig { _phantom: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::create_financial_nft_collection()
+				.expect("Genesis config must be correct; qed");
+		}
+	}
+
 	/// The counter used to uniquely identify bond offers within this pallet.
 	#[pallet::storage]
 	#[pallet::getter(fn bond_offer_count)]
@@ -192,6 +285,13 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Bond positions, by the instance of their financial NFT in
+	/// [`Config::FinancialNftCollectionId`].
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type BondPositions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FinancialNftInstanceId, BondPositionOf<T>, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new bond offer. To be `bond` to later.
@@ -223,6 +323,9 @@ pub mod pallet {
 		/// Once there are no more contracts available on the offer, the `stake` put by the
 		/// offer creator is refunded.
 		///
+		/// The bond is represented by a financial NFT minted to the sender, holding the bonded
+		/// amount, if returned, and the reward share until they are `redeem`ed.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have the
 		/// appropriate funds to buy the desired number of contracts.
 		///
@@ -265,20 +368,93 @@ pub mod pallet {
 					},
 				_ => return Err(DispatchError::BadOrigin),
 			};
-			let offer_account = Self::account_id(offer_id);
-			// NOTE(hussein-aitlahcen): no need to keep the offer account alive
-			T::NativeCurrency::transfer(&offer_account, &issuer, T::Stake::get(), false)?;
+			Self::do_cancel(offer_id, &issuer, &offer)
+		}
+
+		/// Cancel part of the unsold bonds of an offer.
+		///
+		/// The reward share of the cancelled bonds is refunded to the offer creator. Cancelling
+		/// all the remaining bonds cancels the offer, refunding the `stake` put by the offer
+		/// creator.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the
+		/// `beneficiary` of the offer.
+		///
+		/// Emits a `OfferPartiallyCancelled`.
+		/// Possibly Emits a `OfferCancelled`.
+		#[pallet::weight(T::WeightInfo::cancel_partially())]
+		#[transactional]
+		pub fn cancel_partially(
+			origin: OriginFor<T>,
+			offer_id: T::BondOfferId,
+			nb_of_bonds: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (issuer, mut offer) = Self::get_offer(offer_id)?;
+			ensure!(who == offer.beneficiary, DispatchError::BadOrigin);
+			ensure!(!offer.completed(), Error::<T>::OfferCompleted);
+			ensure!(
+				nb_of_bonds > BalanceOf::<T>::zero() && nb_of_bonds <= offer.nb_of_bonds,
+				Error::<T>::InvalidNumberOfBonds
+			);
+			if nb_of_bonds == offer.nb_of_bonds {
+				return Self::do_cancel(offer_id, &issuer, &offer)
+			}
+			let reward_share = Self::reward_share(&offer, nb_of_bonds)?;
 			T::Currency::transfer(
 				offer.reward.asset,
-				&offer_account,
+				&Self::account_id(offer_id),
 				&issuer,
-				offer.reward.amount,
+				reward_share,
 				false,
 			)?;
-			BondOffers::<T>::remove(offer_id);
-			Self::deposit_event(Event::<T>::OfferCancelled { offer_id });
+			// NOTE: can't underflow, same as in `do_bond`
+			offer.nb_of_bonds -= nb_of_bonds;
+			offer.reward.amount -= reward_share;
+			BondOffers::<T>::insert(offer_id, (issuer, offer));
+			Self::deposit_event(Event::<T>::OfferPartiallyCancelled { offer_id, nb_of_bonds });
 			Ok(())
 		}
+
+		/// Redeem the mature amounts of a bond.
+		///
+		/// The bonded amount and the reward share are transferred to the sender once their
+		/// respective maturity is reached. The financial NFT of the bond is burned once both
+		/// are redeemed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the
+		/// financial NFT of the bond.
+		///
+		/// Emits a `BondRedeemed`.
+		#[pallet::weight(T::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem(&who, fnft_instance_id, false)
+		}
+
+		/// Redeem a bond before its maturity, if allowed by its offer.
+		///
+		/// The amounts not yet mature are redeemed minus the `early_redemption` penalty of the
+		/// offer, which is paid to the `beneficiary` of the offer. The financial NFT of the bond
+		/// is burned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the
+		/// financial NFT of the bond.
+		///
+		/// Emits a `BondRedeemed`.
+		#[pallet::weight(T::WeightInfo::redeem_early())]
+		#[transactional]
+		pub fn redeem_early(
+			origin: OriginFor<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem(&who, fnft_instance_id, true)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -333,56 +509,67 @@ pub mod pallet {
 						// can't overflow, subsumed by `offer.valid()` in
 						// `do_offer`
 						let value = nb_of_bonds * offer.bond_price;
-						let reward_share = T::Convert::convert(safe_multiply_by_rational(
-							T::Convert::convert(nb_of_bonds),
-							T::Convert::convert(offer.reward.amount),
-							T::Convert::convert(offer.nb_of_bonds),
-						)?);
+						let reward_share = Self::reward_share(offer, nb_of_bonds)?;
 						let offer_account = Self::account_id(offer_id);
-						T::Currency::transfer(
-							offer.asset,
-							from,
-							&offer.beneficiary,
-							value,
-							keep_alive,
-						)?;
+						let fnft_collection_id = T::FinancialNftCollectionId::get();
+						let fnft_instance_id =
+							T::FinancialNft::get_next_nft_id(&fnft_collection_id)?;
+						let fnft_account =
+							T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
 						let current_block = frame_system::Pallet::<T>::current_block_number();
-						// Schedule the vesting of the reward.
-						T::Vesting::vested_transfer(
-							offer.reward.asset,
-							&offer_account,
-							from,
-							VestingScheduleInfo {
-								window: BlockNumberBased {
-									start: current_block,
-									period: offer.reward.maturity,
-								},
-								period_count: 1,
-								per_period: reward_share,
-							},
-						)?;
-						match offer.maturity {
+						let bond = match offer.maturity {
 							BondDuration::Finite { return_in } => {
-								// Schedule the return of the bonded amount
-								T::Vesting::vested_transfer(
+								// The bonded amount is held by the position until its return
+								T::Currency::transfer(
 									offer.asset,
-									&offer.beneficiary,
 									from,
-									VestingScheduleInfo {
-										window: BlockNumberBased {
-											start: current_block,
-											period: return_in,
-										},
-										period_count: 1,
-										per_period: value,
-									},
+									&fnft_account,
+									value,
+									keep_alive,
 								)?;
+								Some(BondedAmount {
+									asset: offer.asset,
+									amount: value,
+									matures_at: current_block.safe_add(&return_in)?,
+								})
 							},
 							BondDuration::Infinite => {
 								// the offer, the liquidity is never returned to the bonder, meaning
 								// that the protocol is now owning the funds.
+								T::Currency::transfer(
+									offer.asset,
+									from,
+									&offer.beneficiary,
+									value,
+									keep_alive,
+								)?;
+								None
 							},
-						}
+						};
+						T::Currency::transfer(
+							offer.reward.asset,
+							&offer_account,
+							&fnft_account,
+							reward_share,
+							false,
+						)?;
+						T::FinancialNft::mint_into(&fnft_collection_id, &fnft_instance_id, from)?;
+						BondPositions::<T>::insert(
+							fnft_instance_id,
+							BondPosition {
+								offer_id,
+								beneficiary: offer.beneficiary.clone(),
+								nb_of_bonds,
+								bonded_at: current_block,
+								bond,
+								reward: Some(BondedAmount {
+									asset: offer.reward.asset,
+									amount: reward_share,
+									matures_at: current_block.safe_add(&offer.reward.maturity)?,
+								}),
+								early_redemption: offer.early_redemption,
+							},
+						);
 						// NOTE(hussein-aitlahcen): can't overflow as checked to be <=
 						// offer.nb_of_bonds prior to this
 						// Same goes for reward_share as nb_of_bonds * bond_price <= total_price is
@@ -394,6 +581,8 @@ pub mod pallet {
 								offer_id,
 								who: from.clone(),
 								nb_of_bonds,
+								fnft_collection_id,
+								fnft_instance_id,
 							});
 						};
 						if offer.completed() {
@@ -415,11 +604,172 @@ pub mod pallet {
 			})
 		}
 
+		fn do_cancel(
+			offer_id: T::BondOfferId,
+			issuer: &AccountIdOf<T>,
+			offer: &BondOfferOf<T>,
+		) -> DispatchResult {
+			let offer_account = Self::account_id(offer_id);
+			// NOTE(hussein-aitlahcen): no need to keep the offer account alive
+			T::NativeCurrency::transfer(&offer_account, issuer, T::Stake::get(), false)?;
+			T::Currency::transfer(
+				offer.reward.asset,
+				&offer_account,
+				issuer,
+				offer.reward.amount,
+				false,
+			)?;
+			BondOffers::<T>::remove(offer_id);
+			Self::deposit_event(Event::<T>::OfferCancelled { offer_id });
+			Ok(())
+		}
+
+		fn do_redeem(
+			who: &AccountIdOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			early: bool,
+		) -> DispatchResult {
+			let fnft_collection_id = T::FinancialNftCollectionId::get();
+			ensure!(
+				T::FinancialNft::owner(&fnft_collection_id, &fnft_instance_id).as_ref() ==
+					Some(who),
+				Error::<T>::OnlyBondOwnerCanRedeem
+			);
+			let mut position = BondPositions::<T>::get(fnft_instance_id)
+				.ok_or(Error::<T>::BondPositionNotFound)?;
+			let penalty = if early {
+				Some(position.early_redemption.ok_or(Error::<T>::EarlyRedemptionNotAllowed)?)
+			} else {
+				None
+			};
+			let fnft_account =
+				T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			let bond_penalty = Self::redeem_amount(
+				&mut position.bond,
+				position.bonded_at,
+				penalty,
+				&fnft_account,
+				who,
+				&position.beneficiary,
+			)?;
+			let reward_penalty = Self::redeem_amount(
+				&mut position.reward,
+				position.bonded_at,
+				penalty,
+				&fnft_account,
+				who,
+				&position.beneficiary,
+			)?;
+			ensure!(bond_penalty.is_some() || reward_penalty.is_some(), Error::<T>::BondNotMatured);
+
+			if position.bond.is_none() && position.reward.is_none() {
+				BondPositions::<T>::remove(fnft_instance_id);
+				T::FinancialNft::burn(&fnft_collection_id, &fnft_instance_id, Some(who))?;
+			} else {
+				BondPositions::<T>::insert(fnft_instance_id, position);
+			}
+			Self::deposit_event(Event::<T>::BondRedeemed {
+				fnft_instance_id,
+				owner: who.clone(),
+				bond_penalty: bond_penalty.unwrap_or_else(Zero::zero),
+				reward_penalty: reward_penalty.unwrap_or_else(Zero::zero),
+			});
+			Ok(())
+		}
+
+		/// Transfer a bonded `amount` from the financial NFT account to its `owner` if mature, or
+		/// minus the `penalty` paid to the `beneficiary` if redeemed early.
+		///
+		/// Returns the penalty paid, `None` if the amount was not redeemed.
+		fn redeem_amount(
+			amount: &mut Option<BondedAmountOf<T>>,
+			bonded_at: BlockNumberOf<T>,
+			penalty: Option<EarlyRedemptionPenalty>,
+			fnft_account: &AccountIdOf<T>,
+			owner: &AccountIdOf<T>,
+			beneficiary: &AccountIdOf<T>,
+		) -> Result<Option<BalanceOf<T>>, DispatchError> {
+			let Some(bonded) = amount.as_ref() else {
+				return Ok(None)
+			};
+			let current_block = frame_system::Pallet::<T>::current_block_number();
+			let penalty = if current_block >= bonded.matures_at {
+				BalanceOf::<T>::zero()
+			} else if let Some(penalty) = penalty {
+				// maturities are validated to be non zero, hence `matures_at > bonded_at`
+				let progress = Perbill::from_rational(
+					current_block.saturating_sub(bonded_at),
+					bonded.matures_at.saturating_sub(bonded_at),
+				);
+				penalty.at(progress).mul_floor(bonded.amount)
+			} else {
+				return Ok(None)
+			};
+			if !penalty.is_zero() {
+				T::Currency::transfer(bonded.asset, fnft_account, beneficiary, penalty, false)?;
+			}
+			T::Currency::transfer(
+				bonded.asset,
+				fnft_account,
+				owner,
+				bonded.amount.saturating_sub(penalty),
+				false,
+			)?;
+			*amount = None;
+			Ok(Some(penalty))
+		}
+
+		/// Share of the remaining reward of `offer` for `nb_of_bonds`.
+		fn reward_share(
+			offer: &BondOfferOf<T>,
+			nb_of_bonds: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			Ok(T::Convert::convert(safe_multiply_by_rational(
+				T::Convert::convert(nb_of_bonds),
+				T::Convert::convert(offer.reward.amount),
+				T::Convert::convert(offer.nb_of_bonds),
+			)?))
+		}
+
+		pub(crate) fn create_financial_nft_collection() -> DispatchResult {
+			let owner = T::PalletId::get().into_account_truncating();
+			T::FinancialNft::create_collection(&T::FinancialNftCollectionId::get(), &owner, &owner)
+		}
+
 		pub(crate) fn account_id(offer_id: T::BondOfferId) -> AccountIdOf<T> {
 			T::PalletId::get().into_sub_account_truncating(offer_id)
 		}
 	}
 
+	impl<T: Config> FinancialNftProtocol for Pallet<T> {
+		type ItemId = T::FinancialNftInstanceId;
+		type AssetId = AssetIdOf<T>;
+		type Balance = BalanceOf<T>;
+
+		fn collection_asset_ids() -> Vec<Self::AssetId> {
+			vec![T::FinancialNftCollectionId::get()]
+		}
+
+		/// The amounts of the bond not yet redeemed.
+		fn value_of(
+			collection: &Self::AssetId,
+			instance: &Self::ItemId,
+		) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError> {
+			ensure!(
+				*collection == T::FinancialNftCollectionId::get(),
+				Error::<T>::BondPositionNotFound
+			);
+			let position =
+				BondPositions::<T>::get(instance).ok_or(Error::<T>::BondPositionNotFound)?;
+			Ok(position
+				.bond
+				.into_iter()
+				.chain(position.reward)
+				.map(|bonded| (bonded.asset, bonded.amount))
+				.collect())
+		}
+	}
+
 	impl<T: Config> BondedFinance for Pallet<T> {
 		type AccountId = AccountIdOf<T>;
 		type AssetId = AssetIdOf<T>;
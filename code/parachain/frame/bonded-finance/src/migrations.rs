@@ -0,0 +1,62 @@
+//! Storage migrations of the bonded finance pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds an `early_redemption` penalty to stored offers and creates the financial NFT
+	//! collection of bond positions.
+
+	use super::*;
+	use composable_traits::bonded_finance::{BondDuration, BondOffer, BondOfferReward};
+	use frame_support::{pallet_prelude::*, traits::GetStorageVersion};
+
+	/// Bond offer as stored before the introduction of early redemptions.
+	#[derive(Decode)]
+	struct OldBondOffer<AccountId, AssetId, Balance, BlockNumber> {
+		beneficiary: AccountId,
+		asset: AssetId,
+		bond_price: Balance,
+		nb_of_bonds: Balance,
+		maturity: BondDuration<BlockNumber>,
+		reward: BondOfferReward<AssetId, Balance, BlockNumber>,
+	}
+
+	type OldBondOfferOf<T> =
+		OldBondOffer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+	/// Stored offers were only redeemable at maturity, so they are all translated without early
+	/// redemption.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		BondOffers::<T>::translate::<(AccountIdOf<T>, OldBondOfferOf<T>), _>(
+			|_, (issuer, offer)| {
+				translated += 1;
+				Some((
+					issuer,
+					BondOffer {
+						beneficiary: offer.beneficiary,
+						asset: offer.asset,
+						bond_price: offer.bond_price,
+						nb_of_bonds: offer.nb_of_bonds,
+						maturity: offer.maturity,
+						reward: offer.reward,
+						early_redemption: None,
+					},
+				))
+			},
+		);
+		if let Err(error) = Pallet::<T>::create_financial_nft_collection() {
+			frame_support::log::error!(
+				"failed to create the bond financial NFT collection: {:?}",
+				error
+			);
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 2, translated + 2)
+	}
+}
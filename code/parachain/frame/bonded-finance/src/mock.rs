@@ -3,11 +3,15 @@
 #![cfg(test)]
 
 use super::*;
+use composable_traits::{
+	account_proxy::{AccountProxyWrapper, ProxyType},
+	fnft::FnftAccountProxyType,
+};
 use frame_support::{
 	construct_runtime,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{EnsureOrigin, Everything},
+	traits::{ConstU32, EnsureOrigin, Everything, InstanceFilter},
 	PalletId,
 };
 use frame_system::{EnsureRoot, RawOrigin};
@@ -17,7 +21,7 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{ConvertInto, IdentityLookup, Zero},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup, Zero},
 };
 
 pub type BlockNumber = u64;
@@ -25,6 +29,7 @@ pub type Moment = u64;
 pub type Balance = u128;
 pub type Amount = i128;
 pub type AccountId = u128;
+pub type FinancialNftInstanceId = u64;
 
 pub const MIN_VESTED_TRANSFER: u128 = 100;
 pub const NATIVE_CURRENCY_ID: MockCurrencyId = MockCurrencyId::PICA;
@@ -56,6 +61,13 @@ pub enum MockCurrencyId {
 	PICA,
 	BTC,
 	ETH,
+	FNFT,
+}
+
+impl From<MockCurrencyId> for u128 {
+	fn from(currency_id: MockCurrencyId) -> Self {
+		currency_id as u128
+	}
 }
 
 parameter_types! {
@@ -150,6 +162,7 @@ impl pallet_timestamp::Config for Runtime {
 
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 2;
+	pub const MaxVestingCheckpoints: u32 = 3;
 	pub const MinVestedTransfer: u64 = MIN_VESTED_TRANSFER as _;
 }
 
@@ -161,17 +174,70 @@ impl pallet_vesting::Config for Runtime {
 	type UpdateSchedulesOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = ();
 	type MaxVestingSchedules = MaxVestingSchedule;
+	type MaxVestingCheckpoints = MaxVestingCheckpoints;
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
 }
 
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+impl pallet_fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProperties = ConstU32<16>;
+	type FinancialNftCollectionId = MockCurrencyId;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type ProxyType = ProxyType;
+	type AccountProxy = AccountProxyWrapper<Runtime>;
+	type ProxyTypeSelector = FnftAccountProxyType;
+	type PalletId = FnftPalletId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub MaxProxies: u32 = 4;
+	pub MaxPending: u32 = 32;
+	pub ProxyPrice: u32 = 0;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = ();
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyPrice;
+	type ProxyDepositFactor = ProxyPrice;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = ();
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ProxyPrice;
+	type AnnouncementDepositFactor = ProxyPrice;
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _c: &RuntimeCall) -> bool {
+		matches!(self, ProxyType::Any)
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			_ => false,
+		}
+	}
+}
+
 parameter_types! {
 	// cspell:disable-next
 	pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
 	pub const Stake: Balance = 10_000;
 	pub const NativeCurrencyId: MockCurrencyId = NATIVE_CURRENCY_ID;
 	pub const MinReward: Balance = MIN_REWARD;
+	pub const BondFinancialNftCollectionId: MockCurrencyId = MockCurrencyId::FNFT;
 }
 
 impl Config for Runtime {
@@ -179,6 +245,9 @@ impl Config for Runtime {
 	type NativeCurrency = CurrencyAdapter<Runtime, NativeCurrencyId>;
 	type Currency = Tokens;
 	type Vesting = Vesting;
+	type FinancialNft = FinancialNft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FinancialNftCollectionId = BondFinancialNftCollectionId;
 	type BondOfferId = u64;
 	type Convert = ConvertInto;
 	type PalletId = BondedFinanceId;
@@ -200,8 +269,10 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
 		Vesting: pallet_vesting::{Pallet, Storage, Call, Event<T>, Config<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
-		BondedFinance: pallet::{Pallet, Call, Storage, Event<T>},
+		BondedFinance: pallet::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		FinancialNft: pallet_fnft::{Pallet, Call, Storage, Event<T>},
+		Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -219,6 +290,12 @@ impl ExtBuilder {
 			.assimilate_storage(&mut t)
 			.unwrap();
 
+		GenesisBuild::<Runtime>::assimilate_storage(
+			&crate::GenesisConfig::<Runtime>::default(),
+			&mut t,
+		)
+		.unwrap();
+
 		t.into()
 	}
 }
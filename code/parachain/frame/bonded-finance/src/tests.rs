@@ -4,19 +4,20 @@
 
 use super::*;
 use composable_tests_helpers::{prop_assert_acceptable_computation_error, prop_assert_ok};
-use composable_traits::{
-	bonded_finance::{BondDuration, BondOffer, BondOfferReward},
-	vesting::VestingScheduleIdSet,
+use composable_traits::bonded_finance::{
+	BondDuration, BondOffer, BondOfferReward, EarlyRedemptionPenalty,
 };
 use frame_support::{
+	assert_noop, assert_ok,
 	error::BadOrigin,
 	traits::{
 		fungibles::{Inspect, Mutate},
-		tokens::WithdrawConsequence,
+		tokens::nonfungibles::Inspect as NonFungiblesInspect,
 	},
 };
 use mock::{RuntimeEvent, *};
 use proptest::prelude::*;
+use sp_runtime::Perbill;
 
 prop_compose! {
 	  // NOTE(hussein-aitlahcen): we use u32 before casting to avoid overflows
@@ -44,7 +45,8 @@ prop_compose! {
 									  asset: MockCurrencyId::ETH,
 									  amount: Balance::max(MIN_REWARD.saturating_mul(nb_of_bonds), reward_amount),
 									  maturity: reward_maturity,
-							  }
+							  },
+							  early_redemption: None,
 					  }
 			  }
 }
@@ -175,7 +177,7 @@ proptest! {
 											  0
 									  );
 									  System::set_block_number(return_in);
-									  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), offer.asset, VestingScheduleIdSet::All));
+									  prop_assert_ok!(BondedFinance::redeem(RuntimeOrigin::signed(BOB), 0));
 									  prop_assert_eq!(
 											  Tokens::balance(offer.asset, &BOB),
 											  offer.total_price().expect("impossible; qed;")
@@ -235,7 +237,9 @@ proptest! {
 					  System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::NewBond {
 							  offer_id,
 							  who: BOB,
-							  nb_of_bonds: offer.nb_of_bonds - 1
+							  nb_of_bonds: offer.nb_of_bonds - 1,
+							  fnft_collection_id: MockCurrencyId::FNFT,
+							  fnft_instance_id: 0,
 					  }));
 
 					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 1, false));
@@ -243,7 +247,9 @@ proptest! {
 					  System::assert_has_event(RuntimeEvent::BondedFinance(crate::Event::NewBond {
 							  offer_id,
 							  who: BOB,
-							  nb_of_bonds: 1
+							  nb_of_bonds: 1,
+							  fnft_collection_id: MockCurrencyId::FNFT,
+							  fnft_instance_id: 1,
 					  }));
 
 					  System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::OfferCompleted { offer_id }));
@@ -280,16 +286,20 @@ proptest! {
 					  prop_assert_acceptable_computation_error!(bob_reward, half_reward, precision, epsilon);
 					  prop_assert_acceptable_computation_error!(charlie_reward, half_reward, precision, epsilon);
 
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &BOB, bob_reward) == WithdrawConsequence::Frozen);
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &CHARLIE, charlie_reward) == WithdrawConsequence::Frozen);
+					  // the rewards are held by the bond positions until maturity
+					  prop_assert_eq!(Tokens::balance(offer.reward.asset, &BOB), 0);
+					  prop_assert_eq!(
+							  BondedFinance::redeem(RuntimeOrigin::signed(BOB), 0),
+							  Err(Error::<Runtime>::BondNotMatured.into())
+					  );
 
 					  System::set_block_number(offer.reward.maturity);
 
-					  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), offer.reward.asset, VestingScheduleIdSet::All));
-					  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(CHARLIE), offer.reward.asset, VestingScheduleIdSet::All));
+					  prop_assert_ok!(BondedFinance::redeem(RuntimeOrigin::signed(BOB), 0));
+					  prop_assert_ok!(BondedFinance::redeem(RuntimeOrigin::signed(CHARLIE), 1));
 
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &BOB, bob_reward) == WithdrawConsequence::Success);
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &CHARLIE, charlie_reward) == WithdrawConsequence::Success);
+					  prop_assert_eq!(Tokens::balance(offer.reward.asset, &BOB), bob_reward);
+					  prop_assert_eq!(Tokens::balance(offer.reward.asset, &CHARLIE), charlie_reward);
 
 					  Ok(())
 			  })?;
@@ -444,6 +454,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128 * 100_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert_ok!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -462,6 +473,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert_ok!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -480,6 +492,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128 * 100_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert_ok!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -501,6 +514,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -523,6 +537,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -545,6 +560,7 @@ mod test_bond_offer {
 				amount: 1_000_000_u128,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -567,6 +583,7 @@ mod test_bond_offer {
 				amount: 0,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -589,6 +606,7 @@ mod test_bond_offer {
 				amount: MIN_VESTED_TRANSFER * 1_000_u128 - 1,
 				maturity: 96_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -611,6 +629,7 @@ mod test_bond_offer {
 				amount: MIN_VESTED_TRANSFER * 1_000_u128 - 1,
 				maturity: 0_u64,
 			},
+			early_redemption: None,
 		};
 
 		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
@@ -620,3 +639,188 @@ mod test_bond_offer {
 		.is_err());
 	}
 }
+
+#[cfg(test)]
+mod test_bond_positions {
+	use super::*;
+	use crate::BondOfferOf;
+	use composable_traits::bonded_finance::BondedAmount;
+
+	const FNFT: MockCurrencyId = MockCurrencyId::FNFT;
+
+	fn offer(early_redemption: Option<EarlyRedemptionPenalty>) -> BondOfferOf<Runtime> {
+		BondOffer {
+			beneficiary: ALICE,
+			asset: MockCurrencyId::BTC,
+			bond_price: MIN_VESTED_TRANSFER,
+			nb_of_bonds: 10,
+			maturity: BondDuration::Finite { return_in: 100 },
+			reward: BondOfferReward {
+				asset: MockCurrencyId::ETH,
+				amount: MIN_REWARD * 10,
+				maturity: 100,
+			},
+			early_redemption,
+		}
+	}
+
+	/// ALICE creates the offer, BOB buys 2 bonds at block 1.
+	fn offer_and_bond(offer: BondOfferOf<Runtime>) -> u64 {
+		System::set_block_number(1);
+		assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+		assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+		let offer_id = BondedFinance::do_offer(&ALICE, offer, false).unwrap();
+		assert_ok!(Tokens::mint_into(MockCurrencyId::BTC, &BOB, 2 * MIN_VESTED_TRANSFER));
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, false));
+		offer_id
+	}
+
+	#[test]
+	fn bond_is_represented_by_a_transferable_financial_nft() {
+		ExtBuilder::build().execute_with(|| {
+			let offer_id = offer_and_bond(offer(None));
+
+			assert_eq!(FinancialNft::owner(&FNFT, &0), Some(BOB));
+			let position = BondedFinance::positions(0).unwrap();
+			assert_eq!(position.offer_id, offer_id);
+			assert_eq!(
+				position.bond,
+				Some(BondedAmount {
+					asset: MockCurrencyId::BTC,
+					amount: 2 * MIN_VESTED_TRANSFER,
+					matures_at: 101
+				})
+			);
+			assert_eq!(
+				position.reward,
+				Some(BondedAmount {
+					asset: MockCurrencyId::ETH,
+					amount: 2 * MIN_REWARD,
+					matures_at: 101
+				})
+			);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &BOB), 0);
+
+			assert_ok!(FinancialNft::transfer(RuntimeOrigin::signed(BOB), FNFT, 0, CHARLIE));
+			assert_noop!(
+				BondedFinance::redeem(RuntimeOrigin::signed(CHARLIE), 0),
+				Error::<Runtime>::BondNotMatured
+			);
+
+			System::set_block_number(101);
+			assert_noop!(
+				BondedFinance::redeem(RuntimeOrigin::signed(BOB), 0),
+				Error::<Runtime>::OnlyBondOwnerCanRedeem
+			);
+			assert_ok!(BondedFinance::redeem(RuntimeOrigin::signed(CHARLIE), 0));
+			System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::BondRedeemed {
+				fnft_instance_id: 0,
+				owner: CHARLIE,
+				bond_penalty: 0,
+				reward_penalty: 0,
+			}));
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &CHARLIE), 2 * MIN_VESTED_TRANSFER);
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &CHARLIE), 2 * MIN_REWARD);
+			assert_eq!(FinancialNft::owner(&FNFT, &0), None);
+			assert!(BondedFinance::positions(0).is_none());
+		});
+	}
+
+	#[test]
+	fn early_redemption_penalty_is_paid_to_beneficiary() {
+		ExtBuilder::build().execute_with(|| {
+			offer_and_bond(offer(Some(EarlyRedemptionPenalty::Linear {
+				start: Perbill::from_percent(50),
+				end: Perbill::zero(),
+			})));
+
+			// half way to maturity, the penalty went down from 50% to 25%
+			System::set_block_number(51);
+			assert_ok!(BondedFinance::redeem_early(RuntimeOrigin::signed(BOB), 0));
+			System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::BondRedeemed {
+				fnft_instance_id: 0,
+				owner: BOB,
+				bond_penalty: MIN_VESTED_TRANSFER / 2,
+				reward_penalty: MIN_REWARD / 2,
+			}));
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &BOB), MIN_VESTED_TRANSFER * 3 / 2);
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &BOB), MIN_REWARD * 3 / 2);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &ALICE), MIN_VESTED_TRANSFER / 2);
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &ALICE), MIN_REWARD / 2);
+			assert_eq!(FinancialNft::owner(&FNFT, &0), None);
+			assert!(BondedFinance::positions(0).is_none());
+		});
+	}
+
+	#[test]
+	fn early_redemption_must_be_allowed_by_offer() {
+		ExtBuilder::build().execute_with(|| {
+			offer_and_bond(offer(None));
+
+			assert_noop!(
+				BondedFinance::redeem_early(RuntimeOrigin::signed(BOB), 0),
+				Error::<Runtime>::EarlyRedemptionNotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn early_redemption_penalty_curve() {
+		let flat = EarlyRedemptionPenalty::Flat(Perbill::from_percent(10));
+		assert_eq!(flat.at(Perbill::zero()), Perbill::from_percent(10));
+		assert_eq!(flat.at(Perbill::from_percent(90)), Perbill::from_percent(10));
+
+		let increasing = EarlyRedemptionPenalty::Linear {
+			start: Perbill::from_percent(10),
+			end: Perbill::from_percent(30),
+		};
+		assert_eq!(increasing.at(Perbill::zero()), Perbill::from_percent(10));
+		assert_eq!(increasing.at(Perbill::from_percent(50)), Perbill::from_percent(20));
+		assert_eq!(increasing.at(Perbill::one()), Perbill::from_percent(30));
+	}
+
+	#[test]
+	fn beneficiary_can_cancel_unsold_bonds() {
+		ExtBuilder::build().execute_with(|| {
+			let offer_id =
+				offer_and_bond(BondOfferOf::<Runtime> { beneficiary: CHARLIE, ..offer(None) });
+
+			assert_noop!(
+				BondedFinance::cancel_partially(RuntimeOrigin::signed(ALICE), offer_id, 4),
+				BadOrigin
+			);
+			assert_noop!(
+				BondedFinance::cancel_partially(RuntimeOrigin::signed(CHARLIE), offer_id, 9),
+				Error::<Runtime>::InvalidNumberOfBonds
+			);
+
+			assert_ok!(BondedFinance::cancel_partially(
+				RuntimeOrigin::signed(CHARLIE),
+				offer_id,
+				4
+			));
+			System::assert_last_event(RuntimeEvent::BondedFinance(
+				crate::Event::OfferPartiallyCancelled { offer_id, nb_of_bonds: 4 },
+			));
+			// the reward share of the cancelled bonds is refunded to the issuer
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &ALICE), 4 * MIN_REWARD);
+			let (_, offer) = BondedFinance::offers(offer_id).unwrap();
+			assert_eq!(offer.nb_of_bonds, 4);
+			assert_eq!(offer.reward.amount, 4 * MIN_REWARD);
+
+			assert_ok!(BondedFinance::cancel_partially(
+				RuntimeOrigin::signed(CHARLIE),
+				offer_id,
+				4
+			));
+			System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::OfferCancelled {
+				offer_id,
+			}));
+			assert!(BondedFinance::offers(offer_id).is_none());
+			assert_eq!(Tokens::balance(NATIVE_CURRENCY_ID, &ALICE), Stake::get());
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &ALICE), 8 * MIN_REWARD);
+			// the bond already sold is not affected
+			assert!(BondedFinance::positions(0).is_some());
+		});
+	}
+}
@@ -9,6 +9,9 @@ pub trait WeightInfo {
 	fn offer() -> Weight;
 	fn bond() -> Weight;
 	fn cancel() -> Weight;
+	fn cancel_partially() -> Weight;
+	fn redeem() -> Weight;
+	fn redeem_early() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -21,4 +24,13 @@ impl WeightInfo for () {
 	fn cancel() -> Weight {
     Weight::from_ref_time(10_000)
 	}
+	fn cancel_partially() -> Weight {
+    Weight::from_ref_time(10_000)
+	}
+	fn redeem() -> Weight {
+    Weight::from_ref_time(10_000)
+	}
+	fn redeem_early() -> Weight {
+    Weight::from_ref_time(10_000)
+	}
 }
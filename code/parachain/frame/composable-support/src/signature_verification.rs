@@ -2,12 +2,16 @@
 //!
 //! Signed messages/proofs are expected to be in the format of `{prefix}-{msg}` before they
 //! are modified to fit their chains signature specifications.
-use crate::types::{CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress};
+use crate::types::{
+	BitcoinAddress, BitcoinSignature, CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature,
+	EthereumAddress, SolanaPublicKey, SolanaSignature,
+};
 use codec::{Decode, Encode};
 use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use ripemd::{Digest, Ripemd160};
 use scale_info::TypeInfo;
 use sp_io::hashing::{keccak_256, sha2_256};
-use sp_runtime::{traits::Verify, AccountId32, MultiSignature};
+use sp_runtime::{app_crypto::ed25519, traits::Verify, AccountId32, MultiSignature};
 use sp_std::vec::Vec;
 
 /// Error type for all signature verification.
@@ -150,3 +154,124 @@ pub fn cosmos_recover(
 		},
 	}
 }
+
+/// Generates a message that is compatible with the Solana wallet `signMessage` API.
+///
+/// Solana wallets sign the raw message bytes, without any envelope.
+pub fn solana_signable_message(prefix: &[u8], msg: &[u8]) -> Vec<u8> {
+	[prefix, msg].concat()
+}
+
+/// Verify an ed25519 signature produced by a Solana wallet for the given public key.
+///
+/// Returns the public key if the signature is valid.
+pub fn solana_verify(
+	prefix: &[u8],
+	msg: &[u8],
+	public_key: SolanaPublicKey,
+	SolanaSignature(sig): &SolanaSignature,
+) -> Result<SolanaPublicKey> {
+	let msg = solana_signable_message(prefix, msg);
+
+	if sp_io::crypto::ed25519_verify(
+		&ed25519::Signature::from_raw(*sig),
+		&msg,
+		&ed25519::Public::from_raw(public_key.0),
+	) {
+		return Ok(public_key)
+	}
+
+	Err(SignatureVerificationError::FailedVerification)
+}
+
+/// Generates a message that is compatible with the Bitcoin `signmessage` process.
+///
+/// Requires the original message.
+pub fn bitcoin_signable_message(prefix: &[u8], msg: &[u8]) -> Vec<u8> {
+	const MAGIC: &[u8] = b"Bitcoin Signed Message:\n";
+
+	let mut signed_message = Vec::new();
+	push_compact_size(&mut signed_message, MAGIC.len() as u64);
+	signed_message.extend_from_slice(MAGIC);
+	push_compact_size(&mut signed_message, (prefix.len() + msg.len()) as u64);
+	signed_message.extend_from_slice(prefix);
+	signed_message.extend_from_slice(msg);
+
+	signed_message
+}
+
+/// Recover the address of a BIP-137 signature.
+///
+/// The header byte of the signature determines the kind of address which is recovered.
+/// Requires the original message.
+pub fn bitcoin_recover(
+	prefix: &[u8],
+	msg: &[u8],
+	BitcoinSignature(sig): &BitcoinSignature,
+) -> Result<BitcoinAddress> {
+	let msg = sha2_256(&sha2_256(&bitcoin_signable_message(prefix, msg)));
+	let header = sig[0];
+	if !(27..=42).contains(&header) {
+		return Err(SignatureVerificationError::InvalidSignature)
+	}
+
+	let mut recoverable_signature = [0_u8; 65];
+	recoverable_signature[..64].copy_from_slice(&sig[1..]);
+	recoverable_signature[64] = (header - 27) % 4;
+
+	match header {
+		27..=30 => {
+			let mut public_key = [0_u8; 65];
+			public_key[0] = 0x04;
+			public_key[1..].copy_from_slice(&sp_io::crypto::secp256k1_ecdsa_recover(
+				&recoverable_signature,
+				&msg,
+			)?);
+			Ok(BitcoinAddress::P2pkh(hash160(&public_key)))
+		},
+		_ => {
+			let public_key_hash = hash160(&sp_io::crypto::secp256k1_ecdsa_recover_compressed(
+				&recoverable_signature,
+				&msg,
+			)?);
+			match header {
+				31..=34 => Ok(BitcoinAddress::P2pkh(public_key_hash)),
+				35..=38 => {
+					// P2SH-P2WPKH pays to the hash of the `OP_0 <public_key_hash>` witness script
+					let mut script = [0_u8; 22];
+					script[0] = 0x00;
+					script[1] = 0x14;
+					script[2..].copy_from_slice(&public_key_hash);
+					Ok(BitcoinAddress::P2shP2wpkh(hash160(&script)))
+				},
+				_ => Ok(BitcoinAddress::P2wpkh(public_key_hash)),
+			}
+		},
+	}
+}
+
+/// `RIPEMD160(SHA256(data))`, as used by Bitcoin addresses.
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+	let mut hash = [0_u8; 20];
+	hash.copy_from_slice(&Ripemd160::digest(sha2_256(data)));
+	hash
+}
+
+/// Appends `length` in the Bitcoin variable length integer encoding.
+fn push_compact_size(buffer: &mut Vec<u8>, length: u64) {
+	match length {
+		0..=0xFC => buffer.push(length as u8),
+		0xFD..=0xFFFF => {
+			buffer.push(0xFD);
+			buffer.extend_from_slice(&(length as u16).to_le_bytes());
+		},
+		0x1_0000..=0xFFFF_FFFF => {
+			buffer.push(0xFE);
+			buffer.extend_from_slice(&(length as u32).to_le_bytes());
+		},
+		_ => {
+			buffer.push(0xFF);
+			buffer.extend_from_slice(&length.to_le_bytes());
+		},
+	}
+}
@@ -87,3 +87,45 @@ impl From<CosmosEcdsaSignature> for EcdsaSignature {
 		EcdsaSignature(sig)
 	}
 }
+
+/// Solana account, the ed25519 public key of the account.
+#[derive(
+	Hash, Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct SolanaPublicKey(pub [u8; 32]);
+
+/// Struct representing an ed25519 signature produced by a Solana wallet.
+#[derive(PartialEq, Eq, Encode, Decode, Clone, MaxEncodedLen, TypeInfo)]
+pub struct SolanaSignature(pub [u8; 64]);
+
+impl sp_std::fmt::Debug for SolanaSignature {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		write!(f, "SolanaSignature({:?})", &self.0[..])
+	}
+}
+
+/// Bitcoin address, the `HASH160` of the public key or script it pays to.
+///
+/// The kind of address is determined by the header byte of a BIP-137 signature.
+#[derive(
+	Hash, Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum BitcoinAddress {
+	/// Legacy address paying to a public key hash.
+	P2pkh([u8; 20]),
+	/// Nested SegWit address paying to a script hash wrapping a witness public key hash.
+	P2shP2wpkh([u8; 20]),
+	/// Native SegWit address paying to a witness public key hash.
+	P2wpkh([u8; 20]),
+}
+
+/// Struct representing a BIP-137 signature, a header byte followed by the 64 byte compact
+/// signature.
+#[derive(PartialEq, Eq, Encode, Decode, Clone, MaxEncodedLen, TypeInfo)]
+pub struct BitcoinSignature(pub [u8; 65]);
+
+impl sp_std::fmt::Debug for BitcoinSignature {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		write!(f, "BitcoinSignature({:?})", &self.0[..])
+	}
+}
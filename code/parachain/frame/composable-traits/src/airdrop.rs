@@ -9,6 +9,7 @@ pub trait Airdropper {
 	type AirdropId;
 	type AirdropStart;
 	type Balance;
+	type MerkleRoot;
 	type Proof;
 	type Recipient;
 	type RecipientCollection;
@@ -22,6 +23,16 @@ pub trait Airdropper {
 		schedule: Self::VestingSchedule,
 	) -> DispatchResult;
 
+	/// Create a new Airdrop whose recipients are committed to by a Merkle root.
+	fn create_merkle_airdrop(
+		creator_id: Self::AccountId,
+		start: Option<Self::AirdropStart>,
+		schedule: Self::VestingSchedule,
+		merkle_root: Self::MerkleRoot,
+		total_funds: Self::Balance,
+		total_recipients: u32,
+	) -> DispatchResult;
+
 	/// Add one or more recipients to an Airdrop.
 	fn add_recipient(
 		origin_id: Self::AccountId,
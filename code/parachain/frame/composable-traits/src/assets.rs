@@ -24,13 +24,47 @@ impl BasicAssetMetadata {
 	}
 }
 
+/// Decimals of assets registered without explicit decimals.
+pub const DEFAULT_DECIMALS: Exponent = 12;
+
+/// Everything known about an asset, the single source of its decimals.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetInfo<Balance, ForeignId> {
+	pub name: Option<BiBoundedVec<u8, 1, 32>>,
+	pub symbol: Option<BiBoundedVec<u8, 1, 8>>,
+	pub decimals: Exponent,
+	/// If `None`, derived from the native existential deposit at the ratio of the asset.
+	pub existential_deposit: Option<Balance>,
+	/// Reserve location of a foreign asset.
+	pub location: Option<ForeignId>,
+	/// Fees can be paid in the asset, at its ratio to the native asset.
+	pub payable_as_fee: bool,
+}
+
+impl<Balance, ForeignId> AssetInfo<Balance, ForeignId> {
+	/// Unnamed foreign asset payable as fee, with [`DEFAULT_DECIMALS`] if `decimals` is `None`.
+	pub fn foreign(location: ForeignId, decimals: Option<Exponent>) -> Self {
+		Self {
+			name: None,
+			symbol: None,
+			decimals: decimals.unwrap_or(DEFAULT_DECIMALS),
+			existential_deposit: None,
+			location: Some(location),
+			payable_as_fee: true,
+		}
+	}
+}
+
 #[derive(Decode, Encode, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Asset<Balance, ForeignId> {
 	pub name: Option<Vec<u8>>,
+	pub symbol: Option<Vec<u8>>,
 	pub id: u128,
 	pub decimals: Exponent,
 	pub ratio: Option<Rational64>,
 	pub foreign_id: Option<ForeignId>,
 	pub existential_deposit: Balance,
+	pub payable_as_fee: bool,
 }
@@ -4,7 +4,10 @@ use composable_support::{
 };
 use frame_support::{pallet_prelude::*, traits::Get};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Zero, ArithmeticError};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	ArithmeticError, Perbill,
+};
 
 pub trait BondedFinance {
 	type AccountId;
@@ -59,6 +62,9 @@ pub struct BondOffer<AccountId, AssetId, Balance, BlockNumber> {
 	pub maturity: BondDuration<BlockNumber>,
 	/// Total reward for this offer.
 	pub reward: BondOfferReward<AssetId, Balance, BlockNumber>,
+	/// Penalty paid to the `beneficiary` to redeem a bond before its maturity. Bonds can only be
+	/// redeemed at maturity if `None`.
+	pub early_redemption: Option<EarlyRedemptionPenalty>,
 }
 
 /// The Bond reward. Asset and rules reward will be given.
@@ -72,6 +78,57 @@ pub struct BondOfferReward<AssetId, Balance, BlockNumber> {
 	pub maturity: BlockNumber,
 }
 
+/// The penalty curve of an early redemption, applied to the amounts of a bond that are not yet
+/// mature.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum EarlyRedemptionPenalty {
+	/// The same penalty until maturity.
+	Flat(Perbill),
+	/// Moves linearly from `start` when bonding to `end` at maturity.
+	Linear { start: Perbill, end: Perbill },
+}
+
+impl EarlyRedemptionPenalty {
+	/// The penalty once `progress` of the time to maturity has elapsed.
+	pub fn at(&self, progress: Perbill) -> Perbill {
+		match *self {
+			Self::Flat(penalty) => penalty,
+			Self::Linear { start, end } if start >= end =>
+				start.saturating_sub(progress * start.saturating_sub(end)),
+			Self::Linear { start, end } =>
+				start.saturating_add(progress * end.saturating_sub(start)),
+		}
+	}
+}
+
+/// An amount held by a bond position until it matures.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct BondedAmount<AssetId, Balance, BlockNumber> {
+	pub asset: AssetId,
+	pub amount: Balance,
+	/// Block from which the amount can be redeemed.
+	pub matures_at: BlockNumber,
+}
+
+/// A bond bought on an offer, represented by a financial NFT. The amounts are held by the asset
+/// account of the financial NFT until redeemed by its owner.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct BondPosition<BondOfferId, AccountId, AssetId, Balance, BlockNumber> {
+	/// The offer the bond was bought on.
+	pub offer_id: BondOfferId,
+	/// The beneficiary of the offer, receiving the early redemption penalties.
+	pub beneficiary: AccountId,
+	pub nb_of_bonds: Balance,
+	/// Block at which the bond was bought.
+	pub bonded_at: BlockNumber,
+	/// The bonded amount, returned at maturity. `None` once redeemed or if the offer has an
+	/// infinite maturity.
+	pub bond: Option<BondedAmount<AssetId, Balance, BlockNumber>>,
+	/// The share of the offer reward. `None` once redeemed.
+	pub reward: Option<BondedAmount<AssetId, Balance, BlockNumber>>,
+	pub early_redemption: Option<EarlyRedemptionPenalty>,
+}
+
 #[derive(Debug, Decode)]
 pub struct ValidBondOffer<U, V> {
 	_marker: PhantomData<(U, V)>,
@@ -280,6 +280,35 @@ pub trait SellEngine<Configuration>: DeFiEngine {
 		order_id: Self::OrderId,
 		take: Take<Self::Balance>,
 	) -> Result<(), DispatchError>;
+	/// cancel order, returning unsold `base` to seller
+	fn cancel(order_id: Self::OrderId) -> Result<(), DispatchError>;
+}
+
+/// Notified by a [`SellEngine`] about progress of its orders.
+pub trait SellEngineCallback<OrderId, Balance> {
+	/// `sold` amount of `base` was exchanged for `received` amount of `quote`
+	fn on_order_taken(
+		order_id: OrderId,
+		sold: Balance,
+		received: Balance,
+	) -> Result<(), DispatchError>;
+	/// order was removed, either sold out or cancelled with `unsold` amount of `base` returned to
+	/// seller
+	fn on_order_removed(order_id: OrderId, unsold: Balance) -> Result<(), DispatchError>;
+}
+
+impl<OrderId, Balance> SellEngineCallback<OrderId, Balance> for () {
+	fn on_order_taken(
+		_order_id: OrderId,
+		_sold: Balance,
+		_received: Balance,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn on_order_removed(_order_id: OrderId, _unsold: Balance) -> Result<(), DispatchError> {
+		Ok(())
+	}
 }
 
 pub trait DeFiComposableConfig: frame_system::Config {
@@ -6,9 +6,9 @@ use crate::account_proxy::ProxyType;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::collections::vec::bounded::BiBoundedVec;
 use core::fmt::Debug;
-use frame_support::traits::tokens::nonfungibles::Inspect;
+use frame_support::traits::{tokens::nonfungibles::Inspect, LockIdentifier};
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::vec::Vec;
 
 pub type Key = BiBoundedVec<u8, 1, 64>;
@@ -27,6 +27,41 @@ pub trait FinancialNft<AccountId>: Inspect<AccountId> {
 	fn get_next_nft_id(collection: &Self::CollectionId) -> Result<Self::ItemId, DispatchError>;
 }
 
+/// Transfer locks that protocols can put on financial NFTs, e.g. while a position is used as
+/// collateral. A locked financial NFT can neither be transferred nor burned.
+pub trait FinancialNftLock<AccountId>: Inspect<AccountId> {
+	/// Lock the financial NFT under `id`. Fails if the financial NFT is already locked.
+	fn lock(
+		collection: &Self::CollectionId,
+		instance: &Self::ItemId,
+		id: LockIdentifier,
+	) -> DispatchResult;
+
+	/// Remove the lock `id` from the financial NFT. Fails if it is not locked under `id`.
+	fn unlock(
+		collection: &Self::CollectionId,
+		instance: &Self::ItemId,
+		id: LockIdentifier,
+	) -> DispatchResult;
+
+	/// The lock currently put on the financial NFT, if any.
+	fn lock_of(collection: &Self::CollectionId, instance: &Self::ItemId) -> Option<LockIdentifier>;
+}
+
+/// Description of a financial NFT for marketplaces and lending protocols to display and price it.
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct FinancialNftMetadata<AccountId, AssetId, Balance> {
+	pub owner: AccountId,
+	/// The account approved to transfer this financial NFT on behalf of the owner.
+	pub approved: Option<AccountId>,
+	/// The protocol lock preventing transfers, if any.
+	pub lock: Option<LockIdentifier>,
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+	/// The value of the position as reported by the originating protocol, see
+	/// [`FinancialNftProtocol::value_of`].
+	pub value: Vec<(AssetId, Balance)>,
+}
+
 /// Trait to be implemented by protocol supporting financial NFTs.
 pub trait FinancialNftProtocol {
 	/// Type for identifying an item.
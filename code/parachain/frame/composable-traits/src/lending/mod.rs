@@ -88,6 +88,8 @@ pub struct MarketConfig<VaultId, AssetId, AccountId, LiquidationStrategyId, Bloc
 	pub interest_rate_model: InterestRateModel,
 	pub under_collateralized_warn_percent: Percent,
 	pub liquidators: Vec<LiquidationStrategyId>,
+	/// Part of the interest repaid which funds the reserve of the market.
+	pub reserved_factor: Perquintill,
 }
 
 /// Different ways that a market can be repaid.
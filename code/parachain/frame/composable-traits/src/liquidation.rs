@@ -2,17 +2,19 @@ use codec::Encode;
 use sp_runtime::DispatchError;
 use sp_std::vec::*;
 
-use crate::defi::{DeFiEngine, Sell};
+use crate::defi::{DeFiEngine, OrderIdLike, Sell};
 
 /// An object from which we can initiate liquidations from.
 /// Does not cares if liquidation was completed or not, neither can reasonably provide that
 /// information. Off-chain can join relevant ids if needed.
 /// `configuration` - optional list of liquidations strategies
 pub trait Liquidation: DeFiEngine {
-	type OrderId;
+	type OrderId: OrderIdLike;
 	type LiquidationStrategyId;
 
 	/// Initiate a liquidation, this operation should be executed as fast as possible.
+	/// Outcome of the liquidation is reported later with the returned `OrderId`, see
+	/// [`LiquidationCallback`].
 	fn liquidate(
 		from_to: &Self::AccountId,
 		order: Sell<Self::MayBeAssetId, Self::Balance>,
@@ -20,6 +22,29 @@ pub trait Liquidation: DeFiEngine {
 	) -> Result<Self::OrderId, DispatchError>;
 }
 
+/// Notified by a [`Liquidation`] engine once a liquidation is over.
+pub trait LiquidationCallback<AccountId, OrderId, Balance> {
+	/// `order_id` of `from_to` is finalized, `received` amount of `quote` and `unsold` amount of
+	/// `base` are on `from_to`.
+	fn on_liquidation_finalized(
+		order_id: OrderId,
+		from_to: &AccountId,
+		received: Balance,
+		unsold: Balance,
+	) -> Result<(), DispatchError>;
+}
+
+impl<AccountId, OrderId, Balance> LiquidationCallback<AccountId, OrderId, Balance> for () {
+	fn on_liquidation_finalized(
+		_order_id: OrderId,
+		_from_to: &AccountId,
+		_received: Balance,
+		_unsold: Balance,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
 /// generic transaction which can target any pallet and any method in any parachain (local or
 /// remote)
 /// so it must be encoded in format with widest possible values to incorporate some chains we do
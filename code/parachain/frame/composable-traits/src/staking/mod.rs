@@ -39,6 +39,9 @@ pub struct Reward<Balance> {
 
 	/// The last time the reward was updated, in seconds.
 	pub last_updated_timestamp: u64,
+
+	/// The last block the reward was updated at. Only used by [`RewardRatePeriod::PerBlock`].
+	pub last_updated_block: u64,
 }
 
 #[derive(RuntimeDebug, PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
@@ -53,6 +56,14 @@ impl<Balance> RewardRate<Balance> {
 	pub fn per_second<B: Into<Balance>>(amount: B) -> Self {
 		Self { period: RewardRatePeriod::PerSecond, amount: amount.into() }
 	}
+
+	pub fn per_block<B: Into<Balance>>(amount: B) -> Self {
+		Self { period: RewardRatePeriod::PerBlock, amount: amount.into() }
+	}
+
+	pub fn per_epoch<B: Into<Balance>>(epoch: NonZeroU64, amount: B) -> Self {
+		Self { period: RewardRatePeriod::PerEpoch(epoch), amount: amount.into() }
+	}
 }
 impl<Balance> RewardRate<Balance>
 where
@@ -62,23 +73,90 @@ where
 	// TODO(benluelo): Encode somehow that one period will always be rewardable. We can most likely
 	// use `Validated` for this, although we may encounter some issues with orphan rules.
 	pub fn amount_per_period(&self) -> Option<Balance> {
-		u128::from(self.period.as_secs().get())
-			.checked_mul(self.amount.into())
-			.map(Into::into)
+		Some(self.amount)
+	}
+
+	/// The reward rate after halving the amount, rounding down.
+	pub fn halved(&self) -> Self {
+		Self { period: self.period.clone(), amount: (self.amount.into() / 2).into() }
 	}
 }
 
 #[derive(RuntimeDebug, PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
 pub enum RewardRatePeriod {
 	PerSecond,
+	/// Rewards are handed out once per block.
+	PerBlock,
+	/// Rewards are handed out once per epoch of the given length, in seconds.
+	PerEpoch(NonZeroU64),
 }
 
 impl RewardRatePeriod {
-	/// Returns the length of the period in seconds.
-	pub fn as_secs(&self) -> NonZeroU64 {
+	/// Returns the length of the period in seconds, or `None` if the period is measured in blocks.
+	pub fn as_secs(&self) -> Option<NonZeroU64> {
 		match self {
 			RewardRatePeriod::PerSecond =>
-				sp_std::num::NonZeroU64::new(1).expect("1 is non-zero; qed;"),
+				Some(sp_std::num::NonZeroU64::new(1).expect("1 is non-zero; qed;")),
+			RewardRatePeriod::PerBlock => None,
+			RewardRatePeriod::PerEpoch(epoch) => Some(*epoch),
+		}
+	}
+}
+
+/// A pre-scheduled series of reward rate changes for a single reward asset, applied by the reward
+/// accumulation hook once the scheduled time is reached.
+#[derive(
+	RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxChanges))]
+pub enum RewardRateSchedule<Balance: Debug + PartialEq + Eq + Clone, MaxChanges: Get<u32>> {
+	/// Halve the reward rate amount every `interval` seconds, the first time at `next`. The
+	/// schedule ends once the amount reaches zero.
+	Halving { next: u64, interval: NonZeroU64 },
+	/// Switch to each reward rate at the paired timestamp, in seconds. Timestamps must be strictly
+	/// increasing.
+	Changes(BoundedVec<(u64, RewardRate<Balance>), MaxChanges>),
+}
+
+impl<Balance, MaxChanges> RewardRateSchedule<Balance, MaxChanges>
+where
+	Balance: Debug + PartialEq + Eq + Clone + Copy + Zero + Into<u128> + From<u128>,
+	MaxChanges: Get<u32>,
+{
+	/// The timestamp of the next scheduled change, if any.
+	pub fn next_change(&self) -> Option<u64> {
+		match self {
+			RewardRateSchedule::Halving { next, .. } => Some(*next),
+			RewardRateSchedule::Changes(changes) => changes.first().map(|(at, _)| *at),
+		}
+	}
+
+	/// Applies the next scheduled change to `reward_rate`. Returns `false` if the schedule has no
+	/// further changes afterwards.
+	pub fn apply_next(&mut self, reward_rate: &mut RewardRate<Balance>) -> bool {
+		match self {
+			RewardRateSchedule::Halving { next, interval } => {
+				*reward_rate = reward_rate.halved();
+				*next = next.saturating_add(interval.get());
+				!reward_rate.amount.is_zero()
+			},
+			RewardRateSchedule::Changes(changes) => {
+				if !changes.is_empty() {
+					*reward_rate = changes.remove(0).1;
+				}
+				!changes.is_empty()
+			},
+		}
+	}
+
+	/// Whether the schedule only contains changes at or after `now_seconds`, in order.
+	pub fn is_valid(&self, now_seconds: u64) -> bool {
+		match self {
+			RewardRateSchedule::Halving { next, .. } => *next >= now_seconds,
+			RewardRateSchedule::Changes(changes) =>
+				!changes.is_empty() &&
+					changes.first().map_or(false, |(at, _)| *at >= now_seconds) &&
+					changes.windows(2).all(|pair| pair[0].0 < pair[1].0),
 		}
 	}
 }
@@ -92,13 +170,18 @@ pub struct RewardUpdate<Balance> {
 }
 
 impl<Balance: Zero> Reward<Balance> {
-	pub fn from_config(reward_config: RewardConfig<Balance>, now_seconds: u64) -> Reward<Balance> {
+	pub fn from_config(
+		reward_config: RewardConfig<Balance>,
+		now_seconds: u64,
+		now_block: u64,
+	) -> Reward<Balance> {
 		Reward {
 			total_rewards: Zero::zero(),
 			claimed_rewards: Zero::zero(),
 			total_dilution_adjustment: Zero::zero(),
 			reward_rate: reward_config.reward_rate,
 			last_updated_timestamp: now_seconds,
+			last_updated_block: now_block,
 		}
 	}
 }
@@ -278,6 +361,7 @@ pub trait Staking {
 	type RewardPoolId;
 	type Balance;
 	type PositionId;
+	type CollectionId;
 
 	/// Stake an amount of protocol asset.
 	///
@@ -318,10 +402,34 @@ pub trait Staking {
 		ratio: Permill,
 	) -> Result<Self::PositionId, DispatchError>;
 
+	/// Merge `other` into `position`. Both positions must be in the same pool and have
+	/// compatible locks; `other` is burned.
+	fn merge(
+		who: &Self::AccountId,
+		position: &Self::PositionId,
+		other: &Self::PositionId,
+	) -> DispatchResult;
+
 	/// Claim remaining reward earned up to this point in time.
 	///
 	/// Arguments
 	/// * `who` - the account to transfer the final claimed rewards to.
 	/// * `position` - The uniquely identifying NFT from which we will compute the rewards.
 	fn claim(who: &Self::AccountId, position: &Self::PositionId) -> DispatchResult;
+
+	/// The pool whose positions are represented by the financial NFTs of `collection`, if any.
+	fn pool_of_collection(collection: &Self::CollectionId) -> Option<Self::RewardPoolId>;
+}
+
+/// Vote-escrowed governance weight derived from staked positions.
+pub trait StakingVotingPower {
+	type AccountId;
+	type RewardPoolId;
+	type Balance;
+
+	/// The current voting power of `who` in the pool `pool_id`.
+	///
+	/// Each position contributes its stake scaled by its remaining lock time relative to the
+	/// longest lock the pool offers, so voting power decays linearly to zero as locks expire.
+	fn voting_power(pool_id: &Self::RewardPoolId, who: &Self::AccountId) -> Self::Balance;
 }
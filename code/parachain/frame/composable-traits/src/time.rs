@@ -26,6 +26,7 @@ pub const MS_PER_YEAR_NAIVE: DurationSeconds = SECONDS_PER_YEAR_NAIVE * 1000;
 pub enum TimeReleaseFunction {
 	LinearDecrease(LinearDecrease),
 	StairstepExponentialDecrease(StairstepExponentialDecrease),
+	ContinuousExponentialDecrease(ContinuousExponentialDecrease),
 }
 
 impl Default for TimeReleaseFunction {
@@ -48,3 +49,10 @@ pub struct StairstepExponentialDecrease {
 	// Drop per unit of `step`.
 	pub cut: Permill,
 }
+
+#[derive(Default, Decode, Encode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct ContinuousExponentialDecrease {
+	/// Seconds it takes for the amount to halve. Decay is continuous, not stepped at each half
+	/// life.
+	pub half_life: DurationSeconds,
+}
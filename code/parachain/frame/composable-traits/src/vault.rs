@@ -2,7 +2,7 @@ use codec::Codec;
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::Perquintill,
-	sp_std::{collections::btree_map::BTreeMap, fmt::Debug},
+	sp_std::{collections::btree_map::BTreeMap, fmt::Debug, vec::Vec},
 };
 use scale_info::TypeInfo;
 
@@ -52,6 +52,21 @@ impl<Balance, BlockNumber> Deposit<Balance, BlockNumber> {
 	}
 }
 
+/// A withdrawal which could not be honoured immediately, because the vault's idle balance was
+/// deployed to strategies. The LP tokens have already been burned, and `asset_amount` is owed to
+/// `account` once the vault has enough liquidity.
+#[derive(Copy, Clone, Encode, Decode, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct WithdrawalRequest<AccountId, Balance, BlockNumber> {
+	/// The account to which the assets are paid out.
+	pub account: AccountId,
+	/// Amount of LP tokens burned when the request was queued.
+	pub lp_amount: Balance,
+	/// Amount of underlying assets owed, fixed at the share price when the request was queued.
+	pub asset_amount: Balance,
+	/// The block at which the request was queued.
+	pub requested_at: BlockNumber,
+}
+
 #[derive(Clone, Encode, Decode, Default, Debug, PartialEq, Eq, TypeInfo)]
 pub struct VaultConfig<AccountId, CurrencyId>
 where
@@ -162,6 +177,33 @@ pub trait CapabilityVault: Vault {
 	fn deposits_allowed(vault_id: &Self::VaultId) -> Result<bool, DispatchError>;
 }
 
+/// A vault which queues withdrawals it cannot honour from its idle balance. Requests are settled in
+/// FIFO order as strategies return capital to the vault.
+pub trait WithdrawalQueueVault: Vault {
+	/// Burns `lp_amount` LP tokens of `from` and appends a request for the underlying assets to the
+	/// back of the queue. Returns the amount of underlying assets owed.
+	fn request_withdrawal(
+		vault_id: &Self::VaultId,
+		from: &Self::AccountId,
+		lp_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Pays out queued requests, starting at the front of the queue, until the idle balance of the
+	/// vault is insufficient for the next request. Returns the number of settled requests.
+	fn settle_withdrawals(vault_id: &Self::VaultId) -> Result<u32, DispatchError>;
+
+	/// The pending requests of the vault, with the next request to be settled first.
+	fn withdrawal_queue(
+		vault_id: &Self::VaultId,
+	) -> Result<
+		Vec<WithdrawalRequest<Self::AccountId, Self::Balance, Self::BlockNumber>>,
+		DispatchError,
+	>;
+
+	/// Sum of the underlying assets owed to all pending requests of the vault.
+	fn pending_withdrawals(vault_id: &Self::VaultId) -> Result<Self::Balance, DispatchError>;
+}
+
 /// A vault which can be used by different strategies, such as pallets and smart contracts, to
 /// efficiently use capital. An example may be a vault which allocates 40% in a lending protocol,
 /// and 60% of the stored capital in a DEX.
@@ -199,4 +241,14 @@ pub trait ReportableStrategicVault: StrategicVault {
 		strategy: &Self::AccountId,
 		report: &Self::Report,
 	) -> Result<(), DispatchError>;
+
+	/// Writes `loss` off the balance `strategy` owes to the vault. The loss is socialized to the
+	/// holders of the vault's LP tokens through the share price.
+	///
+	/// The loss is capped at the balance of `strategy`, returns the amount written off.
+	fn report_loss(
+		vault: &Self::VaultId,
+		strategy: &Self::AccountId,
+		loss: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
 }
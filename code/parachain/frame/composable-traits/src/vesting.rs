@@ -1,7 +1,7 @@
 use core::fmt::Debug;
 
 use codec::{HasCompact, MaxEncodedLen};
-use composable_support::math::safe::SafeMul;
+use composable_support::math::safe::{SafeAdd, SafeMul};
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -90,10 +90,36 @@ impl<Id: Clone + Copy + Eq + PartialEq + Debug, MaxVestingSchedules: Get<u32>>
 	}
 }
 
+/// Shape of the release of a vesting schedule.
+///
+/// Offsets are expressed in number of `window.period`s passed since `window.start`, so that
+/// shapes work with both `MomentBased` and `BlockNumberBased` windows. With a `window.period` of
+/// one, offsets are plain numbers of moments or blocks.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VestingShape<Balance> {
+	/// `per_period` is released at the end of each of the `period_count` periods.
+	Linear,
+	/// Nothing is released before `cliff_periods` periods have passed, at which point
+	/// `cliff_amount` is released at once. `per_period` is then released at the end of each of
+	/// the `period_count` periods following the cliff.
+	Cliff { cliff_periods: u32, cliff_amount: Balance },
+	/// For each `(periods, amount)` checkpoint, `amount` is released once `periods` periods have
+	/// passed. Checkpoints must be sorted by strictly increasing `periods` and have non-zero
+	/// amounts, while `per_period` and `period_count` must be zero.
+	Checkpoints(Vec<(u32, Balance)>),
+}
+
+impl<Balance> Default for VestingShape<Balance> {
+	fn default() -> Self {
+		VestingShape::Linear
+	}
+}
+
 /// The vesting schedule.
 ///
 /// Benefits would be granted gradually, `per_period` amount every `window.period`
-/// of blocks after `window.start`.
+/// of blocks after `window.start`, unless the `shape` of the schedule says otherwise.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasCompact> {
@@ -107,6 +133,9 @@ pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasC
 	pub per_period: Balance,
 	/// Amount already claimed
 	pub already_claimed: Balance,
+	/// Shape of the release
+	#[cfg_attr(feature = "std", serde(default))]
+	pub shape: VestingShape<Balance>,
 }
 
 /// Vesting schedule input, which is used to create a VestingSchedule.
@@ -121,6 +150,9 @@ pub struct VestingScheduleInfo<BlockNumber, Moment, Balance: HasCompact> {
 	/// Amount of tokens to release per vest
 	#[codec(compact)]
 	pub per_period: Balance,
+	/// Shape of the release
+	#[cfg_attr(feature = "std", serde(default))]
+	pub shape: VestingShape<Balance>,
 }
 
 pub enum VestingWindowResult<BlockNumber, Moment> {
@@ -143,16 +175,46 @@ impl<
 		}
 	}
 
+	/// Check if the shape is consistent with the linear release parameters, see
+	/// [`VestingShape`].
+	pub fn is_valid_shape(&self) -> bool {
+		match &self.shape {
+			VestingShape::Linear | VestingShape::Cliff { .. } => true,
+			VestingShape::Checkpoints(checkpoints) =>
+				self.period_count.is_zero() &&
+					self.per_period.is_zero() &&
+					!checkpoints.is_empty() &&
+					checkpoints.iter().all(|(_, amount)| !amount.is_zero()) &&
+					checkpoints.windows(2).all(|pair| match pair {
+						[(previous, _), (next, _)] => previous < next,
+						_ => true,
+					}),
+		}
+	}
+
+	/// Returns the number of periods until everything is released, `None` if calculation
+	/// overflows.
+	fn periods_to_end(&self) -> Option<u32> {
+		match &self.shape {
+			VestingShape::Linear => Some(self.period_count),
+			VestingShape::Cliff { cliff_periods, .. } =>
+				cliff_periods.checked_add(self.period_count),
+			VestingShape::Checkpoints(checkpoints) =>
+				Some(checkpoints.last().map(|(periods, _)| *periods).unwrap_or_default()),
+		}
+	}
+
 	/// Returns the end of all periods, `None` if calculation overflows.
 	pub fn end(&self) -> Option<VestingWindowResult<BlockNumber, Moment>> {
-		// period * period_count + start
+		// period * periods_to_end + start
+		let periods_to_end = self.periods_to_end()?;
 		match self.window {
 			VestingWindow::BlockNumberBased { start, period } => period
-				.checked_mul(&self.period_count.into())?
+				.checked_mul(&periods_to_end.into())?
 				.checked_add(&start)
 				.map(|val| VestingWindowResult::<BlockNumber, Moment>::BlockNumberResult(val)),
 			VestingWindow::MomentBased { start, period } => period
-				.checked_mul(&self.period_count.into())?
+				.checked_mul(&periods_to_end.into())?
 				.checked_add(&start)
 				.map(|val| VestingWindowResult::<BlockNumber, Moment>::MomentResult(val)),
 		}
@@ -160,7 +222,14 @@ impl<
 
 	/// Returns all locked amount, `None` if calculation overflows.
 	pub fn total_amount(&self) -> Result<Balance, ArithmeticError> {
-		self.per_period.safe_mul(&self.period_count.into())
+		let linear_amount = self.per_period.safe_mul(&self.period_count.into())?;
+		match &self.shape {
+			VestingShape::Linear => Ok(linear_amount),
+			VestingShape::Cliff { cliff_amount, .. } => linear_amount.safe_add(cliff_amount),
+			VestingShape::Checkpoints(checkpoints) => checkpoints
+				.iter()
+				.try_fold(linear_amount, |total, (_, amount)| total.safe_add(amount)),
+		}
 	}
 
 	/// Returns locked amount for a given schedule of VestingWindow.
@@ -169,27 +238,43 @@ impl<
 	/// non-overflow total amount), and it should be guaranteed by callers.
 	pub fn locked_amount(&self, block_number: BlockNumber, moment: Moment) -> Balance {
 		// full = (time - start) / period
+		let full: u32 = match self.window {
+			VestingWindow::BlockNumberBased { start, period } => block_number
+				.saturating_sub(start)
+				.checked_div(&period)
+				.expect("ensured non-zero period; qed")
+				.unique_saturated_into(),
+			VestingWindow::MomentBased { start, period } => moment
+				.saturating_sub(start)
+				.checked_div(&period)
+				.expect("ensured non-zero period; qed")
+				.unique_saturated_into(),
+		};
 		// unrealized = period_count - full
 		// per_period * unrealized
-		let unrealized = match self.window {
-			VestingWindow::BlockNumberBased { start, period } => {
-				let full = block_number
-					.saturating_sub(start)
-					.checked_div(&period)
-					.expect("ensured non-zero period; qed");
-				self.period_count.saturating_sub(full.unique_saturated_into())
-			},
-			VestingWindow::MomentBased { start, period } => {
-				let full = moment
-					.saturating_sub(start)
-					.checked_div(&period)
-					.expect("ensured non-zero period; qed");
-				self.period_count.saturating_sub(full.unique_saturated_into())
-			},
+		let linear_locked_amount = |full: u32| {
+			let unrealized = self.period_count.saturating_sub(full);
+			self.per_period
+				.checked_mul(&unrealized.into())
+				.expect("ensured non-overflow total amount; qed")
 		};
-		self.per_period
-			.checked_mul(&unrealized.into())
-			.expect("ensured non-overflow total amount; qed")
+		match &self.shape {
+			VestingShape::Linear => linear_locked_amount(full),
+			VestingShape::Cliff { cliff_periods, cliff_amount } =>
+				if full < *cliff_periods {
+					linear_locked_amount(0)
+						.checked_add(cliff_amount)
+						.expect("ensured non-overflow total amount; qed")
+				} else {
+					linear_locked_amount(full - cliff_periods)
+				},
+			VestingShape::Checkpoints(checkpoints) => checkpoints
+				.iter()
+				.filter(|(periods, _)| *periods > full)
+				.fold(Zero::zero(), |locked, (_, amount)| {
+					locked.checked_add(amount).expect("ensured non-overflow total amount; qed")
+				}),
+		}
 	}
 
 	pub fn from_input(
@@ -202,6 +287,7 @@ impl<
 			per_period: vesting_schedule_input.per_period,
 			period_count: vesting_schedule_input.period_count,
 			already_claimed: Zero::zero(),
+			shape: vesting_schedule_input.shape,
 		}
 	}
 }
@@ -219,6 +305,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		assert!(!vesting_schedule_time_based.is_zero_period());
 		vesting_schedule_time_based.window = MomentBased { start: 1, period: 0 };
@@ -230,6 +317,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		assert!(!vesting_schedule_block_number_based.is_zero_period());
 		vesting_schedule_block_number_based.window = BlockNumberBased { start: 1, period: 0 };
@@ -244,6 +332,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		match vesting_schedule_time_based.end() {
 			None => {},
@@ -258,6 +347,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		match vesting_schedule_block_number_based.end() {
 			None => {},
@@ -276,6 +366,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_eq!(vesting_schedule.total_amount().unwrap(), 100)
 	}
@@ -289,6 +380,7 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_eq!(vesting_schedule_time_based.locked_amount(1, 1), 100);
 		assert_eq!(vesting_schedule_time_based.locked_amount(1, 11), 99);
@@ -300,9 +392,58 @@ mod tests {
 			period_count: 100,
 			per_period: 1u64,
 			already_claimed: 0_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(1, 1), 100);
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(11, 1), 99);
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(1001, 1), 0);
 	}
+
+	#[test]
+	fn test_cliff_shape() {
+		let vesting_schedule = VestingSchedule::<u128, u64, u64, u64> {
+			vesting_schedule_id: 8u128,
+			window: BlockNumberBased { start: 1u64, period: 10u64 },
+			period_count: 10,
+			per_period: 1u64,
+			already_claimed: 0_u64,
+			shape: VestingShape::Cliff { cliff_periods: 5, cliff_amount: 50u64 },
+		};
+		assert!(vesting_schedule.is_valid_shape());
+		assert_eq!(vesting_schedule.total_amount().unwrap(), 60);
+		assert!(matches!(
+			vesting_schedule.end(),
+			Some(VestingWindowResult::BlockNumberResult(151))
+		));
+		assert_eq!(vesting_schedule.locked_amount(1, 1), 60);
+		assert_eq!(vesting_schedule.locked_amount(50, 1), 60);
+		assert_eq!(vesting_schedule.locked_amount(51, 1), 10);
+		assert_eq!(vesting_schedule.locked_amount(61, 1), 9);
+		assert_eq!(vesting_schedule.locked_amount(151, 1), 0);
+	}
+
+	#[test]
+	fn test_checkpoints_shape() {
+		let mut vesting_schedule = VestingSchedule::<u128, u32, u64, u64> {
+			vesting_schedule_id: 9u128,
+			window: MomentBased { start: 1u64, period: 10u64 },
+			period_count: 0,
+			per_period: 0u64,
+			already_claimed: 0_u64,
+			shape: VestingShape::Checkpoints(vec![(1, 10u64), (3, 20u64), (10, 30u64)]),
+		};
+		assert!(vesting_schedule.is_valid_shape());
+		assert_eq!(vesting_schedule.total_amount().unwrap(), 60);
+		assert!(matches!(vesting_schedule.end(), Some(VestingWindowResult::MomentResult(101))));
+		assert_eq!(vesting_schedule.locked_amount(1, 10), 60);
+		assert_eq!(vesting_schedule.locked_amount(1, 11), 50);
+		assert_eq!(vesting_schedule.locked_amount(1, 31), 30);
+		assert_eq!(vesting_schedule.locked_amount(1, 100), 30);
+		assert_eq!(vesting_schedule.locked_amount(1, 101), 0);
+
+		vesting_schedule.shape = VestingShape::Checkpoints(vec![(3, 20u64), (1, 10u64)]);
+		assert!(!vesting_schedule.is_valid_shape());
+		vesting_schedule.shape = VestingShape::Checkpoints(vec![]);
+		assert!(!vesting_schedule.is_valid_shape());
+	}
 }
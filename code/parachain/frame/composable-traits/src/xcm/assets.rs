@@ -9,7 +9,7 @@ use sp_std::vec::Vec;
 use xcm::latest::MultiLocation;
 
 use crate::{
-	assets::Asset,
+	assets::{Asset, AssetInfo},
 	currency::{Exponent, Rational64},
 };
 
@@ -85,7 +85,7 @@ pub trait RemoteAssetRegistryInspect {
 	// common basic fee for sure fn min_xcm_native_in_fee(parachain_id: Id) ->
 	// Option<Self::Balance>;
 
-	/// Return information about foreign assets stored on assets registry
+	/// Return information about assets stored on assets registry
 	fn get_foreign_assets_list() -> Vec<Asset<Self::Balance, Self::AssetNativeLocation>>;
 }
 
@@ -94,25 +94,20 @@ pub trait RemoteAssetRegistryMutate {
 	type AssetNativeLocation;
 	type Balance;
 
-	/// Set asset native location.
+	/// Set asset metadata, including its native location.
 	///
 	/// Adds mapping between native location and local asset id and vice versa.
 	/// It is assumed that it is possible to use origin as chain who holds reserve of tokens.
 	///
 	/// Inputs:
-	/// `asset_id` local asset id created using `CurrencyFactory`
-	/// `location` - remote location
-	/// `ed` - minimal amount of registered asset allowed to form account
+	/// `asset_id` - local asset id created using `CurrencyFactory`
 	/// `ratio` - of native asset to remote; amount of foreign asset multiplied by ratio will give
-	/// equivalent amount of native; `decimals` - if asset decimals is not 12, than value must be
-	/// provided Emits `LocationSet` event when successful.
-	/// `asset_id` - local asset id create via `CurrencyFactory`
-	/// `location` - remote location relative to this chain
-	fn set_reserve_location(
+	/// equivalent amount of native
+	/// `info` - metadata of the asset, replaces the previous one
+	fn set_asset_info(
 		asset_id: Self::AssetId,
-		location: Self::AssetNativeLocation,
 		ratio: Rational64,
-		decimals: Option<Exponent>,
+		info: AssetInfo<Self::Balance, Self::AssetNativeLocation>,
 	) -> DispatchResult;
 
 	/// allows change  ratio of how much remote assets is needed for unit of native
@@ -72,6 +72,7 @@ pub mod pallet {
 	use codec::{Codec, FullCodec};
 	use composable_support::{
 		math::safe::{SafeAdd, SafeSub},
+		signature_verification,
 		types::{EcdsaSignature, EthereumAddress},
 	};
 	use frame_support::{
@@ -676,6 +677,29 @@ pub mod pallet {
 				);
 				Ok(RemoteAccount::RelayChain(relay_account))
 			},
+			Proof::Solana(solana_address, solana_proof) => {
+				let reward_account_encoded =
+					reward_account.using_encoded(signature_verification::get_encoded_vec);
+				let solana_address = signature_verification::solana_verify(
+					prefix,
+					&reward_account_encoded,
+					solana_address,
+					&solana_proof,
+				)
+				.map_err(|_| Error::<T>::InvalidProof)?;
+				Ok(RemoteAccount::Solana(solana_address))
+			},
+			Proof::Bitcoin(bitcoin_proof) => {
+				let reward_account_encoded =
+					reward_account.using_encoded(signature_verification::get_encoded_vec);
+				let bitcoin_address = signature_verification::bitcoin_recover(
+					prefix,
+					&reward_account_encoded,
+					&bitcoin_proof,
+				)
+				.map_err(|_| Error::<T>::InvalidProof)?;
+				Ok(RemoteAccount::Bitcoin(bitcoin_address))
+			},
 		}
 	}
 
@@ -1,5 +1,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::types::{
+	BitcoinAddress, BitcoinSignature, EcdsaSignature, EthereumAddress, SolanaPublicKey,
+	SolanaSignature,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{MultiSignature, RuntimeDebug};
 
@@ -14,10 +17,14 @@ pub struct Reward<Balance, Period> {
 pub enum Proof<AccountId> {
 	RelayChain(AccountId, MultiSignature),
 	Ethereum(EcdsaSignature),
+	Solana(SolanaPublicKey, SolanaSignature),
+	Bitcoin(BitcoinSignature),
 }
 
 #[derive(Hash, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum RemoteAccount<AccountId> {
 	RelayChain(AccountId),
 	Ethereum(EthereumAddress),
+	Solana(SolanaPublicKey),
+	Bitcoin(BitcoinAddress),
 }
@@ -9,7 +9,13 @@ use crate::{
 	Error, Event, RemoteAccountOf, RewardAmountOf, VestingPeriodOf,
 };
 use codec::Encode;
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::{
+	signature_verification,
+	types::{
+		BitcoinAddress, BitcoinSignature, EcdsaSignature, EthereumAddress, SolanaPublicKey,
+		SolanaSignature,
+	},
+};
 use composable_tests_helpers::test::helper::RuntimeTrait;
 use frame_support::{
 	assert_noop, assert_ok,
@@ -17,6 +23,7 @@ use frame_support::{
 };
 use hex_literal::hex;
 use sp_core::{ed25519, storage::StateVersion, Pair};
+use sp_io::hashing::sha2_256;
 
 fn with_rewards<R>(
 	count: u128,
@@ -517,6 +524,57 @@ fn test_valid_eth_hardcoded() {
 	});
 }
 
+fn associate_and_claim_all(remote_account: RemoteAccountOf<Test>, proof: Proof<[u8; 32]>) {
+	let reward_amount = DEFAULT_REWARD;
+	let rewards = vec![(remote_account, reward_amount, DEFAULT_VESTING_PERIOD)];
+
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&CrowdloanRewards::account_id(), reward_amount);
+		assert_ok!(CrowdloanRewards::populate(RuntimeOrigin::root(), rewards));
+		assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+		assert_ok!(CrowdloanRewards::associate(RuntimeOrigin::none(), ALICE, proof));
+		Timestamp::set_timestamp(DEFAULT_VESTING_PERIOD);
+		assert_ok!(CrowdloanRewards::claim(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(CrowdloanRewards::claimed_rewards(), CrowdloanRewards::total_rewards());
+	});
+}
+
+#[test]
+fn test_valid_solana() {
+	let solana_account = ed25519::Pair::from_seed(&[42; 32]);
+	let solana_address = SolanaPublicKey(solana_account.public().0);
+	let msg = signature_verification::solana_signable_message(
+		PROOF_PREFIX,
+		&ALICE.using_encoded(signature_verification::get_encoded_vec),
+	);
+	let proof = Proof::Solana(solana_address, SolanaSignature(solana_account.sign(&msg).0));
+
+	associate_and_claim_all(RemoteAccount::Solana(solana_address), proof);
+}
+
+#[test]
+fn test_valid_bitcoin() {
+	let bitcoin_account = libsecp256k1::SecretKey::parse(&[42; 32]).expect("valid key; QED");
+	let bitcoin_address = BitcoinAddress::P2pkh(signature_verification::hash160(
+		&libsecp256k1::PublicKey::from_secret_key(&bitcoin_account).serialize_compressed(),
+	));
+	let msg = sha2_256(&sha2_256(&signature_verification::bitcoin_signable_message(
+		PROOF_PREFIX,
+		&ALICE.using_encoded(signature_verification::get_encoded_vec),
+	)));
+	let (sig, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), &bitcoin_account);
+	let mut bitcoin_proof = [0_u8; 65];
+	// compressed P2PKH header
+	bitcoin_proof[0] = 31 + recovery_id.serialize();
+	bitcoin_proof[1..].copy_from_slice(&sig.serialize());
+
+	associate_and_claim_all(
+		RemoteAccount::Bitcoin(bitcoin_address),
+		Proof::Bitcoin(BitcoinSignature(bitcoin_proof)),
+	);
+}
+
 mod test_prevalidate_association {
 	use super::{
 		with_rewards, with_rewards_default, ClaimKey, DEFAULT_NB_OF_CONTRIBUTORS,
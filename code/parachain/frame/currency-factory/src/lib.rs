@@ -35,6 +35,7 @@
 pub use pallet::*;
 pub use weights::{SubstrateWeight, WeightInfo};
 
+pub mod migrations;
 mod ranges;
 mod weights;
 
@@ -52,10 +53,7 @@ pub mod pallet {
 		ranges::{Range, RangeId, Ranges},
 		weights::WeightInfo,
 	};
-	use composable_traits::{
-		assets::BasicAssetMetadata,
-		currency::{AssetIdLike, BalanceLike, CurrencyFactory, Exponent, LocalAssets},
-	};
+	use composable_traits::currency::{AssetIdLike, BalanceLike, CurrencyFactory};
 	use frame_support::{pallet_prelude::*, traits::EnsureOrigin, transactional, PalletId};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
@@ -66,6 +64,8 @@ pub mod pallet {
 	// cspell:disable-next
 	pub const PALLET_ID: PalletId = PalletId(*b"pal_curf");
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -94,15 +94,23 @@ pub mod pallet {
 
 		type Balance: BalanceLike;
 
-		///  can add new ranges or assign metadata
+		///  can add new ranges
 		type AddOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+	}
+
 	#[pallet::storage]
 	#[pallet::getter(fn asset_id_rages)]
 	// Storage is initialized using RangesOnEmpty, so ValueQuery is allowed.
@@ -114,18 +122,6 @@ pub mod pallet {
 	#[pallet::getter(fn get_assets_ed)]
 	pub type AssetEd<T: Config> = StorageMap<_, Twox128, T::AssetId, T::Balance, OptionQuery>;
 
-	// technically that can be stored offchain, but other parachains do int on chain too (and some
-	// other blockchains) also may do routing for approved symbols based, not on ids, too
-	#[pallet::storage]
-	#[pallet::getter(fn get_assets_metadata)]
-	pub type AssetMetadata<T: Config> = StorageMap<
-		_,
-		Twox128,
-		T::AssetId,
-		composable_traits::assets::BasicAssetMetadata,
-		OptionQuery,
-	>;
-
 	#[pallet::type_value]
 	pub fn RangesOnEmpty<T: Config>() -> Ranges<T::AssetId> {
 		Ranges::new()
@@ -147,20 +143,6 @@ pub mod pallet {
 
 			Ok(().into())
 		}
-
-		/// Sets metadata
-		#[pallet::weight(T::WeightInfo::set_metadata())]
-		pub fn set_metadata(
-			origin: OriginFor<T>,
-			asset_id: T::AssetId,
-			metadata: BasicAssetMetadata,
-		) -> DispatchResultWithPostInfo {
-			T::AddOrigin::ensure_origin(origin)?;
-			// note: if will decide to build route on symbol, than better to make second map
-			// from symbol to asset to check unique
-			AssetMetadata::<T>::insert(asset_id, metadata);
-			Ok(().into())
-		}
 	}
 
 	impl<T: Config> CurrencyFactory for Pallet<T> {
@@ -191,13 +173,4 @@ pub mod pallet {
 				.expect("u128 is made of u32 chunks")
 		}
 	}
-
-	impl<T: Config> LocalAssets<T::AssetId> for Pallet<T> {
-		// NOTE: it is not true as of now, so we should not rely on chain for this.
-		// NOTE: XCM does not support decimals and Statemine can have assets without decimals
-		// NOTE: hence we cannot default to 12 decimals robustly
-		fn decimals(_currency_id: T::AssetId) -> Result<Exponent, DispatchError> {
-			Ok(12)
-		}
-	}
 }
@@ -0,0 +1,28 @@
+//! Storage migrations of the currency factory pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Removes the metadata of assets, which is registered in the assets registry instead.
+
+	use super::*;
+	use composable_traits::assets::BasicAssetMetadata;
+	use frame_support::{pallet_prelude::*, traits::GetStorageVersion};
+
+	/// Metadata of assets, replaced by the metadata of the assets registry.
+	#[frame_support::storage_alias]
+	type AssetMetadata<T: Config> =
+		StorageMap<Pallet<T>, Twox128, <T as Config>::AssetId, BasicAssetMetadata>;
+
+	/// The metadata was never read on chain, so it is dropped rather than moved.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let removed = AssetMetadata::<T>::drain().count() as u64;
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(removed + 1, removed + 1)
+	}
+}
@@ -8,7 +8,6 @@ use sp_std::marker::PhantomData;
 
 pub trait WeightInfo {
 	fn add_range() -> Weight;
-	fn set_metadata() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -18,18 +17,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-
-	fn set_metadata() -> Weight {
-		Weight::from_ref_time(10_0000)
-	}
 }
 
 impl WeightInfo for () {
 	fn add_range() -> Weight {
 		Weight::from_ref_time(10_0000)
 	}
-
-	fn set_metadata() -> Weight {
-		Weight::from_ref_time(10_0000)
-	}
 }
@@ -1,5 +1,6 @@
 use crate as dex_router;
 use crate::mock_fnft::MockFnft;
+use composable_traits::currency::{Exponent, LocalAssets};
 use frame_support::{parameter_types, traits::Everything, PalletId};
 use frame_system as system;
 use orml_traits::{parameter_type_with_key, LockIdentifier};
@@ -9,7 +10,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	Permill,
+	DispatchError, Permill,
 };
 use system::{EnsureRoot, EnsureSigned};
 
@@ -173,6 +174,7 @@ parameter_types! {
 	pub const StakingRewardsLockId: LockIdentifier = *b"stk_lock";
 	pub const MaxStakingDurationPresets: u32 = 10;
 	pub const MaxRewardConfigsPerPool: u32 = 10;
+	pub const MaxRewardRateChanges: u32 = 10;
 	pub const PicaAssetId : CurrencyId = 1;
 	pub const PbloAssetId : CurrencyId = 2;
 	pub const XPicaAssetId: CurrencyId = 101;
@@ -194,6 +196,7 @@ impl pallet_staking_rewards::Config for Test {
 	type PalletId = StakingRewardsPalletId;
 	type MaxStakingDurationPresets = MaxStakingDurationPresets;
 	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxRewardRateChanges = MaxRewardRateChanges;
 	type RewardPoolCreationOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 	type RewardPoolUpdateOrigin = EnsureRoot<Self::AccountId>;
@@ -210,6 +213,13 @@ impl pallet_staking_rewards::Config for Test {
 	type ExistentialDeposits = ExistentialDeposits;
 }
 
+pub struct Decimals;
+impl LocalAssets<AssetId> for Decimals {
+	fn decimals(_currency_id: AssetId) -> Result<Exponent, DispatchError> {
+		Ok(12)
+	}
+}
+
 impl pallet_pablo::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -219,7 +229,7 @@ impl pallet_pablo::Config for Test {
 	type Convert = ConvertInto;
 	type PoolId = PoolId;
 	type PalletId = TestPalletID;
-	type LocalAssets = LpTokenFactory;
+	type LocalAssets = Decimals;
 	type PoolCreationOrigin = EnsureSigned<Self::AccountId>;
 	type EnableTwapOrigin = EnsureRoot<AccountId>;
 	type Time = Timestamp;
@@ -2,7 +2,7 @@
 use composable_traits::fnft::FinancialNft;
 use frame_support::{
 	dispatch::DispatchResult,
-	traits::tokens::nonfungibles::{Create, Inspect, Mutate},
+	traits::tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
 };
 use sp_runtime::DispatchError;
 
@@ -17,6 +17,27 @@ impl Inspect<u128> for MockFnft {
 	}
 }
 
+impl InspectEnumerable<u128> for MockFnft {
+	fn collections() -> Box<dyn Iterator<Item = Self::CollectionId>> {
+		Box::new(core::iter::empty())
+	}
+
+	fn items(_collection: &Self::CollectionId) -> Box<dyn Iterator<Item = Self::ItemId>> {
+		Box::new(core::iter::empty())
+	}
+
+	fn owned(_who: &u128) -> Box<dyn Iterator<Item = (Self::CollectionId, Self::ItemId)>> {
+		Box::new(core::iter::empty())
+	}
+
+	fn owned_in_collection(
+		_collection: &Self::CollectionId,
+		_who: &u128,
+	) -> Box<dyn Iterator<Item = Self::ItemId>> {
+		Box::new(core::iter::empty())
+	}
+}
+
 impl FinancialNft<u128> for MockFnft {
 	fn asset_account(_collection: &Self::CollectionId, _instance: &Self::ItemId) -> u128 {
 		todo!()
@@ -1,5 +1,8 @@
 use super::*;
-use crate::Pallet as DutchAuction;
+use crate::{
+	types::{ClearingMode, SellOptions},
+	Pallet as DutchAuction,
+};
 use codec::Decode;
 use composable_traits::{
 	defi::{CurrencyPair, DeFiComposableConfig, Ratio, Sell, Take},
@@ -86,6 +89,7 @@ benchmarks! {
 		}: _(
 			caller,
 			sell,
+			<_>::default(),
 			<_>::default()
 		)
 	take {
@@ -96,7 +100,7 @@ benchmarks! {
 		mint_native_tokens::<T>(&account_id);
 		<T as pallet::Config>::MultiCurrency::mint_into(sell.pair.base, &account_id, amount).unwrap();
 		<T as pallet::Config>::MultiCurrency::mint_into(sell.pair.quote, &account_id, amount).unwrap();
-		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default()).unwrap();
+		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default(), <_>::default()).unwrap();
 		let order_id = OrdersIndex::<T>::get();
 		let take_order = take_identity::<T>();
 		DutchAuction::<T>::take(caller.clone().into(), order_id, take_order.clone()).unwrap();
@@ -112,7 +116,7 @@ benchmarks! {
 		let amount: BalanceOf<T> = 1_000_000_000_000_u64.into();
 		mint_native_tokens::<T>(&account_id);
 		<T as pallet::Config>::MultiCurrency::mint_into(sell.pair.base, &account_id, amount).unwrap();
-		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default()).unwrap();
+		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default(), <_>::default()).unwrap();
 		let order_id = OrdersIndex::<T>::get();
 		}: _(
 			caller,
@@ -145,13 +149,35 @@ benchmarks! {
 		mint_native_tokens::<T>(&account_id);
 		<T as pallet::Config>::MultiCurrency::mint_into(sell.pair.base, &account_id, amount).unwrap();
 		<T as pallet::Config>::MultiCurrency::mint_into(sell.pair.quote, &account_id, amount).unwrap();
-		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default()).unwrap();
+		DutchAuction::<T>::ask(caller.clone().into(), sell, <_>::default(), <_>::default()).unwrap();
 		let order_id = OrdersIndex::<T>::get();
 		let take_order = take_identity::<T>();
 		DutchAuction::<T>::take(caller.into(), order_id, take_order).unwrap();
 	} : {
 		<DutchAuction::<T> as Hooks<BlockNumberFor<T>>>::on_finalize(T::BlockNumber::default())
 	}
+	reserve_price_checks {
+		let n in 1 .. T::ReservePriceChecksPerBlock::get();
+		let account_id: T::AccountId = whitelisted_caller();
+		let caller = RawOrigin::Signed(account_id.clone());
+		let amount: BalanceOf<T> = 1_000_000_000_000_u64.into();
+		mint_native_tokens::<T>(&account_id);
+		<T as pallet::Config>::MultiCurrency::mint_into(sell_identity::<T>().pair.base, &account_id, amount).unwrap();
+		// reserve above the starting price, so all orders are cancelled
+		let options = SellOptions {
+			reserve_price: Some(Ratio::saturating_from_integer(2_u64)),
+			clearing: ClearingMode::PayAsBid,
+		};
+		for _ in 0..n {
+			let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+			DutchAuction::<T>::ask(caller.clone().into(), sell_identity::<T>(), configuration, options.clone()).unwrap();
+		}
+	} : {
+		DutchAuction::<T>::cancel_orders_below_reserve_price()
+	}
+	verify {
+		assert_eq!(SellOrders::<T>::iter().count(), 0);
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -1,8 +1,11 @@
 use crate::{math::*, prelude::*, support::DefiMultiReservableCurrency, types::*};
 pub use crate::{pallet::*, weights::WeightInfo};
-use composable_support::abstractions::utils::increment::Increment;
+use composable_support::abstractions::{
+	block_fold::{FoldStorage, FoldStrategy},
+	utils::increment::Increment,
+};
 use composable_traits::{
-	defi::{DeFiComposableConfig, Sell, SellEngine, Take},
+	defi::{DeFiComposableConfig, LiftedFixedBalance, Sell, SellEngine, SellEngineCallback, Take},
 	time::TimeReleaseFunction,
 	xcm::XcmSellInitialResponseTransact,
 };
@@ -11,7 +14,10 @@ use frame_support::{
 	transactional,
 };
 use orml_traits::MultiReservableCurrency;
-use sp_runtime::{traits::AccountIdConversion, DispatchError};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating},
+	DispatchError,
+};
 use sp_std::convert::TryInto;
 use xcm::latest::{prelude::*, MultiAsset, WeightLimit::Unlimited};
 
@@ -28,12 +34,19 @@ impl<T: Config> Pallet<T> {
 				from_to: ref seller,
 				configuration: _,
 				total_amount_received,
+				options,
 			}) = order_item
 			{
 				let mut amount_received = T::Balance::zero();
+				let mut amount_sold = T::Balance::zero();
 				// users payed N * WEIGHT before, we here pay N * (log N - 1) * Weight. We can
 				// retain pure N by first served principle so, not highest price.
 				takes.sort_by(|a, b| b.take.limit.cmp(&a.take.limit));
+				let clearing_limit = match options.clearing {
+					ClearingMode::PayAsBid => None,
+					ClearingMode::Uniform =>
+						Self::uniform_clearing_limit(order.take.amount, &takes),
+				};
 				// calculate real price
 				for take in takes {
 					let quote_amount = take.take.quote_limit_amount()?;
@@ -45,7 +58,11 @@ impl<T: Config> Pallet<T> {
 					} else {
 						let take_amount = take.take.amount.min(order.take.amount);
 						order.take.amount -= take_amount;
-						let real_quote_amount = take.take.quote_amount(take_amount)?;
+						amount_sold += take_amount;
+						let real_quote_amount = match clearing_limit {
+							Some(limit) => Take::new(take_amount, limit).quote_limit_amount()?,
+							None => take.take.quote_amount(take_amount)?,
+						};
 
 						T::MultiCurrency::exchange_reserved(
 							order.pair.base,
@@ -67,9 +84,13 @@ impl<T: Config> Pallet<T> {
 				}
 
 				*total_amount_received += amount_received;
+				if amount_received > T::Balance::zero() {
+					T::OrderCallback::on_order_taken(order_id, amount_sold, amount_received)?;
+				}
 
 				if order.take.amount == T::Balance::zero() {
 					Self::callback_xcm(order, seller, order_id, *total_amount_received)?;
+					T::OrderCallback::on_order_removed(order_id, T::Balance::zero())?;
 					*order_item = None;
 					Self::deposit_event(Event::OrderRemoved { order_id });
 				}
@@ -82,6 +103,111 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// lowest limit among takes (sorted by descending limit) which get filled
+	fn uniform_clearing_limit(
+		mut remaining: T::Balance,
+		takes: &[TakeOf<T>],
+	) -> Option<LiftedFixedBalance> {
+		let mut limit = None;
+		for take in takes {
+			if remaining.is_zero() {
+				break
+			}
+			remaining = remaining.saturating_sub(take.take.amount);
+			limit = Some(take.take.limit);
+		}
+		limit
+	}
+
+	pub(crate) fn do_ask(
+		from_to: &T::AccountId,
+		order: Sell<T::MayBeAssetId, T::Balance>,
+		configuration: TimeReleaseFunction,
+		options: SellOptions,
+	) -> Result<T::OrderId, DispatchError> {
+		ensure!(order.is_valid(), Error::<T>::OrderParametersIsInvalid,);
+		let order_id = <OrdersIndex<T>>::increment();
+		let treasury = &T::PalletId::get().into_account_truncating();
+		let deposit = T::PositionExistentialDeposit::get();
+		<T::NativeCurrency as NativeTransfer<T::AccountId>>::transfer(
+			from_to, treasury, deposit, true,
+		)?;
+
+		let now = T::UnixTime::now().as_secs();
+		let order = SellOf::<T> {
+			from_to: from_to.clone(),
+			configuration,
+			order,
+			context: EDContext::<T::Balance> { added_at: now, deposit },
+			total_amount_received: T::Balance::zero(),
+			options,
+		};
+
+		T::MultiCurrency::reserve(order.order.pair.base, from_to, order.order.take.amount)?;
+		SellOrders::<T>::insert(order_id, order);
+
+		Ok(order_id)
+	}
+
+	/// current auction price of `order`
+	pub(crate) fn current_price(order: &SellOf<T>) -> Result<LiftedFixedBalance, DispatchError> {
+		let passed = T::UnixTime::now().as_secs().saturating_sub(order.context.added_at);
+		Ok(order.configuration.price(order.order.take.limit, passed)?)
+	}
+
+	pub(crate) fn is_below_reserve_price(order: &SellOf<T>) -> Result<bool, DispatchError> {
+		match order.options.reserve_price {
+			Some(reserve_price) => Ok(Self::current_price(order)? < reserve_price),
+			None => Ok(false),
+		}
+	}
+
+	/// returns unsold `base` and position deposit to seller, does not remove order
+	pub(crate) fn release_order(order: &SellOf<T>) -> Result<(), DispatchError> {
+		let treasury = &T::PalletId::get().into_account_truncating();
+		T::MultiCurrency::unreserve(order.order.pair.base, &order.from_to, order.order.take.amount);
+		<T::NativeCurrency as NativeTransfer<T::AccountId>>::transfer(
+			treasury,
+			&order.from_to,
+			order.context.deposit,
+			false,
+		)?;
+		Ok(())
+	}
+
+	#[transactional]
+	fn cancel_order(order_id: T::OrderId) -> Result<(), DispatchError> {
+		let order = SellOrders::<T>::take(order_id).ok_or(Error::<T>::OrderNotFound)?;
+		Self::release_order(&order)?;
+		Self::callback_xcm(&order.order, &order.from_to, order_id, order.total_amount_received)?;
+		T::OrderCallback::on_order_removed(order_id, order.order.take.amount)?;
+		Self::deposit_event(Event::OrderCancelled { order_id });
+		Ok(())
+	}
+
+	/// checks next chunk of orders against their reserve price, cancelling these below it
+	/// returns number of orders checked
+	pub(crate) fn cancel_orders_below_reserve_price() -> u32 {
+		let chunk = T::ReservePriceChecksPerBlock::get();
+		if chunk == 0 {
+			return 0
+		}
+		<(ReservePriceChecks<T>, SellOrders<T>) as FoldStorage<(), T::OrderId, SellOf<T>>>::step(
+			FoldStrategy::new_chunk(chunk),
+			(),
+			|_, order_id, order| match Self::is_below_reserve_price(&order) {
+				Ok(true) =>
+					if let Err(err) = Self::cancel_order(order_id) {
+						log::error!("failed to cancel order {:?} with {:?}", order_id, err);
+					},
+				Ok(false) => {},
+				Err(err) => log::error!("failed to price order {:?} with {:?}", order_id, err),
+			},
+		);
+		// last chunk of orders may be smaller, but weight is upper bound
+		chunk
+	}
+
 	pub fn callback_xcm(
 		order: &Sell<
 			<T as DeFiComposableConfig>::MayBeAssetId,
@@ -185,27 +311,7 @@ impl<T: Config + DeFiComposableConfig> SellEngine<TimeReleaseFunction> for Palle
 		order: Sell<Self::MayBeAssetId, Self::Balance>,
 		configuration: TimeReleaseFunction,
 	) -> Result<Self::OrderId, DispatchError> {
-		ensure!(order.is_valid(), Error::<T>::OrderParametersIsInvalid,);
-		let order_id = <OrdersIndex<T>>::increment();
-		let treasury = &T::PalletId::get().into_account_truncating();
-		let deposit = T::PositionExistentialDeposit::get();
-		<T::NativeCurrency as NativeTransfer<T::AccountId>>::transfer(
-			from_to, treasury, deposit, true,
-		)?;
-
-		let now = T::UnixTime::now().as_secs();
-		let order = SellOf::<T> {
-			from_to: from_to.clone(),
-			configuration,
-			order,
-			context: EDContext::<Self::Balance> { added_at: now, deposit },
-			total_amount_received: Self::Balance::zero(),
-		};
-
-		T::MultiCurrency::reserve(order.order.pair.base, from_to, order.order.take.amount)?;
-		SellOrders::<T>::insert(order_id, order);
-
-		Ok(order_id)
+		Self::do_ask(from_to, order, configuration, SellOptions::default())
 	}
 
 	fn take(
@@ -217,11 +323,9 @@ impl<T: Config + DeFiComposableConfig> SellEngine<TimeReleaseFunction> for Palle
 		let order = <SellOrders<T>>::try_get(order_id)
 			.map_err(|_x| Error::<T>::RequestedOrderDoesNotExists)?;
 		ensure!(order.order.take.limit <= take.limit, Error::<T>::TakeLimitDoesNotSatisfyOrder,);
-		let limit = order.order.take.limit;
 		// may consider storing calculation results within single block, so that finalize does
 		// not recalculates
-		let passed = T::UnixTime::now().as_secs() - order.context.added_at;
-		let _limit = order.configuration.price(limit, passed)?;
+		ensure!(!Self::is_below_reserve_price(&order)?, Error::<T>::OrderBelowReservePrice);
 		let quote_amount = take.quote_limit_amount()?;
 
 		T::MultiCurrency::reserve(order.order.pair.quote, from_to, quote_amount)?;
@@ -229,4 +333,34 @@ impl<T: Config + DeFiComposableConfig> SellEngine<TimeReleaseFunction> for Palle
 
 		Ok(())
 	}
+
+	fn cancel(order_id: Self::OrderId) -> Result<(), DispatchError> {
+		Self::cancel_order(order_id)
+	}
+}
+
+/// Sells with the [`SellOptions`] of the seller on top of the price curve.
+impl<T: Config + DeFiComposableConfig> SellEngine<(TimeReleaseFunction, SellOptions)>
+	for Pallet<T>
+{
+	type OrderId = T::OrderId;
+	fn ask(
+		from_to: &Self::AccountId,
+		order: Sell<Self::MayBeAssetId, Self::Balance>,
+		(configuration, options): (TimeReleaseFunction, SellOptions),
+	) -> Result<Self::OrderId, DispatchError> {
+		Self::do_ask(from_to, order, configuration, options)
+	}
+
+	fn take(
+		from_to: &Self::AccountId,
+		order_id: Self::OrderId,
+		take: Take<Self::Balance>,
+	) -> Result<(), DispatchError> {
+		<Self as SellEngine<TimeReleaseFunction>>::take(from_to, order_id, take)
+	}
+
+	fn cancel(order_id: Self::OrderId) -> Result<(), DispatchError> {
+		Self::cancel_order(order_id)
+	}
 }
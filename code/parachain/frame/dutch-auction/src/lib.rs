@@ -24,6 +24,16 @@
 //! Dutch action starts with configured price and than and other price value is f(t).
 //! So any external observer can predict what price will be on specified block.
 //!
+//! # Reserve price
+//! Seller may set reserve price. Once auction price decays below it, order is cancelled and
+//! unsold `base` is returned. Orders are checked in chunks each block, so cancellation may lag a
+//! few blocks; takes below reserve are rejected immediately.
+//!
+//! # Uniform clearing
+//! Seller may ask for all takes filled within a block to be cleared at one price, the lowest
+//! filled limit. Takers than gain nothing from ordering their takes within block, which reduces
+//! MEV on liquidations.
+//!
 //! # DEX
 //! Currently this dutch auction does not tries to sell on external DEX.
 //!
@@ -63,6 +73,7 @@
 	unused_extern_crates
 )]
 pub use pallet::*;
+pub use types::{ClearingMode, SellOptions};
 
 pub mod math;
 #[cfg(test)]
@@ -73,6 +84,7 @@ mod benchmarking;
 mod mock;
 
 mod helpers;
+pub mod migrations;
 mod prelude;
 mod support;
 mod types;
@@ -89,6 +101,7 @@ pub mod pallet {
 	};
 	use composable_support::{
 		abstractions::{
+			block_fold::BlockFold,
 			nonce::Nonce,
 			utils::{increment::WrappingIncrement, start_at::ZeroInit},
 		},
@@ -96,7 +109,10 @@ pub mod pallet {
 		validation::Validate,
 	};
 	use composable_traits::{
-		defi::{DeFiComposableConfig, DeFiEngine, OrderIdLike, Sell, SellEngine, Take},
+		defi::{
+			DeFiComposableConfig, DeFiEngine, OrderIdLike, Sell, SellEngine, SellEngineCallback,
+			Take,
+		},
 		time::TimeReleaseFunction,
 		xcm::{ConfigurationId, CumulusMethodId, XcmSellRequest},
 	};
@@ -111,7 +127,7 @@ pub mod pallet {
 		pallet_prelude::{BlockNumberFor, OriginFor},
 	};
 	use orml_traits::{MultiCurrency, MultiReservableCurrency};
-	use sp_runtime::{traits::AccountIdConversion, DispatchError};
+	use sp_runtime::DispatchError;
 	use sp_std::convert::TryInto;
 	use xcm::latest::prelude::*;
 
@@ -157,6 +173,13 @@ pub mod pallet {
 		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
 		type XcmSender: SendXcm;
+
+		/// number of orders checked against their reserve price each block
+		#[pallet::constant]
+		type ReservePriceChecksPerBlock: Get<u32>;
+
+		/// notified when orders are taken or removed, so that sellers can track their progress
+		type OrderCallback: SellEngineCallback<Self::OrderId, Self::Balance>;
 	}
 
 	#[pallet::event]
@@ -174,6 +197,10 @@ pub mod pallet {
 		OrderRemoved {
 			order_id: OrderIdOf<T>,
 		},
+		/// raised when auction price decayed below reserve price and unsold part was returned
+		OrderCancelled {
+			order_id: OrderIdOf<T>,
+		},
 		ConfigurationAdded {
 			configuration_id: ConfigurationId,
 			configuration: TimeReleaseFunction,
@@ -193,10 +220,15 @@ pub mod pallet {
 		XcmCannotDecodeRemoteParametersToLocalRepresentations,
 		XcmCannotFindLocalIdentifiersAsDecodedFromRemote,
 		XcmNotFoundConfigurationById,
+		/// auction price decayed below reserve price of the order
+		OrderBelowReservePrice,
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -259,6 +291,11 @@ pub mod pallet {
 	pub type Takes<T: Config> =
 		StorageMap<_, Twox64Concat, OrderIdOf<T>, Vec<TakeOf<T>>, OptionQuery>;
 
+	/// progress of checking orders against their reserve price, spread over several blocks
+	#[pallet::storage]
+	pub type ReservePriceChecks<T: Config> =
+		StorageValue<_, BlockFold<(), OrderIdOf<T>>, OptionQuery>;
+
 	impl<T: Config + DeFiComposableConfig> DeFiEngine for Pallet<T> {
 		type MayBeAssetId = T::MayBeAssetId;
 		type Balance = T::Balance;
@@ -289,13 +326,13 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			order: Sell<T::MayBeAssetId, T::Balance>,
 			configuration: TimeReleaseFunction,
+			options: SellOptions,
 		) -> DispatchResultWithPostInfo {
 			let who = &(ensure_signed(origin)?);
 
 			let order = SellValid::validate(order)?;
 
-			let order_id =
-				<Self as SellEngine<TimeReleaseFunction>>::ask(who, order, configuration)?;
+			let order_id = Self::do_ask(who, order, configuration, options)?;
 
 			Self::deposit_event(Event::OrderAdded {
 				order_id,
@@ -327,14 +364,8 @@ pub mod pallet {
 			// we can later allow liquidate old orders(or orders with some block liquidation
 			// timeout set) using kind of account per order is possible, but may risk to
 			// pollute account system
-			let treasury = &T::PalletId::get().into_account_truncating();
-			T::MultiCurrency::unreserve(order.order.pair.base, &who, order.order.take.amount);
-			<T::NativeCurrency as NativeTransfer<T::AccountId>>::transfer(
-				treasury,
-				&order.from_to,
-				order.context.deposit,
-				false,
-			)?;
+			Self::release_order(&order)?;
+			T::OrderCallback::on_order_removed(order_id, order.order.take.amount)?;
 
 			<SellOrders<T>>::remove(order_id);
 			Self::deposit_event(Event::OrderRemoved { order_id });
@@ -397,7 +428,13 @@ pub mod pallet {
 		}
 
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			let checked = Self::cancel_orders_below_reserve_price();
 			T::WeightInfo::known_overhead_for_on_finalize()
+				.saturating_add(T::WeightInfo::reserve_price_checks(checked))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
 		}
 	}
 }
@@ -5,10 +5,13 @@
 use composable_support::math::safe::{SafeDiv, SafeMul};
 use composable_traits::{
 	defi::LiftedFixedBalance,
-	time::{DurationSeconds, LinearDecrease, StairstepExponentialDecrease, TimeReleaseFunction},
+	time::{
+		ContinuousExponentialDecrease, DurationSeconds, LinearDecrease,
+		StairstepExponentialDecrease, TimeReleaseFunction,
+	},
 };
 use sp_runtime::{
-	traits::{Saturating, Zero},
+	traits::{One, Saturating, Zero},
 	ArithmeticError, FixedPointNumber,
 };
 
@@ -31,6 +34,8 @@ impl AuctionTimeCurveModel for TimeReleaseFunction {
 			TimeReleaseFunction::LinearDecrease(x) => x.price(initial_price, duration_since_start),
 			TimeReleaseFunction::StairstepExponentialDecrease(x) =>
 				x.price(initial_price, duration_since_start),
+			TimeReleaseFunction::ContinuousExponentialDecrease(x) =>
+				x.price(initial_price, duration_since_start),
 		}
 	}
 }
@@ -77,12 +82,59 @@ impl AuctionTimeCurveModel for StairstepExponentialDecrease {
 	}
 }
 
+/// `ln(2)` with the precision of [`LiftedFixedBalance`].
+const LN_2: u128 = 693_147_180_559_945_309;
+
+/// Taylor series terms are added until they vanish, `e^x` for `x < ln(2)` needs far less than this.
+const MAX_EXP_TERMS: u128 = 32;
+
+/// returns: initial_price * 2 ^ (-duration_since_start / half_life)
+/// whole half lives are applied as shifts, the remaining fraction as `1 / e^(fraction * ln(2))`
+impl AuctionTimeCurveModel for ContinuousExponentialDecrease {
+	fn price(
+		&self,
+		initial_price: LiftedFixedBalance,
+		duration_since_start: DurationSeconds,
+	) -> Result<LiftedFixedBalance, ArithmeticError> {
+		if self.half_life.is_zero() {
+			return Ok(LiftedFixedBalance::zero())
+		}
+		let halvings = duration_since_start.safe_div(&self.half_life)?;
+		if halvings >= u128::BITS as u64 {
+			return Ok(LiftedFixedBalance::zero())
+		}
+		let halved = LiftedFixedBalance::from_inner(initial_price.into_inner() >> halvings);
+		let remainder = duration_since_start % self.half_life;
+		let exponent = LiftedFixedBalance::checked_from_rational(remainder, self.half_life)
+			.ok_or(ArithmeticError::Overflow)?
+			.safe_mul(&LiftedFixedBalance::from_inner(LN_2))?;
+		halved.safe_div(&exp(exponent)?)
+	}
+}
+
+/// `e^x` by Taylor series, good enough for small positive `x`.
+fn exp(x: LiftedFixedBalance) -> Result<LiftedFixedBalance, ArithmeticError> {
+	let mut sum = LiftedFixedBalance::one();
+	let mut term = LiftedFixedBalance::one();
+	for n in 1..=MAX_EXP_TERMS {
+		term = term.safe_mul(&x)?.safe_div(&LiftedFixedBalance::saturating_from_integer(n))?;
+		if term.is_zero() {
+			break
+		}
+		sum = sum.saturating_add(term);
+	}
+	Ok(sum)
+}
+
 #[cfg(test)]
 mod tests {
 
 	use composable_traits::{
 		defi::LiftedFixedBalance,
-		time::{DurationSeconds, LinearDecrease, StairstepExponentialDecrease, ONE_HOUR},
+		time::{
+			ContinuousExponentialDecrease, DurationSeconds, LinearDecrease,
+			StairstepExponentialDecrease, ONE_HOUR,
+		},
 	};
 
 	use sp_arithmetic::assert_eq_error_rate;
@@ -137,8 +189,55 @@ mod tests {
 		}
 	}
 
+	#[test]
+	pub fn test_continuous_exp_decrease_halves_each_half_life() {
+		let calc = ContinuousExponentialDecrease { half_life: 600 };
+		let initial_price = 4000.0;
+		let mut expected_price = initial_price;
+		for i in 0..=5 {
+			let price: f64 = calc
+				.price(LiftedFixedBalance::from_float(initial_price), i * 600)
+				.unwrap()
+				.to_float();
+			assert_eq_error_rate!(price, expected_price, 0.000_001);
+			expected_price /= 2.0;
+		}
+
+		// between half lives the decay follows the curve, not a step
+		let price: f64 = calc
+			.price(LiftedFixedBalance::from_float(initial_price), 300)
+			.unwrap()
+			.to_float();
+		assert_eq_error_rate!(price, initial_price / 2.0_f64.sqrt(), 0.000_001);
+	}
+
+	#[test]
+	pub fn test_continuous_exp_decrease_ends() {
+		let initial_price = LiftedFixedBalance::saturating_from_integer(1_000_000);
+		let calc = ContinuousExponentialDecrease { half_life: 1 };
+		assert_eq!(calc.price(initial_price, 0).unwrap(), initial_price);
+		assert_eq!(calc.price(initial_price, 128).unwrap(), LiftedFixedBalance::zero());
+		let calc = ContinuousExponentialDecrease { half_life: 0 };
+		assert_eq!(calc.price(initial_price, 0).unwrap(), LiftedFixedBalance::zero());
+	}
+
 	use proptest::{prop_assert, strategy::Strategy, test_runner::TestRunner};
 
+	#[test]
+	pub fn proptest_continuous_exp_is_monotonic() {
+		let mut runner = TestRunner::default();
+		let initial_price = LiftedFixedBalance::saturating_from_integer(1_000_000);
+		let calc = ContinuousExponentialDecrease { half_life: ONE_HOUR };
+		runner
+			.run(&(0..ONE_HOUR * 10).prop_map(|time| (time, time + 1)), |(time, time_next)| {
+				let price_1 = calc.price(initial_price, time).unwrap();
+				let price_2 = calc.price(initial_price, time_next).unwrap();
+				prop_assert!(price_2 <= price_1);
+				Ok(())
+			})
+			.unwrap();
+	}
+
 	#[test]
 	pub fn proptest_half_each_second_vs_linear() {
 		let mut runner = TestRunner::default();
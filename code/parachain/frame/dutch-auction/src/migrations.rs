@@ -0,0 +1,56 @@
+//! Storage migrations of the dutch auction pallet.
+
+use crate::{prelude::*, types::*, *};
+
+pub mod v1 {
+	//! Adds seller [`SellOptions`] to stored sell orders.
+
+	use super::*;
+	use composable_traits::{
+		defi::{DeFiComposableConfig, Sell},
+		time::TimeReleaseFunction,
+	};
+	use frame_support::traits::GetStorageVersion;
+
+	/// Sell order as stored before the introduction of [`SellOptions`].
+	#[derive(Decode)]
+	struct OldSellOrder<AssetId, Balance, AccountId, Context, Configuration> {
+		from_to: AccountId,
+		order: Sell<AssetId, Balance>,
+		configuration: Configuration,
+		context: Context,
+		total_amount_received: Balance,
+	}
+
+	type OldSellOf<T> = OldSellOrder<
+		<T as DeFiComposableConfig>::MayBeAssetId,
+		<T as DeFiComposableConfig>::Balance,
+		<T as frame_system::Config>::AccountId,
+		EDContext<<T as DeFiComposableConfig>::Balance>,
+		TimeReleaseFunction,
+	>;
+
+	/// Stored orders were placed without a reserve price and cleared pay-as-bid, which the default
+	/// options keep.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		SellOrders::<T>::translate::<OldSellOf<T>, _>(|_, order| {
+			translated += 1;
+			Some(SellOrder {
+				from_to: order.from_to,
+				order: order.order,
+				configuration: order.configuration,
+				context: order.context,
+				total_amount_received: order.total_amount_received,
+				options: SellOptions::default(),
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -180,6 +180,10 @@ impl DeFiComposableConfig for Runtime {
 	type Balance = Balance;
 }
 
+parameter_types! {
+	pub const ReservePriceChecksPerBlock: u32 = 16;
+}
+
 impl pallet_dutch_auction::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UnixTime = Timestamp;
@@ -192,6 +196,8 @@ impl pallet_dutch_auction::Config for Runtime {
 	type AdminOrigin = EnsureOneOf<EnsureRoot<AccountId>, EnsureSignedBy<RootAccount, AccountId>>;
 	type XcmSender = XcmFake;
 	type XcmOrigin = RuntimeOrigin;
+	type ReservePriceChecksPerBlock = ReservePriceChecksPerBlock;
+	type OrderCallback = ();
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
@@ -1,4 +1,4 @@
-use crate::{self as pallet_dutch_auction, weights::*};
+use crate::{self as pallet_dutch_auction, weights::*, ClearingMode, SellOptions};
 
 use crate::mock::{currency::*, runtime::*};
 use composable_traits::{
@@ -84,7 +84,8 @@ fn setup_sell() {
 		let gas = Assets::balance(PICA, &ALICE);
 		let treasury =
 			Assets::balance(PICA, &DutchAuctionPalletId::get().into_account_truncating());
-		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration).unwrap();
+		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, <_>::default())
+			.unwrap();
 		let treasury_added =
 			Assets::balance(PICA, &DutchAuctionPalletId::get().into_account_truncating()) -
 				treasury;
@@ -118,7 +119,8 @@ fn with_immediate_exact_buy() {
 		let take_amount = 1000_u128;
 		let sell = Sell::new(BTC, USDT, sell_amount, fixed(take_amount));
 		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
-		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration).unwrap();
+		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, <_>::default())
+			.unwrap();
 		let order_id = crate::OrdersIndex::<Runtime>::get();
 		let result =
 			DutchAuction::take(RuntimeOrigin::signed(buyer), order_id, Take::new(1, fixed(999)));
@@ -151,7 +153,8 @@ fn with_two_takes_higher_than_limit_and_not_enough_for_all() {
 		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
 
 		let sell = Sell::new(BTC, USDT, sell_amount, fixed(take_amount));
-		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration).unwrap();
+		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, <_>::default())
+			.unwrap();
 		let order_id = crate::OrdersIndex::<Runtime>::get();
 		assert_ok!(DutchAuction::take(
 			RuntimeOrigin::signed(buyer),
@@ -179,7 +182,8 @@ fn liquidation() {
 			let seller = AccountId::from_raw(ALICE.0);
 			let sell = Sell::new(BTC, USDT, 1, fixed(1000));
 			let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
-			DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration).unwrap();
+			DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, <_>::default())
+				.unwrap();
 			let order_id = crate::OrdersIndex::<Runtime>::get();
 			let balance_before = <Balances as fungible::Inspect<_>>::balance(&ALICE);
 			DutchAuction::liquidate(RuntimeOrigin::signed(seller), order_id).unwrap();
@@ -195,3 +199,66 @@ fn liquidation() {
 		})
 		.unwrap();
 }
+
+#[test]
+fn reserve_price_rejects_takes_and_cancels_order() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let seller = AccountId::from_raw(ALICE.0);
+		let buyer = AccountId::from_raw(BOB.0);
+		let sell = Sell::new(BTC, USDT, 1, fixed(1000));
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let options = SellOptions { reserve_price: Some(fixed(500)), ..Default::default() };
+		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, options).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+		assert_eq!(Assets::reserved_balance(BTC, &ALICE), 1);
+
+		// more than half of linear decrease passed
+		Timestamp::set_timestamp(Timestamp::get() + 22 * 1000);
+		assert_noop!(
+			DutchAuction::take(RuntimeOrigin::signed(buyer), order_id, Take::new(1, fixed(1000))),
+			crate::Error::<Runtime>::OrderBelowReservePrice,
+		);
+
+		DutchAuction::on_initialize(43);
+		assert!(crate::SellOrders::<Runtime>::get(order_id).is_none());
+		assert_eq!(Assets::reserved_balance(BTC, &ALICE), 0);
+		assert_eq!(Tokens::balance(BTC, &ALICE), 10);
+		System::assert_has_event(RuntimeEvent::DutchAuction(crate::Event::OrderCancelled {
+			order_id,
+		}));
+	});
+}
+
+#[test]
+fn uniform_clearing_charges_lowest_filled_limit() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let seller = AccountId::from_raw(ALICE.0);
+		let buyer = AccountId::from_raw(BOB.0);
+		let sell = Sell::new(BTC, USDT, 2, fixed(1000));
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let options = SellOptions { clearing: ClearingMode::Uniform, ..Default::default() };
+		DutchAuction::ask(RuntimeOrigin::signed(seller), sell, configuration, options).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(buyer),
+			order_id,
+			Take::new(1, fixed(1002))
+		));
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(buyer),
+			order_id,
+			Take::new(1, fixed(1001))
+		));
+
+		DutchAuction::on_finalize(42);
+
+		assert!(crate::SellOrders::<Runtime>::get(order_id).is_none());
+		assert_eq!(Tokens::balance(USDT, &ALICE), 2002);
+		assert_eq!(Tokens::balance(USDT, &BOB), 1_000_000 - 2002);
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 0);
+	});
+}
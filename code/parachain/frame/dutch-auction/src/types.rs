@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 use composable_traits::{
-	defi::{Sell, Take},
+	defi::{LiftedFixedBalance, Sell, Take},
 	time::Timestamp,
 };
 
@@ -15,6 +15,27 @@ pub struct SellOrder<AssetId, Balance, AccountId, Context, Configuration> {
 	pub context: Context,
 	/// amount of `quote` received up to now
 	pub total_amount_received: Balance,
+	/// seller choices on top of the price curve
+	pub options: SellOptions,
+}
+
+/// how takes collected within one block are priced
+#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClearingMode {
+	/// highest limits are served first and each take pays its own limit
+	#[default]
+	PayAsBid,
+	/// sealed-bid batch, highest limits are served first but all filled takes pay the lowest
+	/// filled limit, so ordering takes within a block gives no edge
+	Uniform,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo, Clone, Debug, PartialEq, Eq)]
+pub struct SellOptions {
+	/// once the auction price decays below this price, the order is cancelled and the unsold
+	/// `base` returned to the seller
+	pub reserve_price: Option<LiftedFixedBalance>,
+	pub clearing: ClearingMode,
 }
 
 /// existential deposit context with date of creation
@@ -10,6 +10,7 @@ pub trait WeightInfo {
 	fn liquidate() -> Weight;
 	fn xcm_sell() -> Weight;
 	fn known_overhead_for_on_finalize() -> Weight;
+	fn reserve_price_checks(n: u32) -> Weight;
 }
 
 /// Weight functions for `dutch_auction`.
@@ -69,4 +70,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	// Storage: DutchAuction ReservePriceChecks (r:1 w:1)
+	// Storage: DutchAuction SellOrders (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DutchAuction LocalOrderIdToRemote (r:1 w:0)
+	fn reserve_price_checks(n: u32) -> Weight {
+		Weight::from_ref_time(6_112_000_u64)
+			.saturating_add(Weight::from_ref_time(41_870_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
 }
@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+use composable_traits::fnft::FinancialNftMetadata;
+
+// fNFT Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait FnftRuntimeApi<CollectionId, InstanceId, AccountId, Balance>
+	where
+		CollectionId: Codec,
+		InstanceId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Retrieve the owner, approval, lock, attributes and underlying value of a financial NFT,
+		/// the value as reported by the protocol that issued it.
+		fn metadata(
+			collection: CollectionId,
+			instance: InstanceId,
+		) -> Option<FinancialNftMetadata<AccountId, CollectionId, SafeRpcWrapper<Balance>>>;
+	}
+}
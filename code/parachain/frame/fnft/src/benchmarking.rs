@@ -42,5 +42,37 @@ benchmarks! {
 		);
 	}
 
-	impl_benchmark_test_suite!(Fnft, crate::test::mock::new_test_ext(), crate::test::mock::MockRuntime);
+	approve {
+		let user1 = account("user1", 0, 0);
+		let user2 = account::<AccountIdOf<T>>("user2", 0, 0);
+		let collection_id = 1_u128.into();
+		Fnft::<T>::create_collection(&collection_id, &user1, &user1).unwrap();
+		let created_nft_id = 1_u64.into();
+		Fnft::<T>::mint_into(&collection_id, &created_nft_id, &user1)?;
+	}: _(OriginFor::<T>::signed(user1), collection_id, created_nft_id, Some(user2.clone()))
+	verify {
+		T::assert_last_event(
+			Event::FinancialNftApproved {
+				collection_id,
+				instance_id: created_nft_id,
+				approved: Some(user2),
+			}
+		);
+	}
+
+	set_approval_for_all {
+		let user1 = account::<AccountIdOf<T>>("user1", 0, 0);
+		let user2 = account::<AccountIdOf<T>>("user2", 0, 0);
+	}: _(OriginFor::<T>::signed(user1.clone()), user2.clone(), true)
+	verify {
+		T::assert_last_event(
+			Event::ApprovalForAll {
+				owner: user1,
+				operator: user2,
+				approved: true,
+			}
+		);
+	}
+
+		impl_benchmark_test_suite!(Fnft, crate::test::mock::new_test_ext(), crate::test::mock::MockRuntime);
 }
@@ -51,14 +51,16 @@ pub mod pallet {
 	use composable_traits::{
 		account_proxy::AccountProxy,
 		currency::AssetIdLike,
-		fnft::{FinancialNft, FnftAccountProxyTypeSelector},
+		fnft::{
+			FinancialNft, FinancialNftLock, FinancialNftMetadata, FnftAccountProxyTypeSelector,
+		},
 	};
 	use core::fmt::Debug;
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
 			tokens::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate, Transfer},
-			IsType,
+			IsType, LockIdentifier,
 		},
 		PalletId,
 	};
@@ -96,6 +98,24 @@ pub mod pallet {
 			instance_id: FinancialNftInstanceIdOf<T>,
 			to: AccountIdOf<T>,
 		},
+		/// The account approved to transfer an fNFT was set or cleared by the owner.
+		FinancialNftApproved {
+			collection_id: FinancialNftCollectionIdOf<T>,
+			instance_id: FinancialNftInstanceIdOf<T>,
+			approved: Option<AccountIdOf<T>>,
+		},
+		/// An operator was approved or disapproved to transfer all fNFTs of an owner.
+		ApprovalForAll { owner: AccountIdOf<T>, operator: AccountIdOf<T>, approved: bool },
+		FinancialNftLocked {
+			collection_id: FinancialNftCollectionIdOf<T>,
+			instance_id: FinancialNftInstanceIdOf<T>,
+			lock_id: LockIdentifier,
+		},
+		FinancialNftUnlocked {
+			collection_id: FinancialNftCollectionIdOf<T>,
+			instance_id: FinancialNftInstanceIdOf<T>,
+			lock_id: LockIdentifier,
+		},
 	}
 
 	#[pallet::error]
@@ -105,6 +125,10 @@ pub mod pallet {
 		CollectionNotFound,
 		InstanceNotFound,
 		MustBeOwner,
+		/// The fNFT is locked by a protocol and cannot be transferred or burned.
+		InstanceLocked,
+		/// The fNFT is not locked under the given lock identifier.
+		LockNotFound,
 	}
 
 	#[pallet::config]
@@ -208,9 +232,44 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The account approved to transfer a single fNFT on behalf of its owner. Cleared when the
+	/// fNFT is transferred or burned.
+	#[pallet::storage]
+	#[pallet::getter(fn approval)]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		FinancialNftCollectionIdOf<T>,
+		Blake2_128Concat,
+		FinancialNftInstanceIdOf<T>,
+		AccountIdOf<T>,
+		OptionQuery,
+	>;
+
+	/// Operators approved to transfer all of the fNFTs of an owner, keyed by owner and operator.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, AccountIdOf<T>, ()>;
+
+	/// Locks put on fNFTs by protocols, preventing them from being transferred or burned.
+	#[pallet::storage]
+	pub type Locks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		FinancialNftCollectionIdOf<T>,
+		Blake2_128Concat,
+		FinancialNftInstanceIdOf<T>,
+		LockIdentifier,
+		OptionQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// transfer fnft to a new owner
+		///
+		/// Can be called by the owner, the account approved for the fNFT or an operator approved
+		/// for all of the owner's fNFTs.
 		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
@@ -219,10 +278,54 @@ pub mod pallet {
 			destination: AccountIdOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let fnfts = OwnerInstances::<T>::get(&who).ok_or(Error::<T>::MustBeOwner)?;
-			ensure!(fnfts.contains(&(collection, instance)), Error::<T>::MustBeOwner);
-			let _ =
-				<Self as Transfer<AccountIdOf<T>>>::transfer(&collection, &instance, &destination);
+			let owner = Self::owner(&collection, &instance).ok_or(Error::<T>::MustBeOwner)?;
+			ensure!(
+				who == owner ||
+					Approvals::<T>::get(collection, instance).as_ref() == Some(&who) ||
+					OperatorApprovals::<T>::contains_key(&owner, &who),
+				Error::<T>::MustBeOwner
+			);
+			<Self as Transfer<AccountIdOf<T>>>::transfer(&collection, &instance, &destination)
+		}
+
+		/// Approve `approved` to transfer the fNFT on behalf of the owner, or clear the approval
+		/// with `None`. Only one account can be approved per fNFT.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			collection: FinancialNftCollectionIdOf<T>,
+			instance: FinancialNftInstanceIdOf<T>,
+			approved: Option<AccountIdOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				Self::owner(&collection, &instance).as_ref() == Some(&who),
+				Error::<T>::MustBeOwner
+			);
+			Approvals::<T>::set(collection, instance, approved.clone());
+			Self::deposit_event(Event::FinancialNftApproved {
+				collection_id: collection,
+				instance_id: instance,
+				approved,
+			});
+			Ok(())
+		}
+
+		/// Approve or disapprove `operator` to transfer all of the caller's fNFTs, including the
+		/// ones received later.
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: AccountIdOf<T>,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if approved {
+				OperatorApprovals::<T>::insert(&who, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&who, &operator);
+			}
+			Self::deposit_event(Event::ApprovalForAll { owner: who, operator, approved });
 			Ok(())
 		}
 	}
@@ -322,6 +425,7 @@ pub mod pallet {
 			instance: &Self::ItemId,
 			destination: &AccountIdOf<T>,
 		) -> DispatchResult {
+			ensure!(!Locks::<T>::contains_key(collection, instance), Error::<T>::InstanceLocked);
 			Instance::<T>::try_mutate(collection, instance, |entry| match entry {
 				Some((owner, _)) => {
 					OwnerInstances::<T>::mutate(owner.clone(), |x| match x {
@@ -346,6 +450,7 @@ pub mod pallet {
 						Some(owner),
 					)?;
 					*owner = destination.clone();
+					Approvals::<T>::remove(collection, instance);
 
 					Self::deposit_event(Event::FinancialNftTransferred {
 						collection_id: *collection,
@@ -391,6 +496,7 @@ pub mod pallet {
 			instance: &Self::ItemId,
 			_maybe_check_owner: Option<&AccountIdOf<T>>,
 		) -> DispatchResult {
+			ensure!(!Locks::<T>::contains_key(collection, instance), Error::<T>::InstanceLocked);
 			Instance::<T>::try_mutate_exists(collection, instance, |entry| -> DispatchResult {
 				match entry {
 					Some((owner, _)) => {
@@ -408,6 +514,7 @@ pub mod pallet {
 					None => Err(Error::<T>::InstanceNotFound.into()),
 				}
 			})?;
+			Approvals::<T>::remove(collection, instance);
 
 			// TODO (vim): Remove account proxy ??
 			Self::deposit_event(Event::FinancialNftBurned {
@@ -470,6 +577,24 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Describes the fNFT for marketplaces and lending protocols. `value` is the value of the
+		/// position as reported by the originating protocol.
+		pub fn metadata<AssetId, Balance>(
+			collection: &FinancialNftCollectionIdOf<T>,
+			instance: &FinancialNftInstanceIdOf<T>,
+			value: Vec<(AssetId, Balance)>,
+		) -> Option<FinancialNftMetadata<AccountIdOf<T>, AssetId, Balance>> {
+			Instance::<T>::get(collection, instance).map(|(owner, attributes)| {
+				FinancialNftMetadata {
+					owner,
+					approved: Approvals::<T>::get(collection, instance),
+					lock: Locks::<T>::get(collection, instance),
+					attributes: attributes.into_iter().collect(),
+					value,
+				}
+			})
+		}
+
 		fn handle_asset_account_proxy(
 			collection: &<T as Config>::FinancialNftCollectionId,
 			instance: &<T as Config>::FinancialNftInstanceId,
@@ -527,6 +652,49 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> FinancialNftLock<AccountIdOf<T>> for Pallet<T> {
+		fn lock(
+			collection: &Self::CollectionId,
+			instance: &Self::ItemId,
+			id: LockIdentifier,
+		) -> DispatchResult {
+			ensure!(
+				Instance::<T>::contains_key(collection, instance),
+				Error::<T>::InstanceNotFound
+			);
+			ensure!(!Locks::<T>::contains_key(collection, instance), Error::<T>::InstanceLocked);
+			Locks::<T>::insert(collection, instance, id);
+			Self::deposit_event(Event::FinancialNftLocked {
+				collection_id: *collection,
+				instance_id: *instance,
+				lock_id: id,
+			});
+			Ok(())
+		}
+
+		fn unlock(
+			collection: &Self::CollectionId,
+			instance: &Self::ItemId,
+			id: LockIdentifier,
+		) -> DispatchResult {
+			ensure!(Locks::<T>::get(collection, instance) == Some(id), Error::<T>::LockNotFound);
+			Locks::<T>::remove(collection, instance);
+			Self::deposit_event(Event::FinancialNftUnlocked {
+				collection_id: *collection,
+				instance_id: *instance,
+				lock_id: id,
+			});
+			Ok(())
+		}
+
+		fn lock_of(
+			collection: &Self::CollectionId,
+			instance: &Self::ItemId,
+		) -> Option<LockIdentifier> {
+			Locks::<T>::get(collection, instance)
+		}
+	}
+
 	/// Returns a closure that inserts the given value into the contained set, initializing the set
 	/// if the `Option` is `None`.
 	fn insert_or_init_and_insert<T: Ord>(t: T) -> impl FnOnce(&'_ mut Option<BTreeSet<T>>) {
@@ -45,6 +45,12 @@ mod nonfungibles {
 	/// Tests the pallet's extrinsics implementation.
 	mod extrinsic;
 
+	/// Tests the pallet's approval extrinsics.
+	mod approval;
+
+	/// Tests the pallet's [`composable_traits::fnft::FinancialNftLock`] implementation.
+	mod lock;
+
 	/// Tests the pallet's [`Create`] implementation.
 	#[test]
 	fn create_inspect() {
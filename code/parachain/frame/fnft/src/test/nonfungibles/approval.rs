@@ -0,0 +1,102 @@
+use composable_tests_helpers::test::helper::RuntimeTrait;
+use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect};
+
+use crate::{
+	test::{
+		mock::{new_test_ext, MockRuntime, Nft, RuntimeOrigin},
+		prelude::{TEST_COLLECTION_ID, *},
+		ALICE, BOB, CHARLIE,
+	},
+	Approvals, OperatorApprovals,
+};
+
+/// Tests that an approved account can transfer the NFT once, and that the approval is cleared by
+/// the transfer.
+#[test]
+fn approved_account_can_transfer() {
+	new_test_ext().execute_with(|| {
+		let created_nft_id = mint_nft_and_assert();
+
+		assert_noop!(
+			Nft::approve(RuntimeOrigin::signed(BOB), TEST_COLLECTION_ID, created_nft_id, Some(BOB)),
+			crate::Error::<MockRuntime>::MustBeOwner
+		);
+
+		MockRuntime::assert_extrinsic_event(
+			Nft::approve(
+				RuntimeOrigin::signed(ALICE),
+				TEST_COLLECTION_ID,
+				created_nft_id,
+				Some(BOB),
+			),
+			crate::Event::FinancialNftApproved {
+				collection_id: TEST_COLLECTION_ID,
+				instance_id: created_nft_id,
+				approved: Some(BOB),
+			},
+		);
+
+		assert_noop!(
+			Nft::transfer(
+				RuntimeOrigin::signed(CHARLIE),
+				TEST_COLLECTION_ID,
+				created_nft_id,
+				CHARLIE
+			),
+			crate::Error::<MockRuntime>::MustBeOwner
+		);
+
+		assert_ok!(Nft::transfer(
+			RuntimeOrigin::signed(BOB),
+			TEST_COLLECTION_ID,
+			created_nft_id,
+			CHARLIE
+		));
+		assert_eq!(Nft::owner(&TEST_COLLECTION_ID, &created_nft_id), Some(CHARLIE));
+		assert_eq!(
+			Approvals::<MockRuntime>::get(TEST_COLLECTION_ID, created_nft_id),
+			None,
+			"approval should be cleared on transfer"
+		);
+
+		assert_noop!(
+			Nft::transfer(RuntimeOrigin::signed(BOB), TEST_COLLECTION_ID, created_nft_id, BOB),
+			crate::Error::<MockRuntime>::MustBeOwner
+		);
+	});
+}
+
+/// Tests that an operator can transfer all of the owner's NFTs until disapproved.
+#[test]
+fn operator_can_transfer_all() {
+	new_test_ext().execute_with(|| {
+		let created_nft_id = mint_nft_and_assert();
+
+		MockRuntime::assert_extrinsic_event(
+			Nft::set_approval_for_all(RuntimeOrigin::signed(ALICE), BOB, true),
+			crate::Event::ApprovalForAll { owner: ALICE, operator: BOB, approved: true },
+		);
+		assert!(OperatorApprovals::<MockRuntime>::contains_key(ALICE, BOB));
+
+		let [other_nft_id] = mint_many_nfts_and_assert::<1>(ALICE, TEST_COLLECTION_ID);
+
+		for nft_id in [created_nft_id, other_nft_id] {
+			assert_ok!(Nft::transfer(
+				RuntimeOrigin::signed(BOB),
+				TEST_COLLECTION_ID,
+				nft_id,
+				CHARLIE
+			));
+			assert_eq!(Nft::owner(&TEST_COLLECTION_ID, &nft_id), Some(CHARLIE));
+		}
+
+		// the operator is only approved for ALICE's NFTs
+		assert_noop!(
+			Nft::transfer(RuntimeOrigin::signed(BOB), TEST_COLLECTION_ID, created_nft_id, BOB),
+			crate::Error::<MockRuntime>::MustBeOwner
+		);
+
+		assert_ok!(Nft::set_approval_for_all(RuntimeOrigin::signed(ALICE), BOB, false));
+		assert!(!OperatorApprovals::<MockRuntime>::contains_key(ALICE, BOB));
+	});
+}
@@ -0,0 +1,70 @@
+use composable_traits::fnft::FinancialNftLock;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::nonfungibles::{Mutate, Transfer},
+};
+use sp_runtime::DispatchError;
+
+use crate::test::{
+	mock::{new_test_ext, MockRuntime, Nft, RuntimeOrigin},
+	prelude::{TEST_COLLECTION_ID, *},
+	ALICE, BOB,
+};
+
+const LOCK_ID: [u8; 8] = *b"lendlock";
+
+/// Tests that a locked NFT can be neither transferred nor burned until unlocked.
+#[test]
+fn locked_instance_cannot_be_transferred_or_burned() {
+	new_test_ext().execute_with(|| {
+		let created_nft_id = mint_nft_and_assert();
+
+		assert_ok!(Nft::lock(&TEST_COLLECTION_ID, &created_nft_id, LOCK_ID));
+		assert_eq!(Nft::lock_of(&TEST_COLLECTION_ID, &created_nft_id), Some(LOCK_ID));
+
+		assert_noop!(
+			Nft::transfer(RuntimeOrigin::signed(ALICE), TEST_COLLECTION_ID, created_nft_id, BOB),
+			crate::Error::<MockRuntime>::InstanceLocked
+		);
+		assert_noop!(
+			<Nft as Transfer<_>>::transfer(&TEST_COLLECTION_ID, &created_nft_id, &BOB),
+			DispatchError::from(crate::Error::<MockRuntime>::InstanceLocked)
+		);
+		assert_noop!(
+			Nft::burn(&TEST_COLLECTION_ID, &created_nft_id, None),
+			DispatchError::from(crate::Error::<MockRuntime>::InstanceLocked)
+		);
+
+		assert_ok!(Nft::unlock(&TEST_COLLECTION_ID, &created_nft_id, LOCK_ID));
+		assert_eq!(Nft::lock_of(&TEST_COLLECTION_ID, &created_nft_id), None);
+		assert_ok!(Nft::transfer(
+			RuntimeOrigin::signed(ALICE),
+			TEST_COLLECTION_ID,
+			created_nft_id,
+			BOB
+		));
+	});
+}
+
+/// Tests that an NFT can only hold one lock, and only be unlocked with the same lock id.
+#[test]
+fn lock_must_match() {
+	new_test_ext().execute_with(|| {
+		let created_nft_id = mint_nft_and_assert();
+
+		assert_noop!(
+			Nft::lock(&TEST_COLLECTION_ID, &(created_nft_id + 1), LOCK_ID),
+			DispatchError::from(crate::Error::<MockRuntime>::InstanceNotFound)
+		);
+
+		assert_ok!(Nft::lock(&TEST_COLLECTION_ID, &created_nft_id, LOCK_ID));
+		assert_noop!(
+			Nft::lock(&TEST_COLLECTION_ID, &created_nft_id, *b"otherloc"),
+			DispatchError::from(crate::Error::<MockRuntime>::InstanceLocked)
+		);
+		assert_noop!(
+			Nft::unlock(&TEST_COLLECTION_ID, &created_nft_id, *b"otherloc"),
+			DispatchError::from(crate::Error::<MockRuntime>::LockNotFound)
+		);
+	});
+}
@@ -8,9 +8,13 @@ use sp_std::marker::PhantomData;
 
 pub trait WeightInfo {
 	fn transfer() -> Weight;
+	fn approve() -> Weight;
+	fn set_approval_for_all() -> Weight;
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn transfer() -> Weight { Weight::from_ref_time(10_000) }
+	fn approve() -> Weight { Weight::from_ref_time(10_000) }
+	fn set_approval_for_all() -> Weight { Weight::from_ref_time(10_000) }
 }
@@ -14,9 +14,10 @@ use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use sp_std::sync::Arc;
 
 #[rpc(client, server)]
-pub trait LendingApi<BlockHash, MarketId>
+pub trait LendingApi<BlockHash, MarketId, Balance>
 where
 	MarketId: FromStr + Display,
+	Balance: FromStr + Display,
 {
 	#[method(name = "lending_currentInterestRate")]
 	fn current_interest_rate(
@@ -24,6 +25,20 @@ where
 		market_id: SafeRpcWrapper<MarketId>,
 		at: Option<BlockHash>,
 	) -> RpcResult<SafeRpcWrapper<Rate>>;
+
+	#[method(name = "lending_reserveBalance")]
+	fn reserve_balance(
+		&self,
+		market_id: SafeRpcWrapper<MarketId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<SafeRpcWrapper<Balance>>;
+
+	#[method(name = "lending_badDebt")]
+	fn bad_debt(
+		&self,
+		market_id: SafeRpcWrapper<MarketId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<SafeRpcWrapper<Balance>>;
 }
 
 pub struct Lending<C, Block> {
@@ -37,15 +52,16 @@ impl<C, M> Lending<C, M> {
 	}
 }
 
-impl<C, Block, MarketId> LendingApiServer<<Block as BlockT>::Hash, MarketId>
-	for Lending<C, (Block, MarketId)>
+impl<C, Block, MarketId, Balance> LendingApiServer<<Block as BlockT>::Hash, MarketId, Balance>
+	for Lending<C, (Block, MarketId, Balance)>
 where
 	Block: BlockT,
 	MarketId: Send + Sync + 'static + Codec + FromStr + Display,
+	Balance: Send + Sync + 'static + Codec + FromStr + Display,
 	C: Send + Sync + 'static,
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block>,
-	C::Api: LendingRuntimeApi<Block, MarketId>,
+	C::Api: LendingRuntimeApi<Block, MarketId, Balance>,
 {
 	fn current_interest_rate(
 		&self,
@@ -66,4 +82,42 @@ where
 			)))
 		})
 	}
+
+	fn reserve_balance(
+		&self,
+		market_id: SafeRpcWrapper<MarketId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SafeRpcWrapper<Balance>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.reserve_balance(&at, market_id.0);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn bad_debt(
+		&self,
+		market_id: SafeRpcWrapper<MarketId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SafeRpcWrapper<Balance>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.bad_debt(&at, market_id.0);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
 }
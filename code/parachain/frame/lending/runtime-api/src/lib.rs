@@ -7,11 +7,18 @@ use composable_traits::defi::Rate;
 // Lending Runtime API declaration. Implemented for each runtime at
 // `runtime/<runtime-name>/src/lib.rs`.
 sp_api::decl_runtime_apis! {
-	pub trait LendingRuntimeApi<MarketId>
+	pub trait LendingRuntimeApi<MarketId, Balance>
 	where
 		MarketId: Codec,
+		Balance: Codec,
 	{
 		/// Retrieve the current interest rate for the given `market_id`.
 		fn current_interest_rate(market_id: MarketId) -> SafeRpcWrapper<Rate>;
+
+		/// Retrieve the reserve of the given `market_id`, which absorbs bad debt first.
+		fn reserve_balance(market_id: MarketId) -> SafeRpcWrapper<Balance>;
+
+		/// Retrieve the total bad debt written off by the given `market_id`.
+		fn bad_debt(market_id: MarketId) -> SafeRpcWrapper<Balance>;
 	}
 }
@@ -1,22 +1,24 @@
 //! Benchmarks and sanity tests for lending. Only test that action do not error, not that produce
 //! positive side effects
 #![warn(unused_imports)]
-use self::currency::NORMALIZED;
+use self::currency::{NORMALIZED, PICA};
 
 use super::*;
 use crate::{self as pallet_lending, Pallet as Lending};
 use composable_traits::{
 	defi::{CurrencyPair, DeFiComposableConfig},
 	lending::{CreateInput, Lending as LendingTrait, RepayStrategy},
+	staking::Staking,
 	vault::StrategicVault,
 };
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::{
-	traits::{fungible, fungibles::Mutate, Get},
+	traits::{fungible, fungibles::Mutate, tokens::nonfungibles::Transfer, Get},
 	BoundedVec,
 };
 use frame_system::RawOrigin;
 use setup::*;
+use sp_runtime::Permill;
 use sp_std::prelude::*;
 type BalanceOf<T> = <T as DeFiComposableConfig>::Balance;
 
@@ -80,6 +82,10 @@ benchmarks! {
 			<T as frame_system::Config>::BlockNumber: From<u32>,
 			<T as pallet_timestamp::Config>::Moment: From<u64>,
 			<T as pallet_vault::Config>::Balance: From<u64>,
+			<T as pallet_lending::Config>::Staking: Staking<
+				RewardPoolId = <T as DeFiComposableConfig>::MayBeAssetId,
+				Balance = BalanceOf<T>,
+			>,
 	}
 
 	create_market {
@@ -123,6 +129,87 @@ benchmarks! {
 		Lending::<T>::deposit_collateral(origin.clone().into(), market_id, amount, false).unwrap();
 	}: _(origin, market_id, part)
 
+	set_financial_nft_collateral {
+		let LendingBenchmarkingSetup {
+			caller,
+			origin,
+			bank,
+			pair,
+			mut input,
+		} = lending_benchmarking_setup::<T>();
+
+		input.currency_pair.base = priced_pica::<T>();
+		let market_id = create_market_from_raw_origin::<T>(origin.clone(), input);
+
+		let (collection, _) = stake_pica::<T>(&caller, PICA::units(1).into());
+	}: _(origin, market_id, collection, Some(FinancialNftLiquidation::Unstake))
+
+	deposit_financial_nft_collateral {
+		let LendingBenchmarkingSetup {
+			caller,
+			origin,
+			bank,
+			pair,
+			mut input,
+		} = lending_benchmarking_setup::<T>();
+
+		let amount: BalanceOf<T> = PICA::units(1).into();
+
+		input.currency_pair.base = priced_pica::<T>();
+		let market_id = create_market_from_raw_origin::<T>(origin.clone(), input);
+
+		let (collection, instance) = stake_pica::<T>(&caller, amount);
+		Lending::<T>::set_financial_nft_collateral(origin.clone().into(), market_id, collection, Some(FinancialNftLiquidation::Unstake)).unwrap();
+
+		// the account already deposited as many financial NFTs as it can but one
+		for _ in 1..T::MaxFinancialNftCollaterals::get() {
+			let (collection, instance) = stake_pica::<T>(&caller, amount);
+			Lending::<T>::deposit_financial_nft_collateral(origin.clone().into(), market_id, collection, instance).unwrap();
+		}
+	}: _(origin, market_id, collection, instance)
+
+	withdraw_financial_nft_collateral {
+		let LendingBenchmarkingSetup {
+			caller,
+			origin,
+			bank,
+			pair,
+			mut input,
+		} = lending_benchmarking_setup::<T>();
+
+		let amount: BalanceOf<T> = PICA::units(1).into();
+
+		input.currency_pair.base = priced_pica::<T>();
+		let market_id = create_market_from_raw_origin::<T>(origin.clone(), input);
+
+		let (collection, instance) = stake_pica::<T>(&caller, amount);
+		Lending::<T>::set_financial_nft_collateral(origin.clone().into(), market_id, collection, Some(FinancialNftLiquidation::Unstake)).unwrap();
+		Lending::<T>::deposit_financial_nft_collateral(origin.clone().into(), market_id, collection, instance).unwrap();
+
+		// the remaining financial NFTs are valued to check the account stays collateralized
+		for _ in 1..T::MaxFinancialNftCollaterals::get() {
+			let (collection, instance) = stake_pica::<T>(&caller, amount);
+			Lending::<T>::deposit_financial_nft_collateral(origin.clone().into(), market_id, collection, instance).unwrap();
+		}
+	}: _(origin, market_id, collection, instance)
+
+	buy_seized_financial_nft {
+		let LendingBenchmarkingSetup {
+			caller,
+			origin,
+			bank,
+			pair,
+			input,
+		} = lending_benchmarking_setup::<T>();
+
+		let market_id = create_market_from_raw_origin::<T>(origin.clone(), input);
+
+		// seized by a liquidation whose liquidator could not pay for it
+		let (collection, instance) = stake_pica::<T>(&caller, PICA::units(1).into());
+		T::FinancialNft::transfer(&collection, &instance, &Lending::<T>::account_id(&market_id)).unwrap();
+		SeizedFinancialNfts::<T>::insert(market_id, (collection, instance), Permill::zero());
+	}: _(origin, market_id, collection, instance)
+
 	borrow {
 		let LendingBenchmarkingSetup {
 			caller,
@@ -330,7 +417,7 @@ benchmarks! {
 		<T as Config>::MultiCurrency::mint_into(pair.quote, account, bank).unwrap();
 	}:  {
 		// TODO: make it variable with x
-		Lending::<T>::handle_depositable(&market_config, &caller, part).unwrap()
+		Lending::<T>::handle_depositable(&market_id, &market_config, &caller, part).unwrap()
 	}
 
 	handle_must_liquidate {
@@ -358,7 +445,7 @@ benchmarks! {
 		<T as Config>::MultiCurrency::mint_into(pair.quote, account, bank).unwrap();
 	}:  {
 		// TODO: make it variable with x
-		Lending::<T>::handle_must_liquidate(&market_config, &caller).unwrap()
+		Lending::<T>::handle_must_liquidate(&market_id, &market_config, &caller).unwrap()
 	}
 
 	impl_benchmark_test_suite!(Lending, crate::mocks::general::new_test_ext(), crate::mocks::general::Runtime);
@@ -5,8 +5,11 @@ pub use codec::{Codec, Decode, Encode, FullCodec, MaxEncodedLen};
 
 use composable_traits::{
 	defi::{CurrencyPair, DeFiComposableConfig, MoreThanOneFixedU128},
+	fnft::FinancialNftProtocol,
 	lending::{math::InterestRateModel, CreateInput, UpdateInput},
 	oracle::Price,
+	staking::Staking,
+	time::ONE_WEEK,
 };
 use frame_benchmarking::whitelisted_caller;
 use frame_support::traits::Hooks;
@@ -85,6 +88,41 @@ pub(crate) fn setup_currency_pair<T: Config + pallet_oracle::Config + DeFiCompos
 	pair
 }
 
+/// Stakes `amount` of [`PICA`] for `account` in the PICA pool created at genesis by staking
+/// rewards, and prices the assets the position is valued in.
+///
+/// Returns the financial NFT of the position.
+pub(crate) fn stake_pica<T: Config + pallet_oracle::Config>(
+	account: &<T as frame_system::Config>::AccountId,
+	amount: <T as DeFiComposableConfig>::Balance,
+) -> (AssetIdOf<T>, <T as Config>::FinancialNftInstanceId)
+where
+	<T as Config>::Staking:
+		Staking<RewardPoolId = AssetIdOf<T>, Balance = <T as DeFiComposableConfig>::Balance>,
+{
+	use frame_support::traits::tokens::fungibles::Mutate;
+
+	let pica = encode_decode(PICA::ID);
+	<T as Config>::MultiCurrency::mint_into(pica, account, amount).unwrap();
+
+	let (collection, instance) =
+		<T as Config>::Staking::stake(account, &pica, amount, ONE_WEEK, false).unwrap();
+	for (asset_id, _) in
+		<T as Config>::FinancialNftProtocol::value_of(&collection, &instance).unwrap()
+	{
+		set_price::<T>(asset_id, 1_000_000_000_u64);
+	}
+	(collection, instance)
+}
+
+/// [`PICA`], priced to be the collateral of a market accepting the financial NFTs of
+/// [`stake_pica`].
+pub(crate) fn priced_pica<T: Config + pallet_oracle::Config>() -> AssetIdOf<T> {
+	let pica = encode_decode(PICA::ID);
+	set_price::<T>(pica, 1_000_000_000_u64);
+	pica
+}
+
 pub(crate) fn create_market_config<T: Config>(
 	collateral_asset: <T as DeFiComposableConfig>::MayBeAssetId,
 	borrow_asset: <T as DeFiComposableConfig>::MayBeAssetId,
@@ -16,7 +16,10 @@ use frame_support::{
 	},
 	weights::WeightToFee,
 };
-use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, FixedPointNumber, Percent};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, Percent,
+};
 use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
@@ -81,6 +84,11 @@ impl<T: Config> Pallet<T> {
 			amount_to_borrow,
 			false,
 		)?;
+		ensure!(
+			<T as Config>::MultiCurrency::balance(borrow_asset, &market_account) >=
+				MarketReserves::<T>::get(market_id),
+			Error::<T>::CannotBorrowMarketReserve
+		);
 		DebtIndex::<T>::insert(market_id, borrowing_account, new_account_interest_index);
 		BorrowTimestamp::<T>::insert(market_id, borrowing_account, LastBlockTimestamp::<T>::get());
 
@@ -107,10 +115,14 @@ impl<T: Config> Pallet<T> {
 	) -> Result<BorrowerData, DispatchError> {
 		let (_, market) = Self::get_market(market_id)?;
 
-		let collateral_balance_value = Self::get_price(
-			market.collateral_asset,
-			Self::collateral_of_account(market_id, account)?,
-		)?;
+		// financial NFTs alone are enough collateral
+		let collateral_balance = match Self::collateral_of_account(market_id, account) {
+			Err(_) if AccountFinancialNftCollateral::<T>::contains_key(market_id, account) =>
+				T::Balance::zero(),
+			collateral_balance => collateral_balance?,
+		};
+		let collateral_balance_value =
+			Self::collateral_value(market_id, &market, account, collateral_balance)?;
 
 		let account_total_debt_with_interest =
 			Self::total_debt_with_interest(market_id, account)?.unwrap_or_zero();
@@ -193,7 +205,9 @@ impl<T: Config> Pallet<T> {
 	) -> Result<T::Balance, DispatchError> {
 		let (_, market) = Self::get_market(market_id)?;
 		let borrow_asset_id = T::Vault::asset_id(&market.borrow_asset_vault)?;
-		Ok(<T as Config>::MultiCurrency::balance(borrow_asset_id, &Self::account_id(market_id)))
+		// the reserve stays on the market account, but is not available to borrowers
+		Ok(<T as Config>::MultiCurrency::balance(borrow_asset_id, &Self::account_id(market_id))
+			.saturating_sub(MarketReserves::<T>::get(market_id)))
 	}
 
 	pub(crate) fn do_get_borrow_limit(
@@ -205,7 +219,9 @@ impl<T: Config> Pallet<T> {
 			// afterwards.
 			.unwrap_or_else(CollateralLpAmountOf::<Self>::zero);
 
-		if collateral_balance > T::Balance::zero() {
+		if collateral_balance > T::Balance::zero() ||
+			AccountFinancialNftCollateral::<T>::contains_key(market_id, account)
+		{
 			let borrower = Self::create_borrower_data(market_id, account)?;
 			let balance = borrower
 				.get_borrow_limit()
@@ -54,29 +54,18 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(amount <= collateral_balance, Error::<T>::NotEnoughCollateralToWithdraw);
 
-		let borrow_asset = T::Vault::asset_id(&market.borrow_asset_vault)?;
-		let borrower_balance_with_interest =
-			Self::total_debt_with_interest(market_id, account)?.unwrap_or_zero();
-
-		let borrow_balance_value = Self::get_price(borrow_asset, borrower_balance_with_interest)?;
-
-		let collateral_balance_after_withdrawal_value =
-			Self::get_price(market.collateral_asset, collateral_balance.safe_sub(&amount)?)?;
-
-		let borrower_after_withdrawal = BorrowerData::new(
+		let collateral_balance_after_withdrawal_value = Self::collateral_value(
+			market_id,
+			&market,
+			account,
+			collateral_balance.safe_sub(&amount)?,
+		)?;
+		Self::ensure_collateralized(
+			market_id,
+			&market,
+			account,
 			collateral_balance_after_withdrawal_value,
-			borrow_balance_value,
-			market
-				.collateral_factor
-				.try_into_validated()
-				.map_err(|_| ArithmeticError::Overflow)?, // TODO: Use a proper error message?
-			market.under_collateralized_warn_percent,
-		);
-
-		ensure!(
-			!borrower_after_withdrawal.should_liquidate()?,
-			Error::<T>::WouldGoUnderCollateralized
-		);
+		)?;
 
 		let market_account = Self::account_id(market_id);
 		AccountCollateral::<T>::try_mutate(market_id, account, |collateral_balance| {
@@ -99,6 +88,47 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Value of `collateral_balance` of the collateral asset together with the financial NFTs
+	/// deposited by `account`, priced by the oracle.
+	pub(crate) fn collateral_value(
+		market_id: &MarketId,
+		market: &MarketConfigOf<T>,
+		account: &T::AccountId,
+		collateral_balance: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::get_price(market.collateral_asset, collateral_balance)?
+			.safe_add(&Self::financial_nft_collateral_value(market_id, account)?)
+			.map_err(Into::into)
+	}
+
+	/// Ensures the debt of `account` would not be liquidated if its collateral was worth
+	/// `collateral_value`.
+	pub(crate) fn ensure_collateralized(
+		market_id: &MarketId,
+		market: &MarketConfigOf<T>,
+		account: &T::AccountId,
+		collateral_value: T::Balance,
+	) -> Result<(), DispatchError> {
+		let borrow_asset = T::Vault::asset_id(&market.borrow_asset_vault)?;
+		let borrower_balance_with_interest =
+			Self::total_debt_with_interest(market_id, account)?.unwrap_or_zero();
+
+		let borrow_balance_value = Self::get_price(borrow_asset, borrower_balance_with_interest)?;
+
+		let borrower = BorrowerData::new(
+			collateral_value,
+			borrow_balance_value,
+			market
+				.collateral_factor
+				.try_into_validated()
+				.map_err(|_| Error::<T>::InvalidCollateralFactor)?,
+			market.under_collateralized_warn_percent,
+		);
+
+		ensure!(!borrower.should_liquidate()?, Error::<T>::WouldGoUnderCollateralized);
+		Ok(())
+	}
+
 	pub(crate) fn do_collateral_of_account(
 		market_id: &MarketId,
 		account: &T::AccountId,
@@ -0,0 +1,268 @@
+use crate::*;
+use composable_support::{math::safe::SafeAdd, validation::TryIntoValidated};
+use composable_traits::{
+	defi::DeFiComposableConfig,
+	fnft::{FinancialNftLock, FinancialNftProtocol},
+	lending::Lending,
+	oracle::Oracle,
+	staking::Staking,
+};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungibles::{Inspect, Transfer},
+		tokens::nonfungibles::{Inspect as NonFungiblesInspect, Transfer as NonFungiblesTransfer},
+	},
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, Permill,
+};
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_set_financial_nft_collateral(
+		manager: &T::AccountId,
+		market_id: &MarketId,
+		collection: <T as DeFiComposableConfig>::MayBeAssetId,
+		liquidation: Option<FinancialNftLiquidation>,
+	) -> Result<(), DispatchError> {
+		let (_, market) = Self::get_market(market_id)?;
+		ensure!(*manager == market.manager, Error::<T>::Unauthorized);
+		// the unstaked asset is sold as the collateral asset
+		if liquidation == Some(FinancialNftLiquidation::Unstake) {
+			ensure!(
+				T::Staking::pool_of_collection(&collection) == Some(market.collateral_asset),
+				Error::<T>::FinancialNftMustStakeCollateralAsset
+			);
+		}
+		match liquidation {
+			Some(liquidation) => FinancialNftCollaterals::<T>::insert(
+				market_id,
+				collection,
+				FinancialNftCollateral { accepted: true, liquidation },
+			),
+			// kept to liquidate the financial NFTs already deposited
+			None => FinancialNftCollaterals::<T>::mutate(market_id, collection, |collateral| {
+				if let Some(collateral) = collateral {
+					collateral.accepted = false;
+				}
+			}),
+		}
+		Ok(())
+	}
+
+	pub(crate) fn do_deposit_financial_nft_collateral(
+		market_id: &MarketId,
+		account: &T::AccountId,
+		collection: <T as DeFiComposableConfig>::MayBeAssetId,
+		instance: T::FinancialNftInstanceId,
+	) -> Result<(), DispatchError> {
+		Self::get_market(market_id)?;
+		ensure!(
+			FinancialNftCollaterals::<T>::get(market_id, collection)
+				.map_or(false, |collateral| collateral.accepted),
+			Error::<T>::FinancialNftNotAccepted
+		);
+		ensure!(
+			T::FinancialNft::owner(&collection, &instance).as_ref() == Some(account),
+			Error::<T>::MustBeFinancialNftOwner
+		);
+
+		AccountFinancialNftCollateral::<T>::try_mutate(market_id, account, |collaterals| {
+			collaterals
+				.get_or_insert_with(Default::default)
+				.try_push((collection, instance))
+				.map_err(|_| Error::<T>::TooManyFinancialNftCollaterals)
+		})?;
+
+		T::FinancialNft::transfer(&collection, &instance, &Self::account_id(market_id))?;
+		T::FinancialNft::lock(&collection, &instance, T::FinancialNftLockId::get())
+	}
+
+	pub(crate) fn do_withdraw_financial_nft_collateral(
+		market_id: &MarketId,
+		account: &T::AccountId,
+		collection: <T as DeFiComposableConfig>::MayBeAssetId,
+		instance: T::FinancialNftInstanceId,
+	) -> Result<(), DispatchError> {
+		let (_, market) = Self::get_market(market_id)?;
+		Self::remove_financial_nft_collateral(market_id, account, collection, instance)?;
+
+		let collateral_balance =
+			AccountCollateral::<T>::get(market_id, account).unwrap_or_else(Zero::zero);
+		let collateral_balance_after_withdrawal_value =
+			Self::collateral_value(market_id, &market, account, collateral_balance)?;
+		Self::ensure_collateralized(
+			market_id,
+			&market,
+			account,
+			collateral_balance_after_withdrawal_value,
+		)?;
+
+		T::FinancialNft::unlock(&collection, &instance, T::FinancialNftLockId::get())?;
+		T::FinancialNft::transfer(&collection, &instance, account)
+	}
+
+	pub(crate) fn do_buy_seized_financial_nft(
+		market_id: &MarketId,
+		buyer: &T::AccountId,
+		collection: <T as DeFiComposableConfig>::MayBeAssetId,
+		instance: T::FinancialNftInstanceId,
+	) -> Result<T::Balance, DispatchError> {
+		let discount = SeizedFinancialNfts::<T>::take(market_id, (collection, instance))
+			.ok_or(Error::<T>::SeizedFinancialNftNotFound)?;
+		let MarketAssets { borrow_asset, .. } = Self::get_assets_for_market(market_id)?;
+		let price = Self::financial_nft_price(borrow_asset, &collection, &instance, discount)?;
+		<T as Config>::MultiCurrency::transfer(
+			borrow_asset,
+			buyer,
+			&Self::account_id(market_id),
+			price,
+			false,
+		)?;
+		T::FinancialNft::transfer(&collection, &instance, buyer)?;
+		MarketReserves::<T>::mutate(market_id, |reserve| *reserve = reserve.saturating_add(price));
+		Ok(price)
+	}
+
+	/// Value of the financial NFTs deposited by `account` as collateral, priced by the oracle.
+	pub(crate) fn financial_nft_collateral_value(
+		market_id: &MarketId,
+		account: &T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		AccountFinancialNftCollateral::<T>::get(market_id, account)
+			.unwrap_or_default()
+			.iter()
+			.try_fold(T::Balance::zero(), |total, (collection, instance)| {
+				total.safe_add(&Self::financial_nft_value(collection, instance)?)
+			})
+	}
+
+	/// Price of the financial NFT in `borrow_asset`, its value less `discount`.
+	fn financial_nft_price(
+		borrow_asset: <T as DeFiComposableConfig>::MayBeAssetId,
+		collection: &<T as DeFiComposableConfig>::MayBeAssetId,
+		instance: &T::FinancialNftInstanceId,
+		discount: Permill,
+	) -> Result<T::Balance, DispatchError> {
+		Ok(discount.left_from_one().mul_floor(<T::Oracle as Oracle>::get_price_inverse(
+			borrow_asset,
+			Self::financial_nft_value(collection, instance)?,
+		)?))
+	}
+
+	/// Sum of the assets reported by [`Config::FinancialNftProtocol`] for the financial NFT,
+	/// priced by the oracle.
+	fn financial_nft_value(
+		collection: &<T as DeFiComposableConfig>::MayBeAssetId,
+		instance: &T::FinancialNftInstanceId,
+	) -> Result<T::Balance, DispatchError> {
+		T::FinancialNftProtocol::value_of(collection, instance)?
+			.into_iter()
+			.try_fold(T::Balance::zero(), |total, (asset_id, amount)| {
+				total.safe_add(&Self::get_price(asset_id, amount)?)
+			})
+	}
+
+	fn remove_financial_nft_collateral(
+		market_id: &MarketId,
+		account: &T::AccountId,
+		collection: <T as DeFiComposableConfig>::MayBeAssetId,
+		instance: T::FinancialNftInstanceId,
+	) -> Result<(), DispatchError> {
+		AccountFinancialNftCollateral::<T>::try_mutate_exists(market_id, account, |collaterals| {
+			let entries = collaterals.as_mut().ok_or(Error::<T>::FinancialNftCollateralNotFound)?;
+			let position = entries
+				.iter()
+				.position(|entry| *entry == (collection, instance))
+				.ok_or(Error::<T>::FinancialNftCollateralNotFound)?;
+			entries.remove(position);
+			if entries.is_empty() {
+				*collaterals = None;
+			}
+			Ok(())
+		})
+	}
+
+	/// Seize the financial NFTs deposited by `account` as collateral, liquidating each one as
+	/// configured for its collection.
+	///
+	/// Returns the amount of borrow asset paid by `liquidator` for the sold ones, which is already
+	/// on the market account.
+	pub(crate) fn seize_financial_nft_collateral(
+		liquidator: &T::AccountId,
+		market_id: &MarketId,
+		market: &MarketConfigOf<T>,
+		borrow_asset: <T as DeFiComposableConfig>::MayBeAssetId,
+		account: &T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		let market_account = Self::account_id(market_id);
+		let mut received = T::Balance::zero();
+		for (collection, instance) in
+			AccountFinancialNftCollateral::<T>::take(market_id, account).unwrap_or_default()
+		{
+			let FinancialNftCollateral { liquidation, .. } =
+				FinancialNftCollaterals::<T>::get(market_id, collection)
+					.ok_or(Error::<T>::FinancialNftNotAccepted)?;
+			T::FinancialNft::unlock(&collection, &instance, T::FinancialNftLockId::get())?;
+			let proceeds = match liquidation {
+				FinancialNftLiquidation::Unstake => {
+					// unstaked as the borrower, who keeps the rewards claimed on the way
+					T::FinancialNft::transfer(&collection, &instance, account)?;
+					let balance_before =
+						<T as Config>::MultiCurrency::balance(market.collateral_asset, account);
+					T::Staking::unstake(account, &(collection, instance))?;
+					let unstaked =
+						<T as Config>::MultiCurrency::balance(market.collateral_asset, account)
+							.saturating_sub(balance_before);
+					if !unstaked.is_zero() {
+						Self::do_deposit_collateral(
+							market_id,
+							account,
+							unstaked.try_into_validated()?,
+							false,
+						)?;
+					}
+					unstaked
+				},
+				FinancialNftLiquidation::Sale { discount } => {
+					let price =
+						Self::financial_nft_price(borrow_asset, &collection, &instance, discount)?;
+					if <T as Config>::MultiCurrency::can_withdraw(borrow_asset, liquidator, price)
+						.into_result()
+						.is_ok()
+					{
+						<T as Config>::MultiCurrency::transfer(
+							borrow_asset,
+							liquidator,
+							&market_account,
+							price,
+							false,
+						)?;
+						T::FinancialNft::transfer(&collection, &instance, liquidator)?;
+						received = received.safe_add(&price)?;
+						price
+					} else {
+						// the rest of the position is still liquidated, the financial NFT is
+						// sold later
+						SeizedFinancialNfts::<T>::insert(
+							market_id,
+							(collection, instance),
+							discount,
+						);
+						Zero::zero()
+					}
+				},
+			};
+			Self::deposit_event(Event::<T>::FinancialNftCollateralSeized {
+				market_id: *market_id,
+				account: account.clone(),
+				collection,
+				instance,
+				liquidation,
+				proceeds,
+			});
+		}
+		Ok(received)
+	}
+}
@@ -1,17 +1,23 @@
 use crate::*;
 use composable_traits::{
 	defi::{CurrencyPair, DeFiComposableConfig, DeFiEngine, Sell},
-	lending::Lending,
-	liquidation::Liquidation,
+	lending::{Lending, TotalDebtWithInterest},
+	liquidation::{Liquidation, LiquidationCallback},
 	oracle::Oracle,
 	vault::Vault,
 };
 use frame_support::{
 	pallet_prelude::*,
 	storage::{with_transaction, TransactionOutcome},
-	traits::fungible::Transfer as NativeTransfer,
+	traits::{
+		fungible::Transfer as NativeTransfer,
+		fungibles::{Inspect, InspectHold, Mutate, MutateHold, Transfer},
+	},
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError,
 };
-use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
 impl<T: Config> Pallet<T> {
@@ -53,16 +59,55 @@ impl<T: Config> Pallet<T> {
 			DispatchError::Other("Tried liquidate position which is not supposed to be liquidated")
 		);
 
-		let collateral_to_liquidate = Self::collateral_of_account(market_id, account)?;
+		let received = Self::seize_financial_nft_collateral(
+			liquidator,
+			market_id,
+			market,
+			borrow_asset,
+			account,
+		)?;
+		// the collateral is sold, so it cannot be withdrawn until the unsold part is credited back
+		let collateral_to_liquidate =
+			AccountCollateral::<T>::take(market_id, account).unwrap_or_else(Zero::zero);
 
 		let source_target_account = Self::account_id(market_id);
 
-		let unit_price =
-			T::Oracle::get_ratio(CurrencyPair::new(market.collateral_asset, borrow_asset))?;
+		let debt = match Self::total_debt_with_interest(market_id, account)? {
+			TotalDebtWithInterest::Amount(amount) => amount,
+			TotalDebtWithInterest::NoDebt => Zero::zero(),
+		};
+		// the debt is settled out of the proceeds, burn the debt tokens marking the principal
+		let MarketAssets { debt_asset, .. } = Self::get_assets_for_market(market_id)?;
+		let principal = <T as Config>::MultiCurrency::balance_on_hold(debt_asset, account);
+		<T as Config>::MultiCurrency::release(debt_asset, account, principal, false)?;
+		<T as Config>::MultiCurrency::burn_from(debt_asset, account, principal)?;
+		let pending = PendingLiquidation {
+			market_id: **market_id,
+			account: account.clone(),
+			debt,
+			principal,
+			received,
+		};
 
-		let sell =
-			Sell::new(market.collateral_asset, borrow_asset, collateral_to_liquidate, unit_price);
-		T::Liquidation::liquidate(&source_target_account, sell, market.liquidators.clone())?;
+		if collateral_to_liquidate.is_zero() {
+			// only financial NFTs were deposited, nothing is left to sell
+			Self::settle_liquidation(pending, Zero::zero(), Zero::zero())?;
+		} else {
+			let unit_price =
+				T::Oracle::get_ratio(CurrencyPair::new(market.collateral_asset, borrow_asset))?;
+			let sell = Sell::new(
+				market.collateral_asset,
+				borrow_asset,
+				collateral_to_liquidate,
+				unit_price,
+			);
+			let order_id = T::Liquidation::liquidate(
+				&source_target_account,
+				sell,
+				market.liquidators.clone(),
+			)?;
+			PendingLiquidations::<T>::insert(order_id, pending);
+		}
 		if let Some(deposit) = BorrowRent::<T>::get(market_id, account) {
 			let market_account = Self::account_id(market_id);
 			<T as Config>::NativeCurrency::transfer(&market_account, liquidator, deposit, false)?;
@@ -113,3 +158,78 @@ impl<T: Config> Pallet<T> {
 		Ok(subjected_borrowers)
 	}
 }
+
+impl<T: Config>
+	LiquidationCallback<T::AccountId, LiquidationOrderIdOf<T>, <T as DeFiComposableConfig>::Balance>
+	for Pallet<T>
+{
+	fn on_liquidation_finalized(
+		order_id: LiquidationOrderIdOf<T>,
+		_from_to: &T::AccountId,
+		received: T::Balance,
+		unsold: T::Balance,
+	) -> Result<(), DispatchError> {
+		match PendingLiquidations::<T>::take(order_id) {
+			Some(pending) => Self::settle_liquidation(pending, received, unsold),
+			// not initiated by lending
+			None => Ok(()),
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Settles the debt of the liquidated borrower with the actual proceeds of the liquidation,
+	/// including the borrow asset already received for its financial NFTs.
+	///
+	/// Proceeds are already on the market account, so they repay the debt as is. Whatever
+	/// exceeds the debt goes to the borrower, while the shortfall is written off as bad debt.
+	/// Unsold collateral is credited back as the borrower's collateral.
+	fn settle_liquidation(
+		pending: PendingLiquidationOf<T>,
+		received: T::Balance,
+		unsold: T::Balance,
+	) -> Result<(), DispatchError> {
+		let PendingLiquidation { market_id, account, debt, principal, received: received_before } =
+			pending;
+		let received = received.saturating_add(received_before);
+		let market_account = Self::account_id(&market_id);
+		let MarketAssets { borrow_asset, debt_asset } = Self::get_assets_for_market(&market_id)?;
+
+		// the debt is repaid as much as the proceeds allow, burn the interest accrued on it
+		let interest = debt
+			.saturating_sub(principal)
+			.min(<T as Config>::MultiCurrency::balance(debt_asset, &market_account));
+		<T as Config>::MultiCurrency::burn_from(debt_asset, &market_account, interest)?;
+
+		let repaid = received.min(debt);
+		let surplus = received.saturating_sub(repaid);
+		if !surplus.is_zero() {
+			<T as Config>::MultiCurrency::transfer(
+				borrow_asset,
+				&market_account,
+				&account,
+				surplus,
+				false,
+			)?;
+		}
+
+		if !unsold.is_zero() {
+			AccountCollateral::<T>::mutate(market_id, &account, |collateral| {
+				*collateral = Some(collateral.unwrap_or_else(Zero::zero).saturating_add(unsold))
+			});
+		}
+
+		// principal is repaid first, the rest of the proceeds is interest
+		Self::fund_reserve(&market_id, repaid.saturating_sub(principal))?;
+		let shortfall = debt.saturating_sub(repaid);
+		Self::write_off_bad_debt(&market_id, &account, shortfall)?;
+
+		Self::deposit_event(Event::<T>::LiquidationSettled {
+			market_id,
+			account,
+			repaid,
+			shortfall,
+		});
+		Ok(())
+	}
+}
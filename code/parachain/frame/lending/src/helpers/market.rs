@@ -85,6 +85,7 @@ impl<T: Config> Pallet<T> {
 					.updatable
 					.under_collateralized_warn_percent,
 				liquidators: config_input.updatable.liquidators,
+				reserved_factor: config_input.reserved_factor(),
 			};
 			let debt_token_id = T::CurrencyFactory::reserve_lp_token_id()?;
 
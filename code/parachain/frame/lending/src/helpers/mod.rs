@@ -1,5 +1,6 @@
 pub mod borrow;
 pub mod collateral;
+pub mod financial_nft;
 pub mod interest;
 pub mod liquidation;
 pub mod market;
@@ -7,3 +8,4 @@ pub mod offchain_workers;
 pub mod on_init;
 pub mod price;
 pub mod repay_borrow;
+pub mod reserve;
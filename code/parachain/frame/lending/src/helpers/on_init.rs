@@ -7,7 +7,7 @@ use frame_support::{
 	storage::{with_transaction, TransactionOutcome},
 	traits::{fungibles::Inspect, UnixTime},
 };
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::Saturating, DispatchError};
 
 impl<T: Config> Pallet<T> {
 	pub(crate) fn initialize_block(block_number: T::BlockNumber) -> InitializeBlockCallCounters {
@@ -47,11 +47,16 @@ impl<T: Config> Pallet<T> {
 							call_counters.handle_withdrawable += 1;
 						},
 						FundsAvailability::Depositable(balance) => {
-							Self::handle_depositable(&config, &market_account, balance)?;
+							Self::handle_depositable(
+								&market_id,
+								&config,
+								&market_account,
+								balance,
+							)?;
 							call_counters.handle_depositable += 1;
 						},
 						FundsAvailability::MustLiquidate => {
-							Self::handle_must_liquidate(&config, &market_account)?;
+							Self::handle_must_liquidate(&market_id, &config, &market_account)?;
 							call_counters.handle_must_liquidate += 1;
 						},
 						FundsAvailability::None => {},
@@ -106,24 +111,34 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(crate) fn handle_depositable(
+		market_id: &MarketId,
 		config: &MarketConfigOf<T>,
 		market_account: &T::AccountId,
 		balance: T::Balance,
 	) -> Result<(), DispatchError> {
-		let asset_id = <T::Vault as Vault>::asset_id(&config.borrow_asset_vault)?;
-		let balance =
-			<T as Config>::MultiCurrency::reducible_balance(asset_id, market_account, false)
-				.min(balance);
+		let balance = Self::reducible_balance_excluding_reserve(market_id, config, market_account)?
+			.min(balance);
 		<T::Vault as StrategicVault>::deposit(&config.borrow_asset_vault, market_account, balance)
 	}
 
 	pub(crate) fn handle_must_liquidate(
+		market_id: &MarketId,
 		config: &MarketConfigOf<T>,
 		market_account: &T::AccountId,
 	) -> Result<(), DispatchError> {
-		let asset_id = <T::Vault as Vault>::asset_id(&config.borrow_asset_vault)?;
-		let balance =
-			<T as Config>::MultiCurrency::reducible_balance(asset_id, market_account, false);
+		let balance = Self::reducible_balance_excluding_reserve(market_id, config, market_account)?;
 		<T::Vault as StrategicVault>::deposit(&config.borrow_asset_vault, market_account, balance)
 	}
+
+	/// Balance of borrow asset the market may return to the vault, which never includes the
+	/// reserve.
+	fn reducible_balance_excluding_reserve(
+		market_id: &MarketId,
+		config: &MarketConfigOf<T>,
+		market_account: &T::AccountId,
+	) -> Result<T::Balance, DispatchError> {
+		let asset_id = <T::Vault as Vault>::asset_id(&config.borrow_asset_vault)?;
+		Ok(<T as Config>::MultiCurrency::reducible_balance(asset_id, market_account, false)
+			.saturating_sub(MarketReserves::<T>::get(market_id)))
+	}
 }
@@ -68,6 +68,7 @@ impl<T: Config> Pallet<T> {
 					beneficiary_interest_on_market,
 					keep_alive,
 				)?;
+				Self::fund_reserve(market_id, beneficiary_interest_on_market)?;
 
 				// release and burn debt token from beneficiary and transfer borrow asset to
 				// market, paid by `from`
@@ -108,6 +109,11 @@ impl<T: Config> Pallet<T> {
 				)
 				.ok_or(ArithmeticError::Overflow)?;
 
+				let interest_to_repay = interest_percentage
+					.checked_mul_int::<u128>(partial_repay_amount.into())
+					.ok_or(ArithmeticError::Overflow)?
+					.into();
+
 				// pay interest, from -> market
 				// burn interest (debt token) from market
 				Self::pay_interest(
@@ -115,12 +121,10 @@ impl<T: Config> Pallet<T> {
 					debt_asset,
 					from,
 					&market_account,
-					interest_percentage
-						.checked_mul_int::<u128>(partial_repay_amount.into())
-						.ok_or(ArithmeticError::Overflow)?
-						.into(),
+					interest_to_repay,
 					keep_alive,
 				)?;
+				Self::fund_reserve(market_id, interest_to_repay)?;
 
 				// release and burn debt token from beneficiary and transfer borrow asset to
 				// market, paid by `from`
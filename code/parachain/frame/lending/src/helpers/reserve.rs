@@ -0,0 +1,60 @@
+use crate::*;
+use composable_traits::{lending::Lending, vault::ReportableStrategicVault};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Set aside the `reserved_factor` part of `interest` repaid to the market.
+	pub(crate) fn fund_reserve(
+		market_id: &MarketId,
+		interest: T::Balance,
+	) -> Result<(), DispatchError> {
+		let (_, market) = Self::get_market(market_id)?;
+		let amount = market.reserved_factor.mul_floor(interest);
+		if !amount.is_zero() {
+			MarketReserves::<T>::mutate(market_id, |reserve| {
+				*reserve = reserve.saturating_add(amount)
+			});
+		}
+		Ok(())
+	}
+
+	/// Write off the `shortfall` of `account`'s liquidation. The reserve of the market absorbs it
+	/// first, the remaining loss is reported to the vault up to what the market owes to it.
+	pub(crate) fn write_off_bad_debt(
+		market_id: &MarketId,
+		account: &T::AccountId,
+		shortfall: T::Balance,
+	) -> Result<(), DispatchError> {
+		if shortfall.is_zero() {
+			return Ok(())
+		}
+		let (_, market) = Self::get_market(market_id)?;
+		// the reserve is already on the market account, so it only has to be released
+		let from_reserve = MarketReserves::<T>::mutate(market_id, |reserve| {
+			let from_reserve = shortfall.min(*reserve);
+			*reserve = reserve.saturating_sub(from_reserve);
+			from_reserve
+		});
+		let remaining = shortfall.saturating_sub(from_reserve);
+		let socialized = if remaining.is_zero() {
+			remaining
+		} else {
+			<T::Vault as ReportableStrategicVault>::report_loss(
+				&market.borrow_asset_vault,
+				&Self::account_id(market_id),
+				remaining,
+			)?
+		};
+		BadDebt::<T>::mutate(market_id, |bad_debt| *bad_debt = bad_debt.saturating_add(shortfall));
+		Self::deposit_event(Event::<T>::BadDebtWrittenOff {
+			market_id: *market_id,
+			account: account.clone(),
+			from_reserve,
+			socialized,
+		});
+		Ok(())
+	}
+}
@@ -43,6 +43,7 @@ pub use crate::weights::WeightInfo;
 
 pub mod crypto;
 mod helpers;
+pub mod migrations;
 mod models;
 mod types;
 
@@ -65,19 +66,24 @@ pub mod pallet {
 	// ----------------------------------------------------------------------------------------------------
 
 	pub(crate) use crate::types::MarketAssets;
-	pub use crate::types::{MarketId, MarketIdInner};
+	pub use crate::types::{
+		FinancialNftCollateral, FinancialNftLiquidation, MarketId, MarketIdInner,
+		PendingLiquidation,
+	};
 	use crate::weights::WeightInfo;
 	use composable_traits::{
 		currency::CurrencyFactory,
 		defi::{DeFiComposableConfig, *},
+		fnft::{FinancialNftLock, FinancialNftProtocol},
 		lending::{
 			BorrowAmountOf, CollateralLpAmountOf, CreateInput, LendAssetAmountOf, Lending,
 			MarketConfig, RepayStrategy, TotalDebtWithInterest, UpdateInput,
 		},
 		liquidation::Liquidation,
 		oracle::Oracle,
+		staking::Staking,
 		time::Timestamp,
-		vault::{StrategicVault, Vault},
+		vault::{ReportableStrategicVault, Vault},
 	};
 
 	pub use crate::crypto;
@@ -88,7 +94,8 @@ pub mod pallet {
 		traits::{
 			fungible::{Inspect as NativeInspect, Transfer as NativeTransfer},
 			fungibles::{InspectHold, Mutate, MutateHold, Transfer},
-			UnixTime,
+			tokens::nonfungibles::Transfer as NonFungiblesTransfer,
+			LockIdentifier, UnixTime,
 		},
 		transactional,
 		weights::{WeightToFee, WeightToFeePolynomial},
@@ -101,7 +108,7 @@ pub mod pallet {
 	use sp_core::crypto::KeyTypeId;
 	use sp_runtime::{
 		traits::{AccountIdConversion, Get},
-		DispatchError, KeyTypeId as CryptoKeyTypeId, Percent,
+		DispatchError, KeyTypeId as CryptoKeyTypeId, Percent, Permill,
 	};
 	use sp_std::{fmt::Debug, vec::Vec};
 
@@ -109,8 +116,11 @@ pub mod pallet {
 	//                                   @Declaration Of The Pallet Type
 	// ----------------------------------------------------------------------------------------------------
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -134,8 +144,9 @@ pub mod pallet {
 		/// The `id`s to be used for the [`Vault`][Config::Vault].
 		type VaultId: Clone + Codec + MaxEncodedLen + Debug + PartialEq + Default + Parameter;
 
-		/// The Vault used to store the borrow asset.
-		type Vault: StrategicVault<
+		/// The Vault used to store the borrow asset. Losses not covered by the reserve of a
+		/// market are reported to it.
+		type Vault: ReportableStrategicVault<
 			VaultId = Self::VaultId,
 			AssetId = <Self as DeFiComposableConfig>::MayBeAssetId,
 			Balance = Self::Balance,
@@ -230,6 +241,43 @@ pub mod pallet {
 		/// Convert a weight value into a deductible fee based on the currency type.
 		type WeightToFee: WeightToFeePolynomial<Balance = Self::Balance>
 			+ WeightToFee<Balance = Self::Balance>;
+
+		/// Financial NFTs which can be deposited as collateral, see
+		/// [`FinancialNftCollaterals`]. Collection ids are asset ids.
+		type FinancialNft: FinancialNftLock<
+				Self::AccountId,
+				CollectionId = <Self as DeFiComposableConfig>::MayBeAssetId,
+				ItemId = Self::FinancialNftInstanceId,
+			> + NonFungiblesTransfer<Self::AccountId>;
+
+		type FinancialNftInstanceId: Parameter + MaxEncodedLen + Copy;
+
+		/// Reports the value of the financial NFTs deposited as collateral.
+		type FinancialNftProtocol: FinancialNftProtocol<
+			ItemId = Self::FinancialNftInstanceId,
+			AssetId = <Self as DeFiComposableConfig>::MayBeAssetId,
+			Balance = Self::Balance,
+		>;
+
+		/// Unstakes the financial NFTs liquidated with [`FinancialNftLiquidation::Unstake`]. Pools
+		/// are identified by the asset they stake.
+		type Staking: Staking<
+			AccountId = Self::AccountId,
+			RewardPoolId = <Self as DeFiComposableConfig>::MayBeAssetId,
+			PositionId = (
+				<Self as DeFiComposableConfig>::MayBeAssetId,
+				Self::FinancialNftInstanceId,
+			),
+			CollectionId = <Self as DeFiComposableConfig>::MayBeAssetId,
+		>;
+
+		/// Lock put on the financial NFTs held as collateral.
+		#[pallet::constant]
+		type FinancialNftLockId: Get<LockIdentifier>;
+
+		/// The maximum amount of financial NFTs an account can deposit as collateral in a market.
+		#[pallet::constant]
+		type MaxFinancialNftCollaterals: Get<u32>;
 	}
 
 	// ----------------------------------------------------------------------------------------------------
@@ -250,6 +298,17 @@ pub mod pallet {
 		<T as DeFiComposableConfig>::MayBeAssetId,
 		<T as frame_system::Config>::BlockNumber,
 	>;
+	/// Id of a liquidation initiated by this pallet.
+	pub type LiquidationOrderIdOf<T> = <<T as Config>::Liquidation as Liquidation>::OrderId;
+	pub(crate) type PendingLiquidationOf<T> = PendingLiquidation<
+		<T as frame_system::Config>::AccountId,
+		<T as DeFiComposableConfig>::Balance,
+	>;
+	/// Financial NFTs deposited as collateral, as `(collection, instance)`.
+	pub type FinancialNftCollateralsOf<T> = BoundedVec<
+		(<T as DeFiComposableConfig>::MayBeAssetId, <T as Config>::FinancialNftInstanceId),
+		<T as Config>::MaxFinancialNftCollaterals,
+	>;
 
 	// ----------------------------------------------------------------------------------------------------
 	//                                      @Pallet Constants
@@ -351,6 +410,92 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Financial NFT collections accepted as collateral by a market, and how their financial NFTs
+	/// are liquidated. Collections no longer accepted are kept to liquidate the financial NFTs
+	/// already deposited.
+	///
+	/// ```text
+	/// (MarketId, collection) -> FinancialNftCollateral
+	/// ```
+	#[pallet::storage]
+	pub type FinancialNftCollaterals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MarketId,
+		Twox64Concat,
+		<T as DeFiComposableConfig>::MayBeAssetId,
+		FinancialNftCollateral,
+		OptionQuery,
+	>;
+
+	/// Financial NFTs deposited by an account as collateral. They are held by the market account
+	/// under [`Config::FinancialNftLockId`].
+	///
+	/// ```text
+	/// (MarketId, Account) -> [(collection, instance)]
+	/// ```
+	#[pallet::storage]
+	pub type AccountFinancialNftCollateral<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MarketId,
+		Blake2_128Concat,
+		T::AccountId,
+		FinancialNftCollateralsOf<T>,
+		OptionQuery,
+	>;
+
+	/// Financial NFTs seized by a liquidation whose liquidator could not pay for them. They are
+	/// held by the market account until bought with [`Pallet::buy_seized_financial_nft`], at the
+	/// discount of their collection when seized.
+	///
+	/// ```text
+	/// (MarketId, (collection, instance)) -> discount
+	/// ```
+	#[pallet::storage]
+	pub type SeizedFinancialNfts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MarketId,
+		Twox64Concat,
+		(<T as DeFiComposableConfig>::MayBeAssetId, T::FinancialNftInstanceId),
+		Permill,
+		OptionQuery,
+	>;
+
+	/// Liquidations waiting for the engine to report their outcome.
+	///
+	/// ```text
+	/// LiquidationOrderId -> PendingLiquidation
+	/// ```
+	#[pallet::storage]
+	pub type PendingLiquidations<T: Config> =
+		StorageMap<_, Twox64Concat, LiquidationOrderIdOf<T>, PendingLiquidationOf<T>, OptionQuery>;
+
+	/// Part of the borrow asset on the market account set aside from repaid interest, according
+	/// to the market's `reserved_factor`. It is neither borrowed nor returned to the vault, and
+	/// absorbs the shortfalls of liquidations first.
+	///
+	/// ```text
+	/// MarketId -> Balance
+	/// ```
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_balance)]
+	#[allow(clippy::disallowed_types)] // markets without reserve hold zero
+	pub type MarketReserves<T: Config> =
+		StorageMap<_, Twox64Concat, MarketId, T::Balance, ValueQuery>;
+
+	/// Debt of liquidated borrowers which was not covered by the liquidation proceeds, whether
+	/// absorbed by the reserve or socialized to the lenders.
+	///
+	/// ```text
+	/// MarketId -> Balance
+	/// ```
+	#[pallet::storage]
+	#[pallet::getter(fn bad_debt)]
+	#[allow(clippy::disallowed_types)] // markets without bad debt hold zero
+	pub type BadDebt<T: Config> = StorageMap<_, Twox64Concat, MarketId, T::Balance, ValueQuery>;
+
 	/// The timestamp of the previous block or defaults to timestamp at genesis.
 	#[pallet::storage]
 	#[allow(clippy::disallowed_types)] // LastBlockTimestamp is set on genesis (see below) so it will always be set.
@@ -382,6 +527,47 @@ pub mod pallet {
 		CollateralDeposited { sender: T::AccountId, market_id: MarketId, amount: T::Balance },
 		/// Event emitted when collateral is withdrawn.
 		CollateralWithdrawn { sender: T::AccountId, market_id: MarketId, amount: T::Balance },
+		/// Event emitted when the market manager accepts a financial NFT collection as collateral,
+		/// or stops accepting it if `liquidation` is `None`.
+		FinancialNftCollateralSet {
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			liquidation: Option<FinancialNftLiquidation>,
+		},
+		/// Event emitted when a financial NFT is deposited as collateral.
+		FinancialNftCollateralDeposited {
+			sender: T::AccountId,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+		},
+		/// Event emitted when a financial NFT deposited as collateral is withdrawn.
+		FinancialNftCollateralWithdrawn {
+			sender: T::AccountId,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+		},
+		/// Event emitted when a financial NFT deposited as collateral is seized by a liquidation.
+		/// `proceeds` is the amount of collateral asset unstaked, or of borrow asset paid by the
+		/// liquidator, depending on `liquidation`. It is zero for the financial NFTs kept in
+		/// [`SeizedFinancialNfts`].
+		FinancialNftCollateralSeized {
+			market_id: MarketId,
+			account: T::AccountId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+			liquidation: FinancialNftLiquidation,
+			proceeds: T::Balance,
+		},
+		/// Event emitted when a financial NFT kept seized by a liquidation is bought.
+		SeizedFinancialNftBought {
+			buyer: T::AccountId,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+			price: T::Balance,
+		},
 		/// Event emitted when user borrows from given market.
 		Borrowed { sender: T::AccountId, market_id: MarketId, amount: T::Balance },
 		/// Event emitted when user repays borrow of beneficiary in given market.
@@ -393,6 +579,25 @@ pub mod pallet {
 		},
 		/// Event emitted when a liquidation is initiated for a loan.
 		LiquidationInitiated { market_id: MarketId, borrowers: Vec<T::AccountId> },
+		/// Event emitted when a liquidation is finalized and the borrower's debt is settled with
+		/// its proceeds. `shortfall` is the part of the debt not covered, see
+		/// [`Event::BadDebtWrittenOff`].
+		LiquidationSettled {
+			market_id: MarketId,
+			account: T::AccountId,
+			repaid: T::Balance,
+			shortfall: T::Balance,
+		},
+		/// Event emitted when the shortfall of a liquidation is written off. `from_reserve` is
+		/// absorbed by the reserve of the market, while `socialized` is reported as a loss to the
+		/// vault, lowering the share price of the lenders. `socialized` is capped at the balance
+		/// the market owes to the vault.
+		BadDebtWrittenOff {
+			market_id: MarketId,
+			account: T::AccountId,
+			from_reserve: T::Balance,
+			socialized: T::Balance,
+		},
 		/// Event emitted to warn that loan may go under collateralize soon.
 		MayGoUnderCollateralizedSoon { market_id: MarketId, account: T::AccountId },
 	}
@@ -453,6 +658,20 @@ pub mod pallet {
 		// If Vault is unbalanced we can not borrow from it, since
 		// we do not know how many asset one needs to balance the value.
 		CannotBorrowFromMarketWithUnbalancedVault,
+		/// The reserve of the market cannot be borrowed.
+		CannotBorrowMarketReserve,
+		/// The financial NFT collection is not accepted as collateral by the market.
+		FinancialNftNotAccepted,
+		/// Only the owner of a financial NFT can deposit it as collateral.
+		MustBeFinancialNftOwner,
+		/// The financial NFT was not deposited as collateral by the account.
+		FinancialNftCollateralNotFound,
+		/// The account already deposited the maximum amount of financial NFTs in the market.
+		TooManyFinancialNftCollaterals,
+		/// Financial NFTs liquidated by unstaking must stake the collateral asset of the market.
+		FinancialNftMustStakeCollateralAsset,
+		/// The financial NFT is not kept seized by the market.
+		SeizedFinancialNftNotFound,
 	}
 
 	// ----------------------------------------------------------------------------------------------------
@@ -466,6 +685,10 @@ pub mod pallet {
 			call_counters.calculate_weight::<T>()
 		}
 
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+
 		fn offchain_worker(_block_number: T::BlockNumber) {
 			log::info!("Off-chain worker running");
 			Self::do_offchain_worker(_block_number)
@@ -848,6 +1071,108 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Accept financial NFTs of `collection` as collateral, or stop accepting new ones if
+		/// `liquidation` is `None`. Financial NFTs already deposited remain collateral, liquidated
+		/// as last set.
+		/// - `origin` : Sender of this extrinsic. Must be the manager of the market.
+		/// - `market_id` : Market index which accepts the collection.
+		/// - `collection` : Financial NFT collection, valued by [`Config::FinancialNftProtocol`].
+		/// - `liquidation` : How the seized financial NFTs of the collection are liquidated.
+		#[pallet::weight(<T as Config>::WeightInfo::set_financial_nft_collateral())]
+		#[transactional]
+		pub fn set_financial_nft_collateral(
+			origin: OriginFor<T>,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			liquidation: Option<FinancialNftLiquidation>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_set_financial_nft_collateral(&who, &market_id, collection, liquidation)?;
+			Self::deposit_event(Event::<T>::FinancialNftCollateralSet {
+				market_id,
+				collection,
+				liquidation,
+			});
+			Ok(().into())
+		}
+
+		/// Deposit a financial NFT as collateral. It is transferred to the market account and
+		/// locked until withdrawn or seized by a liquidation.
+		/// - `origin` : Sender of this extrinsic. Must own the financial NFT.
+		/// - `market_id` : Market index to which the financial NFT will be deposited.
+		/// - `collection` : Collection of the financial NFT, accepted by the market.
+		/// - `instance` : Instance of the financial NFT.
+		#[pallet::weight(<T as Config>::WeightInfo::deposit_financial_nft_collateral())]
+		#[transactional]
+		pub fn deposit_financial_nft_collateral(
+			origin: OriginFor<T>,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_deposit_financial_nft_collateral(&market_id, &sender, collection, instance)?;
+			Self::deposit_event(Event::<T>::FinancialNftCollateralDeposited {
+				sender,
+				market_id,
+				collection,
+				instance,
+			});
+			Ok(().into())
+		}
+
+		/// Withdraw a financial NFT deposited as collateral.
+		/// - `origin` : Sender of this extrinsic.
+		/// - `market_id` : Market index from which the financial NFT will be withdrawn.
+		/// - `collection` : Collection of the financial NFT.
+		/// - `instance` : Instance of the financial NFT.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_financial_nft_collateral())]
+		#[transactional]
+		pub fn withdraw_financial_nft_collateral(
+			origin: OriginFor<T>,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_withdraw_financial_nft_collateral(&market_id, &sender, collection, instance)?;
+			Self::deposit_event(Event::<T>::FinancialNftCollateralWithdrawn {
+				sender,
+				market_id,
+				collection,
+				instance,
+			});
+			Ok(().into())
+		}
+
+		/// Buy a financial NFT kept seized by a liquidation whose liquidator could not pay for
+		/// it, at its oracle value in the borrow asset less the discount of its collection. The
+		/// liquidation was settled without it, so the price funds the market reserve.
+		/// - `origin` : Sender of this extrinsic, who pays the price.
+		/// - `market_id` : Market index which seized the financial NFT.
+		/// - `collection` : Collection of the financial NFT.
+		/// - `instance` : Instance of the financial NFT.
+		#[pallet::weight(<T as Config>::WeightInfo::buy_seized_financial_nft())]
+		#[transactional]
+		pub fn buy_seized_financial_nft(
+			origin: OriginFor<T>,
+			market_id: MarketId,
+			collection: T::MayBeAssetId,
+			instance: T::FinancialNftInstanceId,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+			let price =
+				Self::do_buy_seized_financial_nft(&market_id, &buyer, collection, instance)?;
+			Self::deposit_event(Event::<T>::SeizedFinancialNftBought {
+				buyer,
+				market_id,
+				collection,
+				instance,
+				price,
+			});
+			Ok(().into())
+		}
+
 		/// Borrow asset against deposited collateral.
 		/// - `origin` : Sender of this extrinsic. (Also the user who wants to borrow from market.)
 		/// - `market_id` : Market index from which user wants to borrow.
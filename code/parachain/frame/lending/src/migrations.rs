@@ -0,0 +1,68 @@
+//! Storage migrations of the lending pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds a `reserved_factor` to the configuration of stored markets.
+
+	use super::*;
+	use codec::Decode;
+	use composable_traits::{
+		defi::{DeFiComposableConfig, MoreThanOneFixedU128},
+		lending::{math::InterestRateModel, MarketConfig},
+	};
+	use frame_support::{
+		traits::{Get, GetStorageVersion, StorageVersion},
+		weights::Weight,
+	};
+	use sp_runtime::{Percent, Perquintill};
+	use sp_std::vec::Vec;
+
+	/// Market configuration as stored before the introduction of market reserves.
+	#[derive(Decode)]
+	struct OldMarketConfig<VaultId, AssetId, AccountId, LiquidationStrategyId, BlockNumber> {
+		manager: AccountId,
+		borrow_asset_vault: VaultId,
+		collateral_asset: AssetId,
+		max_price_age: BlockNumber,
+		collateral_factor: MoreThanOneFixedU128,
+		interest_rate_model: InterestRateModel,
+		under_collateralized_warn_percent: Percent,
+		liquidators: Vec<LiquidationStrategyId>,
+	}
+
+	type OldMarketConfigOf<T> = OldMarketConfig<
+		<T as Config>::VaultId,
+		<T as DeFiComposableConfig>::MayBeAssetId,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::LiquidationStrategyId,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// Existing markets did not fund any reserve out of the interest repaid, so a zero
+	/// `reserved_factor` keeps their behaviour unchanged.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		Markets::<T>::translate::<OldMarketConfigOf<T>, _>(|_, market| {
+			translated += 1;
+			Some(MarketConfig {
+				manager: market.manager,
+				borrow_asset_vault: market.borrow_asset_vault,
+				collateral_asset: market.collateral_asset,
+				max_price_age: market.max_price_age,
+				collateral_factor: market.collateral_factor,
+				interest_rate_model: market.interest_rate_model,
+				under_collateralized_warn_percent: market.under_collateralized_warn_percent,
+				liquidators: market.liquidators,
+				reserved_factor: Perquintill::zero(),
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
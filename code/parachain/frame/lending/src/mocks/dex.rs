@@ -0,0 +1,104 @@
+use composable_traits::{
+	defi::CurrencyPair,
+	dex::{Amm, AssetAmount, SwapResult},
+};
+use sp_runtime::{DispatchError, Permill};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+/// DEX without any pool, so that liquidations always fall back to other strategies.
+pub struct NoDex<AssetId, Balance, AccountId>(PhantomData<(AssetId, Balance, AccountId)>);
+
+fn no_pool<T>() -> Result<T, DispatchError> {
+	Err(DispatchError::Other("no pool"))
+}
+
+impl<AssetId, Balance, AccountId> Amm for NoDex<AssetId, Balance, AccountId> {
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type PoolId = CurrencyPair<AssetId>;
+
+	fn pool_exists(_pool_id: Self::PoolId) -> bool {
+		false
+	}
+
+	fn assets(_pool_id: Self::PoolId) -> Result<BTreeMap<Self::AssetId, Permill>, DispatchError> {
+		no_pool()
+	}
+
+	fn lp_token(_pool_id: Self::PoolId) -> Result<Self::AssetId, DispatchError> {
+		no_pool()
+	}
+
+	fn redeemable_assets_for_lp_tokens(
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+
+	fn simulate_add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<Self::Balance, DispatchError> {
+		no_pool()
+	}
+
+	fn simulate_remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+
+	fn spot_price(
+		_pool_id: Self::PoolId,
+		_base_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_quote_asset_id: Self::AssetId,
+		_calculate_with_fees: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+
+	fn do_buy(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset_id: Self::AssetId,
+		_out_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+
+	fn add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_assets: BTreeMap<Self::AssetId, Self::Balance>,
+		_min_mint_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		no_pool()
+	}
+
+	fn remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_receive: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+
+	fn do_swap(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_min_receive: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		no_pool()
+	}
+}
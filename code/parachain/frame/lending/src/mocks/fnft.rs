@@ -0,0 +1,251 @@
+use codec::{Decode, Encode};
+use composable_traits::{
+	fnft::{FinancialNftLock, FinancialNftProtocol},
+	staking::Staking,
+	time::DurationSeconds,
+};
+use frame_support::traits::{
+	fungibles::Mutate,
+	tokens::nonfungibles::{Inspect, Transfer},
+	LockIdentifier,
+};
+use sp_runtime::{DispatchError, DispatchResult, Perbill, Permill};
+use std::{cell::RefCell, collections::BTreeMap, marker::PhantomData};
+
+pub type CollectionId = u128;
+pub type InstanceId = u64;
+
+/// A staked position represented by a financial NFT.
+#[derive(Debug, Clone)]
+pub struct Position {
+	owner: Vec<u8>,
+	lock: Option<LockIdentifier>,
+	/// Reported by [`FinancialNftProtocol::value_of`].
+	pub value: Vec<(CollectionId, u128)>,
+	/// Asset and amount returned by [`Staking::unstake`], before the penalty.
+	pub stake: (CollectionId, u128),
+	pub unlock_penalty: Perbill,
+}
+
+thread_local! {
+	static POSITIONS: RefCell<BTreeMap<(CollectionId, InstanceId), Position>> =
+		RefCell::new(BTreeMap::new());
+	/// Pool staking into each collection, identified by the staked asset.
+	static POOLS: RefCell<BTreeMap<CollectionId, CollectionId>> = RefCell::new(BTreeMap::new());
+}
+
+/// Financial NFTs of staked positions, unstaked by minting `Assets`. Mirrors the transfer locks
+/// of the fNFT pallet and the unlock penalty of staking rewards.
+pub struct MockFinancialNfts<AccountId, Assets>(PhantomData<(AccountId, Assets)>);
+
+impl<AccountId: Encode, Assets> MockFinancialNfts<AccountId, Assets> {
+	pub fn mint(
+		collection: CollectionId,
+		instance: InstanceId,
+		owner: &AccountId,
+		value: Vec<(CollectionId, u128)>,
+		stake: (CollectionId, u128),
+		unlock_penalty: Perbill,
+	) {
+		POSITIONS.with(|positions| {
+			positions.borrow_mut().insert(
+				(collection, instance),
+				Position { owner: owner.encode(), lock: None, value, stake, unlock_penalty },
+			)
+		});
+	}
+
+	pub fn create_pool(collection: CollectionId, staked_asset: CollectionId) {
+		POOLS.with(|pools| pools.borrow_mut().insert(collection, staked_asset));
+	}
+
+	pub fn position(collection: CollectionId, instance: InstanceId) -> Option<Position> {
+		POSITIONS.with(|positions| positions.borrow().get(&(collection, instance)).cloned())
+	}
+
+	fn mutate<R>(
+		collection: &CollectionId,
+		instance: &InstanceId,
+		f: impl FnOnce(&mut Position) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		POSITIONS.with(|positions| {
+			positions
+				.borrow_mut()
+				.get_mut(&(*collection, *instance))
+				.ok_or(DispatchError::Other("position not found"))
+				.and_then(f)
+		})
+	}
+}
+
+impl<AccountId: Encode + Decode, Assets> Inspect<AccountId>
+	for MockFinancialNfts<AccountId, Assets>
+{
+	type ItemId = InstanceId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		Self::position(*collection, *item)
+			.and_then(|position| AccountId::decode(&mut &position.owner[..]).ok())
+	}
+}
+
+impl<AccountId: Encode + Decode, Assets> Transfer<AccountId>
+	for MockFinancialNfts<AccountId, Assets>
+{
+	fn transfer(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		destination: &AccountId,
+	) -> DispatchResult {
+		Self::mutate(collection, item, |position| {
+			if position.lock.is_some() {
+				return Err(DispatchError::Other("locked"))
+			}
+			position.owner = destination.encode();
+			Ok(())
+		})
+	}
+}
+
+impl<AccountId: Encode + Decode, Assets> FinancialNftLock<AccountId>
+	for MockFinancialNfts<AccountId, Assets>
+{
+	fn lock(
+		collection: &Self::CollectionId,
+		instance: &Self::ItemId,
+		id: LockIdentifier,
+	) -> DispatchResult {
+		Self::mutate(collection, instance, |position| {
+			if position.lock.replace(id).is_some() {
+				return Err(DispatchError::Other("locked"))
+			}
+			Ok(())
+		})
+	}
+
+	fn unlock(
+		collection: &Self::CollectionId,
+		instance: &Self::ItemId,
+		id: LockIdentifier,
+	) -> DispatchResult {
+		Self::mutate(collection, instance, |position| {
+			if position.lock.take() != Some(id) {
+				return Err(DispatchError::Other("lock not found"))
+			}
+			Ok(())
+		})
+	}
+
+	fn lock_of(collection: &Self::CollectionId, instance: &Self::ItemId) -> Option<LockIdentifier> {
+		Self::position(*collection, *instance).and_then(|position| position.lock)
+	}
+}
+
+impl<AccountId: Encode, Assets> FinancialNftProtocol for MockFinancialNfts<AccountId, Assets> {
+	type ItemId = InstanceId;
+	type AssetId = CollectionId;
+	type Balance = u128;
+
+	fn collection_asset_ids() -> Vec<Self::AssetId> {
+		POSITIONS.with(|positions| {
+			positions.borrow().keys().map(|(collection, _)| *collection).collect()
+		})
+	}
+
+	fn value_of(
+		collection: &Self::AssetId,
+		instance: &Self::ItemId,
+	) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError> {
+		Self::position(*collection, *instance)
+			.map(|position| position.value)
+			.ok_or(DispatchError::Other("position not found"))
+	}
+}
+
+impl<AccountId, Assets> Staking for MockFinancialNfts<AccountId, Assets>
+where
+	AccountId: Encode + Decode,
+	Assets: Mutate<AccountId, AssetId = CollectionId, Balance = u128>,
+{
+	type AccountId = AccountId;
+	type RewardPoolId = CollectionId;
+	type Balance = u128;
+	type PositionId = (CollectionId, InstanceId);
+	type CollectionId = CollectionId;
+
+	/// Mints the position in the collection `pool_id`, without unlock penalty.
+	fn stake(
+		who: &Self::AccountId,
+		pool_id: &Self::RewardPoolId,
+		amount: Self::Balance,
+		_duration_preset: DurationSeconds,
+		_keep_alive: bool,
+	) -> Result<Self::PositionId, DispatchError> {
+		Assets::burn_from(*pool_id, who, amount)?;
+		Self::create_pool(*pool_id, *pool_id);
+		let instance = POSITIONS.with(|positions| {
+			positions
+				.borrow()
+				.keys()
+				.filter(|(collection, _)| collection == pool_id)
+				.map(|(_, instance)| instance + 1)
+				.max()
+				.unwrap_or_default()
+		});
+		Self::mint(
+			*pool_id,
+			instance,
+			who,
+			vec![(*pool_id, amount)],
+			(*pool_id, amount),
+			Perbill::zero(),
+		);
+		Ok((*pool_id, instance))
+	}
+
+	fn extend(
+		_who: &Self::AccountId,
+		_position: Self::PositionId,
+		_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> DispatchResult {
+		Err(DispatchError::Other("not supported by mock"))
+	}
+
+	/// Always an early unlock, the penalty applies.
+	fn unstake(who: &Self::AccountId, (collection, instance): &Self::PositionId) -> DispatchResult {
+		let position = Self::position(*collection, *instance)
+			.ok_or(DispatchError::Other("position not found"))?;
+		if position.owner != who.encode() || position.lock.is_some() {
+			return Err(DispatchError::Other("cannot burn"))
+		}
+		POSITIONS.with(|positions| positions.borrow_mut().remove(&(*collection, *instance)));
+		let (asset, amount) = position.stake;
+		Assets::mint_into(asset, who, position.unlock_penalty.left_from_one().mul_ceil(amount))
+	}
+
+	fn split(
+		_who: &Self::AccountId,
+		_position: &Self::PositionId,
+		_ratio: Permill,
+	) -> Result<Self::PositionId, DispatchError> {
+		Err(DispatchError::Other("not supported by mock"))
+	}
+
+	fn merge(
+		_who: &Self::AccountId,
+		_position: &Self::PositionId,
+		_other: &Self::PositionId,
+	) -> DispatchResult {
+		Err(DispatchError::Other("not supported by mock"))
+	}
+
+	fn claim(_who: &Self::AccountId, _position: &Self::PositionId) -> DispatchResult {
+		Err(DispatchError::Other("not supported by mock"))
+	}
+
+	fn pool_of_collection(collection: &Self::CollectionId) -> Option<Self::RewardPoolId> {
+		POOLS.with(|pools| pools.borrow().get(collection).copied())
+	}
+}
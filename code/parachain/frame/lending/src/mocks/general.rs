@@ -10,7 +10,7 @@ use composable_traits::{
 
 use frame_support::{
 	ord_parameter_types, parameter_types,
-	traits::{ConstU32, Everything, GenesisBuild},
+	traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild, LockIdentifier},
 	weights::{WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial},
 	PalletId,
 };
@@ -160,6 +160,7 @@ parameter_types! {
 	pub const RentPerBlock: Balance = 1;
 	pub const MinimumDeposit: Balance = 0;
 	pub const MinimumWithdrawal: Balance = 0;
+	pub const MaxWithdrawalQueueLength: u32 = 10;
 	pub const VaultPalletId: PalletId = PalletId(*b"cubic___");
 	pub const TombstoneDuration: u64 = 42;
 }
@@ -174,6 +175,7 @@ impl pallet_vault::Config for Runtime {
 	type Convert = ConvertInto;
 	type MinimumDeposit = MinimumDeposit;
 	type MinimumWithdrawal = MinimumWithdrawal;
+	type MaxWithdrawalQueueLength = MaxWithdrawalQueueLength;
 	type PalletId = VaultPalletId;
 	type CreationDeposit = CreationDeposit;
 	type ExistentialDeposit = ExistentialDeposit;
@@ -340,6 +342,10 @@ impl SendXcm for XcmFake {
 	}
 }
 
+parameter_types! {
+	pub const ReservePriceChecksPerBlock: u32 = 16;
+}
+
 impl pallet_dutch_auction::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UnixTime = Timestamp;
@@ -352,6 +358,8 @@ impl pallet_dutch_auction::Config for Runtime {
 	type XcmOrigin = XcmFake;
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type XcmSender = XcmFake;
+	type ReservePriceChecksPerBlock = ReservePriceChecksPerBlock;
+	type OrderCallback = Liquidations;
 }
 
 impl pallet_liquidations::Config for Runtime {
@@ -364,6 +372,13 @@ impl pallet_liquidations::Config for Runtime {
 	type WeightInfo = pallet_liquidations::weights::SubstrateWeight<Self>;
 	type CanModifyStrategies = EnsureRoot<Self::AccountId>;
 	type XcmSender = XcmFake;
+	type Dex = super::dex::NoDex<CurrencyId, Balance, AccountId>;
+	type Oracle = Oracle;
+	type Callback = Lending;
+	type NativeCurrency = Balances;
+	type PokeReward = ConstU128<0>;
+	type StalledAfter = ConstU64<{ 24 * 60 * 60 }>;
+	type MaxFinalizedOrdersPerBlock = ConstU32<10>;
 	type MaxLiquidationStrategiesAmount = ConstU32<3>;
 }
 
@@ -403,8 +418,11 @@ parameter_types! {
 	pub LendingPalletId: PalletId = PalletId(*b"liqiudat");
 	pub OracleMarketCreationStake: Balance = NORMALIZED::ONE;
 	pub const MaxLiquidationBatchSize: u32 = 5;
+	pub const FinancialNftLockId: LockIdentifier = *b"lend_nft";
 }
 
+pub type FinancialNfts = super::fnft::MockFinancialNfts<AccountId, Tokens>;
+
 parameter_types! {
 	pub static WeightToFee: Balance = 1;
 }
@@ -442,6 +460,12 @@ impl pallet_lending::Config for Runtime {
 	type MaxLiquidationBatchSize = MaxLiquidationBatchSize;
 
 	type WeightToFee = WeightToFee;
+	type FinancialNft = FinancialNfts;
+	type FinancialNftInstanceId = super::fnft::InstanceId;
+	type FinancialNftProtocol = FinancialNfts;
+	type Staking = FinancialNfts;
+	type FinancialNftLockId = FinancialNftLockId;
+	type MaxFinancialNftCollaterals = ConstU32<3>;
 }
 
 /// Convenience function to set the price of an asset in [`pallet_oracle::Prices`].
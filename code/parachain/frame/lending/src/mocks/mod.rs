@@ -1,3 +1,5 @@
 pub mod authority_id_wrapper;
+pub mod dex;
+pub mod fnft;
 pub mod general;
 pub mod offchain;
@@ -0,0 +1,411 @@
+use super::prelude::*;
+use crate::{
+	tests::{borrow, create_market_for_liquidation_test, process_and_progress_blocks},
+	FinancialNftLiquidation,
+};
+use composable_traits::{fnft::FinancialNftLock, oracle};
+use frame_support::traits::tokens::nonfungibles::Inspect as NonFungiblesInspect;
+use sp_runtime::{Perbill, Permill};
+
+const COLLECTION: CurrencyId = 424_242;
+const INSTANCE: u64 = 1;
+const LOCK_ID: [u8; 8] = *b"lend_nft";
+
+/// Creates a BTC collateral market funded with USDT, accepting [`COLLECTION`] as collateral.
+/// ALICE owns [`INSTANCE`], a stake of 1 BTC with a 10% unlock penalty.
+fn create_market_accepting_fnft(liquidation: FinancialNftLiquidation) -> MarketId {
+	let (market_id, vault_id) = create_market_for_liquidation_test::<Runtime>(*ALICE);
+	let vault_value = USDT::units(100_000_000);
+	assert_ok!(Tokens::mint_into(USDT::ID, &CHARLIE, vault_value));
+	assert_ok!(Vault::deposit(RuntimeOrigin::signed(*CHARLIE), vault_id, vault_value));
+	process_and_progress_blocks::<Lending, Runtime>(1);
+	FinancialNfts::create_pool(COLLECTION, BTC::ID);
+
+	assert_extrinsic_event::<Runtime>(
+		Lending::set_financial_nft_collateral(
+			RuntimeOrigin::signed(*ALICE),
+			market_id,
+			COLLECTION,
+			Some(liquidation),
+		),
+		RuntimeEvent::Lending(crate::Event::FinancialNftCollateralSet {
+			market_id,
+			collection: COLLECTION,
+			liquidation: Some(liquidation),
+		}),
+	);
+	FinancialNfts::mint(
+		COLLECTION,
+		INSTANCE,
+		&ALICE,
+		vec![(BTC::ID, BTC::units(1))],
+		(BTC::ID, BTC::units(1)),
+		Perbill::from_percent(10),
+	);
+	market_id
+}
+
+fn deposit_fnft(market_id: MarketId) {
+	assert_extrinsic_event::<Runtime>(
+		Lending::deposit_financial_nft_collateral(
+			RuntimeOrigin::signed(*ALICE),
+			market_id,
+			COLLECTION,
+			INSTANCE,
+		),
+		RuntimeEvent::Lending(crate::Event::FinancialNftCollateralDeposited {
+			sender: *ALICE,
+			market_id,
+			collection: COLLECTION,
+			instance: INSTANCE,
+		}),
+	);
+}
+
+#[test]
+fn financial_nft_is_locked_in_market_while_deposited() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market_accepting_fnft(FinancialNftLiquidation::Unstake);
+
+		assert_noop!(
+			Lending::set_financial_nft_collateral(
+				RuntimeOrigin::signed(*BOB),
+				market_id,
+				COLLECTION,
+				None
+			),
+			Error::<Runtime>::Unauthorized
+		);
+		assert_noop!(
+			Lending::deposit_financial_nft_collateral(
+				RuntimeOrigin::signed(*BOB),
+				market_id,
+				COLLECTION,
+				INSTANCE
+			),
+			Error::<Runtime>::MustBeFinancialNftOwner
+		);
+		FinancialNfts::mint(
+			COLLECTION + 1,
+			INSTANCE,
+			&ALICE,
+			vec![],
+			(BTC::ID, 0),
+			Perbill::zero(),
+		);
+		assert_noop!(
+			Lending::deposit_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION + 1,
+				INSTANCE
+			),
+			Error::<Runtime>::FinancialNftNotAccepted
+		);
+		assert_eq!(Lending::get_borrow_limit(&market_id, &ALICE), Ok(0));
+
+		deposit_fnft(market_id);
+		assert_eq!(
+			FinancialNfts::owner(&COLLECTION, &INSTANCE),
+			Some(Lending::account_id(&market_id))
+		);
+		assert_eq!(FinancialNfts::lock_of(&COLLECTION, &INSTANCE), Some(LOCK_ID));
+		// valued like 1 BTC of collateral
+		assert_eq!(Lending::get_borrow_limit(&market_id, &ALICE), Ok(USDT::units(25_000)));
+
+		assert_extrinsic_event::<Runtime>(
+			Lending::withdraw_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				INSTANCE,
+			),
+			RuntimeEvent::Lending(crate::Event::FinancialNftCollateralWithdrawn {
+				sender: *ALICE,
+				market_id,
+				collection: COLLECTION,
+				instance: INSTANCE,
+			}),
+		);
+		assert_eq!(FinancialNfts::owner(&COLLECTION, &INSTANCE), Some(*ALICE));
+		assert_eq!(FinancialNfts::lock_of(&COLLECTION, &INSTANCE), None);
+		assert!(!crate::AccountFinancialNftCollateral::<Runtime>::contains_key(market_id, *ALICE));
+		assert_noop!(
+			Lending::withdraw_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				INSTANCE
+			),
+			Error::<Runtime>::FinancialNftCollateralNotFound
+		);
+	})
+}
+
+#[test]
+fn collection_unstaking_other_asset_than_collateral_is_not_accepted() {
+	new_test_ext().execute_with(|| {
+		let (market_id, _) = create_market_for_liquidation_test::<Runtime>(*ALICE);
+		let set = |liquidation| {
+			Lending::set_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				Some(liquidation),
+			)
+		};
+
+		// not a staking collection
+		assert_noop!(
+			set(FinancialNftLiquidation::Unstake),
+			Error::<Runtime>::FinancialNftMustStakeCollateralAsset
+		);
+		FinancialNfts::create_pool(COLLECTION, USDT::ID);
+		assert_noop!(
+			set(FinancialNftLiquidation::Unstake),
+			Error::<Runtime>::FinancialNftMustStakeCollateralAsset
+		);
+		// sold as is, whatever it stakes
+		assert_ok!(set(FinancialNftLiquidation::Sale { discount: Permill::from_percent(5) }));
+	})
+}
+
+#[test]
+fn financial_nft_backing_a_borrow_cannot_be_withdrawn() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market_accepting_fnft(FinancialNftLiquidation::Unstake);
+		deposit_fnft(market_id);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+
+		assert_noop!(
+			Lending::withdraw_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				INSTANCE
+			),
+			Error::<Runtime>::WouldGoUnderCollateralized
+		);
+	})
+}
+
+#[test]
+fn seized_financial_nft_is_unstaked_with_penalty() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market_accepting_fnft(FinancialNftLiquidation::Unstake);
+		deposit_fnft(market_id);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(*BOB),
+			market_id,
+			TestBoundedVec::try_from(vec![*ALICE]).unwrap(),
+		));
+		System::assert_has_event(RuntimeEvent::Lending(
+			crate::Event::FinancialNftCollateralSeized {
+				market_id,
+				account: *ALICE,
+				collection: COLLECTION,
+				instance: INSTANCE,
+				liquidation: FinancialNftLiquidation::Unstake,
+				proceeds: BTC::units(9) / 10,
+			},
+		));
+		assert!(FinancialNfts::position(COLLECTION, INSTANCE).is_none());
+		assert!(!crate::AccountFinancialNftCollateral::<Runtime>::contains_key(market_id, *ALICE));
+		// the unstaked BTC is sold as regular collateral
+		assert!(!crate::AccountCollateral::<Runtime>::contains_key(market_id, *ALICE));
+		let (order_id, pending) = crate::PendingLiquidations::<Runtime>::iter().next().unwrap();
+		assert_eq!(pending.account, *ALICE);
+		assert_eq!(pending.received, 0);
+		assert_eq!(
+			pallet_liquidations::LiquidationOrders::<Runtime>::get(order_id)
+				.map(|order| order.order.take.amount),
+			Some(BTC::units(9) / 10)
+		);
+	})
+}
+
+#[test]
+fn seized_financial_nft_is_sold_to_liquidator_at_discount() {
+	new_test_ext().execute_with(|| {
+		let liquidation = FinancialNftLiquidation::Sale { discount: Permill::from_percent(5) };
+		let market_id = create_market_accepting_fnft(liquidation);
+		deposit_fnft(market_id);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+
+		let debt = Lending::total_debt_with_interest(&market_id, &ALICE).unwrap().unwrap_or_zero();
+		// the liquidator pays 95% of the oracle value of the staked BTC
+		let value = <Oracle as oracle::Oracle>::get_price_inverse(
+			USDT::ID,
+			<Oracle as oracle::Oracle>::get_twap_for_amount(BTC::ID, BTC::units(1)).unwrap(),
+		)
+		.unwrap();
+		let price = Permill::from_percent(95).mul_floor(value);
+		assert!(price < value);
+		assert!(price > debt);
+		assert_ok!(Tokens::mint_into(USDT::ID, &BOB, price));
+		let alice_balance = Tokens::balance(USDT::ID, &ALICE);
+
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(*BOB),
+			market_id,
+			TestBoundedVec::try_from(vec![*ALICE]).unwrap(),
+		));
+		System::assert_has_event(RuntimeEvent::Lending(
+			crate::Event::FinancialNftCollateralSeized {
+				market_id,
+				account: *ALICE,
+				collection: COLLECTION,
+				instance: INSTANCE,
+				liquidation,
+				proceeds: price,
+			},
+		));
+		assert_eq!(FinancialNfts::owner(&COLLECTION, &INSTANCE), Some(*BOB));
+		assert_eq!(FinancialNfts::lock_of(&COLLECTION, &INSTANCE), None);
+		assert_eq!(Tokens::balance(USDT::ID, &BOB), 0);
+
+		// nothing else to sell, the debt is settled right away
+		System::assert_has_event(RuntimeEvent::Lending(crate::Event::LiquidationSettled {
+			market_id,
+			account: *ALICE,
+			repaid: debt,
+			shortfall: 0,
+		}));
+		assert!(crate::PendingLiquidations::<Runtime>::iter().next().is_none());
+		assert_eq!(Tokens::balance(USDT::ID, &ALICE), alice_balance + price - debt);
+	})
+}
+
+#[test]
+fn seized_financial_nft_is_kept_for_later_sale_if_liquidator_cannot_pay() {
+	new_test_ext().execute_with(|| {
+		let liquidation = FinancialNftLiquidation::Sale { discount: Permill::from_percent(5) };
+		let market_id = create_market_accepting_fnft(liquidation);
+		deposit_fnft(market_id);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		let market_account = Lending::account_id(&market_id);
+
+		// BOB has no USDT to pay for the financial NFT, the liquidation goes through without it
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(*BOB),
+			market_id,
+			TestBoundedVec::try_from(vec![*ALICE]).unwrap(),
+		));
+		System::assert_has_event(RuntimeEvent::Lending(
+			crate::Event::FinancialNftCollateralSeized {
+				market_id,
+				account: *ALICE,
+				collection: COLLECTION,
+				instance: INSTANCE,
+				liquidation,
+				proceeds: 0,
+			},
+		));
+		assert_eq!(FinancialNfts::owner(&COLLECTION, &INSTANCE), Some(market_account));
+		assert!(crate::SeizedFinancialNfts::<Runtime>::contains_key(
+			market_id,
+			(COLLECTION, INSTANCE)
+		));
+		assert_eq!(Lending::reserve_balance(market_id), 0);
+
+		let value = <Oracle as oracle::Oracle>::get_price_inverse(
+			USDT::ID,
+			<Oracle as oracle::Oracle>::get_twap_for_amount(BTC::ID, BTC::units(1)).unwrap(),
+		)
+		.unwrap();
+		let price = Permill::from_percent(95).mul_floor(value);
+		assert_ok!(Tokens::mint_into(USDT::ID, &CHARLIE, price));
+		assert_extrinsic_event::<Runtime>(
+			Lending::buy_seized_financial_nft(
+				RuntimeOrigin::signed(*CHARLIE),
+				market_id,
+				COLLECTION,
+				INSTANCE,
+			),
+			RuntimeEvent::Lending(crate::Event::SeizedFinancialNftBought {
+				buyer: *CHARLIE,
+				market_id,
+				collection: COLLECTION,
+				instance: INSTANCE,
+				price,
+			}),
+		);
+		assert_eq!(FinancialNfts::owner(&COLLECTION, &INSTANCE), Some(*CHARLIE));
+		assert_eq!(Tokens::balance(USDT::ID, &CHARLIE), 0);
+		// the liquidation was settled without it, the price funds the reserve
+		assert_eq!(Lending::reserve_balance(market_id), price);
+
+		assert_noop!(
+			Lending::buy_seized_financial_nft(
+				RuntimeOrigin::signed(*CHARLIE),
+				market_id,
+				COLLECTION,
+				INSTANCE,
+			),
+			Error::<Runtime>::SeizedFinancialNftNotFound
+		);
+	})
+}
+
+#[test]
+fn financial_nft_of_delisted_collection_is_liquidated_as_last_set() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market_accepting_fnft(FinancialNftLiquidation::Unstake);
+		deposit_fnft(market_id);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+
+		assert_extrinsic_event::<Runtime>(
+			Lending::set_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				None,
+			),
+			RuntimeEvent::Lending(crate::Event::FinancialNftCollateralSet {
+				market_id,
+				collection: COLLECTION,
+				liquidation: None,
+			}),
+		);
+		FinancialNfts::mint(
+			COLLECTION,
+			INSTANCE + 1,
+			&ALICE,
+			vec![(BTC::ID, BTC::units(1))],
+			(BTC::ID, BTC::units(1)),
+			Perbill::from_percent(10),
+		);
+		assert_noop!(
+			Lending::deposit_financial_nft_collateral(
+				RuntimeOrigin::signed(*ALICE),
+				market_id,
+				COLLECTION,
+				INSTANCE + 1
+			),
+			Error::<Runtime>::FinancialNftNotAccepted
+		);
+
+		// the financial NFT already deposited still backs the borrow
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(*BOB),
+			market_id,
+			TestBoundedVec::try_from(vec![*ALICE]).unwrap(),
+		));
+		System::assert_has_event(RuntimeEvent::Lending(
+			crate::Event::FinancialNftCollateralSeized {
+				market_id,
+				account: *ALICE,
+				collection: COLLECTION,
+				instance: INSTANCE,
+				liquidation: FinancialNftLiquidation::Unstake,
+				proceeds: BTC::units(9) / 10,
+			},
+		));
+	})
+}
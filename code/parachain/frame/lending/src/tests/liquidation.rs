@@ -3,6 +3,8 @@ use crate::tests::{
 	borrow, create_market_for_liquidation_test, mint_and_deposit_collateral,
 	process_and_progress_blocks,
 };
+use composable_traits::{lending::TotalDebtWithInterest, liquidation::LiquidationCallback};
+use frame_support::traits::fungibles::InspectHold;
 
 #[test]
 fn test_liquidate_multiple() {
@@ -200,6 +202,218 @@ fn liquidation() {
 	});
 }
 
+#[test]
+fn liquidation_settles_debt_with_proceeds() {
+	new_test_ext().execute_with(|| {
+		let manager = *ALICE;
+		let lender = *CHARLIE;
+		let (market_id, vault_id) = create_market_for_liquidation_test::<Runtime>(manager);
+		let vault_value = USDT::units(100_000_000);
+		assert_ok!(Tokens::mint_into(USDT::ID, &lender, vault_value));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(lender), vault_id, vault_value));
+		process_and_progress_blocks::<Lending, Runtime>(1);
+		mint_and_deposit_collateral::<Runtime>(*ALICE, BTC::units(1), market_id, BTC::ID);
+		mint_and_deposit_collateral::<Runtime>(*BOB, BTC::units(1), market_id, BTC::ID);
+		borrow::<Runtime>(*ALICE, market_id, USDT::units(20_000));
+		borrow::<Runtime>(*BOB, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(manager),
+			market_id,
+			TestBoundedVec::try_from(vec![*ALICE, *BOB]).unwrap(),
+		));
+
+		let pending_of = |account| {
+			crate::PendingLiquidations::<Runtime>::iter()
+				.find(|(_, pending)| pending.account == account)
+				.expect("liquidation is pending")
+		};
+		let debt_asset = crate::DebtTokenForMarket::<Runtime>::get(market_id).unwrap();
+		let market_account = Lending::account_id(&market_id);
+
+		// the collateral being sold cannot be withdrawn
+		assert!(!crate::AccountCollateral::<Runtime>::contains_key(market_id, *ALICE));
+		assert_noop!(
+			Lending::withdraw_collateral(RuntimeOrigin::signed(*ALICE), market_id, BTC::units(1)),
+			Error::<Runtime>::NotEnoughCollateralToWithdraw
+		);
+
+		// proceeds do not cover the debt, part of the collateral was not sold
+		let (order_id, pending) = pending_of(*ALICE);
+		let received = pending.debt / 2;
+		assert_ok!(<Lending as LiquidationCallback<_, _, _>>::on_liquidation_finalized(
+			order_id,
+			&market_account,
+			received,
+			BTC::units(1) / 10,
+		));
+		System::assert_last_event(RuntimeEvent::Lending(crate::Event::LiquidationSettled {
+			market_id,
+			account: *ALICE,
+			repaid: received,
+			shortfall: pending.debt - received,
+		}));
+		assert_eq!(Tokens::balance(debt_asset, &ALICE), 0);
+		assert_eq!(
+			crate::AccountCollateral::<Runtime>::get(market_id, *ALICE),
+			Some(BTC::units(1) / 10)
+		);
+		assert!(!crate::PendingLiquidations::<Runtime>::contains_key(order_id));
+
+		// surplus of the proceeds goes to the borrower
+		let (order_id, pending) = pending_of(*BOB);
+		let bob_balance = Tokens::balance(USDT::ID, &BOB);
+		assert_ok!(<Lending as LiquidationCallback<_, _, _>>::on_liquidation_finalized(
+			order_id,
+			&market_account,
+			pending.debt + USDT::units(1_000),
+			0,
+		));
+		System::assert_last_event(RuntimeEvent::Lending(crate::Event::LiquidationSettled {
+			market_id,
+			account: *BOB,
+			repaid: pending.debt,
+			shortfall: 0,
+		}));
+		assert_eq!(Tokens::balance(USDT::ID, &BOB), bob_balance + USDT::units(1_000));
+		assert_eq!(Tokens::balance(debt_asset, &BOB), 0);
+		assert!(!crate::AccountCollateral::<Runtime>::contains_key(market_id, *BOB));
+	})
+}
+
+#[test]
+fn shortfall_is_absorbed_by_reserve_then_socialized() {
+	new_test_ext().execute_with(|| {
+		let manager = *ALICE;
+		let lender = *CHARLIE;
+		let (market_id, vault_id) = create_market_for_liquidation_test::<Runtime>(manager);
+		let vault_value = USDT::units(100_000_000);
+		assert_ok!(Tokens::mint_into(USDT::ID, &lender, vault_value));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(lender), vault_id, vault_value));
+		process_and_progress_blocks::<Lending, Runtime>(1);
+		mint_and_deposit_collateral::<Runtime>(*BOB, BTC::units(1), market_id, BTC::ID);
+		borrow::<Runtime>(*BOB, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(manager),
+			market_id,
+			TestBoundedVec::try_from(vec![*BOB]).unwrap(),
+		));
+		let (order_id, pending) = crate::PendingLiquidations::<Runtime>::iter().next().unwrap();
+
+		let market_account = Lending::account_id(&market_id);
+		crate::MarketReserves::<Runtime>::insert(market_id, USDT::units(1_000));
+		let strategy_balance =
+			|| pallet_vault::CapitalStructure::<Runtime>::get(vault_id, market_account).balance;
+		let owed_to_vault = strategy_balance();
+
+		assert_ok!(<Lending as LiquidationCallback<_, _, _>>::on_liquidation_finalized(
+			order_id,
+			&market_account,
+			pending.debt - USDT::units(1_500),
+			0,
+		));
+		System::assert_has_event(RuntimeEvent::Lending(crate::Event::BadDebtWrittenOff {
+			market_id,
+			account: *BOB,
+			from_reserve: USDT::units(1_000),
+			socialized: USDT::units(500),
+		}));
+		assert_eq!(Lending::reserve_balance(market_id), 0);
+		assert_eq!(Lending::bad_debt(market_id), USDT::units(1_500));
+		// lenders bear the part of the loss not covered by the reserve
+		assert_eq!(strategy_balance(), owed_to_vault - USDT::units(500));
+	})
+}
+
+#[test]
+fn socialized_shortfall_is_capped_at_balance_owed_to_vault() {
+	new_test_ext().execute_with(|| {
+		let manager = *ALICE;
+		let lender = *CHARLIE;
+		let (market_id, vault_id) = create_market_for_liquidation_test::<Runtime>(manager);
+		let vault_value = USDT::units(100_000_000);
+		assert_ok!(Tokens::mint_into(USDT::ID, &lender, vault_value));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(lender), vault_id, vault_value));
+		process_and_progress_blocks::<Lending, Runtime>(1);
+		mint_and_deposit_collateral::<Runtime>(*BOB, BTC::units(1), market_id, BTC::ID);
+		borrow::<Runtime>(*BOB, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(manager),
+			market_id,
+			TestBoundedVec::try_from(vec![*BOB]).unwrap(),
+		));
+		let (order_id, pending) = crate::PendingLiquidations::<Runtime>::iter().next().unwrap();
+
+		let market_account = Lending::account_id(&market_id);
+		crate::MarketReserves::<Runtime>::insert(market_id, USDT::units(1_000));
+		pallet_vault::CapitalStructure::<Runtime>::mutate(vault_id, market_account, |state| {
+			state.balance = USDT::units(200)
+		});
+
+		assert_ok!(<Lending as LiquidationCallback<_, _, _>>::on_liquidation_finalized(
+			order_id,
+			&market_account,
+			pending.debt - USDT::units(1_500),
+			0,
+		));
+		System::assert_has_event(RuntimeEvent::Lending(crate::Event::BadDebtWrittenOff {
+			market_id,
+			account: *BOB,
+			from_reserve: USDT::units(1_000),
+			socialized: USDT::units(200),
+		}));
+		assert_eq!(Lending::bad_debt(market_id), USDT::units(1_500));
+		assert_eq!(
+			pallet_vault::CapitalStructure::<Runtime>::get(vault_id, market_account).balance,
+			0
+		);
+	})
+}
+
+#[test]
+fn settlement_burns_only_liquidated_debt() {
+	new_test_ext().execute_with(|| {
+		let manager = *ALICE;
+		let lender = *CHARLIE;
+		let (market_id, vault_id) = create_market_for_liquidation_test::<Runtime>(manager);
+		let vault_value = USDT::units(100_000_000);
+		assert_ok!(Tokens::mint_into(USDT::ID, &lender, vault_value));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(lender), vault_id, vault_value));
+		process_and_progress_blocks::<Lending, Runtime>(1);
+		mint_and_deposit_collateral::<Runtime>(*BOB, BTC::units(1), market_id, BTC::ID);
+		borrow::<Runtime>(*BOB, market_id, USDT::units(20_000));
+		set_price(BTC::ID, NORMALIZED::units(38_000));
+		assert_ok!(Lending::liquidate(
+			RuntimeOrigin::signed(manager),
+			market_id,
+			TestBoundedVec::try_from(vec![*BOB]).unwrap(),
+		));
+		let (order_id, pending) = crate::PendingLiquidations::<Runtime>::iter().next().unwrap();
+		assert_eq!(pending.principal, USDT::units(20_000));
+
+		// the borrower takes a new loan while the collateral is being sold
+		process_and_progress_blocks::<Lending, Runtime>(1);
+		mint_and_deposit_collateral::<Runtime>(*BOB, BTC::units(1), market_id, BTC::ID);
+		borrow::<Runtime>(*BOB, market_id, USDT::units(10_000));
+		let debt_asset = crate::DebtTokenForMarket::<Runtime>::get(market_id).unwrap();
+		assert_eq!(Tokens::balance_on_hold(debt_asset, &BOB), USDT::units(10_000));
+
+		assert_ok!(<Lending as LiquidationCallback<_, _, _>>::on_liquidation_finalized(
+			order_id,
+			&Lending::account_id(&market_id),
+			pending.debt,
+			0,
+		));
+		assert_eq!(Tokens::balance_on_hold(debt_asset, &BOB), USDT::units(10_000));
+		assert_eq!(
+			Lending::total_debt_with_interest(&market_id, &BOB).unwrap(),
+			TotalDebtWithInterest::Amount(USDT::units(10_000))
+		);
+	})
+}
+
 #[test]
 fn test_warn_soon_under_collateralized() {
 	new_test_ext().execute_with(|| {
@@ -21,6 +21,7 @@ use pallet_timestamp::Config as PalletTimestampConfig;
 use sp_runtime::traits::One;
 
 pub mod borrow;
+pub mod financial_nft;
 pub mod interest;
 pub mod liquidation;
 pub mod market;
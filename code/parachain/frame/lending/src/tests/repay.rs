@@ -281,3 +281,42 @@ fn test_repay_total_debt() {
 		}
 	});
 }
+
+#[test]
+fn repaid_interest_funds_market_reserve() {
+	new_test_ext().execute_with(|| {
+		let (market_id, vault_id) = create_simple_market();
+		mint_and_deposit_collateral::<Runtime>(*ALICE, BTC::ONE, market_id, BTC::ID);
+
+		let borrow_asset_deposit = USDT::units(1_000_000);
+		assert_ok!(Tokens::mint_into(USDT::ID, &CHARLIE, borrow_asset_deposit));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(*CHARLIE), vault_id, borrow_asset_deposit));
+		process_and_progress_blocks::<Lending, Runtime>(1);
+
+		let borrowed = USDT::units(10_000);
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(*ALICE), market_id, borrowed));
+		process_and_progress_blocks::<Lending, Runtime>(1000);
+
+		let total_debt =
+			Lending::total_debt_with_interest(&market_id, &ALICE).unwrap().unwrap_amount();
+		let interest = total_debt - borrowed;
+		assert!(interest > 0);
+		assert_ok!(Tokens::mint_into(USDT::ID, &ALICE, interest));
+		assert_eq!(Lending::reserve_balance(market_id), 0);
+		assert_ok!(Lending::repay_borrow(
+			RuntimeOrigin::signed(*ALICE),
+			market_id,
+			*ALICE,
+			RepayStrategy::TotalDebt,
+			false,
+		));
+
+		let reserve = DEFAULT_MARKET_VAULT_RESERVE.mul_floor(interest);
+		assert_eq!(Lending::reserve_balance(market_id), reserve);
+		// the reserve is kept on the market account, but cannot be borrowed
+		assert_eq!(
+			Lending::total_available_to_be_borrowed(&market_id),
+			Ok(Tokens::balance(USDT::ID, &Lending::account_id(&market_id)) - reserve)
+		);
+	});
+}
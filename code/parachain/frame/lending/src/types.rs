@@ -3,7 +3,7 @@ use composable_traits::defi::DeFiComposableConfig;
 use frame_support::pallet_prelude::*;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, Permill};
 use sp_std::{
 	fmt::{Debug, Display},
 	str::FromStr,
@@ -77,6 +77,44 @@ impl Display for MarketId {
 	}
 }
 
+/// Liquidation of a borrower's position which is not finalized yet by the
+/// [`Liquidation`](composable_traits::liquidation::Liquidation) engine.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct PendingLiquidation<AccountId, Balance> {
+	pub market_id: MarketId,
+	pub account: AccountId,
+	/// Principal and interest owed by `account` when the liquidation was initiated.
+	pub debt: Balance,
+	/// Principal of `debt`, whose debt tokens were burnt when the liquidation was initiated so
+	/// that borrowing again in the meantime starts afresh.
+	pub principal: Balance,
+	/// Borrow asset already received for the financial NFTs seized by the liquidation, see
+	/// [`FinancialNftLiquidation::Sale`].
+	pub received: Balance,
+}
+
+/// How the financial NFTs of a collection accepted as collateral are liquidated.
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum FinancialNftLiquidation {
+	/// The position is unstaked, bearing its early unlock penalty. The unstaked collateral asset
+	/// is sold along with the rest of the borrower's collateral.
+	Unstake,
+	/// The financial NFT is sold as is to the liquidator, who pays its oracle value in the borrow
+	/// asset less `discount`. The discount rewards the liquidator for taking over the position
+	/// without a price discovery. If the liquidator cannot pay, the financial NFT is kept seized
+	/// for anyone to buy later.
+	Sale { discount: Permill },
+}
+
+/// A financial NFT collection which is or was accepted as collateral by a market.
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct FinancialNftCollateral {
+	/// New financial NFTs can be deposited. Those already deposited remain collateral either way.
+	pub accepted: bool,
+	/// How the seized financial NFTs of the collection are liquidated.
+	pub liquidation: FinancialNftLiquidation,
+}
+
 pub(crate) struct MarketAssets<T: DeFiComposableConfig> {
 	/// The borrow asset for the market.
 	pub(crate) borrow_asset: <T as DeFiComposableConfig>::MayBeAssetId,
@@ -25,6 +25,10 @@ pub trait WeightInfo {
 	fn handle_withdrawable() -> Weight;
 	fn handle_depositable() -> Weight;
 	fn handle_must_liquidate() -> Weight;
+	fn set_financial_nft_collateral() -> Weight;
+	fn deposit_financial_nft_collateral() -> Weight;
+	fn withdraw_financial_nft_collateral() -> Weight;
+	fn buy_seized_financial_nft() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -99,4 +103,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn set_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(34_571_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn deposit_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(97_412_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn withdraw_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(263_917_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn buy_seized_financial_nft() -> Weight {
+		Weight::from_ref_time(142_305_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
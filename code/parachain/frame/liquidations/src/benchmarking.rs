@@ -6,8 +6,9 @@ use composable_traits::{
 	time::{LinearDecrease, TimeReleaseFunction},
 };
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::{fungibles::Mutate, Currency, Get};
+use frame_support::traits::{fungibles::Mutate, Currency, Get, Hooks};
 use frame_system::RawOrigin;
+use pallet_dutch_auction::SellOptions;
 use sp_runtime::{traits::Saturating, FixedPointNumber};
 use sp_std::prelude::*;
 pub type AssetIdOf<T> = <T as DeFiComposableConfig>::MayBeAssetId;
@@ -36,6 +37,7 @@ benchmarks! {
 		let origin = RawOrigin::Root;
 		let config = LiquidationStrategyConfiguration::DutchAuction(
 				TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 10 * 60 }),
+				SellOptions::default(),
 			);
 		}: _(origin, config)
 
@@ -49,6 +51,7 @@ benchmarks! {
 		let root_origin = RawOrigin::<T::AccountId>::Root;
 		let config = LiquidationStrategyConfiguration::DutchAuction(
 				TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 10 * 60 }),
+				SellOptions::default(),
 		);
 		Liquidations::<T>::add_liquidation_strategy(root_origin.clone().into(), config.clone()).unwrap();
 		let native_token_amount = <<T as pallet_dutch_auction::Config>::NativeCurrency as Currency<T::AccountId>>::minimum_balance().saturating_mul(1_000_000_000_u32.into());
@@ -59,6 +62,32 @@ benchmarks! {
 		let mut configurations:Vec<T::LiquidationStrategyId> = (begin..end).map(|x| x.into()).collect();
 		configurations.push(1.into());
 		   }: _(origin, order, configurations)
+
+	on_finalize {
+		let x in 0..<T as Config>::MaxFinalizedOrdersPerBlock::get();
+		let pair = assets::<T>();
+		let one: <T as DeFiComposableConfig>::Balance = 1_u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		for _ in 0..x {
+			let order_id = LiquidationOrderIndex::<T>::increment();
+			LiquidationOrders::<T>::insert(order_id, LiquidationOrderOf::<T> {
+				from_to: caller.clone(),
+				order: Sell::new(pair.base, pair.quote, one, Ratio::saturating_from_integer(one)),
+				strategy_id: 1.into(),
+				engine_order_id: None,
+				sold: one,
+				received: one,
+				started_at: 0,
+				state: LiquidationOrderState::Completed,
+			});
+			FinalizedOrders::<T>::insert(order_id, ());
+		}
+	}: {
+		Liquidations::<T>::on_finalize(0_u32.into());
+	}
+	verify {
+		assert_eq!(FinalizedOrders::<T>::iter().count(), 0);
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -33,6 +33,7 @@
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarking;
+pub mod migrations;
 mod mock;
 
 #[cfg(test)]
@@ -59,19 +60,33 @@ pub mod pallet {
 		math::wrapping_next::WrappingNext,
 	};
 	use composable_traits::{
-		defi::{DeFiComposableConfig, DeFiEngine, Sell, SellEngine},
-		liquidation::Liquidation,
-		time::{LinearDecrease, StairstepExponentialDecrease, TimeReleaseFunction},
+		defi::{
+			CurrencyPair, DeFiComposableConfig, DeFiEngine, OrderIdLike, Sell, SellEngine,
+			SellEngineCallback,
+		},
+		dex::{Amm, AssetAmount},
+		liquidation::{Liquidation, LiquidationCallback},
+		oracle::Oracle,
+		time::{LinearDecrease, StairstepExponentialDecrease, TimeReleaseFunction, Timestamp},
 	};
 	use frame_support::{
-		dispatch::DispatchResultWithPostInfo,
-		pallet_prelude::{OptionQuery, StorageMap, StorageValue},
-		traits::{EnsureOrigin, Get, IsType, UnixTime},
+		dispatch::{DispatchResultWithPostInfo, Pays},
+		ensure,
+		pallet_prelude::{Hooks, OptionQuery, StorageMap, StorageValue, StorageVersion, Weight},
+		storage::{with_transaction, TransactionOutcome},
+		traits::{fungible::Transfer as NativeTransfer, EnsureOrigin, Get, IsType, UnixTime},
 		BoundedVec, PalletId, Parameter, Twox64Concat,
 	};
-	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use frame_system::{
+		ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+	};
+	use pallet_dutch_auction::SellOptions;
 	use scale_info::TypeInfo;
-	use sp_runtime::{DispatchError, Permill, Perquintill};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		ArithmeticError, DispatchError, FixedPointNumber, Permill, Perquintill,
+	};
 	use sp_std::vec::Vec;
 
 	#[cfg(feature = "std")]
@@ -87,7 +102,7 @@ pub mod pallet {
 		type UnixTime: UnixTime;
 
 		type DutchAuction: SellEngine<
-			TimeReleaseFunction,
+			(TimeReleaseFunction, SellOptions),
 			OrderId = Self::OrderId,
 			MayBeAssetId = <Self as DeFiComposableConfig>::MayBeAssetId,
 			Balance = Self::Balance,
@@ -102,7 +117,8 @@ pub mod pallet {
 			+ Copy
 			+ From<u32>;
 
-		type OrderId: Default + FullCodec + MaxEncodedLen + sp_std::fmt::Debug;
+		/// Identifies the orders of the engines, as well as the liquidations themselves.
+		type OrderId: OrderIdLike + WrappingNext;
 
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -115,25 +131,89 @@ pub mod pallet {
 		/// is used to talk to external liquidation engines
 		type XcmSender: xcm::latest::SendXcm;
 
+		/// Swaps the collateral sold with the Pablo strategy, usually the dex-router.
+		type Dex: Amm<
+			AssetId = Self::MayBeAssetId,
+			Balance = Self::Balance,
+			AccountId = Self::AccountId,
+			PoolId = CurrencyPair<Self::MayBeAssetId>,
+		>;
+
+		/// Prices the collateral sold with the Pablo strategy, so that the swap respects the
+		/// configured slippage.
+		type Oracle: Oracle<AssetId = Self::MayBeAssetId, Balance = Self::Balance>;
+
+		/// Notified once a liquidation is finalized, usually the protocol which requested it.
+		type Callback: LiquidationCallback<Self::AccountId, Self::OrderId, Self::Balance>;
+
+		/// Pays the rewards of `poke`.
+		type NativeCurrency: NativeTransfer<Self::AccountId, Balance = Self::Balance>;
+
+		/// Reward paid from the pallet account to callers of `poke` finalizing a liquidation.
+		#[pallet::constant]
+		type PokeReward: Get<Self::Balance>;
+
+		/// Number of seconds after which a liquidation which is not finalized can be poked.
+		#[pallet::constant]
+		type StalledAfter: Get<Timestamp>;
+
+		/// Maximal number of finalized liquidations reported to `Callback` per block, the others
+		/// are reported in the next blocks.
+		#[pallet::constant]
+		type MaxFinalizedOrdersPerBlock: Get<u32>;
+
 		type CanModifyStrategies: EnsureOrigin<Self::RuntimeOrigin>;
 		type MaxLiquidationStrategiesAmount: Get<u32>;
 	}
 
+	pub type LiquidationOrderOf<T> = LiquidationOrder<
+		<T as frame_system::Config>::AccountId,
+		<T as DeFiComposableConfig>::MayBeAssetId,
+		<T as DeFiComposableConfig>::Balance,
+		<T as Config>::LiquidationStrategyId,
+		<T as Config>::OrderId,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		PositionWasSentToLiquidation {},
+		/// The position was sold on the DEX in exchange of `received` amount of `quote`.
+		PositionWasSold {
+			strategy_id: T::LiquidationStrategyId,
+			received: T::Balance,
+		},
+		/// Liquidation is over, `received` amount of `quote` and `unsold` amount of `base` are on
+		/// the account of the liquidated position.
+		LiquidationFinalized {
+			order_id: T::OrderId,
+			state: LiquidationOrderState,
+			received: T::Balance,
+			unsold: T::Balance,
+		},
+		/// Stalled liquidation was finalized by `who`.
+		LiquidationPoked {
+			order_id: T::OrderId,
+			who: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		NoLiquidationEngineFound,
 		InvalidLiquidationStrategiesVector,
-		OnlyDutchAuctionStrategyIsImplemented,
+		LiquidationOrderNotFound,
+		/// The liquidation is already finalized.
+		LiquidationOrderIsFinalized,
+		/// The liquidation can be poked only `StalledAfter` it started.
+		LiquidationOrderIsNotStalled,
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::call]
@@ -163,9 +243,84 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		// TODO: Add API to manage callback from liquidation engine and managing it state
-		// TODO: each step from request to have its slots so can tackle
-		// TODO: add incentivised API to allow "progress" finalization if it stalled (or OCW)
+		/// Finalize a liquidation which is not over `StalledAfter` it started, cancelling the order
+		/// of the engine if any, or report again a finalized liquidation which `Callback` failed
+		/// to process.
+		/// The caller is rewarded with `PokeReward` and does not pay fees when succeeding.
+		#[pallet::weight(T::WeightInfo::poke().max(T::WeightInfo::on_finalize(1)))]
+		pub fn poke(origin: OriginFor<T>, order_id: T::OrderId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let order = LiquidationOrders::<T>::get(order_id)
+				.ok_or(Error::<T>::LiquidationOrderNotFound)?;
+			if UnreportedOrders::<T>::contains_key(order_id) {
+				Self::notify_finalized(order_id)?;
+				UnreportedOrders::<T>::remove(order_id);
+			} else {
+				ensure!(!order.state.is_finalized(), Error::<T>::LiquidationOrderIsFinalized);
+				ensure!(
+					T::UnixTime::now().as_secs() >=
+						order.started_at.saturating_add(T::StalledAfter::get()),
+					Error::<T>::LiquidationOrderIsNotStalled
+				);
+				match order.engine_order_id {
+					// the engine reports the cancellation back, see `on_order_removed`
+					Some(engine_order_id) => T::DutchAuction::cancel(engine_order_id)?,
+					None => {
+						let unsold = order.order.take.amount.saturating_sub(order.sold);
+						Self::finalize(order_id, unsold)?;
+					},
+				}
+			}
+			// rewards are best effort, so that liquidations can be finalized even if the pallet
+			// account is not funded
+			if let Err(error) = T::NativeCurrency::transfer(
+				&T::PalletId::get().into_account_truncating(),
+				&who,
+				T::PokeReward::get(),
+				false,
+			) {
+				log::warn!("failed to reward poke of {:?} with {:?}", order_id, error);
+			}
+			Self::deposit_event(Event::<T>::LiquidationPoked { order_id, who });
+			Ok(Pays::No.into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			T::WeightInfo::on_finalize(T::MaxFinalizedOrdersPerBlock::get())
+		}
+
+		// finalized liquidations are reported at the end of the block, so that those finalized
+		// within `liquidate` are reported after the caller has recorded them
+		fn on_finalize(_n: T::BlockNumber) {
+			let order_ids: Vec<_> = FinalizedOrders::<T>::iter_keys()
+				.take(T::MaxFinalizedOrdersPerBlock::get() as usize)
+				.collect();
+			for order_id in order_ids {
+				FinalizedOrders::<T>::remove(order_id);
+				let result = with_transaction(|| {
+					let result = Self::notify_finalized(order_id);
+					match result {
+						Ok(_) => TransactionOutcome::Commit(result),
+						Err(_) => TransactionOutcome::Rollback(result),
+					}
+				});
+				if let Err(error) = result {
+					log::error!(
+						"failed to notify finalized liquidation {:?} with {:?}",
+						order_id,
+						error
+					);
+					UnreportedOrders::<T>::insert(order_id, ());
+				}
+			}
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
 	}
 
 	#[pallet::storage]
@@ -188,6 +343,29 @@ pub mod pallet {
 		Nonce<DefaultInit, WrappingIncrement>,
 	>;
 
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type LiquidationOrderIndex<T: Config> =
+		StorageValue<_, T::OrderId, ValueQuery, Nonce<DefaultInit, WrappingIncrement>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_orders)]
+	pub type LiquidationOrders<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderId, LiquidationOrderOf<T>, OptionQuery>;
+
+	/// Liquidation of each order placed on an engine, until the order is removed.
+	#[pallet::storage]
+	pub type EngineOrders<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderId, T::OrderId, OptionQuery>;
+
+	/// Finalized liquidations not reported yet, reported on finalize.
+	#[pallet::storage]
+	pub type FinalizedOrders<T: Config> = StorageMap<_, Twox64Concat, T::OrderId, (), OptionQuery>;
+
+	/// Finalized liquidations which `Callback` failed to process, reported again by `poke`.
+	#[pallet::storage]
+	pub type UnreportedOrders<T: Config> = StorageMap<_, Twox64Concat, T::OrderId, (), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn default_strategy_index)]
 	#[allow(clippy::disallowed_types)]
@@ -213,11 +391,44 @@ pub mod pallet {
 		}
 	}
 
+	#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub enum LiquidationOrderState {
+		/// Nothing was sold yet.
+		Pending,
+		/// Part of the `base` was sold.
+		PartiallyFilled,
+		/// All the `base` was sold.
+		Completed,
+		/// The order was cancelled or poked before all the `base` was sold.
+		Stalled,
+	}
+
+	impl LiquidationOrderState {
+		pub fn is_finalized(&self) -> bool {
+			matches!(self, Self::Completed | Self::Stalled)
+		}
+	}
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct LiquidationOrder<AccountId, AssetId, Balance, StrategyId, OrderId> {
+		pub from_to: AccountId,
+		pub order: Sell<AssetId, Balance>,
+		/// strategy which accepted the order
+		pub strategy_id: StrategyId,
+		/// order placed on the engine, if the strategy does not sell immediately
+		pub engine_order_id: Option<OrderId>,
+		/// amount of `base` sold up to now
+		pub sold: Balance,
+		/// amount of `quote` received up to now
+		pub received: Balance,
+		pub started_at: Timestamp,
+		pub state: LiquidationOrderState,
+	}
+
 	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 	pub enum LiquidationStrategyConfiguration {
-		DutchAuction(TimeReleaseFunction),
+		DutchAuction(TimeReleaseFunction, SellOptions),
 		Pablo { slippage: Perquintill },
-		Xcm(composable_traits::xcm::XcmSellRequestTransactConfiguration),
 	}
 
 	#[cfg(feature = "std")]
@@ -229,6 +440,7 @@ pub mod pallet {
 			DefaultStrategyIndex::<T>::set(index);
 			let linear_ten_minutes = LiquidationStrategyConfiguration::DutchAuction(
 				TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 10 * 60 }),
+				SellOptions::default(),
 			);
 			Strategies::<T>::insert(index, linear_ten_minutes);
 
@@ -237,6 +449,7 @@ pub mod pallet {
 				StairstepExponentialDecrease { step: 10, cut: Permill::from_rational(95_u32, 100) };
 			let exponential = LiquidationStrategyConfiguration::DutchAuction(
 				TimeReleaseFunction::StairstepExponentialDecrease(exponential),
+				SellOptions::default(),
 			);
 			Strategies::<T>::insert(index, exponential);
 		}
@@ -246,6 +459,8 @@ pub mod pallet {
 		type LiquidationStrategyId = T::LiquidationStrategyId;
 		type OrderId = T::OrderId;
 
+		/// Try the strategies in order until one succeeds, each attempt is rolled back on failure.
+		/// Returns the id of the liquidation in [`LiquidationOrders`].
 		fn liquidate(
 			from_to: &Self::AccountId,
 			order: Sell<Self::MayBeAssetId, Self::Balance>,
@@ -257,6 +472,36 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> SellEngineCallback<T::OrderId, T::Balance> for Pallet<T> {
+		fn on_order_taken(
+			engine_order_id: T::OrderId,
+			sold: T::Balance,
+			received: T::Balance,
+		) -> Result<(), DispatchError> {
+			// orders placed by others on the engine are ignored
+			if let Some(order_id) = EngineOrders::<T>::get(engine_order_id) {
+				LiquidationOrders::<T>::try_mutate(order_id, |order| {
+					let order = order.as_mut().ok_or(Error::<T>::LiquidationOrderNotFound)?;
+					order.sold = order.sold.saturating_add(sold);
+					order.received = order.received.saturating_add(received);
+					order.state = LiquidationOrderState::PartiallyFilled;
+					Ok::<_, DispatchError>(())
+				})?;
+			}
+			Ok(())
+		}
+
+		fn on_order_removed(
+			engine_order_id: T::OrderId,
+			unsold: T::Balance,
+		) -> Result<(), DispatchError> {
+			match EngineOrders::<T>::take(engine_order_id) {
+				Some(order_id) => Self::finalize(order_id, unsold),
+				None => Ok(()),
+			}
+		}
+	}
+
 	impl<T: Config> Pallet<T> {
 		fn do_liquidate(
 			from_to: &T::AccountId,
@@ -270,21 +515,125 @@ pub mod pallet {
 					.map_err(|()| Error::<T>::InvalidLiquidationStrategiesVector)?;
 			};
 			for id in configuration {
-				let configuration = Strategies::<T>::get(id);
-				if let Some(configuration) = configuration {
-					let result = match configuration {
-						LiquidationStrategyConfiguration::DutchAuction(configuration) =>
-							T::DutchAuction::ask(from_to, order.clone(), configuration),
-						_ => return Err(Error::<T>::OnlyDutchAuctionStrategyIsImplemented.into()),
-					};
-					if let Ok(order_id) = result {
-						Self::deposit_event(Event::<T>::PositionWasSentToLiquidation {});
-						return Ok(order_id)
+				if let Some(configuration) = Strategies::<T>::get(id) {
+					let result = with_transaction(|| {
+						let result =
+							Self::liquidate_with(id, from_to, order.clone(), configuration);
+						match result {
+							Ok(_) => TransactionOutcome::Commit(result),
+							Err(_) => TransactionOutcome::Rollback(result),
+						}
+					});
+					match result {
+						Ok(order_id) => return Ok(order_id),
+						Err(error) => log::debug!(
+							"liquidation strategy {:?} failed with {:?}, trying next one",
+							id,
+							error
+						),
 					}
 				}
 			}
 
 			Err(Error::<T>::NoLiquidationEngineFound.into())
 		}
+
+		fn liquidate_with(
+			strategy_id: T::LiquidationStrategyId,
+			from_to: &T::AccountId,
+			order: Sell<T::MayBeAssetId, T::Balance>,
+			configuration: LiquidationStrategyConfiguration,
+		) -> Result<T::OrderId, DispatchError> {
+			let mut liquidation_order = LiquidationOrderOf::<T> {
+				from_to: from_to.clone(),
+				order: order.clone(),
+				strategy_id,
+				engine_order_id: None,
+				sold: T::Balance::zero(),
+				received: T::Balance::zero(),
+				started_at: T::UnixTime::now().as_secs(),
+				state: LiquidationOrderState::Pending,
+			};
+			match configuration {
+				LiquidationStrategyConfiguration::DutchAuction(configuration, options) => {
+					let engine_order_id =
+						T::DutchAuction::ask(from_to, order, (configuration, options))?;
+					liquidation_order.engine_order_id = Some(engine_order_id);
+					Self::deposit_event(Event::<T>::PositionWasSentToLiquidation {});
+				},
+				LiquidationStrategyConfiguration::Pablo { slippage } => {
+					let received = Self::sell_on_dex(from_to, order.clone(), slippage)?;
+					liquidation_order.sold = order.take.amount;
+					liquidation_order.received = received;
+					liquidation_order.state = LiquidationOrderState::Completed;
+					Self::deposit_event(Event::<T>::PositionWasSold { strategy_id, received });
+				},
+			}
+			let order_id = LiquidationOrderIndex::<T>::increment();
+			if let Some(engine_order_id) = liquidation_order.engine_order_id {
+				EngineOrders::<T>::insert(engine_order_id, order_id);
+			}
+			if liquidation_order.state.is_finalized() {
+				FinalizedOrders::<T>::insert(order_id, ());
+			}
+			LiquidationOrders::<T>::insert(order_id, liquidation_order);
+			Ok(order_id)
+		}
+
+		/// Mark the liquidation as over, to be reported on finalize.
+		fn finalize(order_id: T::OrderId, unsold: T::Balance) -> Result<(), DispatchError> {
+			LiquidationOrders::<T>::try_mutate(order_id, |order| {
+				let order = order.as_mut().ok_or(Error::<T>::LiquidationOrderNotFound)?;
+				order.state = if unsold.is_zero() {
+					LiquidationOrderState::Completed
+				} else {
+					LiquidationOrderState::Stalled
+				};
+				Ok::<_, DispatchError>(())
+			})?;
+			FinalizedOrders::<T>::insert(order_id, ());
+			Ok(())
+		}
+
+		fn notify_finalized(order_id: T::OrderId) -> Result<(), DispatchError> {
+			let order = LiquidationOrders::<T>::get(order_id)
+				.ok_or(Error::<T>::LiquidationOrderNotFound)?;
+			let unsold = order.order.take.amount.saturating_sub(order.sold);
+			T::Callback::on_liquidation_finalized(
+				order_id,
+				&order.from_to,
+				order.received,
+				unsold,
+			)?;
+			Self::deposit_event(Event::<T>::LiquidationFinalized {
+				order_id,
+				state: order.state,
+				received: order.received,
+				unsold,
+			});
+			Ok(())
+		}
+
+		/// Swap the whole `order` on the DEX, receiving at least the oracle price of the `base`
+		/// amount minus `slippage`.
+		fn sell_on_dex(
+			from_to: &T::AccountId,
+			order: Sell<T::MayBeAssetId, T::Balance>,
+			slippage: Perquintill,
+		) -> Result<T::Balance, DispatchError> {
+			let price = T::Oracle::get_ratio(order.pair)?;
+			let expected: u128 = price
+				.checked_mul_int(order.take.amount.into())
+				.ok_or(ArithmeticError::Overflow)?;
+			let min_receive = expected.saturating_sub(slippage.mul_ceil(expected));
+			let result = T::Dex::do_swap(
+				from_to,
+				order.pair,
+				AssetAmount::new(order.pair.base, order.take.amount),
+				AssetAmount::new(order.pair.quote, min_receive.into()),
+				false,
+			)?;
+			Ok(result.value.amount)
+		}
 	}
 }
@@ -0,0 +1,49 @@
+//! Storage migrations of the liquidations pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds the [`SellOptions`] of the Dutch Auction to stored strategies, and removes the XCM
+	//! strategies which could never move the position.
+
+	use super::*;
+	use codec::Decode;
+	use composable_traits::{time::TimeReleaseFunction, xcm::XcmSellRequestTransactConfiguration};
+	use frame_support::{pallet_prelude::*, traits::GetStorageVersion};
+	use pallet_dutch_auction::SellOptions;
+	use sp_runtime::Perquintill;
+
+	/// Strategy as stored before the introduction of [`SellOptions`].
+	#[derive(Decode)]
+	enum OldLiquidationStrategyConfiguration {
+		DutchAuction(TimeReleaseFunction),
+		Pablo { slippage: Perquintill },
+		Xcm(XcmSellRequestTransactConfiguration),
+	}
+
+	/// Stored Dutch Auction strategies sold without a reserve price and cleared pay-as-bid, which
+	/// the default options keep. Liquidations skip the ids of the removed XCM strategies.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		Strategies::<T>::translate::<OldLiquidationStrategyConfiguration, _>(|_, strategy| {
+			translated += 1;
+			Some(match strategy {
+				OldLiquidationStrategyConfiguration::DutchAuction(configuration) =>
+					LiquidationStrategyConfiguration::DutchAuction(
+						configuration,
+						SellOptions::default(),
+					),
+				OldLiquidationStrategyConfiguration::Pablo { slippage } =>
+					LiquidationStrategyConfiguration::Pablo { slippage },
+				OldLiquidationStrategyConfiguration::Xcm(_) => return None,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -0,0 +1,122 @@
+use super::{
+	currency::CurrencyId,
+	runtime::{AccountId, Balance, Tokens},
+};
+use composable_traits::{
+	defi::{CurrencyPair, Ratio},
+	dex::{Amm, AssetAmount, SwapResult},
+};
+use frame_support::{parameter_types, traits::fungibles::Mutate};
+use sp_runtime::{DispatchError, FixedPointNumber, Permill};
+use sp_std::collections::btree_map::BTreeMap;
+
+parameter_types! {
+	/// Price of `base` in `quote` the DEX swaps at, `None` if there is no route.
+	pub static DexPrice: Option<Ratio> = None;
+}
+
+/// DEX swapping any pair at [`DexPrice`], without fees.
+pub struct MockDex;
+
+fn unsupported<T>() -> Result<T, DispatchError> {
+	Err(DispatchError::Other("unsupported by the mock DEX"))
+}
+
+impl Amm for MockDex {
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type PoolId = CurrencyPair<CurrencyId>;
+
+	fn pool_exists(_pool_id: Self::PoolId) -> bool {
+		DexPrice::get().is_some()
+	}
+
+	fn assets(_pool_id: Self::PoolId) -> Result<BTreeMap<Self::AssetId, Permill>, DispatchError> {
+		unsupported()
+	}
+
+	fn lp_token(_pool_id: Self::PoolId) -> Result<Self::AssetId, DispatchError> {
+		unsupported()
+	}
+
+	fn redeemable_assets_for_lp_tokens(
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		unsupported()
+	}
+
+	fn simulate_add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<Self::Balance, DispatchError> {
+		unsupported()
+	}
+
+	fn simulate_remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		unsupported()
+	}
+
+	fn spot_price(
+		_pool_id: Self::PoolId,
+		_base_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_quote_asset_id: Self::AssetId,
+		_calculate_with_fees: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		unsupported()
+	}
+
+	fn do_buy(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset_id: Self::AssetId,
+		_out_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		unsupported()
+	}
+
+	fn add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_assets: BTreeMap<Self::AssetId, Self::Balance>,
+		_min_mint_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		unsupported()
+	}
+
+	fn remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_receive: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		unsupported()
+	}
+
+	fn do_swap(
+		who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		in_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		min_receive: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		let price = DexPrice::get().ok_or(DispatchError::Other("no route"))?;
+		let received =
+			price.checked_mul_int(in_asset.amount).ok_or(DispatchError::Other("overflow"))?;
+		if received < min_receive.amount {
+			return Err(DispatchError::Other("cannot respect minimal amount requested"))
+		}
+		Tokens::burn_from(in_asset.asset_id, who, in_asset.amount)?;
+		Tokens::mint_into(min_receive.asset_id, who, received)?;
+		Ok(SwapResult::new(min_receive.asset_id, received, min_receive.asset_id, 0))
+	}
+}
@@ -1,6 +1,10 @@
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 pub mod currency;
 #[cfg(test)]
+pub mod dex;
+#[cfg(test)]
 pub mod governance_registry;
 #[cfg(test)]
+pub mod oracle;
+#[cfg(test)]
 pub mod runtime;
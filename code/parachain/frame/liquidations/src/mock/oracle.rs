@@ -0,0 +1,50 @@
+use super::{currency::CurrencyId, runtime::Balance};
+use composable_traits::{
+	defi::{CurrencyPair, Ratio},
+	oracle::{Oracle, Price},
+};
+use frame_support::{parameter_types, traits::ConstU32};
+use sp_runtime::{DispatchError, FixedPointNumber};
+
+parameter_types! {
+	/// Price of `base` in `quote` for any pair.
+	pub static OraclePrice: Ratio = Ratio::saturating_from_integer(1_u64);
+	pub const TwapWindow: u16 = 0;
+}
+
+/// Oracle only able to provide [`OraclePrice`] as the ratio of any pair.
+pub struct MockOracle;
+
+impl Oracle for MockOracle {
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type Timestamp = ();
+	type LocalAssets = ();
+	type MaxAnswerBound = ConstU32<0>;
+	type TwapWindow = TwapWindow;
+
+	fn get_price(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Price<Self::Balance, Self::Timestamp>, DispatchError> {
+		Err(DispatchError::Other("unsupported by the mock oracle"))
+	}
+
+	fn get_twap_for_amount(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported by the mock oracle"))
+	}
+
+	fn get_ratio(_pair: CurrencyPair<Self::AssetId>) -> Result<Ratio, DispatchError> {
+		Ok(OraclePrice::get())
+	}
+
+	fn get_price_inverse(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported by the mock oracle"))
+	}
+}
@@ -4,10 +4,12 @@ use crate::{
 	weights::SubstrateWeight,
 };
 
-use composable_traits::{defi::DeFiComposableConfig, xcm::assets::XcmAssetLocation};
+use composable_traits::{
+	defi::DeFiComposableConfig, liquidation::LiquidationCallback, xcm::assets::XcmAssetLocation,
+};
 use frame_support::{
 	ord_parameter_types, parameter_types,
-	traits::{ConstU32, Everything, GenesisBuild},
+	traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild},
 	weights::{WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial},
 	PalletId,
 };
@@ -23,11 +25,11 @@ use sp_core::{
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
-	Perbill,
+	DispatchError, Perbill,
 };
 use xcm::latest::SendXcm;
 
-use super::governance_registry::GovernanceRegistry;
+use super::{dex::MockDex, governance_registry::GovernanceRegistry, oracle::MockOracle};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 pub type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -222,6 +224,10 @@ impl SendXcm for XcmFake {
 	}
 }
 
+parameter_types! {
+	pub const ReservePriceChecksPerBlock: u32 = 16;
+}
+
 impl pallet_dutch_auction::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UnixTime = Timestamp;
@@ -234,12 +240,39 @@ impl pallet_dutch_auction::Config for Runtime {
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type XcmSender = XcmFake;
 	type XcmOrigin = XcmFake;
+	type ReservePriceChecksPerBlock = ReservePriceChecksPerBlock;
+	type OrderCallback = Liquidations;
 }
 
 parameter_types! {
 	pub const LiquidationPalletId : PalletId = PalletId(*b"liquidat");
 }
 
+parameter_types! {
+	/// Liquidations reported to [`FinalizedLiquidations`] as `(order_id, from_to, received, unsold)`.
+	pub static Finalized: Vec<(OrderId, AccountId, Balance, Balance)> = vec![];
+	/// Whether [`FinalizedLiquidations`] fails to process the reported liquidations.
+	pub static FailFinalized: bool = false;
+}
+
+pub struct FinalizedLiquidations;
+impl LiquidationCallback<AccountId, OrderId, Balance> for FinalizedLiquidations {
+	fn on_liquidation_finalized(
+		order_id: OrderId,
+		from_to: &AccountId,
+		received: Balance,
+		unsold: Balance,
+	) -> Result<(), DispatchError> {
+		if FailFinalized::get() {
+			return Err(DispatchError::Other("failed to settle"))
+		}
+		Finalized::mutate(|finalized| {
+			finalized.push((order_id, from_to.clone(), received, unsold))
+		});
+		Ok(())
+	}
+}
+
 type LiquidationStrategyId = u32;
 impl pallet_liquidations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -251,6 +284,13 @@ impl pallet_liquidations::Config for Runtime {
 	type PalletId = LiquidationPalletId;
 	type CanModifyStrategies = EnsureRoot<Self::AccountId>;
 	type XcmSender = XcmFake;
+	type Dex = MockDex;
+	type Oracle = MockOracle;
+	type Callback = FinalizedLiquidations;
+	type NativeCurrency = Balances;
+	type PokeReward = ConstU128<10>;
+	type StalledAfter = ConstU64<{ 24 * 60 * 60 }>;
+	type MaxFinalizedOrdersPerBlock = ConstU32<2>;
 	type MaxLiquidationStrategiesAmount = ConstU32<3>;
 }
 
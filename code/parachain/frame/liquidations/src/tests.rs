@@ -1,17 +1,35 @@
 use crate::{
 	self as pallet_liquidations,
-	mock::{currency::*, runtime::*},
+	mock::{currency::*, dex::DexPrice, oracle::OraclePrice, runtime::*},
+	LiquidationOrderState, LiquidationOrders, LiquidationStrategyConfiguration,
 };
 use codec::Encode;
 use composable_traits::{
-	defi::{Ratio, Sell},
+	defi::{Ratio, Sell, Take},
 	liquidation::Liquidation,
+	time::{LinearDecrease, TimeReleaseFunction},
 };
 use frame_support::{
-	assert_noop,
-	traits::{fungible::Mutate as NativeMutate, fungibles::Mutate},
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{Inspect as NativeInspect, Mutate as NativeMutate},
+		fungibles::{Inspect, Mutate},
+		Get, Hooks,
+	},
 };
-use sp_runtime::{FixedPointNumber, FixedU128};
+use pallet_dutch_auction::{ClearingMode, SellOptions};
+use sp_runtime::{
+	traits::AccountIdConversion, DispatchError, FixedPointNumber, FixedU128, Perquintill,
+};
+
+/// Order placed on the Dutch auction for the liquidation `order_id`.
+fn engine_order(order_id: OrderId) -> pallet_dutch_auction::SellOf<Runtime> {
+	let engine_order_id = LiquidationOrders::<Runtime>::get(order_id)
+		.expect("liquidation exists")
+		.engine_order_id
+		.expect("order was placed on the engine");
+	pallet_dutch_auction::SellOrders::<Runtime>::get(engine_order_id).expect("order was placed")
+}
 
 // ensure that we take extra for sell, at least amount to remove
 #[test]
@@ -30,8 +48,7 @@ fn successful_liquidate() {
 			vec![],
 		)
 		.expect("can creator order for existing currencies if enough of amounts");
-		let order =
-			pallet_dutch_auction::SellOrders::<Runtime>::get(order).expect("order was placed");
+		let order = engine_order(order);
 		assert_eq!(order.from_to, who);
 		assert_eq!(order.order.take.amount, amount);
 	});
@@ -93,4 +110,241 @@ fn pallet_do_not_treat_infinitely_large_strategies_vector() {
 	});
 }
 
+fn add_pablo_strategy(slippage: Perquintill) -> u32 {
+	assert_ok!(Liquidations::add_liquidation_strategy(
+		RuntimeOrigin::root(),
+		LiquidationStrategyConfiguration::Pablo { slippage },
+	));
+	Liquidations::strategy_index()
+}
+
+#[test]
+fn pablo_strategy_sells_on_dex() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		OraclePrice::set(Ratio::saturating_from_integer(2));
+		// the DEX price is 1% below the oracle one
+		DexPrice::set(Some(Ratio::saturating_from_rational(198, 100)));
+		let strategy_id = add_pablo_strategy(Perquintill::from_percent(1));
+		let order_id = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(2)),
+			vec![strategy_id],
+		)
+		.expect("the DEX price is within the slippage");
+		assert_eq!(Tokens::balance(KUSD, &ALICE), 900);
+		assert_eq!(Tokens::balance(PICA, &ALICE), 198);
+		System::assert_last_event(RuntimeEvent::Liquidations(crate::Event::PositionWasSold {
+			strategy_id,
+			received: 198,
+		}));
+		assert_eq!(
+			LiquidationOrders::<Runtime>::get(order_id).map(|order| order.state),
+			Some(LiquidationOrderState::Completed)
+		);
+		// reported at the end of the block, once the caller has recorded the liquidation
+		assert_eq!(Finalized::get(), vec![]);
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get(), vec![(order_id, ALICE, 198, 0)]);
+	});
+}
+
+#[test]
+fn pablo_strategy_falls_back_to_dutch_auction() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		<Balances as NativeMutate<_>>::mint_into(&ALICE, NativeExistentialDeposit::get() * 3)
+			.unwrap();
+		OraclePrice::set(Ratio::saturating_from_integer(2));
+		DexPrice::set(Some(Ratio::saturating_from_integer(1)));
+		let strategy_id = add_pablo_strategy(Perquintill::from_percent(10));
+		let order = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(2)),
+			vec![strategy_id, Liquidations::default_strategy_index()],
+		)
+		.expect("falls back to the Dutch auction");
+		let order = engine_order(order);
+		assert_eq!(order.order.take.amount, 100);
+		assert_eq!(Tokens::balance(PICA, &ALICE), 0);
+		System::assert_last_event(RuntimeEvent::Liquidations(
+			crate::Event::PositionWasSentToLiquidation {},
+		));
+	});
+}
+
+#[test]
+fn dutch_auction_strategy_sells_with_its_options() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		<Balances as NativeMutate<_>>::mint_into(&ALICE, NativeExistentialDeposit::get() * 3)
+			.unwrap();
+		let options = SellOptions {
+			reserve_price: Some(Ratio::saturating_from_integer(1)),
+			clearing: ClearingMode::Uniform,
+		};
+		assert_ok!(Liquidations::add_liquidation_strategy(
+			RuntimeOrigin::root(),
+			LiquidationStrategyConfiguration::DutchAuction(
+				TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 10 * 60 }),
+				options.clone(),
+			),
+		));
+		let order = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(2)),
+			vec![Liquidations::strategy_index()],
+		)
+		.expect("can sell on the Dutch auction");
+		assert_eq!(engine_order(order).options, options);
+	});
+}
+
+#[test]
+fn fails_if_no_strategy_succeeds() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		let strategy_id = add_pablo_strategy(Perquintill::from_percent(1));
+		assert_noop!(
+			<Liquidations as Liquidation>::liquidate(
+				&ALICE,
+				Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(1)),
+				vec![strategy_id],
+			),
+			crate::Error::<Runtime>::NoLiquidationEngineFound,
+		);
+	});
+}
+
+#[test]
+fn auction_progress_is_tracked_until_completion() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		let order_id = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(1)),
+			vec![],
+		)
+		.unwrap();
+		let state = || LiquidationOrders::<Runtime>::get(order_id).map(|order| order.state);
+		assert_eq!(state(), Some(LiquidationOrderState::Pending));
+		let engine_order_id =
+			LiquidationOrders::<Runtime>::get(order_id).unwrap().engine_order_id.unwrap();
+
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			engine_order_id,
+			Take::new(40, Ratio::saturating_from_integer(1)),
+		));
+		DutchAuction::on_finalize(System::block_number());
+		assert_eq!(state(), Some(LiquidationOrderState::PartiallyFilled));
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get(), vec![]);
+
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			engine_order_id,
+			Take::new(60, Ratio::saturating_from_integer(1)),
+		));
+		DutchAuction::on_finalize(System::block_number());
+		assert_eq!(state(), Some(LiquidationOrderState::Completed));
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get(), vec![(order_id, ALICE, 100, 0)]);
+	});
+}
+
+#[test]
+fn poke_finalizes_stalled_liquidation() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		let pallet_account: AccountId = LiquidationPalletId::get().into_account_truncating();
+		<Balances as NativeMutate<_>>::mint_into(&pallet_account, 1_000).unwrap();
+		let order_id = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(1)),
+			vec![],
+		)
+		.unwrap();
+		assert_noop!(
+			Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id),
+			crate::Error::<Runtime>::LiquidationOrderIsNotStalled,
+		);
+
+		let stalled_after = <Runtime as crate::Config>::StalledAfter::get();
+		Timestamp::set_timestamp(Timestamp::now() + (stalled_after + 1) * 1_000);
+		assert_ok!(Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id));
+		assert_eq!(<Balances as NativeInspect<_>>::balance(&CHARLIE), 10);
+		assert_eq!(
+			LiquidationOrders::<Runtime>::get(order_id).map(|order| order.state),
+			Some(LiquidationOrderState::Stalled)
+		);
+		// the unsold position is back to the owner
+		assert_eq!(Tokens::balance(KUSD, &ALICE), 1_000);
+		assert_noop!(
+			Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id),
+			crate::Error::<Runtime>::LiquidationOrderIsFinalized,
+		);
+
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get(), vec![(order_id, ALICE, 0, 100)]);
+	});
+}
+
+#[test]
+fn finalized_liquidations_are_reported_in_batches() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		OraclePrice::set(Ratio::saturating_from_integer(1));
+		DexPrice::set(Some(Ratio::saturating_from_integer(1)));
+		let strategy_id = add_pablo_strategy(Perquintill::from_percent(1));
+		for _ in 0..3 {
+			<Liquidations as Liquidation>::liquidate(
+				&ALICE,
+				Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(1)),
+				vec![strategy_id],
+			)
+			.expect("the DEX price is the oracle one");
+		}
+
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get().len(), 2);
+		Liquidations::on_finalize(System::block_number() + 1);
+		assert_eq!(Finalized::get().len(), 3);
+	});
+}
+
+#[test]
+fn poke_reports_liquidation_again_when_callback_failed() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(KUSD, &ALICE, 1_000).unwrap();
+		OraclePrice::set(Ratio::saturating_from_integer(1));
+		DexPrice::set(Some(Ratio::saturating_from_integer(1)));
+		let strategy_id = add_pablo_strategy(Perquintill::from_percent(1));
+		let order_id = <Liquidations as Liquidation>::liquidate(
+			&ALICE,
+			Sell::new(KUSD, PICA, 100, Ratio::saturating_from_integer(1)),
+			vec![strategy_id],
+		)
+		.expect("the DEX price is the oracle one");
+
+		FailFinalized::set(true);
+		Liquidations::on_finalize(System::block_number());
+		assert_eq!(Finalized::get(), vec![]);
+		assert!(crate::UnreportedOrders::<Runtime>::contains_key(order_id));
+		assert_noop!(
+			Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id),
+			DispatchError::Other("failed to settle"),
+		);
+
+		FailFinalized::set(false);
+		assert_ok!(Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id));
+		assert_eq!(Finalized::get(), vec![(order_id, ALICE, 100, 0)]);
+		assert!(!crate::UnreportedOrders::<Runtime>::contains_key(order_id));
+		assert_noop!(
+			Liquidations::poke(RuntimeOrigin::signed(CHARLIE), order_id),
+			crate::Error::<Runtime>::LiquidationOrderIsFinalized,
+		);
+	});
+}
+
 // TODO: add XCM end to end tests with callbacks
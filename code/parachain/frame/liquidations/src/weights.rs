@@ -9,6 +9,8 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_liquidation_strategy() -> Weight;
 	fn sell(vector_length: u32) -> Weight;
+	fn poke() -> Weight;
+	fn on_finalize(x: u32) -> Weight;
 }
 
 /// Weight functions for `liquidations`.
@@ -35,4 +37,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x as u64)))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// Storage: Liquidations LiquidationOrders (r:1 w:1)
+	// Storage: DutchAuction SellOrders (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DutchAuction LocalOrderIdToRemote (r:1 w:0)
+	// Storage: Liquidations EngineOrders (r:1 w:1)
+	// Storage: Liquidations FinalizedOrders (r:0 w:1)
+	fn poke() -> Weight {
+		Weight::from_ref_time(45_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Liquidations FinalizedOrders (r:1 w:1)
+	// Storage: Liquidations LiquidationOrders (r:1 w:0)
+	fn on_finalize(x: u32) -> Weight {
+		Weight::from_ref_time(2_118_000_u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(9_452_000_u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x as u64)))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x as u64)))
+	}
 }
@@ -2,6 +2,7 @@
 
 use crate as pablo;
 use composable_tests_helpers::test::currency;
+use composable_traits::currency::{Exponent, LocalAssets};
 use frame_support::{
 	ord_parameter_types,
 	pallet_prelude::GenesisBuild,
@@ -16,7 +17,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	Permill,
+	DispatchError, Permill,
 };
 
 pub type CurrencyId = u128;
@@ -160,6 +161,7 @@ parameter_types! {
 	pub const StakingRewardsLockId: LockIdentifier = *b"stk_lock";
 	pub const MaxStakingDurationPresets: u32 = 10;
 	pub const MaxRewardConfigsPerPool: u32 = 10;
+	pub const MaxRewardRateChanges: u32 = 10;
 	pub const PicaAssetId : CurrencyId = 1;
 	pub const PbloAssetId : CurrencyId = 2;
 	pub const XPicaAssetId: CurrencyId = 101;
@@ -183,6 +185,7 @@ impl pallet_staking_rewards::Config for Test {
 	type PalletId = StakingRewardsPalletId;
 	type MaxStakingDurationPresets = MaxStakingDurationPresets;
 	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxRewardRateChanges = MaxRewardRateChanges;
 	type RewardPoolCreationOrigin = EnsureRoot<Self::AccountId>;
 	type RewardPoolUpdateOrigin = EnsureRoot<Self::AccountId>;
 	type PicaAssetId = PicaAssetId;
@@ -201,6 +204,13 @@ ord_parameter_types! {
 	pub const RootAccount: AccountId = ALICE;
 }
 
+pub struct Decimals;
+impl LocalAssets<AssetId> for Decimals {
+	fn decimals(_currency_id: AssetId) -> Result<Exponent, DispatchError> {
+		Ok(12)
+	}
+}
+
 impl pablo::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -210,7 +220,7 @@ impl pablo::Config for Test {
 	type Convert = ConvertInto;
 	type PoolId = PoolId;
 	type PalletId = TestPalletID;
-	type LocalAssets = LpTokenFactory;
+	type LocalAssets = Decimals;
 	type PoolCreationOrigin = EitherOfDiverse<
 		EnsureSignedBy<RootAccount, AccountId>, // for tests
 		EnsureRoot<AccountId>,                  // for benchmarks
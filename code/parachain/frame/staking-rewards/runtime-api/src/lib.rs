@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+
+// Staking Rewards Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait StakingRewardsRuntimeApi<AssetId, AccountId, Balance>
+	where
+		AssetId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Retrieve the current voting power of `account` in the reward pool `pool_id`, derived
+		/// from the stake and remaining lock time of its positions.
+		fn voting_power(pool_id: AssetId, account: AccountId) -> SafeRpcWrapper<Balance>;
+	}
+}
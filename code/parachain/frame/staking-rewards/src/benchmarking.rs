@@ -8,7 +8,7 @@ use composable_tests_helpers::test::helper::RuntimeTrait;
 use composable_traits::{
 	staking::{
 		lock::LockConfig, RewardConfig, RewardPoolConfiguration::RewardRateBasedIncentive,
-		RewardRate, RewardUpdate,
+		RewardRate, RewardRateSchedule, RewardUpdate,
 	},
 	time::{ONE_HOUR, ONE_MINUTE},
 };
@@ -244,7 +244,7 @@ benchmarks! {
 
 		let mut reward = RewardPools::<T>::get(&pool_id).unwrap().rewards.get(&reward_asset_id).unwrap().clone();
 	}: {
-		crate::reward_accumulation_hook_reward_update_calculation::<T>(pool_id, reward_asset_id,&mut reward, now);
+		crate::reward_accumulation_hook_reward_update_calculation::<T>(pool_id, reward_asset_id,&mut reward, now, 3);
 	}
 
 	unix_time_now {}: {
@@ -301,5 +301,100 @@ benchmarks! {
 
 	}: _(OriginFor::<T>::signed(user), pool_id,  asset_id, amount, true)
 
+	set_auto_compound {
+		let asset_id = BASE_ASSET_ID.into();
+		let amount = 100_500_u128.into();
+		let staker = whitelisted_caller();
+		let pool_owner: T::AccountId = account("owner", 0, 0);
+
+		frame_system::Pallet::<T>::set_block_number(1.into());
+		<Pallet<T>>::create_reward_pool(OriginFor::<T>::root(), get_reward_pool::<T>(pool_owner, 1))?;
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &staker, amount * 2.into())?;
+
+		frame_system::Pallet::<T>::set_block_number(2.into());
+		<Pallet<T>>::stake(OriginFor::<T>::signed(staker.clone()), asset_id, amount, ONE_HOUR)?;
+	}: _(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into(), true)
+	verify {
+		assert_last_event::<T>(
+			Event::AutoCompoundSet {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID.into(),
+				fnft_instance_id: FNFT_INSTANCE_ID_BASE.into(),
+				enabled: true,
+			}.into()
+		);
+	}
+
+	merge {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let asset_id = BASE_ASSET_ID.into();
+		let amount = 100_500_u128.into();
+		let staker = whitelisted_caller();
+		let pool_owner: T::AccountId = account("owner", 0, 0);
+
+		frame_system::Pallet::<T>::set_block_number(1.into());
+		<Pallet<T>>::create_reward_pool(OriginFor::<T>::root(), get_reward_pool::<T>(pool_owner, r))?;
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &staker, amount * 3.into())?;
+
+		frame_system::Pallet::<T>::set_block_number(2.into());
+		let instance_id = stake_and_assert::<T>(staker.clone(), asset_id, amount, ONE_HOUR);
+		let other_instance_id = stake_and_assert::<T>(staker.clone(), asset_id, amount, ONE_HOUR);
+	}: _(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), instance_id, other_instance_id)
+	verify {
+		assert_last_event::<T>(
+			Event::PositionsMerged {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID.into(),
+				fnft_instance_id: instance_id,
+				merged_fnft_instance_id: other_instance_id,
+				stake: amount * 2.into(),
+			}.into()
+		);
+	}
+
+	compound {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let asset_id = BASE_ASSET_ID.into();
+		let amount = 100_500_u128.into();
+		let staker = whitelisted_caller();
+		let pool_owner: T::AccountId = account("owner", 0, 0);
+
+		// the first reward asset of the pool is the staked asset
+		frame_system::Pallet::<T>::set_block_number(1.into());
+		<Pallet<T>>::create_reward_pool(OriginFor::<T>::root(), get_reward_pool::<T>(pool_owner, r))?;
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &staker, amount * 2.into())?;
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &Pallet::<T>::pool_account_id(&asset_id), amount)?;
+
+		frame_system::Pallet::<T>::set_block_number(2.into());
+		<Pallet<T>>::stake(OriginFor::<T>::signed(staker.clone()), asset_id, amount, ONE_HOUR)?;
+
+		RewardPools::<T>::mutate(asset_id, |pool| {
+			let reward = pool.as_mut().unwrap().rewards.get_mut(&asset_id).unwrap();
+			reward.total_rewards = reward.total_rewards + amount;
+		});
+	}: {
+		Pallet::<T>::compound_position(&STAKING_FNFT_COLLECTION_ID.into(), &FNFT_INSTANCE_ID_BASE.into())?;
+	}
+
+	set_reward_rate_schedule {
+		let c in 1 .. T::MaxRewardRateChanges::get();
+		let pool_owner: T::AccountId = account("owner", 0, 0);
+		let asset_id = BASE_ASSET_ID.into();
+		let now = T::UnixTime::now().as_secs();
+
+		frame_system::Pallet::<T>::set_block_number(1.into());
+		<Pallet<T>>::create_reward_pool(OriginFor::<T>::root(), get_reward_pool::<T>(pool_owner, 1))?;
+
+		let schedule = RewardRateSchedule::Changes(
+			(0..c)
+				.map(|c| (now + u64::from(c) * ONE_HOUR, RewardRate::per_second(10_000_u128 >> c)))
+				.try_collect()
+				.unwrap(),
+		);
+	}: _(OriginFor::<T>::root(), asset_id, asset_id, Some(schedule))
+	verify {
+		assert_last_event::<T>(
+			Event::RewardRateScheduleSet { pool_id: asset_id, asset_id, enabled: true }.into()
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::test::new_test_ext(), crate::runtime::Test);
 }
@@ -46,11 +46,12 @@ pub(crate) mod test_helpers;
 
 mod validation;
 
+pub mod migrations;
 pub mod prelude;
 pub mod weights;
 
 use composable_support::math::safe::{SafeDiv, SafeMul, SafeSub};
-use composable_traits::staking::{Reward, RewardUpdate};
+use composable_traits::staking::{Reward, RewardRate, RewardUpdate};
 use core::{
 	cmp,
 	cmp::Ordering,
@@ -64,6 +65,7 @@ use frame_support::{
 	},
 	BoundedBTreeMap,
 };
+use sp_runtime::SaturatedConversion;
 
 use crate::prelude::*;
 
@@ -96,6 +98,7 @@ pub mod pallet {
 			tokens::{
 				nonfungibles::{
 					Create as NonFungiblesCreate, Inspect as NonFungiblesInspect,
+					InspectEnumerable as NonFungiblesInspectEnumerable,
 					Mutate as NonFungiblesMutate,
 				},
 				WithdrawConsequence,
@@ -108,17 +111,17 @@ pub mod pallet {
 	use orml_traits::{GetByKey, LockIdentifier, MultiLockableCurrency};
 	use sp_arithmetic::{
 		fixed_point::{FixedPointNumber, FixedU64},
-		Permill,
+		Perbill, Permill,
 	};
 	use sp_runtime::{
 		traits::{AccountIdConversion, BlockNumberProvider, One},
-		ArithmeticError, PerThing,
+		ArithmeticError, PerThing, SaturatedConversion,
 	};
 	use sp_std::{fmt::Debug, ops::Mul, vec, vec::Vec};
 
 	use crate::{
-		accumulate_rewards_hook, add_to_rewards_pot, claim_of_stake, prelude::*,
-		update_rewards_pool, validation::ValidSplitRatio,
+		accumulate_rewards_hook, add_to_rewards_pot, auto_compound_hook, claim_of_stake,
+		prelude::*, update_rewards_pool, validation::ValidSplitRatio,
 	};
 
 	#[pallet::event]
@@ -221,6 +224,43 @@ pub mod pallet {
 			pool_id: T::AssetId,
 			asset_id: T::AssetId,
 		},
+		/// Auto-compounding was enabled or disabled for a position.
+		AutoCompoundSet {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			enabled: bool,
+		},
+		/// Rewards paid in the staked asset were restaked into the position.
+		RewardsCompounded {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			/// Amount of the staked asset added to the stake.
+			amount: T::Balance,
+			/// Shares awarded for the compounded amount.
+			shares: T::Balance,
+		},
+		/// A reward rate schedule was set or removed for a reward asset of a pool.
+		RewardRateScheduleSet {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			enabled: bool,
+		},
+		/// A scheduled reward rate change was applied.
+		RewardRateChanged {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			reward_rate: RewardRate<T::Balance>,
+		},
+		/// Two positions were merged into one.
+		PositionsMerged {
+			fnft_collection_id: T::AssetId,
+			/// The position that was kept.
+			fnft_instance_id: T::FinancialNftInstanceId,
+			/// The position that was burned.
+			merged_fnft_instance_id: T::FinancialNftInstanceId,
+			/// Total stake of the merged position.
+			stake: T::Balance,
+		},
 	}
 
 	#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
@@ -281,6 +321,14 @@ pub mod pallet {
 		StakedAmountTooLowAfterSplit,
 		/// Some operation resulted in an arithmetic overflow.
 		ArithmeticError,
+		/// A position cannot be merged with itself.
+		CannotMergePositionWithItself,
+		/// Positions must be in the same pool and have the same duration and unlock penalty to be
+		/// merged.
+		IncompatibleLocks,
+		/// Reward rate schedules must have at least one change, all of them in the future and in
+		/// order.
+		InvalidRewardRateSchedule,
 	}
 
 	pub(crate) type AssetIdOf<T> = <T as Config>::AssetId;
@@ -313,6 +361,7 @@ pub mod pallet {
 
 		// REVIEW(benluelo): Mutate::CollectionId type?
 		type FinancialNft: NonFungiblesMutate<AccountIdOf<Self>>
+			+ NonFungiblesInspectEnumerable<AccountIdOf<Self>>
 			+ NonFungiblesCreate<
 				AccountIdOf<Self>,
 				CollectionId = Self::AssetId,
@@ -373,6 +422,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRewardConfigsPerPool: Get<u32>;
 
+		/// Maximum number of changes in a reward rate schedule.
+		#[pallet::constant]
+		type MaxRewardRateChanges: Get<u32>;
+
 		/// Required origin for reward pool creation.
 		type RewardPoolCreationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -429,6 +482,10 @@ pub mod pallet {
 		<T as Config>::MaxRewardConfigsPerPool,
 	>;
 
+	/// Abstraction over RewardRateSchedule type
+	pub(crate) type RewardRateScheduleOf<T> =
+		RewardRateSchedule<BalanceOf<T>, <T as Config>::MaxRewardRateChanges>;
+
 	/// Abstraction over Stake type
 	pub(crate) type StakeOf<T> = Stake<
 		AssetIdOf<T>,
@@ -437,8 +494,11 @@ pub mod pallet {
 		<T as Config>::MaxRewardConfigsPerPool,
 	>;
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -463,6 +523,34 @@ pub mod pallet {
 	pub(super) type RewardsPotIsEmpty<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, ()>;
 
+	/// Pre-scheduled reward rate changes, keyed by pool id and reward asset id.
+	#[pallet::storage]
+	pub type RewardRateSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AssetId,
+		RewardRateScheduleOf<T>,
+	>;
+
+	/// Positions whose rewards in the staked asset are restaked instead of paid out.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type AutoCompound<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId, // collection id
+		Blake2_128Concat,
+		FinancialNftInstanceIdOf<T>,
+		(),
+	>;
+
+	/// Raw storage key of the last position compounded by the `on_idle` sweep, used to resume
+	/// the sweep in the next block.
+	#[pallet::storage]
+	pub(super) type AutoCompoundCursor<T: Config> = StorageValue<_, Vec<u8>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		_phantom: sp_std::marker::PhantomData<T>,
@@ -538,6 +626,15 @@ pub mod pallet {
 		fn on_initialize(_: T::BlockNumber) -> Weight {
 			accumulate_rewards_hook::<T>()
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+
+		/// Compounds auto-compounding positions with whatever weight is left in the block.
+		fn on_idle(_: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			auto_compound_hook::<T>(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -659,6 +756,39 @@ pub mod pallet {
 			update_rewards_pool::<T>(pool_id, reward_updates)
 		}
 
+		/// Set or remove the reward rate schedule of a reward asset in a pool. Scheduled changes
+		/// are applied by the reward accumulation hook once their time is reached.
+		///
+		/// Emits `RewardRateScheduleSet` when successful.
+		#[pallet::weight(T::WeightInfo::set_reward_rate_schedule(T::MaxRewardRateChanges::get()))]
+		pub fn set_reward_rate_schedule(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			schedule: Option<RewardRateScheduleOf<T>>,
+		) -> DispatchResult {
+			T::RewardPoolUpdateOrigin::ensure_origin(origin)?;
+
+			let pool = RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardsPoolNotFound)?;
+			ensure!(pool.rewards.contains_key(&asset_id), Error::<T>::RewardAssetNotFound);
+
+			let enabled = schedule.is_some();
+			match schedule {
+				Some(schedule) => {
+					ensure!(
+						schedule.is_valid(T::UnixTime::now().as_secs()),
+						Error::<T>::InvalidRewardRateSchedule
+					);
+					RewardRateSchedules::<T>::insert(pool_id, asset_id, schedule);
+				},
+				None => RewardRateSchedules::<T>::remove(pool_id, asset_id),
+			}
+
+			Self::deposit_event(Event::<T>::RewardRateScheduleSet { pool_id, asset_id, enabled });
+
+			Ok(())
+		}
+
 		/// Claim a current reward for some position.
 		///
 		/// Emits `Claimed` when successful.
@@ -692,6 +822,67 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			add_to_rewards_pot::<T>(who, pool_id, asset_id, amount, keep_alive)
 		}
+
+		/// Enable or disable auto-compounding for a position. When enabled, rewards paid in the
+		/// staked asset are added to the stake on claim and by the `on_idle` sweep.
+		///
+		/// Emits `AutoCompoundSet` when successful.
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			enabled: bool,
+		) -> DispatchResult {
+			Self::ensure_stake_owner(
+				ensure_signed(origin)?,
+				&fnft_collection_id,
+				&fnft_instance_id,
+			)?;
+			ensure!(
+				Stakes::<T>::contains_key(fnft_collection_id, fnft_instance_id),
+				Error::<T>::StakeNotFound
+			);
+
+			if enabled {
+				AutoCompound::<T>::insert(fnft_collection_id, fnft_instance_id, ());
+			} else {
+				AutoCompound::<T>::remove(fnft_collection_id, fnft_instance_id);
+			}
+
+			Self::deposit_event(Event::<T>::AutoCompoundSet {
+				fnft_collection_id,
+				fnft_instance_id,
+				enabled,
+			});
+
+			Ok(())
+		}
+
+		/// Merge two positions in the same pool with compatible locks. The second position is
+		/// burned and its stake, shares and reductions are added to the first.
+		///
+		/// Emits `PositionsMerged` when successful.
+		#[pallet::weight(T::WeightInfo::merge(T::MaxRewardConfigsPerPool::get()))]
+		pub fn merge(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			other_fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = Self::ensure_stake_owner(
+				ensure_signed(origin)?,
+				&fnft_collection_id,
+				&fnft_instance_id,
+			)?;
+			let who = Self::ensure_stake_owner(who, &fnft_collection_id, &other_fnft_instance_id)?;
+
+			<Self as Staking>::merge(
+				&who,
+				&(fnft_collection_id, fnft_instance_id),
+				&(fnft_collection_id, other_fnft_instance_id),
+			)
+		}
 	}
 
 	impl<T: Config> ManageStaking for Pallet<T> {
@@ -740,6 +931,7 @@ pub mod pallet {
 					ensure!(lock.duration_presets.len() > 0, Error::<T>::NoDurationPresetsProvided);
 
 					let now_seconds = T::UnixTime::now().as_secs();
+					let now_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
 					let existential_deposit = T::ExistentialDeposits::get(&pool_asset);
 
@@ -771,7 +963,7 @@ pub mod pallet {
 					let rewards = initial_reward_config
 						.into_iter()
 						.map(|(asset_id, reward_config)| {
-							(asset_id, Reward::from_config(reward_config, now_seconds))
+							(asset_id, Reward::from_config(reward_config, now_seconds, now_block))
 						})
 						.try_collect()
 						.expect("No items were added; qed;");
@@ -819,12 +1011,12 @@ pub mod pallet {
 			collection: &Self::AssetId,
 			instance: &Self::ItemId,
 		) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError> {
-			RewardPools::<T>::get(collection)
-				.zip(Stakes::<T>::get(collection, instance))
+			Stakes::<T>::get(collection, instance)
+				.and_then(|stake| RewardPools::<T>::get(stake.reward_pool_id).zip(Some(stake)))
 				// This can take into account the value of assets held in the asset account as
 				// well as the claimable rewards in the future when market places exists for these
 				// NFTs.
-				.map(|pool| vec![(pool.0.share_asset_id, pool.1.share)])
+				.map(|(pool, stake)| vec![(pool.share_asset_id, stake.share)])
 				.ok_or_else(|| DispatchError::Other(Error::<T>::StakeNotFound.into()))
 		}
 	}
@@ -834,6 +1026,7 @@ pub mod pallet {
 		type RewardPoolId = T::AssetId;
 		type Balance = T::Balance;
 		type PositionId = (T::AssetId, T::FinancialNftInstanceId);
+		type CollectionId = T::AssetId;
 
 		#[transactional]
 		fn stake(
@@ -941,48 +1134,12 @@ pub mod pallet {
 						Error::<T>::NotEnoughAssets
 					);
 
-					// SAFETY: The duration preset on an existing stake should be valid in the
-					// pool since it's currently not possible to modify the presets after pool
-					// creation.
-					let reward_multiplier = rewards_pool
-						.lock
-						.duration_presets
-						.get(&stake.lock.duration)
-						.copied()
-						.defensive_unwrap_or_else(|| {
-							FixedU64::one().try_into_validated().expect("1 is >= 1")
-						});
+					let reward_multiplier =
+						Self::reward_multiplier_of_stake(rewards_pool, stake.lock.duration);
 
 					let new_shares = Self::boosted_amount(reward_multiplier, amount)?;
 
-					let total_shares = T::Assets::total_issuance(rewards_pool.share_asset_id);
-
-					for (reward_asset_id, reward) in &mut rewards_pool.rewards {
-						let new_inflation = if total_shares.is_zero() {
-							T::Balance::zero()
-						} else {
-							reward.total_rewards.safe_mul(&new_shares)?.safe_div(&total_shares)?
-						};
-
-						reward.total_rewards = reward.total_rewards.safe_add(&new_inflation)?;
-						reward.total_dilution_adjustment =
-							reward.total_dilution_adjustment.safe_add(&new_inflation)?;
-
-						match stake.reductions.get_mut(reward_asset_id) {
-							Some(previous_inflation_and_claims) => {
-								*previous_inflation_and_claims =
-									previous_inflation_and_claims.safe_add(&new_inflation)?;
-							},
-							None => {
-								// REVIEW(benluelo): Is this an invariant we expect? In
-								// ProtocolStaking::transfer_reward assets can be added (and is
-								// currently the only way to add a new reward asset to a pool),
-								// but they are not added to all existing stakes so this
-								// invariant is not upheld
-								defensive!("stake.reductions should contain the same assets as reward_pool.rewards");
-							},
-						}
-					}
+					Self::dilute_rewards(rewards_pool, &mut stake.reductions, new_shares)?;
 
 					let fnft_asset_account =
 						T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
@@ -1042,6 +1199,7 @@ pub mod pallet {
 						&mut stake,
 						who,
 						is_early_unlock,
+						false, // the whole position is paid out, nothing to compound into
 					)?;
 
 					Ok::<_, DispatchError>((stake.reward_pool_id, rewards_pool.share_asset_id))
@@ -1068,6 +1226,7 @@ pub mod pallet {
 			)?;
 
 			Stakes::<T>::remove(fnft_collection_id, fnft_instance_id);
+			AutoCompound::<T>::remove(fnft_collection_id, fnft_instance_id);
 
 			// transfer slashed stake to the treasury
 			if is_early_unlock {
@@ -1213,11 +1372,105 @@ pub mod pallet {
 			Ok((*fnft_collection_id, new_fnft_instance_id))
 		}
 
+		#[transactional]
+		fn merge(
+			who: &Self::AccountId,
+			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
+			(other_fnft_collection_id, other_fnft_instance_id): &Self::PositionId,
+		) -> DispatchResult {
+			ensure!(
+				(fnft_collection_id, fnft_instance_id) !=
+					(other_fnft_collection_id, other_fnft_instance_id),
+				Error::<T>::CannotMergePositionWithItself
+			);
+
+			let other_position =
+				Stakes::<T>::take(other_fnft_collection_id, other_fnft_instance_id)
+					.ok_or(Error::<T>::StakeNotFound)?;
+
+			let merged_stake =
+				Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |maybe_position| {
+					let position = maybe_position.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+
+					ensure!(
+						position.reward_pool_id == other_position.reward_pool_id &&
+							position.lock.duration == other_position.lock.duration &&
+							position.lock.unlock_penalty == other_position.lock.unlock_penalty,
+						Error::<T>::IncompatibleLocks
+					);
+
+					let rewards_pool = RewardPools::<T>::get(position.reward_pool_id)
+						.ok_or(Error::<T>::RewardsPoolNotFound)?;
+
+					// A claim is `total_rewards * share / total_shares - reduction`, which is
+					// linear in both `share` and `reduction`; summing the reductions keeps the
+					// unclaimed rewards of both positions claimable from the merged one.
+					for (reward_asset_id, other_reduction) in other_position.reductions {
+						match position.reductions.get_mut(&reward_asset_id) {
+							Some(reduction) => *reduction = reduction.safe_add(&other_reduction)?,
+							None => {
+								position
+									.reductions
+									.try_insert(reward_asset_id, other_reduction)
+									.map_err(|_| Error::<T>::ReductionConfigProblem)?;
+							},
+						}
+					}
+
+					position.stake = position.stake.safe_add(&other_position.stake)?;
+					position.share = position.share.safe_add(&other_position.share)?;
+					// the merged position unlocks when the later of the two would have
+					position.lock.started_at =
+						sp_std::cmp::max(position.lock.started_at, other_position.lock.started_at);
+
+					let fnft_asset_account =
+						T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+					let other_fnft_asset_account = T::FinancialNft::asset_account(
+						other_fnft_collection_id,
+						other_fnft_instance_id,
+					);
+
+					// staked asset
+					Self::merge_lock(
+						position.reward_pool_id,
+						&other_fnft_asset_account,
+						&fnft_asset_account,
+						other_position.stake,
+						position.stake,
+					)?;
+
+					// share asset (x-token)
+					Self::merge_lock(
+						rewards_pool.share_asset_id,
+						&other_fnft_asset_account,
+						&fnft_asset_account,
+						other_position.share,
+						position.share,
+					)?;
+
+					Ok::<_, DispatchError>(position.stake)
+				})?;
+
+			AutoCompound::<T>::remove(other_fnft_collection_id, other_fnft_instance_id);
+			T::FinancialNft::burn(other_fnft_collection_id, other_fnft_instance_id, Some(who))?;
+
+			Self::deposit_event(Event::<T>::PositionsMerged {
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				merged_fnft_instance_id: *other_fnft_instance_id,
+				stake: merged_stake,
+			});
+
+			Ok(())
+		}
+
 		#[transactional]
 		fn claim(
 			who: &Self::AccountId,
 			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
 		) -> DispatchResult {
+			let compound = AutoCompound::<T>::contains_key(fnft_collection_id, fnft_instance_id);
+
 			Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |stake| {
 				let stake = stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
 				RewardPools::<T>::try_mutate(stake.reward_pool_id, |rewards_pool| {
@@ -1231,8 +1484,18 @@ pub mod pallet {
 						stake,
 						who,
 						false, // claims aren't penalized
+						compound,
 					)?;
 
+					if compound {
+						Self::compound_rewards(
+							fnft_collection_id,
+							fnft_instance_id,
+							rewards_pool,
+							stake,
+						)?;
+					}
+
 					Ok::<_, DispatchError>(())
 				})
 			})?;
@@ -1245,6 +1508,12 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		fn pool_of_collection(collection: &Self::CollectionId) -> Option<Self::RewardPoolId> {
+			RewardPools::<T>::iter()
+				.find(|(_, pool)| pool.financial_nft_asset_id == *collection)
+				.map(|(pool_id, _)| pool_id)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1323,6 +1592,28 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Inverse of [`Self::split_lock`]: moves `merged_amount` out of the merged fNFT asset
+		/// account and locks `total_amount` on the account that is kept.
+		pub(crate) fn merge_lock(
+			asset_id: T::AssetId,
+			merged_fnft_asset_account: &T::AccountId,
+			fnft_asset_account: &T::AccountId,
+			merged_amount: T::Balance,
+			total_amount: T::Balance,
+		) -> DispatchResult {
+			T::Assets::remove_lock(T::LockId::get(), asset_id, merged_fnft_asset_account)?;
+
+			T::Assets::transfer(
+				asset_id,
+				merged_fnft_asset_account,
+				fnft_asset_account,
+				merged_amount,
+				false, // not a user account, doesn't need to be kept alive
+			)?;
+
+			T::Assets::set_lock(T::LockId::get(), asset_id, fnft_asset_account, total_amount)
+		}
+
 		/// Transfers the rewards a staker has earned while updating the provided `rewards_pool`.
 		///
 		/// # Params
@@ -1331,6 +1622,8 @@ pub mod pallet {
 		/// * `stake` - Stake position
 		/// * `early_unlock` - If there should be an early unlock penalty
 		/// * `keep_alive` - If the transaction should be kept alive
+		/// * `skip_staked_asset` - If rewards in the staked asset should be left in the pool, to be
+		///   compounded with [`Self::compound_rewards`]
 		// TODO(benluelo): This function does too much - while claim and unstake have similar
 		// functionality, I don't think this is the best abstraction of that. Refactor to have
 		// smaller functions that can then be used in both claim and unstake.
@@ -1342,8 +1635,13 @@ pub mod pallet {
 			stake: &mut StakeOf<T>,
 			owner: &T::AccountId,
 			penalize_for_early_unlock: bool,
+			skip_staked_asset: bool,
 		) -> Result<(), DispatchError> {
 			for (reward_asset_id, reward) in &mut rewards_pool.rewards {
+				if skip_staked_asset && *reward_asset_id == stake.reward_pool_id {
+					continue
+				}
+
 				let claim = claim_of_stake::<T>(
 					stake,
 					&rewards_pool.share_asset_id,
@@ -1408,6 +1706,164 @@ pub mod pallet {
 			T::PalletId::get().into_sub_account_truncating(pool_id)
 		}
 
+		/// Restakes the rewards a position has earned in the pool's staked asset, awarding shares
+		/// for them the same way [`Staking::extend`] does. The lock start is left untouched.
+		///
+		/// Returns the compounded amount.
+		pub(crate) fn compound_rewards(
+			fnft_collection_id: &T::AssetId,
+			fnft_instance_id: &T::FinancialNftInstanceId,
+			rewards_pool: &mut RewardPoolOf<T>,
+			stake: &mut StakeOf<T>,
+		) -> Result<T::Balance, DispatchError> {
+			let staked_asset_id = stake.reward_pool_id;
+			let share_asset_id = rewards_pool.share_asset_id;
+
+			let Some(reward) = rewards_pool.rewards.get_mut(&staked_asset_id) else {
+				// the pool doesn't reward in the staked asset, nothing to compound
+				return Ok(T::Balance::zero())
+			};
+
+			let claim = sp_std::cmp::min(
+				claim_of_stake::<T>(stake, &share_asset_id, reward, &staked_asset_id)?,
+				reward.total_rewards.safe_sub(&reward.claimed_rewards)?,
+			);
+
+			if claim.is_zero() {
+				return Ok(claim)
+			}
+
+			reward.claimed_rewards = reward.claimed_rewards.safe_add(&claim)?;
+			if let Some(inflation) = stake.reductions.get_mut(&staked_asset_id) {
+				*inflation = inflation.safe_add(&claim)?;
+			}
+
+			let fnft_asset_account =
+				T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+
+			T::Assets::transfer(
+				staked_asset_id,
+				&Self::pool_account_id(&staked_asset_id),
+				&fnft_asset_account,
+				claim,
+				false, // pallet account doesn't need to be kept alive
+			)?;
+
+			let reward_multiplier =
+				Self::reward_multiplier_of_stake(rewards_pool, stake.lock.duration);
+			let new_shares = Self::boosted_amount(reward_multiplier, claim)?;
+
+			Self::dilute_rewards(rewards_pool, &mut stake.reductions, new_shares)?;
+
+			T::Assets::mint_into(share_asset_id, &fnft_asset_account, new_shares)?;
+
+			stake.stake = stake.stake.safe_add(&claim)?;
+			stake.share = stake.share.safe_add(&new_shares)?;
+
+			T::Assets::set_lock(
+				T::LockId::get(),
+				staked_asset_id,
+				&fnft_asset_account,
+				stake.stake,
+			)?;
+			T::Assets::set_lock(
+				T::LockId::get(),
+				share_asset_id,
+				&fnft_asset_account,
+				stake.share,
+			)?;
+
+			Self::deposit_event(Event::<T>::RewardsCompounded {
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				amount: claim,
+				shares: new_shares,
+			});
+
+			Ok(claim)
+		}
+
+		/// Compounds a single position. Used by the `on_idle` sweep.
+		#[transactional]
+		pub(crate) fn compound_position(
+			fnft_collection_id: &T::AssetId,
+			fnft_instance_id: &T::FinancialNftInstanceId,
+		) -> Result<T::Balance, DispatchError> {
+			Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |stake| {
+				let stake = stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+				RewardPools::<T>::try_mutate(stake.reward_pool_id, |rewards_pool| {
+					let rewards_pool =
+						rewards_pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
+
+					Self::compound_rewards(
+						fnft_collection_id,
+						fnft_instance_id,
+						rewards_pool,
+						stake,
+					)
+				})
+			})
+		}
+
+		/// Adds the inflation caused by minting `new_shares` to the pool's rewards and to the
+		/// position's `reductions`, so that the new shares only earn rewards from now on.
+		pub(crate) fn dilute_rewards(
+			rewards_pool: &mut RewardPoolOf<T>,
+			reductions: &mut BoundedBTreeMap<T::AssetId, T::Balance, T::MaxRewardConfigsPerPool>,
+			new_shares: T::Balance,
+		) -> DispatchResult {
+			let total_shares = T::Assets::total_issuance(rewards_pool.share_asset_id);
+
+			for (reward_asset_id, reward) in &mut rewards_pool.rewards {
+				let new_inflation = if total_shares.is_zero() {
+					T::Balance::zero()
+				} else {
+					reward.total_rewards.safe_mul(&new_shares)?.safe_div(&total_shares)?
+				};
+
+				reward.total_rewards = reward.total_rewards.safe_add(&new_inflation)?;
+				reward.total_dilution_adjustment =
+					reward.total_dilution_adjustment.safe_add(&new_inflation)?;
+
+				match reductions.get_mut(reward_asset_id) {
+					Some(previous_inflation_and_claims) => {
+						*previous_inflation_and_claims =
+							previous_inflation_and_claims.safe_add(&new_inflation)?;
+					},
+					None => {
+						// REVIEW(benluelo): Is this an invariant we expect? In
+						// ProtocolStaking::transfer_reward assets can be added (and is
+						// currently the only way to add a new reward asset to a pool),
+						// but they are not added to all existing stakes so this
+						// invariant is not upheld
+						defensive!(
+							"stake.reductions should contain the same assets as reward_pool.rewards"
+						);
+					},
+				}
+			}
+
+			Ok(())
+		}
+
+		/// The reward multiplier of an existing stake with the given lock `duration`.
+		pub(crate) fn reward_multiplier_of_stake(
+			rewards_pool: &RewardPoolOf<T>,
+			duration: DurationSeconds,
+		) -> Validated<FixedU64, GeOne> {
+			// SAFETY: The duration preset on an existing stake should be valid in the
+			// pool since it's currently not possible to modify the presets after pool
+			// creation.
+			rewards_pool
+				.lock
+				.duration_presets
+				.get(&duration)
+				.copied()
+				.defensive_unwrap_or_else(|| {
+					FixedU64::one().try_into_validated().expect("1 is >= 1")
+				})
+		}
+
 		// TODO(benluelo): Rename to 'reward_multiplier_of' and return a Result<&_, Error<T>>
 		// (remove the clone as well)
 		// REVIEW(benluelo): Does this function provide anything meaningful?
@@ -1418,6 +1874,26 @@ pub mod pallet {
 			rewards_pool.lock.duration_presets.get(&duration_preset).cloned()
 		}
 
+		/// The stake of a position scaled by its remaining lock time relative to `max_duration`.
+		pub(crate) fn voting_power_of_stake(
+			stake: &StakeOf<T>,
+			max_duration: DurationSeconds,
+			now_seconds: u64,
+		) -> T::Balance {
+			if max_duration.is_zero() {
+				return T::Balance::zero()
+			}
+
+			let remaining = stake
+				.lock
+				.started_at
+				.saturating_add(stake.lock.duration)
+				.saturating_sub(now_seconds)
+				.min(max_duration);
+
+			Perbill::from_rational(remaining, max_duration).mul_floor(stake.stake)
+		}
+
 		pub(crate) fn boosted_amount(
 			reward_multiplier: Validated<FixedU64, GeOne>,
 			amount: T::Balance,
@@ -1512,6 +1988,8 @@ pub mod pallet {
 								period: RewardRatePeriod::PerSecond,
 							},
 							last_updated_timestamp: T::UnixTime::now().as_secs(),
+							last_updated_block: frame_system::Pallet::<T>::block_number()
+								.saturated_into(),
 						};
 						reward_pool
 							.rewards
@@ -1532,6 +2010,32 @@ pub mod pallet {
 			})
 		}
 	}
+
+	impl<T: Config> StakingVotingPower for Pallet<T> {
+		type AccountId = T::AccountId;
+		type RewardPoolId = T::AssetId;
+		type Balance = T::Balance;
+
+		fn voting_power(pool_id: &Self::RewardPoolId, who: &Self::AccountId) -> Self::Balance {
+			let Some(rewards_pool) = RewardPools::<T>::get(pool_id) else {
+				return T::Balance::zero()
+			};
+
+			let Some(max_duration) = rewards_pool.lock.duration_presets.keys().max().copied() else {
+				return T::Balance::zero()
+			};
+
+			let now_seconds = T::UnixTime::now().as_secs();
+			let fnft_collection_id = rewards_pool.financial_nft_asset_id;
+
+			T::FinancialNft::owned_in_collection(&fnft_collection_id, who)
+				.filter_map(|fnft_instance_id| {
+					Stakes::<T>::get(fnft_collection_id, fnft_instance_id)
+				})
+				.map(|stake| Self::voting_power_of_stake(&stake, max_duration, now_seconds))
+				.fold(T::Balance::zero(), |total, power| total.saturating_add(power))
+		}
+	}
 }
 /// Accumulates the rewards in a pool, if the pot isn't empty. Emits the relevant events
 /// after accumulation. See [`accumulate_reward`] for more information about how the
@@ -1541,6 +2045,7 @@ pub(crate) fn reward_accumulation_hook_reward_update_calculation<T: Config>(
 	reward_asset_id: T::AssetId,
 	reward: &mut Reward<T::Balance>,
 	now_seconds: u64,
+	now_block: u64,
 ) {
 	let pool_account = Pallet::<T>::pool_account_id(&pool_id);
 
@@ -1557,7 +2062,7 @@ pub(crate) fn reward_accumulation_hook_reward_update_calculation<T: Config>(
 
 	log::info!("accumulating rewards for pool {pool_id:?}, asset {reward_asset_id:?}");
 
-	match accumulate_reward::<T>(reward_asset_id, reward, &pool_account, now_seconds) {
+	match accumulate_reward::<T>(reward_asset_id, reward, &pool_account, now_seconds, now_block) {
 		RewardAccumulationCalculationOutcome::Success => {
 			log::info!("accumulation successful");
 		},
@@ -1616,6 +2121,42 @@ pub(crate) fn accumulate_rewards_hook<T: Config>() -> Weight {
 	total_weight
 }
 
+/// Compounds auto-compounding positions for as long as `remaining_weight` allows, resuming from
+/// where the previous sweep stopped. Returns the weight used.
+pub(crate) fn auto_compound_hook<T: Config>(remaining_weight: Weight) -> Weight {
+	let weight_per_position = T::WeightInfo::compound(T::MaxRewardConfigsPerPool::get());
+	// reading and writing the cursor
+	let mut total_weight = T::DbWeight::get().reads(1) + T::DbWeight::get().writes(1);
+
+	if (total_weight + weight_per_position).ref_time() > remaining_weight.ref_time() {
+		return Weight::zero()
+	}
+
+	let mut positions = match AutoCompoundCursor::<T>::take() {
+		Some(cursor) => AutoCompound::<T>::iter_keys_from(cursor),
+		None => AutoCompound::<T>::iter_keys(),
+	};
+
+	while (total_weight + weight_per_position).ref_time() <= remaining_weight.ref_time() {
+		let Some((fnft_collection_id, fnft_instance_id)) = positions.next() else {
+			// the sweep is complete, the next one starts from the beginning
+			return total_weight
+		};
+
+		total_weight += weight_per_position;
+
+		if let Err(error) = Pallet::<T>::compound_position(&fnft_collection_id, &fnft_instance_id) {
+			log::warn!(
+				"failed to compound position {fnft_collection_id:?}/{fnft_instance_id:?}: {error:?}"
+			);
+		}
+	}
+
+	AutoCompoundCursor::<T>::put(positions.last_raw_key().to_vec());
+
+	total_weight
+}
+
 /// Accumulates all of the rewards in the provided pool, updating them in-place. Returns the weight
 /// of the calculations.
 #[must_use = "the calculated weight does nothing on it's own"]
@@ -1625,18 +2166,32 @@ fn accumulate_pool_rewards<T: Config>(
 	current_block: T::BlockNumber,
 	now_seconds: u64,
 ) -> Weight {
+	let now_block = current_block.saturated_into();
+
 	// If reward pool has not started, do not accumulate rewards or adjust weight
 	Weight::from_ref_time(match reward_pool.start_block.cmp(&current_block) {
-		// start block < current -> accumulate normally
+		// start block < current -> apply any scheduled reward rate changes and accumulate normally
 		Ordering::Less =>
 			(&mut reward_pool.rewards)
 				.into_iter()
 				.fold(0_u64, |mut acc, (asset_id, reward)| {
+					acc = acc.defensive_saturating_add(
+						apply_reward_rate_schedule::<T>(
+							pool_id,
+							*asset_id,
+							reward,
+							now_seconds,
+							now_block,
+						)
+						.ref_time(),
+					);
+
 					reward_accumulation_hook_reward_update_calculation::<T>(
 						pool_id,
 						*asset_id,
 						reward,
 						now_seconds,
+						now_block,
 					);
 
 					acc = acc.defensive_saturating_add(
@@ -1647,12 +2202,13 @@ fn accumulate_pool_rewards<T: Config>(
 					acc
 				}),
 		// start block == current -> accumulation starts now, but the effects won't be seen until
-		// the next block; set all of the reward's last updated timestamp to `now` so that reward
-		// accumulation starts from this point in time, not when the pool was created. Also notify
-		// that the pool has started.
+		// the next block; set all of the reward's last updated timestamp and block to `now` so that
+		// reward accumulation starts from this point in time, not when the pool was created. Also
+		// notify that the pool has started.
 		Ordering::Equal => {
 			for (_asset_id, reward) in &mut reward_pool.rewards {
 				reward.last_updated_timestamp = now_seconds;
+				reward.last_updated_block = now_block;
 			}
 
 			Pallet::<T>::deposit_event(Event::RewardPoolStarted { pool_id });
@@ -1664,6 +2220,81 @@ fn accumulate_pool_rewards<T: Config>(
 	})
 }
 
+/// Applies the scheduled reward rate changes of a reward that are due, accumulating the rewards
+/// at the previous rate up until each change first. Returns the weight of the calculations.
+#[must_use = "the calculated weight does nothing on it's own"]
+fn apply_reward_rate_schedule<T: Config>(
+	pool_id: T::AssetId,
+	reward_asset_id: T::AssetId,
+	reward: &mut Reward<T::Balance>,
+	now_seconds: u64,
+	now_block: u64,
+) -> Weight {
+	let mut total_weight = T::DbWeight::get().reads(1);
+
+	let Some(mut schedule) = RewardRateSchedules::<T>::get(pool_id, reward_asset_id) else {
+		return total_weight
+	};
+
+	let mut changed = false;
+	let mut finished = false;
+
+	while let Some(change_at) = schedule.next_change().filter(|at| *at <= now_seconds) {
+		reward_accumulation_hook_reward_update_calculation::<T>(
+			pool_id,
+			reward_asset_id,
+			reward,
+			cmp::max(change_at, reward.last_updated_timestamp),
+			now_block,
+		);
+		total_weight += T::WeightInfo::reward_accumulation_hook_reward_update_calculation();
+
+		let mut reward_rate = reward.reward_rate.clone();
+		finished = !schedule.apply_next(&mut reward_rate);
+		set_reward_rate::<T>(reward, reward_rate.clone(), now_seconds, now_block);
+		changed = true;
+
+		Pallet::<T>::deposit_event(Event::<T>::RewardRateChanged {
+			pool_id,
+			asset_id: reward_asset_id,
+			reward_rate,
+		});
+
+		if finished {
+			break
+		}
+	}
+
+	if finished {
+		RewardRateSchedules::<T>::remove(pool_id, reward_asset_id);
+	} else if changed {
+		RewardRateSchedules::<T>::insert(pool_id, reward_asset_id, schedule);
+	}
+
+	if changed {
+		total_weight += T::DbWeight::get().writes(1);
+	}
+
+	total_weight
+}
+
+/// Sets the reward rate of a reward whose rewards have already been accumulated. If the reward
+/// rate period changes, accumulation restarts from `now` since the last updated timestamp or block
+/// of the new period may not have been kept up to date.
+fn set_reward_rate<T: Config>(
+	reward: &mut Reward<T::Balance>,
+	reward_rate: RewardRate<T::Balance>,
+	now_seconds: u64,
+	now_block: u64,
+) {
+	if reward.reward_rate.period != reward_rate.period {
+		reward.last_updated_timestamp = now_seconds;
+		reward.last_updated_block = now_block;
+	}
+
+	reward.reward_rate = reward_rate;
+}
+
 fn add_to_rewards_pot<T: Config>(
 	who: T::AccountId,
 	pool_id: T::AssetId,
@@ -1703,6 +2334,7 @@ fn add_to_rewards_pot<T: Config>(
 					.defensive_unwrap_or_else(|| u128::MAX.into())
 		{
 			reward.last_updated_timestamp = T::UnixTime::now().as_secs();
+			reward.last_updated_block = frame_system::Pallet::<T>::block_number().saturated_into();
 			RewardsPotIsEmpty::<T>::remove(pool_id, asset_id);
 
 			Pallet::<T>::deposit_event(Event::<T>::RewardPoolResumed { pool_id, asset_id });
@@ -1724,6 +2356,7 @@ fn update_rewards_pool<T: Config>(
 		let pool = pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
 
 		let now_seconds = T::UnixTime::now().as_secs();
+		let now_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
 		for (asset_id, update) in reward_updates {
 			let reward = pool.rewards.get_mut(&asset_id).ok_or(Error::<T>::RewardAssetNotFound)?;
@@ -1733,9 +2366,10 @@ fn update_rewards_pool<T: Config>(
 				asset_id,
 				reward,
 				now_seconds,
+				now_block,
 			);
 
-			reward.reward_rate = update.reward_rate;
+			set_reward_rate::<T>(reward, update.reward_rate, now_seconds, now_block);
 		}
 
 		Pallet::<T>::deposit_event(Event::<T>::RewardPoolUpdated { pool_id });
@@ -1758,6 +2392,7 @@ pub(crate) fn accumulate_reward<T: Config>(
 	reward: &mut Reward<T::Balance>,
 	pool_account: &T::AccountId,
 	now_seconds: u64,
+	now_block: u64,
 ) -> RewardAccumulationCalculationOutcome {
 	// TODO(benluelo): Refactor the calculations here into a separate function to make it easier to
 	// test with proptest/ kani. The nonzero checks can be left outside of said function.
@@ -1768,18 +2403,25 @@ pub(crate) fn accumulate_reward<T: Config>(
 			return RewardAccumulationCalculationOutcome::Success
 		};
 
+	// periods measured in blocks are counted on the block clock instead, as if each block were one
+	// second long
+	let (last_updated, now, reward_rate_period_seconds) = match reward.reward_rate.period.as_secs()
+	{
+		Some(period_seconds) => (reward.last_updated_timestamp, now_seconds, period_seconds),
+		None =>
+			(reward.last_updated_block, now_block, NonZeroU64::new(1).expect("1 is non-zero; qed;")),
+	};
+
 	// REVIEW(benluelo): Should this be a user-facing error? Or would defensively saturating at zero
 	// for elapsed_time be better? This should never be hit, and if it is then it's either a logic
 	// error or the chain state is wonky (in which case there are probably bigger issues than this
 	// hook!)
-	let Some(elapsed_time) = now_seconds
-		.checked_sub(reward.last_updated_timestamp)
+	let Some(elapsed_time) = now
+		.checked_sub(last_updated)
 		else {
 			return RewardAccumulationCalculationOutcome::BackToTheFuture
 		};
 
-	let reward_rate_period_seconds = reward.reward_rate.period.as_secs();
-
 	//          elapsed_time
 	// = --------------------------
 	//   reward_rate_period_seconds
@@ -1845,7 +2487,7 @@ pub(crate) fn accumulate_reward<T: Config>(
 	// `u64::MAX` in seconds is roughly 584.9 billion years in the future, so saturating at that
 	// should be ok; we should never reach a case where the timestamp overflows that. Use defensive
 	// anyways so we get notified if this is hit somehow due to some sort of logic error.
-	let last_updated_timestamp = reward.last_updated_timestamp.defensive_saturating_add(
+	let last_updated = last_updated.defensive_saturating_add(
 		reward_rate_period_seconds.get().defensive_saturating_mul(
 			releasable_periods_surpassed.get().try_into().defensive_unwrap_or(u64::MAX),
 		),
@@ -1860,7 +2502,10 @@ pub(crate) fn accumulate_reward<T: Config>(
 	.expect("funds should be available to release; see above for proof; qed;");
 
 	reward.total_rewards = new_total_rewards.into();
-	reward.last_updated_timestamp = last_updated_timestamp;
+	match reward.reward_rate.period {
+		RewardRatePeriod::PerBlock => reward.last_updated_block = last_updated,
+		_ => reward.last_updated_timestamp = last_updated,
+	}
 
 	RewardAccumulationCalculationOutcome::Success
 }
@@ -0,0 +1,104 @@
+//! Storage migrations of the staking rewards pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds a `last_updated_block` to the rewards of stored pools, see
+	//! [`RewardRatePeriod::PerBlock`].
+
+	use super::*;
+	use composable_traits::staking::{lock::LockConfig, RewardPool, RewardRatePeriod};
+	use frame_support::traits::GetStorageVersion;
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Reward as stored before the introduction of [`RewardRatePeriod::PerBlock`].
+	#[derive(Decode)]
+	struct OldReward<Balance> {
+		total_rewards: Balance,
+		claimed_rewards: Balance,
+		total_dilution_adjustment: Balance,
+		reward_rate: RewardRate<Balance>,
+		last_updated_timestamp: u64,
+	}
+
+	/// Reward pool as stored before the introduction of [`RewardRatePeriod::PerBlock`].
+	#[derive(Decode)]
+	struct OldRewardPool<
+		AccountId,
+		AssetId: Ord,
+		Balance,
+		BlockNumber,
+		MaxDurationPresets,
+		MaxRewards,
+	> where
+		MaxDurationPresets: Get<u32>,
+		MaxRewards: Get<u32>,
+	{
+		owner: AccountId,
+		rewards: BoundedBTreeMap<AssetId, OldReward<Balance>, MaxRewards>,
+		claimed_shares: Balance,
+		start_block: BlockNumber,
+		end_block: BlockNumber,
+		lock: LockConfig<MaxDurationPresets>,
+		share_asset_id: AssetId,
+		financial_nft_asset_id: AssetId,
+		minimum_staking_amount: Balance,
+	}
+
+	type OldRewardPoolOf<T> = OldRewardPool<
+		AccountIdOf<T>,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::MaxStakingDurationPresets,
+		<T as Config>::MaxRewardConfigsPerPool,
+	>;
+
+	/// Stored rewards were only ever accumulated per second, so the current block is a sound
+	/// starting point for `last_updated_block`.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let now_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+		let mut translated = 0_u64;
+		RewardPools::<T>::translate::<OldRewardPoolOf<T>, _>(|_, pool| {
+			translated += 1;
+			// the bound of the map is unchanged, so the conversion cannot fail
+			let rewards = pool
+				.rewards
+				.into_iter()
+				.map(|(asset_id, reward)| {
+					(
+						asset_id,
+						Reward {
+							total_rewards: reward.total_rewards,
+							claimed_rewards: reward.claimed_rewards,
+							total_dilution_adjustment: reward.total_dilution_adjustment,
+							reward_rate: reward.reward_rate,
+							last_updated_timestamp: reward.last_updated_timestamp,
+							last_updated_block: now_block,
+						},
+					)
+				})
+				.collect::<BTreeMap<_, _>>()
+				.try_into()
+				.ok()?;
+			Some(RewardPool {
+				owner: pool.owner,
+				rewards,
+				claimed_shares: pool.claimed_shares,
+				start_block: pool.start_block,
+				end_block: pool.end_block,
+				lock: pool.lock,
+				share_asset_id: pool.share_asset_id,
+				financial_nft_asset_id: pool.financial_nft_asset_id,
+				minimum_staking_amount: pool.minimum_staking_amount,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -244,6 +244,7 @@ parameter_types! {
 	pub const StakingRewardsPalletId : PalletId = PalletId(*b"stk_rwrd");
 	pub const MaxStakingDurationPresets : u32 = 10;
 	pub const MaxRewardConfigsPerPool : u32 = 10;
+	pub const MaxRewardRateChanges : u32 = 10;
 	pub const PicaAssetId : CurrencyId = 1;
 	pub const PbloAssetId : CurrencyId = 2;
 	pub const XPicaAssetId: CurrencyId = 101;
@@ -268,6 +269,7 @@ impl crate::Config for Test {
 	type PalletId = StakingRewardsPalletId;
 	type MaxStakingDurationPresets = MaxStakingDurationPresets;
 	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxRewardRateChanges = MaxRewardRateChanges;
 	type RewardPoolCreationOrigin = EnsureRoot<Self::AccountId>;
 	type RewardPoolUpdateOrigin = EnsureRoot<Self::AccountId>;
 	type PicaAssetId = PicaAssetId;
@@ -51,8 +51,12 @@ use self::prelude::init_logger;
 
 pub(crate) mod prelude;
 
+mod test_auto_compound;
+mod test_merge;
 mod test_reward_accumulation_hook;
+mod test_reward_rate_schedule;
 mod test_update_reward_pools;
+mod test_voting_power;
 
 #[test]
 fn test_create_reward_pool() {
@@ -608,6 +612,50 @@ fn stake_in_case_of_zero_inflation_should_work() {
 	});
 }
 
+#[test]
+fn value_of_financial_nft_is_looked_up_through_its_stake() {
+	new_test_ext().execute_with(|| {
+		// the collection id differs from the pool id
+		let fnft_collection_id = STAKING_FNFT_COLLECTION_ID + 4_242;
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+		assert_ok!(StakingRewards::create_reward_pool(
+			RuntimeOrigin::root(),
+			RewardRateBasedIncentive {
+				owner: ALICE,
+				asset_id: PICA::ID,
+				start_block: 2,
+				end_block: 5,
+				reward_configs: default_reward_config(),
+				lock: default_lock_config(),
+				share_asset_id: XPICA::ID,
+				financial_nft_asset_id: fnft_collection_id,
+				minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+			},
+		));
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		mint_assets([ALICE], [PICA::ID], 100_500 * 2);
+		assert_ok!(StakingRewards::stake(
+			RuntimeOrigin::signed(ALICE),
+			PICA::ID,
+			100_500,
+			ONE_HOUR
+		));
+		let fnft_instance_id = 0;
+
+		let share = Stakes::<Test>::get(fnft_collection_id, fnft_instance_id)
+			.expect("stake expected")
+			.share;
+		assert_eq!(
+			<StakingRewards as FinancialNftProtocol>::value_of(
+				&fnft_collection_id,
+				&fnft_instance_id
+			),
+			Ok(vec![(XPICA::ID, share)])
+		);
+	});
+}
+
 // this is almost the exact same as the above function
 // spot the difference!
 // maybe do a proptest with different inflation rates?
@@ -0,0 +1,204 @@
+use composable_tests_helpers::test::{
+	block::process_and_progress_blocks,
+	currency::{PICA, USDT, XPICA},
+	helper::RuntimeTrait,
+};
+use composable_traits::{
+	fnft::FinancialNft as FinancialNftT,
+	staking::{RewardConfig, RewardPoolConfiguration::RewardRateBasedIncentive, RewardRate},
+	time::ONE_HOUR,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks, TryCollect},
+	weights::Weight,
+};
+
+use crate::{
+	runtime::{FinancialNft, RuntimeOrigin, StakingRewards, System, Test, ALICE, BOB, CHARLIE},
+	test::{
+		balance, default_lock_config, mint_assets, new_test_ext,
+		prelude::{init_logger, MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{
+		add_to_rewards_pot_and_assert, create_rewards_pool_and_assert, stake_and_assert,
+	},
+	weights::WeightInfo,
+	AutoCompound, Stakes,
+};
+
+/// Creates a PICA pool rewarding both PICA and USDT, funds both pots and returns once the pool
+/// has started.
+fn create_pool_rewarding_staked_asset() {
+	create_rewards_pool_and_assert::<Test>(RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: PICA::ID,
+		start_block: 2,
+		end_block: 100_000,
+		reward_configs: [
+			(PICA::ID, RewardConfig { reward_rate: RewardRate::per_second(PICA::units(1)) }),
+			(USDT::ID, RewardConfig { reward_rate: RewardRate::per_second(USDT::units(1)) }),
+		]
+		.into_iter()
+		.try_collect()
+		.unwrap(),
+		lock: default_lock_config(),
+		share_asset_id: XPICA::ID,
+		financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+		minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+	});
+
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+
+	mint_assets([CHARLIE], [PICA::ID], PICA::units(10_000));
+	mint_assets([CHARLIE], [USDT::ID], USDT::units(10_000));
+	add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, PICA::ID, PICA::units(10_000), false);
+	add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, USDT::units(10_000), false);
+}
+
+#[test]
+fn only_owner_can_set_auto_compound() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool_rewarding_staked_asset();
+
+		mint_assets([ALICE], [PICA::ID], PICA::units(2_000));
+		let instance_id = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+
+		assert_noop!(
+			StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				STAKING_FNFT_COLLECTION_ID,
+				instance_id,
+				true
+			),
+			crate::Error::<Test>::OnlyStakeOwnerCanInteractWithStake
+		);
+
+		Test::assert_extrinsic_event(
+			StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				instance_id,
+				true,
+			),
+			crate::Event::AutoCompoundSet {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id: instance_id,
+				enabled: true,
+			},
+		);
+		assert!(AutoCompound::<Test>::contains_key(STAKING_FNFT_COLLECTION_ID, instance_id));
+
+		// the flag is cleaned up with the position
+		assert_ok!(StakingRewards::unstake(
+			RuntimeOrigin::signed(ALICE),
+			STAKING_FNFT_COLLECTION_ID,
+			instance_id
+		));
+		assert!(!AutoCompound::<Test>::contains_key(STAKING_FNFT_COLLECTION_ID, instance_id));
+	});
+}
+
+#[test]
+fn claim_compounds_staked_asset_rewards() {
+	new_test_ext().execute_with(|| {
+		init_logger();
+		System::set_block_number(1);
+		create_pool_rewarding_staked_asset();
+
+		mint_assets([ALICE], [PICA::ID], PICA::units(2_000));
+		let instance_id = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+		assert_ok!(StakingRewards::set_auto_compound(
+			RuntimeOrigin::signed(ALICE),
+			STAKING_FNFT_COLLECTION_ID,
+			instance_id,
+			true
+		));
+
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+
+		let stake_before = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, instance_id).unwrap();
+		let pica_before = balance(PICA::ID, &ALICE);
+
+		assert_ok!(StakingRewards::claim(
+			RuntimeOrigin::signed(ALICE),
+			STAKING_FNFT_COLLECTION_ID,
+			instance_id
+		));
+
+		let stake = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, instance_id).unwrap();
+		let compounded = stake.stake - stake_before.stake;
+
+		assert!(compounded > 0);
+		Test::assert_event(crate::Event::RewardsCompounded {
+			fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id: instance_id,
+			amount: compounded,
+			shares: stake.share - stake_before.share,
+		});
+
+		// PICA rewards went into the stake, USDT rewards were paid out
+		assert_eq!(balance(PICA::ID, &ALICE), pica_before);
+		assert!(balance(USDT::ID, &ALICE) > 0);
+
+		let fnft_asset_account =
+			FinancialNft::asset_account(&STAKING_FNFT_COLLECTION_ID, &instance_id);
+		assert_eq!(balance(PICA::ID, &fnft_asset_account), stake.stake);
+		assert_eq!(balance(XPICA::ID, &fnft_asset_account), stake.share);
+
+		// nothing is left to compound until more rewards accumulate
+		assert_ok!(StakingRewards::claim(
+			RuntimeOrigin::signed(ALICE),
+			STAKING_FNFT_COLLECTION_ID,
+			instance_id
+		));
+		assert_eq!(Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, instance_id).unwrap(), stake);
+	});
+}
+
+#[test]
+fn on_idle_sweep_compounds_opted_in_positions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool_rewarding_staked_asset();
+
+		mint_assets([ALICE, BOB], [PICA::ID], PICA::units(3_000));
+		let first = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+		let second = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+		let not_compounding = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(1_000), ONE_HOUR);
+
+		for instance_id in [first, second] {
+			assert_ok!(StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				instance_id,
+				true
+			));
+		}
+
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+
+		let stake_of = |instance_id| {
+			Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, instance_id).unwrap().stake
+		};
+		let compounded = |instance_id| stake_of(instance_id) > PICA::units(1_000);
+
+		// enough weight for a single position
+		let weight = <Test as crate::Config>::WeightInfo::compound(
+			<Test as crate::Config>::MaxRewardConfigsPerPool::get(),
+		);
+		StakingRewards::on_idle(System::block_number(), weight);
+		assert_eq!([first, second].into_iter().filter(|id| compounded(*id)).count(), 1);
+
+		// the next sweep resumes with the other position
+		StakingRewards::on_idle(System::block_number(), weight);
+		assert!(compounded(first) && compounded(second));
+
+		assert_eq!(stake_of(not_compounding), PICA::units(1_000));
+		assert_eq!(balance(USDT::ID, &ALICE), 0);
+
+		// no weight, no work
+		assert_eq!(StakingRewards::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+	});
+}
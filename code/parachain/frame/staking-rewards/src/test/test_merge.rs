@@ -0,0 +1,167 @@
+use composable_tests_helpers::test::{
+	block::process_and_progress_blocks,
+	currency::{PICA, USDT, XPICA},
+	helper::RuntimeTrait,
+};
+use composable_traits::{
+	fnft::FinancialNft as FinancialNftT,
+	staking::{RewardConfig, RewardPoolConfiguration::RewardRateBasedIncentive, RewardRate},
+	time::{ONE_HOUR, ONE_MINUTE},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{tokens::nonfungibles::Inspect as NonFungiblesInspect, TryCollect},
+};
+
+use crate::{
+	claim_of_stake,
+	runtime::{FinancialNft, RuntimeOrigin, StakingRewards, System, Test, ALICE, BOB},
+	test::{
+		balance, default_lock_config, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{
+		add_to_rewards_pot_and_assert, create_rewards_pool_and_assert, stake_and_assert,
+	},
+	FinancialNftInstanceIdOf, RewardPools, Stakes,
+};
+
+fn create_pool() {
+	create_rewards_pool_and_assert::<Test>(RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: PICA::ID,
+		start_block: 2,
+		end_block: 100_000,
+		reward_configs: [(
+			USDT::ID,
+			RewardConfig { reward_rate: RewardRate::per_second(USDT::units(1)) },
+		)]
+		.into_iter()
+		.try_collect()
+		.unwrap(),
+		lock: default_lock_config(),
+		share_asset_id: XPICA::ID,
+		financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+		minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+	});
+
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+
+	mint_assets([BOB], [USDT::ID], USDT::units(1_000));
+	add_to_rewards_pot_and_assert::<Test>(BOB, PICA::ID, USDT::ID, USDT::units(1_000), false);
+}
+
+fn claimable(fnft_instance_id: FinancialNftInstanceIdOf<Test>) -> u128 {
+	let stake = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id).unwrap();
+	let pool = RewardPools::<Test>::get(PICA::ID).unwrap();
+	claim_of_stake::<Test>(&stake, &XPICA::ID, pool.rewards.get(&USDT::ID).unwrap(), &USDT::ID)
+		.unwrap()
+}
+
+#[test]
+fn merged_position_keeps_stake_and_unclaimed_rewards() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool();
+
+		mint_assets([ALICE], [PICA::ID], PICA::units(2_000));
+		let kept = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+		let merged = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(500), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+
+		let kept_before = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, kept).unwrap();
+		let merged_before = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, merged).unwrap();
+		let claimable_before = claimable(kept) + claimable(merged);
+
+		Test::assert_extrinsic_event(
+			StakingRewards::merge(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				kept,
+				merged,
+			),
+			crate::Event::PositionsMerged {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id: kept,
+				merged_fnft_instance_id: merged,
+				stake: PICA::units(1_500),
+			},
+		);
+
+		assert!(Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, merged).is_none());
+		assert_eq!(FinancialNft::owner(&STAKING_FNFT_COLLECTION_ID, &merged), None);
+
+		let position = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, kept).unwrap();
+		assert_eq!(position.stake, kept_before.stake + merged_before.stake);
+		assert_eq!(position.share, kept_before.share + merged_before.share);
+		assert_eq!(
+			position.reductions.get(&USDT::ID),
+			Some(
+				&(kept_before.reductions.get(&USDT::ID).unwrap() +
+					merged_before.reductions.get(&USDT::ID).unwrap())
+			)
+		);
+		assert_eq!(position.lock.started_at, merged_before.lock.started_at);
+
+		let fnft_asset_account = FinancialNft::asset_account(&STAKING_FNFT_COLLECTION_ID, &kept);
+		assert_eq!(balance(PICA::ID, &fnft_asset_account), position.stake);
+		assert_eq!(balance(XPICA::ID, &fnft_asset_account), position.share);
+		let merged_asset_account =
+			FinancialNft::asset_account(&STAKING_FNFT_COLLECTION_ID, &merged);
+		assert_eq!(balance(PICA::ID, &merged_asset_account), 0);
+		assert_eq!(balance(XPICA::ID, &merged_asset_account), 0);
+
+		// rounding can only work in the staker's favour by a single unit
+		let claimable_after = claimable(kept);
+		assert!((claimable_before..=claimable_before + 1).contains(&claimable_after));
+
+		assert_ok!(StakingRewards::claim(
+			RuntimeOrigin::signed(ALICE),
+			STAKING_FNFT_COLLECTION_ID,
+			kept
+		));
+		assert_eq!(balance(USDT::ID, &ALICE), claimable_after);
+	});
+}
+
+#[test]
+fn merge_requires_compatible_positions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool();
+
+		mint_assets([ALICE, BOB], [PICA::ID], PICA::units(2_000));
+		let hour = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(500), ONE_HOUR);
+		let minute = stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(500), ONE_MINUTE);
+		let bobs = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(500), ONE_HOUR);
+
+		assert_noop!(
+			StakingRewards::merge(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				hour,
+				hour
+			),
+			crate::Error::<Test>::CannotMergePositionWithItself
+		);
+		assert_noop!(
+			StakingRewards::merge(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				hour,
+				minute
+			),
+			crate::Error::<Test>::IncompatibleLocks
+		);
+		assert_noop!(
+			StakingRewards::merge(
+				RuntimeOrigin::signed(ALICE),
+				STAKING_FNFT_COLLECTION_ID,
+				hour,
+				bobs
+			),
+			crate::Error::<Test>::OnlyStakeOwnerCanInteractWithStake
+		);
+	});
+}
@@ -84,6 +84,7 @@ fn test_reward_update_calculation() {
 				PICA::ID,
 				&mut reward,
 				now.safe_add(&block_seconds(block_number).try_into().unwrap()).unwrap(),
+				block_number,
 			);
 
 			println!("blocks surpassed: {}", block_number);
@@ -113,6 +114,7 @@ fn test_reward_update_calculation() {
 			PICA::ID,
 			&mut reward,
 			now.safe_add(&block_seconds(current_block_number).try_into().unwrap()).unwrap(),
+			current_block_number,
 		);
 
 		// should not report an error since the pot is not empty
@@ -0,0 +1,230 @@
+use core::num::NonZeroU64;
+
+use composable_tests_helpers::test::{
+	block::process_and_progress_blocks,
+	currency::{PICA, USDT, XPICA},
+	helper::RuntimeTrait,
+};
+use composable_traits::staking::{
+	RewardConfig, RewardPoolConfiguration::RewardRateBasedIncentive, RewardRate, RewardRateSchedule,
+};
+use frame_support::{assert_noop, assert_ok, traits::TryCollect};
+use sp_runtime::DispatchError;
+
+use crate::{
+	runtime::{MaxRewardRateChanges, RuntimeOrigin, StakingRewards, System, Test, ALICE, BOB},
+	test::{
+		default_lock_config, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{add_to_rewards_pot_and_assert, create_rewards_pool_and_assert},
+	RewardPools, RewardRateSchedules,
+};
+
+/// Creates a PICA pool rewarding USDT at `reward_rate`, starting at block 2 (12 seconds).
+fn create_pool(reward_rate: RewardRate<u128>) {
+	create_rewards_pool_and_assert::<Test>(RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: PICA::ID,
+		start_block: 2,
+		end_block: 100_000,
+		reward_configs: [(USDT::ID, RewardConfig { reward_rate })]
+			.into_iter()
+			.try_collect()
+			.unwrap(),
+		lock: default_lock_config(),
+		share_asset_id: XPICA::ID,
+		financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+		minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+	});
+
+	mint_assets([BOB], [USDT::ID], USDT::units(1_000));
+	add_to_rewards_pot_and_assert::<Test>(BOB, PICA::ID, USDT::ID, USDT::units(1_000), false);
+}
+
+fn usdt_reward() -> (u128, RewardRate<u128>) {
+	let reward = RewardPools::<Test>::get(PICA::ID)
+		.unwrap()
+		.rewards
+		.get(&USDT::ID)
+		.unwrap()
+		.clone();
+	(reward.total_rewards, reward.reward_rate)
+}
+
+fn changes(
+	changes: impl IntoIterator<Item = (u64, RewardRate<u128>)>,
+) -> RewardRateSchedule<u128, MaxRewardRateChanges> {
+	RewardRateSchedule::Changes(changes.into_iter().try_collect().unwrap())
+}
+
+#[test]
+fn per_block_rewards_accumulate_every_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool(RewardRate::per_block(USDT::units(1)));
+
+		// the pool starts at block 2 and accumulates once in each of the next 5 blocks
+		process_and_progress_blocks::<StakingRewards, Test>(6);
+		assert_eq!(usdt_reward().0, USDT::units(5));
+	});
+}
+
+#[test]
+fn per_epoch_rewards_accumulate_every_epoch() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool(RewardRate::per_epoch(NonZeroU64::new(30).unwrap(), USDT::units(1)));
+
+		// 11 blocks of 6 seconds after the start, two full epochs have passed
+		process_and_progress_blocks::<StakingRewards, Test>(12);
+		assert_eq!(usdt_reward().0, USDT::units(2));
+	});
+}
+
+#[test]
+fn halving_schedule_halves_the_reward_rate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool(RewardRate::per_second(USDT::units(2)));
+
+		// halve 30 seconds after the pool starts, then every 30 seconds
+		Test::assert_extrinsic_event(
+			StakingRewards::set_reward_rate_schedule(
+				RuntimeOrigin::root(),
+				PICA::ID,
+				USDT::ID,
+				Some(RewardRateSchedule::Halving {
+					next: 42,
+					interval: NonZeroU64::new(30).unwrap(),
+				}),
+			),
+			crate::Event::RewardRateScheduleSet {
+				pool_id: PICA::ID,
+				asset_id: USDT::ID,
+				enabled: true,
+			},
+		);
+
+		process_and_progress_blocks::<StakingRewards, Test>(6);
+		Test::assert_event(crate::Event::RewardRateChanged {
+			pool_id: PICA::ID,
+			asset_id: USDT::ID,
+			reward_rate: RewardRate::per_second(USDT::units(1)),
+		});
+		assert_eq!(usdt_reward(), (USDT::units(60), RewardRate::per_second(USDT::units(1))));
+
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+		assert_eq!(usdt_reward(), (USDT::units(90), RewardRate::per_second(USDT::units(1) / 2)));
+		assert!(RewardRateSchedules::<Test>::contains_key(PICA::ID, USDT::ID));
+	});
+}
+
+#[test]
+fn scheduled_changes_are_applied_in_order() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool(RewardRate::per_second(USDT::units(1)));
+
+		assert_ok_set_schedule(changes([
+			(30, RewardRate::per_block(USDT::units(10))),
+			(42, RewardRate::per_second(0_u128)),
+		]));
+
+		// 18 seconds at the initial rate, accumulated up until the change at block 5
+		process_and_progress_blocks::<StakingRewards, Test>(4);
+		assert_eq!(usdt_reward(), (USDT::units(18), RewardRate::per_block(USDT::units(10))));
+
+		// two blocks at the per block rate, after which the last change stops the rewards
+		process_and_progress_blocks::<StakingRewards, Test>(2);
+		assert_eq!(usdt_reward(), (USDT::units(38), RewardRate::per_second(0_u128)));
+		assert!(!RewardRateSchedules::<Test>::contains_key(PICA::ID, USDT::ID));
+
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+		assert_eq!(usdt_reward().0, USDT::units(38));
+	});
+}
+
+#[test]
+fn invalid_schedules_are_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_pool(RewardRate::per_second(USDT::units(1)));
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+
+		let set_schedule = |asset_id, schedule| {
+			StakingRewards::set_reward_rate_schedule(
+				RuntimeOrigin::root(),
+				PICA::ID,
+				asset_id,
+				Some(schedule),
+			)
+		};
+
+		assert_noop!(
+			StakingRewards::set_reward_rate_schedule(
+				RuntimeOrigin::signed(ALICE),
+				PICA::ID,
+				USDT::ID,
+				None
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			set_schedule(PICA::ID, changes([(60, RewardRate::per_second(1_u128))])),
+			crate::Error::<Test>::RewardAssetNotFound
+		);
+		assert_noop!(
+			set_schedule(USDT::ID, changes([])),
+			crate::Error::<Test>::InvalidRewardRateSchedule
+		);
+		// in the past
+		assert_noop!(
+			set_schedule(USDT::ID, changes([(12, RewardRate::per_second(1_u128))])),
+			crate::Error::<Test>::InvalidRewardRateSchedule
+		);
+		assert_noop!(
+			set_schedule(
+				USDT::ID,
+				RewardRateSchedule::Halving { next: 12, interval: NonZeroU64::new(30).unwrap() }
+			),
+			crate::Error::<Test>::InvalidRewardRateSchedule
+		);
+		// out of order
+		assert_noop!(
+			set_schedule(
+				USDT::ID,
+				changes([
+					(90, RewardRate::per_second(1_u128)),
+					(60, RewardRate::per_second(2_u128))
+				])
+			),
+			crate::Error::<Test>::InvalidRewardRateSchedule
+		);
+
+		assert_ok_set_schedule(changes([(60, RewardRate::per_second(1_u128))]));
+		Test::assert_extrinsic_event(
+			StakingRewards::set_reward_rate_schedule(
+				RuntimeOrigin::root(),
+				PICA::ID,
+				USDT::ID,
+				None,
+			),
+			crate::Event::RewardRateScheduleSet {
+				pool_id: PICA::ID,
+				asset_id: USDT::ID,
+				enabled: false,
+			},
+		);
+		assert!(!RewardRateSchedules::<Test>::contains_key(PICA::ID, USDT::ID));
+	});
+}
+
+fn assert_ok_set_schedule(schedule: RewardRateSchedule<u128, MaxRewardRateChanges>) {
+	assert_ok!(StakingRewards::set_reward_rate_schedule(
+		RuntimeOrigin::root(),
+		PICA::ID,
+		USDT::ID,
+		Some(schedule),
+	));
+}
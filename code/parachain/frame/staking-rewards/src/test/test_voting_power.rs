@@ -0,0 +1,47 @@
+use composable_tests_helpers::test::{
+	block::process_and_progress_blocks,
+	currency::{PICA, USDT},
+};
+use composable_traits::{
+	staking::StakingVotingPower,
+	time::{ONE_HOUR, ONE_MINUTE},
+};
+use sp_arithmetic::Perbill;
+
+use crate::{
+	runtime::{StakingRewards, System, Test, ALICE, BOB},
+	test::{create_default_reward_pool, mint_assets, new_test_ext},
+	test_helpers::stake_and_assert,
+};
+
+#[test]
+fn voting_power_decays_with_remaining_lock_time() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_default_reward_pool();
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		mint_assets([ALICE, BOB], [PICA::ID], PICA::units(3_000));
+		stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_HOUR);
+		stake_and_assert::<Test>(ALICE, PICA::ID, PICA::units(1_000), ONE_MINUTE);
+		stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(500), ONE_HOUR);
+
+		// the longest lock of the pool counts fully, shorter locks proportionally
+		assert_eq!(
+			StakingRewards::voting_power(&PICA::ID, &ALICE),
+			PICA::units(1_000) +
+				Perbill::from_rational(ONE_MINUTE, ONE_HOUR).mul_floor(PICA::units(1_000))
+		);
+		assert_eq!(StakingRewards::voting_power(&PICA::ID, &BOB), PICA::units(500));
+
+		// 10 blocks of 6 seconds, the one minute lock has expired
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+		assert_eq!(
+			StakingRewards::voting_power(&PICA::ID, &ALICE),
+			Perbill::from_rational(ONE_HOUR - ONE_MINUTE, ONE_HOUR).mul_floor(PICA::units(1_000))
+		);
+
+		// unknown pools confer no voting power
+		assert_eq!(StakingRewards::voting_power(&USDT::ID, &ALICE), 0);
+	});
+}
@@ -11,6 +11,10 @@ pub trait WeightInfo {
 	fn update_rewards_pool(r: u32) -> Weight;
 	fn claim(r: u32) -> Weight;
 	fn add_to_rewards_pot() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn merge(r: u32) -> Weight;
+	fn compound(r: u32) -> Weight;
+	fn set_reward_rate_schedule(c: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -53,4 +57,20 @@ impl WeightInfo for () {
 	fn add_to_rewards_pot() -> Weight {
 		Weight::from_ref_time(10_000)
 	}
+
+	fn set_auto_compound() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn merge(_r: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn compound(_r: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn set_reward_rate_schedule(_c: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
 }
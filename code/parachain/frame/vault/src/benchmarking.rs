@@ -3,11 +3,18 @@ use super::*;
 use crate::Pallet as Vault;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::validation::Validated;
-use composable_traits::vault::{CapabilityVault, Deposit, Vault as VaultTrait, VaultConfig};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use composable_traits::vault::{
+	CapabilityVault, Deposit, StrategicVault, Vault as VaultTrait, VaultConfig,
+	WithdrawalQueueVault,
+};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
 	assert_ok,
-	traits::{fungible::Mutate as FungibleMutate, fungibles::Mutate as FungiblesMutate, Get},
+	traits::{
+		fungible::Mutate as FungibleMutate,
+		fungibles::{Mutate as FungiblesMutate, Transfer as FungiblesTransfer},
+		Get,
+	},
 };
 use frame_system::{EventRecord, Pallet as System, RawOrigin};
 use sp_runtime::Perquintill;
@@ -128,6 +135,50 @@ benchmarks! {
 		}.into())
 	}
 
+	request_withdrawal {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		let (vault, _) = create_vault::<T>(A, caller.clone());
+		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * 2u32.into())?;
+		<Vault<T> as VaultTrait>::deposit(&vault, &caller, amount)?;
+	}: _(RawOrigin::Signed(caller.clone()), vault, amount)
+	verify {
+		assert_last_event::<T>(Event::WithdrawalSettled {
+			vault,
+			account: caller,
+			asset_amount: amount,
+		}.into())
+	}
+
+	settle_withdrawals {
+		let n in 1 .. T::MaxWithdrawalQueueLength::get();
+		let strategy: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		let (vault, _) = create_vault::<T>(A, strategy.clone());
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			T::Currency::mint_into(recode_unwrap_u128(A), &user, amount * 2u32.into())?;
+			<Vault<T> as VaultTrait>::deposit(&vault, &user, amount)?;
+		}
+		let total = amount * n.into();
+		<Vault<T> as StrategicVault>::withdraw(&vault, &strategy, total)?;
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			<Vault<T> as WithdrawalQueueVault>::request_withdrawal(&vault, &user, amount)?;
+		}
+		// Return the funds without going through the vault, which would settle the queue.
+		T::Currency::transfer(
+			recode_unwrap_u128(A),
+			&strategy,
+			&<Vault<T> as VaultTrait>::account_id(&vault),
+			total,
+			false,
+		)?;
+	}: _(RawOrigin::Signed(strategy), vault)
+	verify {
+		assert!(WithdrawalQueue::<T>::get(vault).is_empty());
+	}
+
 	emergency_shutdown {
 		let caller: T::AccountId = whitelisted_caller();
 		let (vault, _) = create_vault::<T>(A, caller);
@@ -186,6 +237,7 @@ benchmarks! {
 	}: _(RawOrigin::Signed(caller), vault, None)
 
 	delete_tombstoned {
+		let n in 0 .. T::MaxWithdrawalQueueLength::get();
 		let caller: T::AccountId = whitelisted_caller();
 		let amount = T::CreationDeposit::get() * 10u32.into();
 		let block = System::<T>::block_number();
@@ -193,11 +245,34 @@ benchmarks! {
 		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * 2u32.into())?;
 		T::NativeCurrency::mint_into(&caller, amount * 2u32.into())?;
 		let (vault, _) = create_vault_extended::<T>(A, caller.clone(), DEFAULT_STRATEGY_SHARE, DEFAULT_RESERVE, deposit_);
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			T::Currency::mint_into(recode_unwrap_u128(A), &user, amount * 2u32.into())?;
+			<Vault<T> as VaultTrait>::deposit(&vault, &user, amount)?;
+		}
+		let total = amount * n.into();
+		<Vault<T> as StrategicVault>::withdraw(&vault, &caller, total)?;
+		for i in 0..n {
+			let user: T::AccountId = account("user", i, 0);
+			<Vault<T> as WithdrawalQueueVault>::request_withdrawal(&vault, &user, amount)?;
+		}
+		// Return the funds without going through the vault, so that the deletion settles the queue.
+		T::Currency::transfer(
+			recode_unwrap_u128(A),
+			&caller,
+			&<Vault<T> as VaultTrait>::account_id(&vault),
+			total,
+			false,
+		)?;
 		System::<T>::set_block_number(10_000_000u32.into());
 		Vault::<T>::claim_surcharge(RawOrigin::Signed(caller.clone()).into(), vault, None).expect("goo");
 		// Wait until the vault is deletable.
 		System::<T>::set_block_number(System::<T>::block_number() + T::TombstoneDuration::get());
 	}: _(RawOrigin::Signed(caller), vault, None)
+	verify {
+		assert!(Vaults::<T>::get(vault).is_none());
+		assert!(WithdrawalQueue::<T>::get(vault).is_empty());
+	}
 }
 
 impl_benchmark_test_suite!(
@@ -73,7 +73,7 @@ pub mod pallet {
 		defi::Rate,
 		vault::{
 			CapabilityVault, Deposit, FundsAvailability, ReportableStrategicVault, Vault,
-			VaultConfig,
+			VaultConfig, WithdrawalQueueVault, WithdrawalRequest,
 		},
 	};
 	use frame_support::{
@@ -103,7 +103,7 @@ pub mod pallet {
 		},
 		ArithmeticError, DispatchError, FixedPointNumber, Perquintill,
 	};
-	use sp_std::{cmp::Ordering, fmt::Debug};
+	use sp_std::{cmp::Ordering, fmt::Debug, vec::Vec};
 
 	#[allow(missing_docs)]
 	pub type AssetIdOf<T> =
@@ -120,6 +120,10 @@ pub mod pallet {
 	pub type VaultInfo<T> =
 		crate::models::VaultInfo<AccountIdOf<T>, BalanceOf<T>, AssetIdOf<T>, BlockNumberOf<T>>;
 
+	#[allow(missing_docs)]
+	pub type WithdrawalRequestOf<T> =
+		WithdrawalRequest<AccountIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		#[allow(missing_docs)]
@@ -196,6 +200,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumWithdrawal: Get<Self::Balance>;
 
+		/// The maximum number of pending withdrawal requests a vault can have queued.
+		#[pallet::constant]
+		type MaxWithdrawalQueueLength: Get<u32>;
+
 		/// The minimum native asset needed to create a vault.
 		#[pallet::constant]
 		type CreationDeposit: Get<Self::Balance>;
@@ -263,6 +271,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Withdrawal requests which could not be honoured from the idle balance of the vault, in the
+	/// order in which they will be settled.
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_queue_of)]
+	#[allow(clippy::disallowed_types)]
+	pub type WithdrawalQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::VaultId,
+		BoundedVec<WithdrawalRequestOf<T>, T::MaxWithdrawalQueueLength>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -293,6 +314,26 @@ pub mod pallet {
 			/// Assets received in exchange for the withdrawal.
 			asset_amount: T::Balance,
 		},
+		/// Emitted after a user burns LP tokens into a pending withdrawal request.
+		WithdrawalQueued {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account owed the assets.
+			account: T::AccountId,
+			/// Amount of LP tokens burned for the request.
+			lp_amount: T::Balance,
+			/// Assets owed once the request is settled.
+			asset_amount: T::Balance,
+		},
+		/// Emitted after a pending withdrawal request has been paid out.
+		WithdrawalSettled {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account receiving the assets.
+			account: T::AccountId,
+			/// Assets paid out to the account.
+			asset_amount: T::Balance,
+		},
 		/// Emitted after a successful emergency shutdown.
 		EmergencyShutdown {
 			/// The ID of the vault.
@@ -364,6 +405,12 @@ pub mod pallet {
 		TombstoneDurationNotExceeded,
 		/// Existentially funded vaults do not require extra funds.
 		InvalidAddSurcharge,
+		/// The vault already has [`MaxWithdrawalQueueLength`](Config::MaxWithdrawalQueueLength)
+		/// pending withdrawal requests.
+		WithdrawalQueueFull,
+		/// The vault could not be deleted, as it cannot yet settle its pending withdrawal
+		/// requests.
+		WithdrawalsPending,
 	}
 
 	#[pallet::call]
@@ -495,7 +542,12 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(<T as Config>::WeightInfo::delete_tombstoned())]
+		/// Deletes a vault tombstoned for long enough, rewarding `address` or the caller with the
+		/// remaining rent. Pending withdrawal requests are settled first, as their LP tokens are
+		/// already burned, and the deletion fails if any of them cannot be settled.
+		#[pallet::weight(<T as Config>::WeightInfo::delete_tombstoned(
+			T::MaxWithdrawalQueueLength::get()
+		))]
 		pub fn delete_tombstoned(
 			origin: OriginFor<T>,
 			dest: T::VaultId,
@@ -517,6 +569,11 @@ pub mod pallet {
 				) {
 					return Err(Error::<T>::TombstoneDurationNotExceeded.into())
 				} else {
+					Self::do_settle_withdrawals(&dest)?;
+					ensure!(
+						WithdrawalQueue::<T>::get(dest).is_empty(),
+						Error::<T>::WithdrawalsPending
+					);
 					let deletion_reward_account = &Self::deletion_reward_account(dest);
 					let reward =
 						T::NativeCurrency::reducible_balance(deletion_reward_account, false);
@@ -529,6 +586,7 @@ pub mod pallet {
 						false,
 					)?;
 					LpTokensToVaults::<T>::remove(vault.asset_id);
+					WithdrawalQueue::<T>::remove(dest);
 					v.take();
 				}
 				Ok(().into())
@@ -575,6 +633,51 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Burns LP tokens into a pending withdrawal request, to be used when the vault does not
+		/// hold enough idle assets for a regular `withdraw`. The owed amount is fixed at the
+		/// current share price and paid out once the request reaches the front of the queue and
+		/// the vault has enough liquidity.
+		///
+		/// # Emits
+		///  - Event::WithdrawalQueued
+		///  - Event::WithdrawalSettled, if the request could be settled immediately.
+		///
+		/// # Errors
+		///  - When the origin is not signed.
+		///  - When `lp_amount < MinimumWithdrawal`.
+		///  - When the withdrawal queue of the vault is full.
+		#[pallet::weight(<T as Config>::WeightInfo::request_withdrawal())]
+		pub fn request_withdrawal(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			lp_amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			<Self as WithdrawalQueueVault>::request_withdrawal(&vault, &from, lp_amount)?;
+			Ok(().into())
+		}
+
+		/// Pays out pending withdrawal requests of a vault in FIFO order, stopping at the first
+		/// request the vault cannot afford. Callable by anyone.
+		///
+		/// # Emits
+		///  - Event::WithdrawalSettled, for every settled request.
+		///
+		/// # Errors
+		///  - When the origin is not signed.
+		///  - When `vault` does not exist.
+		#[pallet::weight(<T as Config>::WeightInfo::settle_withdrawals(
+			T::MaxWithdrawalQueueLength::get()
+		))]
+		pub fn settle_withdrawals(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let settled = <Self as WithdrawalQueueVault>::settle_withdrawals(&vault)?;
+			Ok(Some(<T as Config>::WeightInfo::settle_withdrawals(settled)).into())
+		}
+
 		/// Stops a vault. To be used in case of severe protocol flaws.
 		///
 		/// # Emits
@@ -736,8 +839,11 @@ pub mod pallet {
 
 			let lp_shares_value_amount = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
 
+			// Assets owed to queued requests are not available for direct withdrawals, otherwise
+			// users could jump the queue.
 			let vault_owned_amount =
-				T::Currency::balance(vault.asset_id, &Self::account_id(vault_id));
+				T::Currency::balance(vault.asset_id, &Self::account_id(vault_id))
+					.saturating_sub(&Self::do_pending_withdrawals(vault_id));
 
 			// TODO(hussein-aitlahcen): should we provide what we can to reduce the available
 			// liquidity in order to force strategies to rebalance?
@@ -765,6 +871,93 @@ pub mod pallet {
 			Ok(lp_shares_value_amount)
 		}
 
+		fn do_request_withdrawal(
+			vault_id: &T::VaultId,
+			from: &T::AccountId,
+			lp_amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+
+			ensure!(vault.capabilities.withdrawals_allowed(), Error::<T>::WithdrawalsHalted);
+			ensure!(
+				T::Currency::can_withdraw(vault.lp_token_id, from, lp_amount)
+					.into_result()
+					.is_ok(),
+				Error::<T>::InsufficientLpTokens
+			);
+
+			let asset_amount = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
+
+			WithdrawalQueue::<T>::try_mutate(vault_id, |queue| {
+				queue
+					.try_push(WithdrawalRequest {
+						account: from.clone(),
+						lp_amount,
+						asset_amount,
+						requested_at: <frame_system::Pallet<T>>::block_number(),
+					})
+					.map_err(|_| Error::<T>::WithdrawalQueueFull)
+			})?;
+			// The burn happens after the share value is computed; queued assets are excluded from
+			// the assets under management from here on, so the share price is unaffected.
+			T::Currency::burn_from(vault.lp_token_id, from, lp_amount)
+				.map_err(|_| Error::<T>::InsufficientLpTokens)?;
+
+			Self::deposit_event(Event::WithdrawalQueued {
+				vault: *vault_id,
+				account: from.clone(),
+				lp_amount,
+				asset_amount,
+			});
+			Ok(asset_amount)
+		}
+
+		fn do_settle_withdrawals(vault_id: &T::VaultId) -> Result<u32, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+			let vault_account = Self::account_id(vault_id);
+			WithdrawalQueue::<T>::try_mutate(vault_id, |queue| {
+				let mut available = T::Currency::balance(vault.asset_id, &vault_account);
+				let settled = queue
+					.iter()
+					.take_while(|request| {
+						let affordable = request.asset_amount <= available;
+						if affordable {
+							available -= request.asset_amount;
+						}
+						affordable
+					})
+					.count();
+				for request in queue.iter().take(settled) {
+					T::Currency::transfer(
+						vault.asset_id,
+						&vault_account,
+						&request.account,
+						request.asset_amount,
+						false,
+					)
+					.map_err(|_| Error::<T>::TransferFromFailed)?;
+					Self::deposit_event(Event::WithdrawalSettled {
+						vault: *vault_id,
+						account: request.account.clone(),
+						asset_amount: request.asset_amount,
+					});
+				}
+				let mut index = 0;
+				queue.retain(|_| {
+					index += 1;
+					index > settled
+				});
+				Ok(settled as u32)
+			})
+		}
+
+		/// Sum of the assets owed to the pending withdrawal requests of the vault.
+		fn do_pending_withdrawals(vault_id: &T::VaultId) -> T::Balance {
+			WithdrawalQueue::<T>::get(vault_id)
+				.iter()
+				.fold(T::Balance::zero(), |sum, request| sum.saturating_add(request.asset_amount))
+		}
+
 		fn do_deposit(
 			vault_id: &T::VaultId,
 			from: &T::AccountId,
@@ -877,7 +1070,8 @@ pub mod pallet {
 			let owned = T::Currency::balance(vault.asset_id, &Self::account_id(vault_id));
 			let outstanding = CapitalStructure::<T>::iter_prefix_values(vault_id)
 				.fold(T::Balance::zero(), |sum, item| sum + item.balance);
-			Ok(owned + outstanding)
+			// Assets owed to queued withdrawals no longer belong to the LP token holders.
+			Ok((owned + outstanding).saturating_sub(&Self::do_pending_withdrawals(vault_id)))
 		}
 
 		/// Tries to fetch a stored [VaultInfo] through its index.
@@ -1012,6 +1206,17 @@ pub mod pallet {
 						allocation
 							.mul_floor(<T::Convert as Convert<T::Balance, u128>>::convert(aum)),
 					);
+					// If the idle balance cannot cover the withdrawal queue, strategies are asked
+					// to unwind the shortfall, regardless of their allocation.
+					let shortfall = Self::do_pending_withdrawals(vault_id).saturating_sub(
+						&T::Currency::balance(vault.asset_id, &Self::account_id(vault_id)),
+					);
+					if !shortfall.is_zero() && !balance.is_zero() {
+						let rebalance = balance.saturating_sub(&max_allowed);
+						return Ok(FundsAvailability::Depositable(
+							rebalance.max(shortfall.min(balance)),
+						))
+					}
 					match balance.cmp(&max_allowed) {
 						Ordering::Greater =>
 							Ok(FundsAvailability::Depositable(balance - max_allowed)),
@@ -1077,8 +1282,43 @@ pub mod pallet {
 					true,
 				)
 				.map_err(|_| Error::<T>::InsufficientFunds)?;
-				Ok(())
-			})
+				Ok::<_, DispatchError>(())
+			})?;
+			// Returned capital goes to pending withdrawals first.
+			Self::do_settle_withdrawals(vault_id)?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config> WithdrawalQueueVault for Pallet<T> {
+		fn request_withdrawal(
+			vault_id: &Self::VaultId,
+			from: &Self::AccountId,
+			lp_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			ensure!(
+				lp_amount > T::MinimumWithdrawal::get(),
+				Error::<T>::AmountMustGteMinimumWithdrawal
+			);
+			let asset_amount = Self::do_request_withdrawal(vault_id, from, lp_amount)?;
+			Self::do_settle_withdrawals(vault_id)?;
+			Ok(asset_amount)
+		}
+
+		fn settle_withdrawals(vault_id: &Self::VaultId) -> Result<u32, DispatchError> {
+			Self::do_settle_withdrawals(vault_id)
+		}
+
+		fn withdrawal_queue(
+			vault_id: &Self::VaultId,
+		) -> Result<Vec<WithdrawalRequestOf<T>>, DispatchError> {
+			Self::vault_info(vault_id)?;
+			Ok(WithdrawalQueue::<T>::get(vault_id).into_inner())
+		}
+
+		fn pending_withdrawals(vault_id: &Self::VaultId) -> Result<Self::Balance, DispatchError> {
+			Self::vault_info(vault_id)?;
+			Ok(Self::do_pending_withdrawals(vault_id))
 		}
 	}
 
@@ -1093,6 +1333,18 @@ pub mod pallet {
 			CapitalStructure::<T>::mutate(vault, strategy, |state| state.balance = *report);
 			Ok(())
 		}
+
+		fn report_loss(
+			vault: &Self::VaultId,
+			strategy: &Self::AccountId,
+			loss: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			Ok(CapitalStructure::<T>::mutate(vault, strategy, |state| {
+				let written_off = loss.min(state.balance);
+				state.balance = state.balance.saturating_sub(&written_off);
+				written_off
+			}))
+		}
 	}
 
 	impl<T: Config> CapabilityVault for Pallet<T> {
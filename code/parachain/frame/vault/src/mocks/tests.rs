@@ -87,6 +87,7 @@ parameter_types! {
 	pub const StrategyTestPalletID: PalletId = PalletId(*b"sest_pid");
 	pub const MinimumDeposit: Balance = 0;
 	pub const MinimumWithdrawal: Balance = 0;
+	pub const MaxWithdrawalQueueLength: u32 = 10;
 	pub const TombstoneDuration: BlockNumber = 10;
 }
 
@@ -105,6 +106,7 @@ impl pallet_vault::Config for Test {
 	type NativeCurrency = Balances;
 	type MinimumDeposit = MinimumDeposit;
 	type MinimumWithdrawal = MinimumWithdrawal;
+	type MaxWithdrawalQueueLength = MaxWithdrawalQueueLength;
 	type TombstoneDuration = TombstoneDuration;
 	type VaultId = u64;
 	type WeightInfo = ();
@@ -19,11 +19,15 @@ use composable_traits::{
 	defi::Rate,
 	vault::{
 		Deposit, FundsAvailability, ReportableStrategicVault, StrategicVault, Vault, VaultConfig,
+		WithdrawalQueueVault,
 	},
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::fungibles::{Inspect, Mutate},
+	traits::{
+		fungibles::{Inspect, Mutate, Transfer},
+		Get,
+	},
 };
 use proptest::prelude::*;
 use sp_runtime::{ArithmeticError, FixedPointNumber, Perbill, Perquintill};
@@ -981,3 +985,160 @@ fn do_liquidate_strategy_successfully_liquidates_a_strategy_account(
 		assert_eq!(Tokens::balance(currency_id, &strategy_account_id), 0);
 	});
 }
+
+/// Deposits `amount` for each of `accounts`, then lets `strategy` withdraw all of the vault's
+/// funds, leaving no idle balance.
+fn deploy_all_funds(
+	id: u64,
+	strategy: AccountId,
+	accounts: &[AccountId],
+	amount: Balance,
+) -> Balance {
+	for account in accounts {
+		Tokens::mint_into(MockCurrencyId::A, account, amount).unwrap();
+		Vaults::deposit(RuntimeOrigin::signed(*account), id, amount).unwrap();
+	}
+	let total = amount * accounts.len() as Balance;
+	<Vaults as StrategicVault>::withdraw(&id, &strategy, total).unwrap();
+	total
+}
+
+#[test]
+fn withdrawal_queue_request_is_settled_when_strategy_returns_capital() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let strategy = JEREMY;
+		let (id, _) = create_vault(strategy, MockCurrencyId::A);
+		deploy_all_funds(id, strategy, &[BOB], 1_000);
+
+		assert_noop!(
+			Vaults::withdraw(RuntimeOrigin::signed(BOB), id, 1_000),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 1_000));
+		assert_eq!(Tokens::balance(Vaults::lp_asset_id(&id).unwrap(), &BOB), 0);
+		assert_eq!(<Vaults as WithdrawalQueueVault>::pending_withdrawals(&id), Ok(1_000));
+
+		<Vaults as StrategicVault>::deposit(&id, &strategy, 1_000).unwrap();
+		assert_eq!(Tokens::balance(MockCurrencyId::A, &BOB), 1_000);
+		assert_eq!(<Vaults as WithdrawalQueueVault>::withdrawal_queue(&id), Ok(vec![]));
+		System::assert_last_event(RuntimeEvent::Vaults(crate::Event::WithdrawalSettled {
+			vault: id,
+			account: BOB,
+			asset_amount: 1_000,
+		}));
+	});
+}
+
+#[test]
+fn withdrawal_queue_is_settled_in_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		let strategy = JEREMY;
+		let (id, _) = create_vault(strategy, MockCurrencyId::A);
+		deploy_all_funds(id, strategy, &[BOB, CHARLIE], 1_000);
+
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(CHARLIE), id, 1_000));
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 1_000));
+
+		// Only enough liquidity for the first request.
+		<Tokens as Transfer<AccountId>>::transfer(
+			MockCurrencyId::A,
+			&strategy,
+			&Vaults::account_id(&id),
+			1_500,
+			false,
+		)
+		.unwrap();
+		assert_ok!(Vaults::settle_withdrawals(RuntimeOrigin::signed(ALICE), id));
+		assert_eq!(Tokens::balance(MockCurrencyId::A, &CHARLIE), 1_000);
+		assert_eq!(Tokens::balance(MockCurrencyId::A, &BOB), 0);
+
+		let queue = <Vaults as WithdrawalQueueVault>::withdrawal_queue(&id).unwrap();
+		assert_eq!(queue.len(), 1);
+		assert_eq!(queue[0].account, BOB);
+		assert_eq!(<Vaults as WithdrawalQueueVault>::pending_withdrawals(&id), Ok(1_000));
+	});
+}
+
+#[test]
+fn withdrawal_queue_asks_strategies_to_unwind() {
+	ExtBuilder::default().build().execute_with(|| {
+		let strategy = JEREMY;
+		let (id, _) = create_vault(strategy, MockCurrencyId::A);
+		deploy_all_funds(id, strategy, &[BOB], 1_000);
+
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 400));
+		// The queued 400 plus the 10% reserve of the remaining 600.
+		assert_eq!(
+			<Vaults as StrategicVault>::available_funds(&id, &strategy),
+			Ok(FundsAvailability::Depositable(460))
+		);
+	});
+}
+
+#[test]
+fn withdrawal_queue_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let strategy = JEREMY;
+		let (id, _) = create_vault(strategy, MockCurrencyId::A);
+		deploy_all_funds(id, strategy, &[BOB], 1_000);
+
+		for _ in 0..<Test as Config>::MaxWithdrawalQueueLength::get() {
+			assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 10));
+		}
+		assert_noop!(
+			Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 10),
+			Error::<Test>::WithdrawalQueueFull
+		);
+	});
+}
+
+#[test]
+fn withdrawal_queue_is_settled_before_deleting_tombstoned_vault() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::mint_into(&ALICE, ExistentialDeposit::get() * 3).unwrap();
+		System::set_block_number(0);
+		let id = create_vault_with_deposit(MockCurrencyId::A, ExistentialDeposit::get() - 1);
+		deploy_all_funds(id, ALICE, &[BOB], 1_000);
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 1_000));
+
+		System::set_block_number(1000000);
+		Vaults::claim_surcharge(RuntimeOrigin::none(), id, Some(CHARLIE))
+			.expect("claiming surcharge for rent should work");
+		System::set_block_number(1000000 + TombstoneDuration::get());
+		// BOB's LP tokens are burned, so the vault must pay BOB out before being deleted.
+		assert_noop!(
+			Vaults::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), id, None),
+			Error::<Test>::WithdrawalsPending
+		);
+
+		<Tokens as Transfer<AccountId>>::transfer(
+			MockCurrencyId::A,
+			&ALICE,
+			&Vaults::account_id(&id),
+			1_000,
+			false,
+		)
+		.unwrap();
+		assert_ok!(Vaults::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), id, None));
+		assert_eq!(Tokens::balance(MockCurrencyId::A, &BOB), 1_000);
+		assert!(Vaults::vault_data(id).is_none());
+		assert_eq!(WithdrawalQueue::<Test>::get(id).len(), 0);
+	});
+}
+
+#[test]
+fn strategy_loss_is_socialized_through_share_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		let strategy = JEREMY;
+		let (id, _) = create_vault(strategy, MockCurrencyId::A);
+		deploy_all_funds(id, strategy, &[BOB], 1_000);
+		assert_eq!(<Vaults as Vault>::lp_share_value(&id, 1_000), Ok(1_000));
+
+		assert_eq!(<Vaults as ReportableStrategicVault>::report_loss(&id, &strategy, 200), Ok(200));
+		assert_eq!(<Vaults as Vault>::lp_share_value(&id, 1_000), Ok(800));
+		// the strategy cannot lose more than it owes to the vault
+		assert_eq!(<Vaults as ReportableStrategicVault>::report_loss(&id, &strategy, 801), Ok(800));
+		assert_eq!(<Vaults as Vault>::lp_share_value(&id, 1_000), Ok(0));
+	});
+}
@@ -17,7 +17,9 @@ pub trait WeightInfo {
 	fn start_() -> Weight;
 	fn add_surcharge() -> Weight;
 	fn claim_surcharge() -> Weight;
-	fn delete_tombstoned() -> Weight;
+	fn delete_tombstoned(n: u32) -> Weight;
+	fn request_withdrawal() -> Weight;
+	fn settle_withdrawals(n: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -79,12 +81,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Vault Vaults (r:1 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
 	// Storage: System Account (r:1 w:0)
 	// Storage: Vault LpTokensToVaults (r:0 w:1)
-	fn delete_tombstoned() -> Weight {
+	fn delete_tombstoned(n: u32) -> Weight {
 		Weight::from_ref_time(25_030_000_u64)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_ref_time(39_117_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	// Storage: Tokens TotalIssuance (r:2 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_ref_time(128_412_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn settle_withdrawals(n: u32) -> Weight {
+		Weight::from_ref_time(31_204_000_u64)
+			.saturating_add(Weight::from_ref_time(39_117_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n as u64)))
 	}
 }
 
@@ -146,11 +174,37 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: Vault Vaults (r:1 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
 	// Storage: System Account (r:1 w:0)
 	// Storage: Vault LpTokensToVaults (r:0 w:1)
-	fn delete_tombstoned() -> Weight {
+	fn delete_tombstoned(n: u32) -> Weight {
 		Weight::from_ref_time(25_030_000_u64)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_ref_time(39_117_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	// Storage: Tokens TotalIssuance (r:2 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_ref_time(128_412_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn settle_withdrawals(n: u32) -> Weight {
+		Weight::from_ref_time(31_204_000_u64)
+			.saturating_add(Weight::from_ref_time(39_117_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n as u64)))
 	}
 }
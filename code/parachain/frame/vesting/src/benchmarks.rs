@@ -9,7 +9,8 @@ use crate::{
 use codec::Decode;
 use composable_support::abstractions::utils::increment::Increment;
 use composable_traits::vesting::{
-	VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo, VestingWindow::BlockNumberBased,
+	VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo, VestingShape,
+	VestingWindow::BlockNumberBased,
 };
 use frame_benchmarking::{account, benchmarks, vec, whitelisted_caller};
 use frame_support::traits::{fungibles::Mutate, Get};
@@ -56,7 +57,12 @@ where
 	T: Config,
 	BalanceOf<T>: From<u64>,
 {
-	VestingScheduleInfo { window: BlockNumberBased { start, period }, period_count, per_period }
+	VestingScheduleInfo {
+		window: BlockNumberBased { start, period },
+		period_count,
+		per_period,
+		shape: VestingShape::Linear,
+	}
 }
 
 fn vesting_schedule<T>(
@@ -75,6 +81,7 @@ where
 		period_count,
 		per_period,
 		already_claimed: Zero::zero(),
+		shape: VestingShape::Linear,
 	}
 }
 
@@ -15,6 +15,14 @@
 //! timestamps as well as block numbers for vesting schedules. All `VestingSchedule`s under
 //! an account could be queried in chain state.
 //!
+//! The `shape` of a schedule allows non-linear releases, with offsets counted in `window.period`s
+//! since `window.start`:
+//! - `Linear` - the graded vesting described above.
+//! - `Cliff` - nothing is unlocked before the cliff, where `cliff_amount` is unlocked at once,
+//!   followed by the graded vesting of `per_period` over `period_count` periods.
+//! - `Checkpoints` - a list of `(periods, amount)` pairs, each `amount` being unlocked once
+//!   `periods` periods have passed. At most `MaxVestingCheckpoints` checkpoints are allowed.
+//!
 //! ## Interface
 //! - `VestedTransfer` - allowing a third party pallet to have this implementation as dependency to
 //!   execute vested transfers.
@@ -47,7 +55,7 @@ use composable_support::{
 	math::safe::{SafeAdd, SafeSub},
 };
 use composable_traits::vesting::{
-	VestedTransfer, VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo,
+	VestedTransfer, VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo, VestingShape,
 };
 use frame_support::{
 	ensure,
@@ -63,6 +71,7 @@ use sp_runtime::{
 };
 use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, vec, vec::Vec};
 
+pub mod migrations;
 mod weights;
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -77,6 +86,8 @@ pub use weights::WeightInfo;
 
 pub const VESTING_LOCK_ID: LockIdentifier = *b"compvest";
 
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod module {
 	use codec::{FullCodec, MaxEncodedLen};
@@ -87,7 +98,9 @@ pub mod module {
 		},
 		math::safe::SafeAdd,
 	};
-	use composable_traits::vesting::{VestingSchedule, VestingScheduleInfo, VestingWindow};
+	use composable_traits::vesting::{
+		VestingSchedule, VestingScheduleInfo, VestingShape, VestingWindow,
+	};
 	use frame_support::{traits::Time, BoundedBTreeMap};
 	use orml_traits::{MultiCurrency, MultiLockableCurrency};
 	use sp_runtime::traits::AtLeast32Bit;
@@ -139,6 +152,10 @@ pub mod module {
 		/// The maximum vesting schedules
 		type MaxVestingSchedules: Get<u32>;
 
+		/// The maximum number of checkpoints of a vesting schedule
+		#[pallet::constant]
+		type MaxVestingCheckpoints: Get<u32>;
+
 		/// Type of time
 		type Moment: AtLeast32Bit
 			+ Parameter
@@ -181,6 +198,10 @@ pub mod module {
 		TryingToSelfVest,
 		/// There is no vesting schedule with a given id
 		VestingScheduleNotFound,
+		/// The shape of the vesting schedule is inconsistent with its linear release
+		InvalidVestingShape,
+		/// The vesting schedule has too many checkpoints
+		TooManyVestingCheckpoints,
 	}
 
 	#[pallet::event]
@@ -263,6 +284,7 @@ pub mod module {
 							period_count: *period_count,
 							per_period: *per_period,
 							already_claimed: BalanceOf::<T>::zero(),
+							shape: VestingShape::Linear,
 						},
 					)
 					.expect("Max vesting schedules exceeded");
@@ -291,11 +313,16 @@ pub mod module {
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberOf<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberOf<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -675,8 +702,19 @@ fn ensure_valid_vesting_schedule<T: Config>(
 	schedule: &VestingScheduleOf<T>,
 ) -> Result<BalanceOf<T>, DispatchError> {
 	ensure!(!schedule.is_zero_period(), Error::<T>::ZeroVestingPeriod);
+	ensure!(schedule.is_valid_shape(), Error::<T>::InvalidVestingShape);
 	ensure!(schedule.end().is_some(), ArithmeticError::Overflow);
-	ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+	match &schedule.shape {
+		VestingShape::Linear | VestingShape::Cliff { .. } => {
+			ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+		},
+		VestingShape::Checkpoints(checkpoints) => {
+			ensure!(
+				checkpoints.len() as u32 <= T::MaxVestingCheckpoints::get(),
+				Error::<T>::TooManyVestingCheckpoints
+			);
+		},
+	}
 
 	let total_total = schedule.total_amount()?;
 
@@ -0,0 +1,68 @@
+//! Storage migrations of the vesting pallet.
+
+use crate::*;
+
+pub mod v1 {
+	//! Adds a `shape` to stored vesting schedules, see [`VestingShape`].
+
+	use super::*;
+	use codec::HasCompact;
+	use composable_traits::vesting::VestingWindow;
+
+	/// Vesting schedule as stored before the introduction of [`VestingShape`].
+	#[derive(Decode)]
+	struct OldVestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasCompact> {
+		vesting_schedule_id: VestingScheduleId,
+		window: VestingWindow<BlockNumber, Moment>,
+		period_count: u32,
+		#[codec(compact)]
+		per_period: Balance,
+		already_claimed: Balance,
+	}
+
+	type OldVestingSchedulesOf<T> = BoundedBTreeMap<
+		<T as Config>::VestingScheduleId,
+		OldVestingSchedule<
+			<T as Config>::VestingScheduleId,
+			BlockNumberOf<T>,
+			MomentOf<T>,
+			BalanceOf<T>,
+		>,
+		<T as Config>::MaxVestingSchedules,
+	>;
+
+	/// Stored schedules only ever had a linear release, so they are all translated to
+	/// [`VestingShape::Linear`].
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Weight::zero()
+		}
+
+		let mut translated = 0_u64;
+		VestingSchedules::<T>::translate::<OldVestingSchedulesOf<T>, _>(|_, _, schedules| {
+			translated += 1;
+			// the bound of the map is unchanged, so the conversion cannot fail
+			schedules
+				.into_iter()
+				.map(|(id, schedule)| {
+					(
+						id,
+						VestingSchedule {
+							vesting_schedule_id: schedule.vesting_schedule_id,
+							window: schedule.window,
+							period_count: schedule.period_count,
+							per_period: schedule.per_period,
+							already_claimed: schedule.already_claimed,
+							shape: VestingShape::Linear,
+						},
+					)
+				})
+				.collect::<BTreeMap<_, _>>()
+				.try_into()
+				.ok()
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
@@ -154,6 +154,7 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 3;
+	pub const MaxVestingCheckpoints: u32 = 3;
 	pub const MinVestedTransfer: u64 = 5;
 }
 
@@ -165,6 +166,7 @@ impl Config for Runtime {
 	type UpdateSchedulesOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = ();
 	type MaxVestingSchedules = MaxVestingSchedule;
+	type MaxVestingCheckpoints = MaxVestingCheckpoints;
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
@@ -4,7 +4,7 @@
 
 use super::*;
 use composable_traits::vesting::{
-	VestingSchedule, VestingScheduleInfo,
+	VestingSchedule, VestingScheduleInfo, VestingShape,
 	VestingWindow::{BlockNumberBased, MomentBased},
 };
 use frame_support::{
@@ -38,6 +38,7 @@ fn vesting_from_chain_spec_works() {
 					period_count: 1_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					shape: VestingShape::Linear,
 				},
 			),
 			(
@@ -60,6 +61,7 @@ fn vesting_from_chain_spec_works() {
 					period_count: 3_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					shape: VestingShape::Linear,
 				},
 			),
 			(
@@ -86,6 +88,7 @@ fn vesting_from_chain_spec_works() {
 					period_count: 3_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					shape: VestingShape::Linear,
 				},
 			),
 		]
@@ -156,6 +159,7 @@ fn vested_transfer_self_vest_ko() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -179,6 +183,7 @@ fn vested_transfer_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -213,6 +218,7 @@ fn vested_transfer_trait_emits_vesting_schedule_added_event() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
@@ -245,6 +251,7 @@ fn vested_transfer_for_moment_based_schedule_works() {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
 		assert_ok!(Vesting::vested_transfer(
@@ -276,6 +283,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -292,6 +300,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: BlockNumberBased { start: 10_u64, period: 13_u64 },
 			period_count: 1_u32,
 			per_period: 7_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -305,6 +314,7 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: MomentBased { start: 72000_u64, period: 5000_u64 },
 			period_count: 2_u32,
 			per_period: 7_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -328,6 +338,7 @@ fn cannot_use_fund_if_not_claimed() {
 			window: BlockNumberBased { start: 10_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 50_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -340,6 +351,7 @@ fn cannot_use_fund_if_not_claimed() {
 			window: MomentBased { start: 1000_u64, period: 5000_u64 },
 			period_count: 1_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -367,6 +379,7 @@ fn vested_transfer_fails_if_zero_period_count() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 0_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -388,6 +401,7 @@ fn vested_transfer_fails_if_zero_period() {
 			window: BlockNumberBased { start: 1_u64, period: 0_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -420,6 +434,7 @@ fn vested_transfer_fails_if_transfer_err() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -441,6 +456,7 @@ fn vested_transfer_fails_if_overflow() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: u64::MAX,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -457,6 +473,7 @@ fn vested_transfer_fails_if_overflow() {
 			window: BlockNumberBased { start: u64::MAX, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: 1_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -473,6 +490,7 @@ fn vested_transfer_fails_if_overflow() {
 			window: MomentBased { start: u64::MAX, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: 1_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -494,6 +512,7 @@ fn vested_transfer_fails_if_bad_origin() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -515,6 +534,7 @@ fn claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -609,6 +629,7 @@ fn claim_nonexistent_schedules() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -671,6 +692,7 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -685,6 +707,7 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -699,6 +722,7 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 3_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -833,6 +857,7 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -847,6 +872,7 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -861,6 +887,7 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 3_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -945,6 +972,7 @@ fn claim_for_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -959,6 +987,7 @@ fn claim_for_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1029,6 +1058,7 @@ fn claim_for_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1082,6 +1112,7 @@ fn claim_for_works_moment_based() {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1120,6 +1151,227 @@ fn claim_for_works_moment_based() {
 	});
 }
 
+#[test]
+fn claim_cliff_schedule_works() {
+	ExtBuilder::build().execute_with(|| {
+		/*
+			+------+------+------+
+			|block |vested|locked|
+			+------+------+------+
+			|19    |0     |50    |
+			|20    |30    |20    |
+			|30    |40    |10    |
+			|40    |50    |0     |
+			+------+------+------+
+		*/
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 2_u32,
+			per_period: 10_u64,
+			shape: VestingShape::Cliff { cliff_periods: 2, cliff_amount: 30_u64 },
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+		assert_eq!(
+			Tokens::locks(&BOB, MockCurrencyId::BTC).get(0),
+			Some(&BalanceLock { id: VESTING_LOCK_ID, amount: 50_u64 })
+		);
+
+		for (block_number, locked_amount) in [(19, 50_u64), (20, 20), (30, 10)] {
+			System::set_block_number(block_number);
+			assert_ok!(Vesting::claim(
+				RuntimeOrigin::signed(BOB),
+				MockCurrencyId::BTC,
+				VestingScheduleIdSet::All
+			));
+			assert_eq!(
+				Tokens::locks(&BOB, MockCurrencyId::BTC).get(0),
+				Some(&BalanceLock { id: VESTING_LOCK_ID, amount: locked_amount })
+			);
+		}
+
+		System::set_block_number(40);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All
+		));
+		assert_eq!(Tokens::locks(&BOB, MockCurrencyId::BTC), vec![]);
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+	});
+}
+
+#[test]
+fn claim_checkpoints_schedule_works_moment_based() {
+	ExtBuilder::build().execute_with(|| {
+		/*
+			+------+------+------+
+			|moment|vested|locked|
+			+------+------+------+
+			|999   |0     |50    |
+			|1000  |20    |30    |
+			|4999  |20    |30    |
+			|5000  |50    |0     |
+			+------+------+------+
+		*/
+		let schedule_input = VestingScheduleInfo {
+			window: MomentBased { start: 0_u64, period: 1000_u64 },
+			period_count: 0_u32,
+			per_period: 0_u64,
+			shape: VestingShape::Checkpoints(vec![(1, 20_u64), (5, 30_u64)]),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		for (moment, locked_amount) in [(999, 50_u64), (1000, 30), (4999, 30)] {
+			Timestamp::set_timestamp(moment);
+			assert_ok!(Vesting::claim(
+				RuntimeOrigin::signed(BOB),
+				MockCurrencyId::BTC,
+				VestingScheduleIdSet::One(4_u128)
+			));
+			assert_eq!(
+				Tokens::locks(&BOB, MockCurrencyId::BTC).get(0),
+				Some(&BalanceLock { id: VESTING_LOCK_ID, amount: locked_amount })
+			);
+		}
+
+		Timestamp::set_timestamp(5000);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::One(4_u128)
+		));
+		assert_eq!(Tokens::locks(&BOB, MockCurrencyId::BTC), vec![]);
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &BOB), 50);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_invalid_shape() {
+	ExtBuilder::build().execute_with(|| {
+		let checkpoints_schedule = |per_period, period_count, checkpoints| VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count,
+			per_period,
+			shape: VestingShape::Checkpoints(checkpoints),
+		};
+		for (schedule_input, error) in [
+			(checkpoints_schedule(0, 0, vec![]), Error::<Runtime>::InvalidVestingShape),
+			(
+				checkpoints_schedule(0, 0, vec![(2, 10), (1, 10)]),
+				Error::<Runtime>::InvalidVestingShape,
+			),
+			(
+				checkpoints_schedule(0, 0, vec![(1, 10), (1, 10)]),
+				Error::<Runtime>::InvalidVestingShape,
+			),
+			(checkpoints_schedule(0, 0, vec![(1, 0)]), Error::<Runtime>::InvalidVestingShape),
+			(checkpoints_schedule(10, 1, vec![(1, 10)]), Error::<Runtime>::InvalidVestingShape),
+			(
+				checkpoints_schedule(0, 0, vec![(1, 10), (2, 10), (3, 10), (4, 10)]),
+				Error::<Runtime>::TooManyVestingCheckpoints,
+			),
+			(
+				VestingScheduleInfo {
+					window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+					period_count: 0_u32,
+					per_period: 10_u64,
+					shape: VestingShape::Cliff { cliff_periods: 1, cliff_amount: 10_u64 },
+				},
+				Error::<Runtime>::ZeroVestingPeriodCount,
+			),
+		] {
+			assert_noop!(
+				Vesting::vested_transfer(
+					RuntimeOrigin::root(),
+					ALICE,
+					BOB,
+					MockCurrencyId::BTC,
+					schedule_input,
+				),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn update_vesting_schedules_with_cliff_and_checkpoints_works() {
+	ExtBuilder::build().execute_with(|| {
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 2_u32,
+			per_period: 20_u64,
+			shape: VestingShape::Linear,
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		let cliff_schedule = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 1_u32,
+			per_period: 10_u64,
+			shape: VestingShape::Cliff { cliff_periods: 1, cliff_amount: 10_u64 },
+		};
+		let checkpoints_schedule = VestingScheduleInfo {
+			window: MomentBased { start: 0_u64, period: 60000_u64 },
+			period_count: 0_u32,
+			per_period: 0_u64,
+			shape: VestingShape::Checkpoints(vec![(2, 20_u64)]),
+		};
+		assert_ok!(Vesting::update_vesting_schedules(
+			RuntimeOrigin::root(),
+			BOB,
+			MockCurrencyId::BTC,
+			vec![cliff_schedule, checkpoints_schedule],
+		));
+		assert_eq!(
+			Tokens::locks(&BOB, MockCurrencyId::BTC).get(0),
+			Some(&BalanceLock { id: VESTING_LOCK_ID, amount: 40_u64 })
+		);
+
+		// the cliff is reached, the checkpoint is not
+		System::set_block_number(10);
+		Timestamp::set_timestamp(System::block_number() * MILLISECS_PER_BLOCK);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All
+		));
+		assert_eq!(
+			Tokens::locks(&BOB, MockCurrencyId::BTC).get(0),
+			Some(&BalanceLock { id: VESTING_LOCK_ID, amount: 30_u64 })
+		);
+
+		System::set_block_number(20);
+		Timestamp::set_timestamp(System::block_number() * MILLISECS_PER_BLOCK);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All
+		));
+		assert_eq!(Tokens::locks(&BOB, MockCurrencyId::BTC), vec![]);
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+	});
+}
+
 #[test]
 fn update_vesting_schedules_works() {
 	ExtBuilder::build().execute_with(|| {
@@ -1127,6 +1379,7 @@ fn update_vesting_schedules_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1140,6 +1393,7 @@ fn update_vesting_schedules_works() {
 			window: MomentBased { start: 0_u64, period: 60000_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1153,11 +1407,13 @@ fn update_vesting_schedules_works() {
 			window: BlockNumberBased { start: 0_u64, period: 20_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		let updated_moment_based_schedule = VestingScheduleInfo {
 			window: MomentBased { start: 0_u64, period: 120000_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::update_vesting_schedules(
 			RuntimeOrigin::root(),
@@ -1216,6 +1472,7 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 0_u64, period: 1_u64 },
 			period_count: 100_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 
 		assert_ok!(Vesting::vested_transfer(
@@ -1231,6 +1488,7 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 0_u64, period: 1_u64 },
 			period_count: 50_u32,
 			per_period: 8_u64,
+			shape: VestingShape::Linear,
 		};
 
 		assert_ok!(Vesting::vested_transfer(
@@ -1260,6 +1518,7 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 30_u64, period: 1_u64 },
 			period_count: 60_u32,
 			per_period: 5_u64,
+			shape: VestingShape::Linear,
 		};
 
 		// Locks 200 * 2 = 400
@@ -1267,6 +1526,7 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 40_u64, period: 1_u64 },
 			period_count: 200_u32,
 			per_period: 2_u64,
+			shape: VestingShape::Linear,
 		};
 
 		// Unlocks all and locks 300 + 400 = 700
@@ -1307,6 +1567,7 @@ fn vested_transfer_check_for_min() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 1_u32,
 			per_period: 3_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -1328,6 +1589,7 @@ fn multiple_vesting_schedule_claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
 		assert_ok!(Vesting::vested_transfer(
@@ -1342,6 +1604,7 @@ fn multiple_vesting_schedule_claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 3_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		let schedule2 = VestingSchedule::from_input(5_u128, schedule2_input.clone());
 
@@ -1393,11 +1656,13 @@ fn exceeding_maximum_schedules_should_fail() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		let moment_schedule_input = VestingScheduleInfo {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			shape: VestingShape::Linear,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1,4 +1,4 @@
-use common::{AccountId, AuraId, Balance};
+use common::{fees::well_known_assets, AccountId, AuraId, Balance};
 use picasso_runtime::GenesisConfig;
 
 use super::{Extensions, ParaId};
@@ -70,9 +70,10 @@ pub fn genesis_config(
 		technical_committee: Default::default(),
 		technical_committee_membership: Default::default(),
 		relayer_xcm: Default::default(),
-		assets_registry: Default::default(),
+		assets_registry: picasso_runtime::AssetsRegistryConfig { assets: well_known_assets() },
 		tokens: Default::default(),
 		transaction_payment: Default::default(),
 		vesting: Default::default(),
+		bonded_finance: Default::default(),
 	}
 }
@@ -1,12 +1,16 @@
 use crate::{prelude::*, Balance};
-use composable_support::math::safe::safe_multiply_by_rational;
+use composable_support::{
+	collections::vec::bounded::BiBoundedVec, math::safe::safe_multiply_by_rational,
+};
 use composable_traits::{
-	currency::{AssetRatioInspect, Rational64},
+	assets::AssetInfo,
+	currency::{AssetExistentialDepositInspect, AssetRatioInspect, Rational64},
 	rational,
+	xcm::assets::XcmAssetLocation,
 };
 
 use frame_support::{
-	traits::ConstU128,
+	traits::{ConstU128, Get},
 	weights::{
 		constants::ExtrinsicBaseWeight, WeightToFeeCoefficient, WeightToFeeCoefficients,
 		WeightToFeePolynomial,
@@ -14,7 +18,7 @@ use frame_support::{
 };
 use primitives::currency::CurrencyId;
 use sp_runtime::Perbill;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub const NATIVE_EXISTENTIAL_DEPOSIT: NativeBalance = 100_000_000_000;
 pub type NativeExistentialDeposit = ConstU128<NATIVE_EXISTENTIAL_DEPOSIT>;
@@ -47,10 +51,19 @@ pub fn multi_existential_deposits<AssetsRegistry>(_currency_id: &CurrencyId) ->
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
-pub fn multi_existential_deposits<AssetsRegistry: AssetRatioInspect<AssetId = CurrencyId>>(
+pub fn multi_existential_deposits<
+	AssetsRegistry: AssetRatioInspect<AssetId = CurrencyId>
+		+ AssetExistentialDepositInspect<AssetId = CurrencyId, Balance = Balance>,
+>(
 	currency_id: &CurrencyId,
 ) -> Balance {
-	PriceConverter::<AssetsRegistry>::to_asset_balance(NATIVE_EXISTENTIAL_DEPOSIT, *currency_id)
+	AssetsRegistry::existential_deposit(*currency_id)
+		.or_else(|_| {
+			PriceConverter::<AssetsRegistry>::to_asset_balance(
+				NATIVE_EXISTENTIAL_DEPOSIT,
+				*currency_id,
+			)
+		})
 		.unwrap_or(Balance::MAX)
 }
 
@@ -93,6 +106,37 @@ impl WellKnownForeignToNativePriceConverter {
 
 pub type NativeBalance = Balance;
 
+/// Metadata of the well known assets, registered into the assets registry at genesis.
+pub fn well_known_assets() -> Vec<(CurrencyId, AssetInfo<Balance, XcmAssetLocation>)> {
+	CurrencyId::list_assets()
+		.into_iter()
+		.map(|asset| {
+			let asset_id = CurrencyId(asset.id);
+			let info = AssetInfo {
+				name: asset.name.and_then(|name| BiBoundedVec::try_from(name).ok()),
+				symbol: None,
+				decimals: asset.decimals,
+				existential_deposit: WellKnownForeignToNativePriceConverter::existential_deposit(
+					asset_id,
+				),
+				location: asset.foreign_id,
+				payable_as_fee: WellKnownForeignToNativePriceConverter::get_ratio(asset_id)
+					.is_some(),
+			};
+			(asset_id, info)
+		})
+		.collect()
+}
+
+/// The [`well_known_assets`], registered by the migration of the assets registry.
+pub struct WellKnownAssets;
+
+impl Get<Vec<(CurrencyId, AssetInfo<Balance, XcmAssetLocation>)>> for WellKnownAssets {
+	fn get() -> Vec<(CurrencyId, AssetInfo<Balance, XcmAssetLocation>)> {
+		well_known_assets()
+	}
+}
+
 impl<AssetsRegistry: AssetRatioInspect<AssetId = CurrencyId>>
 	frame_support::traits::tokens::BalanceConversion<NativeBalance, CurrencyId, Balance>
 	for PriceConverter<AssetsRegistry>
@@ -131,6 +175,10 @@ mod commons_sense {
 	impl AssetRatioInspect for Dummy {
 		type AssetId = CurrencyId;
 	}
+	impl AssetExistentialDepositInspect for Dummy {
+		type AssetId = CurrencyId;
+		type Balance = Balance;
+	}
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	#[test]
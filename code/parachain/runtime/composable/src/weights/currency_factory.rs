@@ -13,10 +13,4 @@ impl<T: frame_system::Config> currency_factory::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	// Storage: System Account (r:1 w:1)
-	fn set_metadata() -> Weight {
-		Weight::from_ref_time(59_284_000_u64)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
 }
@@ -46,6 +46,7 @@ pub use xcmp::{MaxInstructions, UnitWeightCost};
 use common::{
 	fees::{
 		multi_existential_deposits, NativeExistentialDeposit, PriceConverter, WeightToFeeConverter,
+		WellKnownAssets,
 	},
 	governance::native::{
 		EnsureRootOrHalfNativeCouncil, EnsureRootOrOneThirdNativeTechnical, NativeTreasury,
@@ -99,6 +100,7 @@ use codec::Encode;
 use common::fees::WellKnownForeignToNativePriceConverter;
 use composable_traits::{
 	account_proxy::{AccountProxyWrapper, ProxyType},
+	assets::AssetInfo,
 	currency::{CurrencyFactory as CurrencyFactoryT, RangeId, Rational64},
 	fnft::FnftAccountProxyType,
 	xcm::assets::{RemoteAssetRegistryMutate, XcmAssetLocation},
@@ -517,7 +519,7 @@ impl oracle::Config for Runtime {
 	type MaxPrePrices = MaxPrePrices;
 	type MsPerBlock = MsPerBlock;
 	type WeightInfo = weights::oracle::WeightInfo<Runtime>;
-	type LocalAssets = CurrencyFactory;
+	type LocalAssets = AssetsRegistry;
 	type Moment = Moment;
 	type Time = Timestamp;
 	type PalletId = OraclePalletId;
@@ -774,6 +776,7 @@ parameter_types! {
 	pub RentPerBlock: Balance = CurrencyId::milli();
 	pub const VaultMinimumDeposit: Balance = 10_000;
 	pub const VaultMinimumWithdrawal: Balance = 10_000;
+	pub const VaultMaxWithdrawalQueueLength: u32 = 256;
 	pub const VaultPalletId: PalletId = PalletId(*b"cubic___");
 	pub const TombstoneDuration: BlockNumber = DAYS * 7;
 }
@@ -793,6 +796,7 @@ impl vault::Config for Runtime {
 	type NativeCurrency = Balances;
 	type MinimumDeposit = VaultMinimumDeposit;
 	type MinimumWithdrawal = VaultMinimumWithdrawal;
+	type MaxWithdrawalQueueLength = VaultMaxWithdrawalQueueLength;
 	type TombstoneDuration = TombstoneDuration;
 	type VaultId = u64;
 	type WeightInfo = weights::vault::WeightInfo<Runtime>;
@@ -814,6 +818,8 @@ impl assets_registry::Config for Runtime {
 	type UpdateAssetRegistryOrigin = EnsureRootOrHalfNativeCouncil;
 	type ParachainOrGovernanceOrigin = EnsureRootOrHalfNativeCouncil;
 	type Balance = Balance;
+	type NativeExistentialDeposit = NativeExistentialDeposit;
+	type WellKnownAssets = WellKnownAssets;
 	type WeightInfo = weights::assets_registry::WeightInfo<Runtime>;
 }
 
@@ -863,6 +869,7 @@ parameter_types! {
 	pub const StakingRewardsPalletId : PalletId = PalletId(*b"stk_rwrd");
 	pub const MaxStakingDurationPresets : u32 = 10;
 	pub const MaxRewardConfigsPerPool : u32 = 10;
+	pub const MaxRewardRateChanges : u32 = 10;
 	pub const PicaAssetId : CurrencyId = CurrencyId::PICA;
 	pub const PbloAssetId : CurrencyId = CurrencyId::PBLO;
 	pub const XPicaAssetId: CurrencyId = CurrencyId::xPICA;
@@ -883,6 +890,7 @@ impl pallet_staking_rewards::Config for Runtime {
 	type PalletId = StakingRewardsPalletId;
 	type MaxStakingDurationPresets = MaxStakingDurationPresets;
 	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxRewardRateChanges = MaxRewardRateChanges;
 	type RewardPoolCreationOrigin = EnsureRootOrHalfNativeCouncil;
 	type WeightInfo = weights::pallet_staking_rewards::WeightInfo<Runtime>;
 	type RewardPoolUpdateOrigin = EnsureRootOrHalfNativeCouncil;
@@ -923,6 +931,7 @@ impl call_filter::Config for Runtime {
 
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 100;
+	pub const MaxVestingCheckpoints: u32 = 32;
 	pub MinVestedTransfer: u64 = 10 * CurrencyId::unit::<u64>();
 }
 
@@ -930,6 +939,7 @@ impl vesting::Config for Runtime {
 	type Currency = Assets;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxVestingSchedules = MaxVestingSchedule;
+	type MaxVestingCheckpoints = MaxVestingCheckpoints;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrHalfNativeCouncil;
 	type UpdateSchedulesOrigin = EnsureRootOrHalfNativeCouncil;
@@ -944,6 +954,7 @@ parameter_types! {
 	pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
 	pub MinReward: Balance = 100 * CurrencyId::unit::<Balance>();
 	pub Stake: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub const BondFinancialNftCollectionId: CurrencyId = CurrencyId::BOND_FNFT_COLLECTION;
 }
 
 impl bonded_finance::Config for Runtime {
@@ -957,11 +968,15 @@ impl bonded_finance::Config for Runtime {
 	type PalletId = BondedFinanceId;
 	type Stake = Stake;
 	type Vesting = Vesting;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FinancialNftCollectionId = BondFinancialNftCollectionId;
 	type WeightInfo = weights::bonded_finance::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const DutchAuctionId: PalletId = PalletId(*b"dtch_ctn");
+	pub const ReservePriceChecksPerBlock: u32 = 16;
 }
 
 impl composable_traits::defi::DeFiComposableConfig for Runtime {
@@ -981,6 +996,8 @@ impl dutch_auction::Config for Runtime {
 	type XcmOrigin = RuntimeOrigin;
 	type AdminOrigin = EnsureRootOrHalfNativeCouncil;
 	type XcmSender = XcmRouter;
+	type ReservePriceChecksPerBlock = ReservePriceChecksPerBlock;
+	type OrderCallback = Liquidations;
 }
 
 parameter_types! {
@@ -1009,6 +1026,9 @@ pub type OrderId = u128;
 
 parameter_types! {
 	pub const LiquidationsPalletId: PalletId = PalletId(*b"liqdatns");
+	pub LiquidationPokeReward: Balance = 10 * CurrencyId::milli::<Balance>();
+	pub const LiquidationStalledAfter: u64 = 24 * 60 * 60;
+	pub const LiquidationMaxFinalizedOrdersPerBlock: u32 = 10;
 }
 
 impl liquidations::Config for Runtime {
@@ -1021,6 +1041,13 @@ impl liquidations::Config for Runtime {
 	type PalletId = LiquidationsPalletId;
 	type CanModifyStrategies = EnsureRootOrHalfNativeCouncil;
 	type XcmSender = XcmRouter;
+	type Dex = DexRouter;
+	type Oracle = Oracle;
+	type Callback = Lending;
+	type NativeCurrency = Balances;
+	type PokeReward = LiquidationPokeReward;
+	type StalledAfter = LiquidationStalledAfter;
+	type MaxFinalizedOrdersPerBlock = LiquidationMaxFinalizedOrdersPerBlock;
 	type MaxLiquidationStrategiesAmount = ConstU32<10>;
 }
 
@@ -1029,6 +1056,8 @@ parameter_types! {
 	pub LendingPalletId: PalletId = PalletId(*b"liqiudat");
 	pub OracleMarketCreationStake: Balance = 300;
 	pub const MaxLiquidationBatchSize: u32 = 1000;
+	pub const LendingFinancialNftLockId: LockIdentifier = *b"lend_nft";
+	pub const MaxFinancialNftCollaterals: u32 = 16;
 }
 
 impl lending::Config for Runtime {
@@ -1050,6 +1079,12 @@ impl lending::Config for Runtime {
 	type NativeCurrency = Balances;
 	type MaxLiquidationBatchSize = MaxLiquidationBatchSize;
 	type WeightToFee = WeightToFeeConverter;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FinancialNftProtocol = StakingRewards;
+	type Staking = StakingRewards;
+	type FinancialNftLockId = LendingFinancialNftLockId;
+	type MaxFinancialNftCollaterals = MaxFinancialNftCollaterals;
 }
 
 parameter_types! {
@@ -1066,7 +1101,7 @@ impl pablo::Config for Runtime {
 	type Assets = Assets;
 	type PoolId = PoolId;
 	type PalletId = PabloId;
-	type LocalAssets = CurrencyFactory;
+	type LocalAssets = AssetsRegistry;
 	type PoolCreationOrigin = EnsureRootOrHalfNativeCouncil;
 	// TODO: consider making it is own origin
 	type EnableTwapOrigin = EnsureRootOrHalfNativeCouncil;
@@ -1252,11 +1287,10 @@ impl DenomToAssetId<Runtime> for IbcDenomToAssetIdConversion {
 			1,
 			X1(Junction::GeneralIndex(asset_id.into())),
 		));
-		assets_registry::Pallet::<Runtime>::set_reserve_location(
+		assets_registry::Pallet::<Runtime>::set_asset_info(
 			asset_id,
-			location,
 			Rational64::one(),
-			Some(12),
+			AssetInfo::foreign(location, Some(12)),
 		)?;
 
 		Ok(asset_id)
@@ -1486,7 +1520,7 @@ mod benches {
 }
 
 impl_runtime_apis! {
-	impl lending_runtime_api::LendingRuntimeApi<Block, MarketId> for Runtime {
+	impl lending_runtime_api::LendingRuntimeApi<Block, MarketId, Balance> for Runtime {
 		fn current_interest_rate(_market_id: MarketId) -> SafeRpcWrapper<Rate> {
 			SafeRpcWrapper(
 				// TODO: Actually implement this
@@ -1495,6 +1529,45 @@ impl_runtime_apis! {
 				// 	.unwrap_or_else(Rate::zero)
 			)
 		}
+
+		fn reserve_balance(market_id: MarketId) -> SafeRpcWrapper<Balance> {
+			SafeRpcWrapper(lending::MarketReserves::<Runtime>::get(market_id))
+		}
+
+		fn bad_debt(market_id: MarketId) -> SafeRpcWrapper<Balance> {
+			SafeRpcWrapper(lending::BadDebt::<Runtime>::get(market_id))
+		}
+	}
+
+	impl staking_rewards_runtime_api::StakingRewardsRuntimeApi<Block, CurrencyId, AccountId, Balance> for Runtime {
+		fn voting_power(pool_id: CurrencyId, account: AccountId) -> SafeRpcWrapper<Balance> {
+			SafeRpcWrapper(
+				<StakingRewards as composable_traits::staking::StakingVotingPower>::voting_power(&pool_id, &account)
+			)
+		}
+	}
+
+	impl fnft_runtime_api::FnftRuntimeApi<Block, CurrencyId, FinancialNftInstanceId, AccountId, Balance> for Runtime {
+		fn metadata(
+			collection: CurrencyId,
+			instance: FinancialNftInstanceId,
+		) -> Option<composable_traits::fnft::FinancialNftMetadata<AccountId, CurrencyId, SafeRpcWrapper<Balance>>> {
+			use composable_traits::fnft::FinancialNftProtocol;
+
+			let value = if StakingRewards::collection_asset_ids().contains(&collection) {
+				StakingRewards::value_of(&collection, &instance).unwrap_or_default()
+			} else if BondedFinance::collection_asset_ids().contains(&collection) {
+				BondedFinance::value_of(&collection, &instance).unwrap_or_default()
+			} else {
+				Vec::new()
+			};
+
+			Fnft::metadata(
+				&collection,
+				&instance,
+				value.into_iter().map(|(asset_id, balance)| (asset_id, SafeRpcWrapper(balance))).collect(),
+			)
+		}
 	}
 
 	impl assets_runtime_api::AssetsRuntimeApi<Block, CurrencyId, AccountId, Balance, ForeignAssetId> for Runtime {
@@ -1508,25 +1581,25 @@ impl_runtime_apis! {
 				// Add hardcoded ratio and ED for well known assets
 				asset.ratio = WellKnownForeignToNativePriceConverter::get_ratio(CurrencyId(asset.id));
 				asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&asset.id.into());
+				asset.payable_as_fee = asset.ratio.is_some();
 				asset
 			}).collect::<Vec<_>>();
+
 			// Assets from the assets-registry pallet
-			let foreign_assets = assets_registry::Pallet::<Runtime>::get_foreign_assets_list();
+			let registered_assets = assets_registry::Pallet::<Runtime>::get_foreign_assets_list();
 
-			// Override asset data for hardcoded assets that have been manually updated, and append
+			// Override asset data for hardcoded assets that have been registered, and append
 			// new assets without duplication
-			foreign_assets.into_iter().fold(assets, |mut acc, mut foreign_asset| {
-				if let Some(i) = acc.iter().position(|asset_i| asset_i.id == foreign_asset.id) {
-					// Assets that have been updated
-					if let Some(asset) = acc.get_mut(i) {
-						// Update asset with data from assets-registry
-						asset.decimals = foreign_asset.decimals;
-						asset.foreign_id = foreign_asset.foreign_id.clone();
-						asset.ratio = foreign_asset.ratio;
-					}
+			registered_assets.into_iter().fold(assets, |mut acc, mut registered_asset| {
+				registered_asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&registered_asset.id.into());
+				if let Some(asset) = acc.iter_mut().find(|asset_i| asset_i.id == registered_asset.id) {
+					// The assets-registry is the source of truth, hardcoded name and ratio are kept
+					// if not registered
+					registered_asset.name = registered_asset.name.or_else(|| asset.name.take());
+					registered_asset.ratio = registered_asset.ratio.or(asset.ratio);
+					*asset = registered_asset;
 				} else {
-					foreign_asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&foreign_asset.id.into());
-					acc.push(foreign_asset.clone())
+					acc.push(registered_asset)
 				}
 				acc
 			})
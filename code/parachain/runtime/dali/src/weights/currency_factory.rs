@@ -36,9 +36,4 @@ impl<T: frame_system::Config> currency_factory::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	// Storage: CurrencyFactory AssetMetadata (r:0 w:1)
-	fn set_metadata() -> Weight {
-		Weight::from_ref_time(5_343_000_u64)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
 }
@@ -11,4 +11,10 @@ impl<T: frame_system::Config> pallet_fnft::WeightInfo for WeightInfo<T> {
 	fn transfer() -> Weight {
 		Weight::from_ref_time(10_000)
 	}
+	fn approve() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+	fn set_approval_for_all() -> Weight {
+		Weight::from_ref_time(10_000)
+	}
 }
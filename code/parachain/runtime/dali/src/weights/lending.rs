@@ -187,4 +187,57 @@ impl<T: frame_system::Config> lending::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// Storage: Lending Markets (r:1 w:0)
+	// Storage: StakingRewards RewardPools (r:1 w:0)
+	// Storage: Lending FinancialNftCollaterals (r:0 w:1)
+	fn set_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(38_912_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Lending Markets (r:1 w:0)
+	// Storage: Lending FinancialNftCollaterals (r:1 w:0)
+	// Storage: Fnft Instance (r:1 w:1)
+	// Storage: Lending AccountFinancialNftCollateral (r:1 w:1)
+	// Storage: Fnft Locks (r:1 w:1)
+	// Storage: Fnft OwnerInstances (r:2 w:2)
+	fn deposit_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(98_633_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Lending Markets (r:1 w:0)
+	// Storage: Lending AccountFinancialNftCollateral (r:1 w:1)
+	// Storage: Lending AccountCollateral (r:1 w:0)
+	// Storage: Oracle PriceHistory (r:3 w:0)
+	// Storage: Oracle Prices (r:3 w:0)
+	// Storage: StakingRewards Stakes (r:15 w:0)
+	// Storage: StakingRewards RewardPools (r:1 w:0)
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Lending DebtTokenForMarket (r:1 w:0)
+	// Storage: Lending DebtIndex (r:1 w:0)
+	// Storage: Fnft Locks (r:1 w:1)
+	// Storage: Fnft Instance (r:1 w:1)
+	// Storage: Fnft OwnerInstances (r:2 w:2)
+	fn withdraw_financial_nft_collateral() -> Weight {
+		Weight::from_ref_time(271_054_000_u64)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Lending SeizedFinancialNfts (r:1 w:1)
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Lending DebtTokenForMarket (r:1 w:0)
+	// Storage: StakingRewards Stakes (r:1 w:0)
+	// Storage: StakingRewards RewardPools (r:1 w:0)
+	// Storage: Oracle PriceHistory (r:2 w:0)
+	// Storage: Oracle Prices (r:2 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Fnft Instance (r:1 w:1)
+	// Storage: Fnft OwnerInstances (r:2 w:2)
+	// Storage: Lending MarketReserves (r:1 w:1)
+	fn buy_seized_financial_nft() -> Weight {
+		Weight::from_ref_time(145_208_000_u64)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 }
@@ -52,4 +52,28 @@ impl<T: frame_system::Config> liquidations::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x as u64)))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// Storage: Liquidations LiquidationOrders (r:1 w:1)
+	// Storage: DutchAuction SellOrders (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DutchAuction LocalOrderIdToRemote (r:1 w:0)
+	// Storage: Liquidations EngineOrders (r:1 w:1)
+	// Storage: Liquidations FinalizedOrders (r:0 w:1)
+	fn poke() -> Weight {
+		Weight::from_ref_time(150_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Liquidations FinalizedOrders (r:11 w:10)
+	// Storage: Liquidations LiquidationOrders (r:10 w:0)
+	// Storage: Lending PendingLiquidations (r:10 w:10)
+	/// The range of component `x` is `[0, 10]`.
+	fn on_finalize(x: u32, ) -> Weight {
+		Weight::from_ref_time(7_804_000_u64)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_ref_time(28_617_000_u64).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x as u64)))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x as u64)))
+	}
 }
@@ -154,4 +154,52 @@ impl<T: frame_system::Config> pallet_staking_rewards::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// Storage: Fnft Instance (r:1 w:0)
+	// Storage: StakingRewards Stakes (r:1 w:0)
+	// Storage: StakingRewards AutoCompound (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_ref_time(38_412_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Fnft Instance (r:2 w:1)
+	// Storage: StakingRewards Stakes (r:2 w:2)
+	// Storage: StakingRewards RewardPools (r:1 w:0)
+	// Storage: Tokens Locks (r:4 w:4)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: System Account (r:2 w:1)
+	// Storage: StakingRewards AutoCompound (r:0 w:1)
+	// Storage: Fnft OwnerInstances (r:1 w:1)
+	/// The range of component `r` is `[1, 10]`.
+	fn merge(r: u32, ) -> Weight {
+		Weight::from_ref_time(398_215_000_u64)
+			// Standard Error: 902_000
+			.saturating_add(Weight::from_ref_time(1_318_000_u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	// Storage: StakingRewards Stakes (r:1 w:1)
+	// Storage: StakingRewards RewardPools (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: Tokens Locks (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `r` is `[1, 10]`.
+	fn compound(r: u32, ) -> Weight {
+		Weight::from_ref_time(221_504_000_u64)
+			// Standard Error: 587_000
+			.saturating_add(Weight::from_ref_time(4_212_000_u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: StakingRewards RewardPools (r:1 w:0)
+	// Storage: StakingRewards RewardRateSchedules (r:0 w:1)
+	/// The range of component `c` is `[1, 10]`.
+	fn set_reward_rate_schedule(c: u32, ) -> Weight {
+		Weight::from_ref_time(31_806_000_u64)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_ref_time(312_000_u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
@@ -86,11 +86,37 @@ impl<T: frame_system::Config> vault::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Vault Vaults (r:1 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
 	// Storage: System Account (r:1 w:0)
 	// Storage: Vault LpTokensToVaults (r:0 w:1)
-	fn delete_tombstoned() -> Weight {
+	fn delete_tombstoned(n: u32, ) -> Weight {
 		Weight::from_ref_time(42_146_000_u64)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(Weight::from_ref_time(58_906_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	// Storage: Tokens TotalIssuance (r:2 w:1)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_ref_time(181_530_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault WithdrawalQueue (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn settle_withdrawals(n: u32, ) -> Weight {
+		Weight::from_ref_time(47_882_000_u64)
+			.saturating_add(Weight::from_ref_time(58_906_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n as u64)))
 	}
 }
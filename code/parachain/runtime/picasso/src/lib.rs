@@ -35,19 +35,21 @@ pub use xcmp::XcmConfig;
 use common::{
 	fees::{
 		multi_existential_deposits, NativeExistentialDeposit, PriceConverter, WeightToFeeConverter,
-		WellKnownForeignToNativePriceConverter,
+		WellKnownAssets, WellKnownForeignToNativePriceConverter,
 	},
 	governance::native::*,
 	rewards::StakingPot,
 	AccountId, AccountIndex, Address, Amount, AuraId, Balance, BlockNumber, BondOfferId,
-	ForeignAssetId, Hash, MaxStringSize, Moment, PoolId, ReservedDmpWeight, ReservedXcmpWeight,
-	Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MILLISECS_PER_BLOCK,
-	NORMAL_DISPATCH_RATIO, SLOT_DURATION,
+	FinancialNftInstanceId, ForeignAssetId, Hash, MaxStringSize, Moment, PoolId, ReservedDmpWeight,
+	ReservedXcmpWeight, Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT,
+	MILLISECS_PER_BLOCK, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
 };
 use composable_support::rpc_helpers::SafeRpcWrapper;
 use composable_traits::{
+	account_proxy::AccountProxyWrapper,
 	assets::Asset,
 	dex::{Amm, PriceAggregate},
+	fnft::FnftAccountProxyType,
 	xcm::assets::RemoteAssetRegistryInspect,
 };
 use governance::*;
@@ -247,6 +249,8 @@ impl assets_registry::Config for Runtime {
 	type UpdateAssetRegistryOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type ParachainOrGovernanceOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type CurrencyFactory = CurrencyFactory;
+	type NativeExistentialDeposit = NativeExistentialDeposit;
+	type WellKnownAssets = WellKnownAssets;
 	type WeightInfo = weights::assets_registry::WeightInfo<Runtime>;
 }
 
@@ -264,7 +268,7 @@ impl pablo::Config for Runtime {
 	type Assets = Assets;
 	type PoolId = PoolId;
 	type PalletId = PabloPalletId;
-	type LocalAssets = CurrencyFactory;
+	type LocalAssets = AssetsRegistry;
 	type PoolCreationOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type EnableTwapOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type Time = Timestamp;
@@ -749,6 +753,7 @@ impl crowdloan_rewards::Config for Runtime {
 
 parameter_types! {
 	  pub const MaxVestingSchedule: u32 = 128;
+	  pub const MaxVestingCheckpoints: u32 = 32;
 	  pub MinVestedTransfer: u64 = CurrencyId::milli::<u64>();
 }
 
@@ -756,6 +761,7 @@ impl vesting::Config for Runtime {
 	type Currency = Assets;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxVestingSchedules = MaxVestingSchedule;
+	type MaxVestingCheckpoints = MaxVestingCheckpoints;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type UpdateSchedulesOrigin = EnsureRootOrTwoThirdNativeCouncil;
@@ -765,11 +771,28 @@ impl vesting::Config for Runtime {
 	type VestingScheduleId = u128;
 }
 
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+impl pallet_fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProperties = ConstU32<16>;
+	type FinancialNftCollectionId = CurrencyId;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type ProxyType = ProxyType;
+	type AccountProxy = AccountProxyWrapper<Runtime>;
+	type ProxyTypeSelector = FnftAccountProxyType;
+	type PalletId = FnftPalletId;
+	type WeightInfo = weights::fnft::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// cspell:disable-next
 	  pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
 	  pub MinReward: Balance = 10 * CurrencyId::unit::<Balance>();
 	  pub Stake: Balance = 10 * CurrencyId::unit::<Balance>();
+	  pub const BondFinancialNftCollectionId: CurrencyId = CurrencyId::BOND_FNFT_COLLECTION;
 }
 
 impl bonded_finance::Config for Runtime {
@@ -783,6 +806,9 @@ impl bonded_finance::Config for Runtime {
 	type PalletId = BondedFinanceId;
 	type Stake = Stake;
 	type Vesting = Vesting;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FinancialNftCollectionId = BondFinancialNftCollectionId;
 	type WeightInfo = weights::bonded_finance::WeightInfo<Runtime>;
 }
 
@@ -866,6 +892,7 @@ construct_runtime!(
 		BondedFinance: bonded_finance = 58,
 		AssetsRegistry: assets_registry = 59,
 		Pablo: pablo = 60,
+		Fnft: pallet_fnft = 61,
 
 		CallFilter: call_filter = 100,
 	}
@@ -945,23 +972,25 @@ impl_runtime_apis! {
 				// Add hardcoded ratio and ED for well known assets
 				asset.ratio = WellKnownForeignToNativePriceConverter::get_ratio(CurrencyId(asset.id));
 				asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&asset.id.into());
+				asset.payable_as_fee = asset.ratio.is_some();
 				asset
 			}).collect::<Vec<_>>();
 
 			// Assets from the assets-registry pallet
-			let foreign_assets = assets_registry::Pallet::<Runtime>::get_foreign_assets_list();
+			let registered_assets = assets_registry::Pallet::<Runtime>::get_foreign_assets_list();
 
-			// Override asset data for hardcoded assets that have been manually updated, and append
+			// Override asset data for hardcoded assets that have been registered, and append
 			// new assets without duplication
-			foreign_assets.into_iter().fold(assets, |mut acc, mut foreign_asset| {
-				if let Some(asset) = acc.iter_mut().find(|asset_i| asset_i.id == foreign_asset.id) {
-					// Update asset with data from assets-registry
-					asset.decimals = foreign_asset.decimals;
-					asset.foreign_id = foreign_asset.foreign_id.clone();
-					asset.ratio = foreign_asset.ratio;
+			registered_assets.into_iter().fold(assets, |mut acc, mut registered_asset| {
+				registered_asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&registered_asset.id.into());
+				if let Some(asset) = acc.iter_mut().find(|asset_i| asset_i.id == registered_asset.id) {
+					// The assets-registry is the source of truth, hardcoded name and ratio are kept
+					// if not registered
+					registered_asset.name = registered_asset.name.or_else(|| asset.name.take());
+					registered_asset.ratio = registered_asset.ratio.or(asset.ratio);
+					*asset = registered_asset;
 				} else {
-					foreign_asset.existential_deposit = multi_existential_deposits::<AssetsRegistry>(&foreign_asset.id.into());
-					acc.push(foreign_asset.clone())
+					acc.push(registered_asset)
 				}
 				acc
 			})
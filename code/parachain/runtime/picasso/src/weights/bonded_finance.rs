@@ -57,4 +57,35 @@ impl<T: frame_system::Config> bonded_finance::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	// Storage: BondedFinance BondOffers (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn cancel_partially() -> Weight {
+		Weight::from_ref_time(104_906_000_u64)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Fnft Instance (r:1 w:1)
+	// Storage: BondedFinance BondPositions (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Fnft OwnerInstances (r:1 w:1)
+	// Storage: Fnft Locks (r:1 w:0)
+	// Storage: Fnft Approvals (r:0 w:1)
+	fn redeem() -> Weight {
+		Weight::from_ref_time(184_237_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: Fnft Instance (r:1 w:1)
+	// Storage: BondedFinance BondPositions (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: Fnft OwnerInstances (r:1 w:1)
+	// Storage: Fnft Locks (r:1 w:0)
+	// Storage: Fnft Approvals (r:0 w:1)
+	fn redeem_early() -> Weight {
+		Weight::from_ref_time(241_059_000_u64)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
 }
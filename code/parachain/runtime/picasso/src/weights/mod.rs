@@ -8,6 +8,7 @@ pub mod collective;
 pub mod crowdloan_rewards;
 pub mod currency_factory;
 pub mod democracy;
+pub mod fnft;
 pub mod frame_system;
 pub mod identity;
 pub mod indices;
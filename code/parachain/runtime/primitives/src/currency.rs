@@ -140,10 +140,12 @@ macro_rules! list_assets {
 				$(Asset {
 					id: CurrencyId::$NAME.0 as u128,
 					name: Some(stringify!($NAME).as_bytes().to_vec()),
+					symbol: None,
 					ratio: None,
 					decimals: Self::remote_decimals_for_local(CurrencyId::$NAME).unwrap_or(Self::decimals()),
 					foreign_id: Self::local_to_xcm_reserve(CurrencyId::$NAME).map(XcmAssetLocation::new),
 					existential_deposit: 0_u128,
+					payable_as_fee: false,
 				},)*
 			]
 			.to_vec()
@@ -198,6 +200,8 @@ impl CurrencyId {
 		pub const PICA_STAKE_FNFT_COLLECTION: CurrencyId = CurrencyId(2001, None);
 		/// PBLO Stake fNFT Collection
 		pub const PBLO_STAKE_FNFT_COLLECTION: CurrencyId = CurrencyId(2005, None);
+		/// Bond positions fNFT Collection
+		pub const BOND_FNFT_COLLECTION: CurrencyId = CurrencyId(2100, None);
 
 		// Non-Native Tokens (101 - 1000)
 		/// Karura KAR
@@ -0,0 +1,67 @@
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use serde::de::DeserializeOwned;
+use std::{collections::VecDeque, path::Path, process::ExitCode};
+use xcvm_core::{Funds, Instruction, NetworkId, Program, ValidationContext};
+
+/// Accounts are not inspected, any JSON value is accepted.
+type AnyProgram = Program<VecDeque<Instruction<NetworkId, Vec<u8>, serde_json::Value, Funds>>>;
+
+#[derive(Parser, Debug)]
+#[clap(version = "1.0", author = "Composable")]
+pub struct Opts {
+	#[clap(subcommand)]
+	pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+	/// Validate a program against the networks described by a context.
+	Validate {
+		/// Path of the JSON encoded `ValidationContext`.
+		#[clap(short, long)]
+		context: String,
+		/// Network the program is submitted on.
+		#[clap(short, long)]
+		network: u32,
+		/// Path of the JSON encoded program.
+		#[clap(short, long)]
+		program: String,
+	},
+	/// Estimate the funds and hops required by a program on each network.
+	Estimate {
+		/// Network the program is submitted on.
+		#[clap(short, long)]
+		network: u32,
+		/// Path of the JSON encoded program.
+		#[clap(short, long)]
+		program: String,
+	},
+}
+
+fn read_json<T: DeserializeOwned>(path: &str) -> Result<T> {
+	let data = std::fs::read(Path::new(path)).with_context(|| format!("Failed to read {path}"))?;
+	serde_json::from_slice(&data).with_context(|| format!("Failed to decode {path}"))
+}
+
+fn main() -> Result<ExitCode> {
+	match Opts::parse().command {
+		Command::Validate { context, network, program } => {
+			let context = read_json::<ValidationContext>(&context)?;
+			let program = read_json::<AnyProgram>(&program)?;
+			match xcvm_core::validate(&context, NetworkId(network), &program) {
+				Ok(()) => Ok(ExitCode::SUCCESS),
+				Err(errors) => {
+					println!("{}", serde_json::to_string_pretty(&errors)?);
+					Ok(ExitCode::FAILURE)
+				},
+			}
+		},
+		Command::Estimate { network, program } => {
+			let program = read_json::<AnyProgram>(&program)?;
+			let estimate = xcvm_core::estimate(NetworkId(network), &program);
+			println!("{}", serde_json::to_string_pretty(&estimate)?);
+			Ok(ExitCode::SUCCESS)
+		},
+	}
+}
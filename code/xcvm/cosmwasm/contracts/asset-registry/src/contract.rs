@@ -1,31 +1,39 @@
 use crate::{
 	error::ContractError,
 	msg::{
-		AssetKey, AssetReference, ExecuteMsg, InstantiateMsg, LookupResponse, MigrateMsg, QueryMsg,
+		AssetKey, AssetMetadata, AssetReference, AssetsResponse, ExecuteMsg, InstantiateMsg,
+		LookupByDenomResponse, LookupResponse, MetadataResponse, MigrateMsg, QueryMsg,
+		RemoteAssetReference, RemoteAssetsResponse, RemoteLookupResponse,
 	},
-	state::ASSETS,
+	state::{ADMIN, ASSETS, ASSET_METADATA, DENOM_ASSETS, REMOTE_ASSETS},
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-	to_binary, Binary, Deps, DepsMut, Env, Event, MessageInfo, QuerierWrapper, Response, StdResult,
-	WasmQuery,
+	to_binary, Addr, Binary, ContractInfoResponse, Deps, DepsMut, Env, Event, MessageInfo, Order,
+	QuerierWrapper, Response, StdError, StdResult, WasmQuery,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use cw_utils::ensure_from_older_version;
+use xcvm_core::NetworkId;
 
 const CONTRACT_NAME: &str = "composable:xcvm-asset-registry";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const XCVM_ASSET_REGISTRY_EVENT_PREFIX: &str = "xcvm.registry";
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
 	deps: DepsMut,
 	_env: Env,
-	_info: MessageInfo,
+	info: MessageInfo,
 	_msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
 	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	ADMIN.save(deps.storage, &info.sender)?;
 	Ok(Response::default().add_event(
 		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX).add_attribute("action", "instantiated"),
 	))
@@ -35,19 +43,41 @@ pub fn instantiate(
 pub fn execute(
 	deps: DepsMut,
 	_env: Env,
-	_info: MessageInfo,
+	info: MessageInfo,
 	msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
 	match msg {
 		ExecuteMsg::RegisterAsset { asset_id, reference } =>
 			handle_register_asset(deps, asset_id, reference),
 		ExecuteMsg::UnregisterAsset { asset_id } => handle_unregister_asset(deps, asset_id),
+		ExecuteMsg::SetAssetMetadata { asset_id, metadata } => {
+			ensure_admin(deps.as_ref(), &info.sender)?;
+			handle_set_asset_metadata(deps, asset_id, metadata)
+		},
+		ExecuteMsg::RegisterRemoteAsset { asset_id, network_id, reference } => {
+			ensure_admin(deps.as_ref(), &info.sender)?;
+			handle_register_remote_asset(deps, asset_id, network_id, reference)
+		},
+		ExecuteMsg::UnregisterRemoteAsset { asset_id, network_id } => {
+			ensure_admin(deps.as_ref(), &info.sender)?;
+			handle_unregister_remote_asset(deps, asset_id, network_id)
+		},
 	}
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
 	let _ = ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	// Older versions had no admin, the admin of the contract, which is migrating it, becomes one.
+	if ADMIN.may_load(deps.storage)?.is_none() {
+		let contract_info: ContractInfoResponse = deps.querier.query(
+			&WasmQuery::ContractInfo { contract_addr: env.contract.address.into_string() }.into(),
+		)?;
+		let admin = contract_info
+			.admin
+			.ok_or_else(|| StdError::generic_err("the contract has no admin"))?;
+		ADMIN.save(deps.storage, &deps.api.addr_validate(&admin)?)?;
+	}
 	Ok(Response::default())
 }
 
@@ -55,6 +85,14 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 	match msg {
 		QueryMsg::Lookup { asset_id } => to_binary(&query_lookup(deps, asset_id)?),
+		QueryMsg::LookupByDenom { denom } => to_binary(&query_lookup_by_denom(deps, denom)?),
+		QueryMsg::Metadata { asset_id } => to_binary(&query_metadata(deps, asset_id)?),
+		QueryMsg::RemoteLookup { asset_id, network_id } =>
+			to_binary(&query_remote_lookup(deps, asset_id, network_id)?),
+		QueryMsg::Assets { start_after, limit } =>
+			to_binary(&query_assets(deps, start_after, limit)?),
+		QueryMsg::RemoteAssets { asset_id, start_after, limit } =>
+			to_binary(&query_remote_assets(deps, asset_id, start_after, limit)?),
 	}
 }
 
@@ -63,7 +101,17 @@ pub fn handle_register_asset(
 	asset_id: AssetKey,
 	reference: AssetReference,
 ) -> Result<Response, ContractError> {
+	match DENOM_ASSETS.may_load(deps.storage, reference.denom())? {
+		Some(registered) if registered != asset_id =>
+			return Err(ContractError::DenomAlreadyRegistered),
+		_ => {},
+	}
+	// The previous reference of the asset is replaced, so is its denom.
+	if let Some(previous) = ASSETS.may_load(deps.storage, asset_id)? {
+		DENOM_ASSETS.remove(deps.storage, previous.denom());
+	}
 	ASSETS.save(deps.storage, asset_id, &reference)?;
+	DENOM_ASSETS.save(deps.storage, reference.denom(), &asset_id)?;
 	Ok(Response::new().add_event(
 		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX)
 			.add_attribute("action", "register")
@@ -76,7 +124,18 @@ pub fn handle_unregister_asset(
 	deps: DepsMut,
 	asset_id: AssetKey,
 ) -> Result<Response, ContractError> {
+	if let Some(reference) = ASSETS.may_load(deps.storage, asset_id)? {
+		DENOM_ASSETS.remove(deps.storage, reference.denom());
+	}
 	ASSETS.remove(deps.storage, asset_id);
+	ASSET_METADATA.remove(deps.storage, asset_id);
+	let networks = REMOTE_ASSETS
+		.prefix(asset_id)
+		.keys(deps.storage, None, None, Order::Ascending)
+		.collect::<StdResult<Vec<_>>>()?;
+	for network_id in networks {
+		REMOTE_ASSETS.remove(deps.storage, (asset_id, network_id.into()));
+	}
 	Ok(Response::new().add_event(
 		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX)
 			.add_attribute("action", "unregister")
@@ -84,11 +143,124 @@ pub fn handle_unregister_asset(
 	))
 }
 
+pub fn handle_set_asset_metadata(
+	deps: DepsMut,
+	asset_id: AssetKey,
+	metadata: AssetMetadata,
+) -> Result<Response, ContractError> {
+	ensure_registered(deps.as_ref(), asset_id)?;
+	ASSET_METADATA.save(deps.storage, asset_id, &metadata)?;
+	Ok(Response::new().add_event(
+		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX)
+			.add_attribute("action", "set_metadata")
+			.add_attribute("asset_id", format!("{:?}", asset_id))
+			.add_attribute("decimals", format!("{}", metadata.decimals))
+			.add_attribute("symbol", metadata.symbol),
+	))
+}
+
+pub fn handle_register_remote_asset(
+	deps: DepsMut,
+	asset_id: AssetKey,
+	network_id: NetworkId,
+	reference: RemoteAssetReference,
+) -> Result<Response, ContractError> {
+	ensure_registered(deps.as_ref(), asset_id)?;
+	REMOTE_ASSETS.save(deps.storage, (asset_id, network_id), &reference)?;
+	Ok(Response::new().add_event(
+		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX)
+			.add_attribute("action", "register_remote")
+			.add_attribute("asset_id", format!("{:?}", asset_id))
+			.add_attribute("network_id", format!("{}", u32::from(network_id)))
+			.add_attribute("reference", format!("{:?}", reference)),
+	))
+}
+
+pub fn handle_unregister_remote_asset(
+	deps: DepsMut,
+	asset_id: AssetKey,
+	network_id: NetworkId,
+) -> Result<Response, ContractError> {
+	REMOTE_ASSETS.remove(deps.storage, (asset_id, network_id));
+	Ok(Response::new().add_event(
+		Event::new(XCVM_ASSET_REGISTRY_EVENT_PREFIX)
+			.add_attribute("action", "unregister_remote")
+			.add_attribute("asset_id", format!("{:?}", asset_id))
+			.add_attribute("network_id", format!("{}", u32::from(network_id))),
+	))
+}
+
+fn ensure_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+	if &ADMIN.load(deps.storage)? == sender {
+		Ok(())
+	} else {
+		Err(ContractError::NotAuthorized)
+	}
+}
+
+fn ensure_registered(deps: Deps, asset_id: AssetKey) -> Result<(), ContractError> {
+	if ASSETS.has(deps.storage, asset_id) {
+		Ok(())
+	} else {
+		Err(ContractError::UnknownAsset)
+	}
+}
+
 pub fn query_lookup(deps: Deps, asset_id: AssetKey) -> StdResult<LookupResponse> {
 	let reference = ASSETS.load(deps.storage, asset_id)?;
 	Ok(LookupResponse { reference })
 }
 
+pub fn query_lookup_by_denom(deps: Deps, denom: String) -> StdResult<LookupByDenomResponse> {
+	let asset_id = DENOM_ASSETS.load(deps.storage, denom)?;
+	let reference = ASSETS.load(deps.storage, asset_id)?;
+	Ok(LookupByDenomResponse { asset_id, reference })
+}
+
+pub fn query_metadata(deps: Deps, asset_id: AssetKey) -> StdResult<MetadataResponse> {
+	let metadata = ASSET_METADATA.load(deps.storage, asset_id)?;
+	Ok(MetadataResponse { metadata })
+}
+
+pub fn query_remote_lookup(
+	deps: Deps,
+	asset_id: AssetKey,
+	network_id: NetworkId,
+) -> StdResult<RemoteLookupResponse> {
+	let reference = REMOTE_ASSETS.load(deps.storage, (asset_id, network_id))?;
+	Ok(RemoteLookupResponse { reference })
+}
+
+pub fn query_assets(
+	deps: Deps,
+	start_after: Option<AssetKey>,
+	limit: Option<u32>,
+) -> StdResult<AssetsResponse> {
+	let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+	let assets = ASSETS
+		.range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+		.take(limit)
+		.map(|entry| entry.map(|(asset_id, reference)| (asset_id.into(), reference)))
+		.collect::<StdResult<Vec<_>>>()?;
+	Ok(AssetsResponse { assets })
+}
+
+pub fn query_remote_assets(
+	deps: Deps,
+	asset_id: AssetKey,
+	start_after: Option<NetworkId>,
+	limit: Option<u32>,
+) -> StdResult<RemoteAssetsResponse> {
+	let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+	let assets = REMOTE_ASSETS
+		.prefix(asset_id)
+		.range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+		.take(limit)
+		.map(|entry| entry.map(|(network_id, reference)| (network_id.into(), reference)))
+		.collect::<StdResult<Vec<_>>>()?;
+	Ok(RemoteAssetsResponse { assets })
+}
+
 pub fn external_query_lookup_asset(
 	querier: QuerierWrapper,
 	registry_addr: String,
@@ -105,6 +277,72 @@ pub fn external_query_lookup_asset(
 		.map(|response| response.reference)
 }
 
+pub fn external_query_lookup_asset_by_denom(
+	querier: QuerierWrapper,
+	registry_addr: String,
+	denom: String,
+) -> StdResult<(AssetKey, AssetReference)> {
+	querier
+		.query::<LookupByDenomResponse>(
+			&WasmQuery::Smart {
+				contract_addr: registry_addr,
+				msg: to_binary(&QueryMsg::LookupByDenom { denom })?,
+			}
+			.into(),
+		)
+		.map(|response| (response.asset_id, response.reference))
+}
+
+/// The metadata of an asset, if any.
+///
+/// Read from the storage of the registry, so that only a missing entry yields `None`.
+pub fn external_query_metadata(
+	querier: QuerierWrapper,
+	registry_addr: String,
+	asset_id: impl Into<AssetKey>,
+) -> StdResult<Option<AssetMetadata>> {
+	ASSET_METADATA.query(&querier, Addr::unchecked(registry_addr), asset_id.into())
+}
+
+/// The representation of an asset on the counterparty `network_id`, if any.
+///
+/// Read from the storage of the registry, so that only a missing entry yields `None`.
+pub fn external_query_remote_lookup(
+	querier: QuerierWrapper,
+	registry_addr: String,
+	asset_id: impl Into<AssetKey>,
+	network_id: NetworkId,
+) -> StdResult<Option<RemoteAssetReference>> {
+	REMOTE_ASSETS.query(&querier, Addr::unchecked(registry_addr), (asset_id.into(), network_id))
+}
+
+/// All the registered assets, fetched page by page.
+pub fn external_query_assets(
+	querier: QuerierWrapper,
+	registry_addr: String,
+) -> StdResult<Vec<(AssetKey, AssetReference)>> {
+	let mut assets = Vec::new();
+	loop {
+		let page = querier
+			.query::<AssetsResponse>(
+				&WasmQuery::Smart {
+					contract_addr: registry_addr.clone(),
+					msg: to_binary(&QueryMsg::Assets {
+						start_after: assets.last().map(|(asset_id, _)| *asset_id),
+						limit: Some(MAX_LIMIT),
+					})?,
+				}
+				.into(),
+			)?
+			.assets;
+		let done = page.len() < MAX_LIMIT as usize;
+		assets.extend(page);
+		if done {
+			return Ok(assets)
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -235,5 +473,261 @@ mod tests {
 		// This should fail since there the asset doesn't exist
 		assert!(query(deps.as_ref(), mock_env(), QueryMsg::Lookup { asset_id: AssetKey::from(2) })
 			.is_err());
+
+		let addr2 = AssetReference::Native { denom: "pica".into() };
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::RegisterAsset { asset_id: AssetKey::from(2), reference: addr2.clone() },
+		)
+		.unwrap();
+
+		let res: AssetsResponse = from_binary(
+			&query(deps.as_ref(), mock_env(), QueryMsg::Assets { start_after: None, limit: None })
+				.unwrap(),
+		)
+		.unwrap();
+
+		// All the registered assets are listed
+		assert_eq!(
+			res,
+			AssetsResponse {
+				assets: vec![
+					(asset_id, AssetReference::Virtual { cw20_address: Addr::unchecked("addr1") }),
+					(2.into(), addr2)
+				]
+			}
+		);
+	}
+
+	#[test]
+	fn paginate_assets() {
+		let mut deps = mock_dependencies();
+		let info = mock_info("sender", &vec![]);
+		let _ = instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+		for i in 1..=5_u128 {
+			let _ = execute(
+				deps.as_mut(),
+				mock_env(),
+				info.clone(),
+				ExecuteMsg::RegisterAsset {
+					asset_id: i.into(),
+					reference: AssetReference::Native { denom: format!("denom{i}") },
+				},
+			)
+			.unwrap();
+		}
+
+		let page = |start_after: Option<u128>| -> AssetsResponse {
+			let msg = QueryMsg::Assets { start_after: start_after.map(Into::into), limit: Some(2) };
+			from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+		};
+		let ids = |response: AssetsResponse| {
+			response.assets.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+		};
+
+		assert_eq!(ids(page(None)), vec![1.into(), 2.into()]);
+		assert_eq!(ids(page(Some(2))), vec![3.into(), 4.into()]);
+		assert_eq!(ids(page(Some(4))), vec![5.into()]);
+		assert_eq!(ids(page(Some(5))), Vec::<AssetKey>::new());
+	}
+
+	#[test]
+	fn lookup_by_denom() {
+		let mut deps = mock_dependencies();
+		let info = mock_info("sender", &vec![]);
+		let _ = instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+		let pica = AssetReference::Native { denom: "pica".into() };
+		let cw20 = AssetReference::Virtual { cw20_address: Addr::unchecked("addr1") };
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::RegisterAsset { asset_id: 1.into(), reference: pica.clone() },
+		)
+		.unwrap();
+
+		let lookup = |deps: Deps, denom: &str| {
+			query(deps, mock_env(), QueryMsg::LookupByDenom { denom: denom.into() })
+				.and_then(|res| from_binary::<LookupByDenomResponse>(&res))
+		};
+
+		assert_eq!(
+			lookup(deps.as_ref(), "pica").unwrap(),
+			LookupByDenomResponse { asset_id: 1.into(), reference: pica.clone() }
+		);
+
+		// A denom belongs to a single asset
+		assert!(execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::RegisterAsset { asset_id: 2.into(), reference: pica },
+		)
+		.is_err());
+
+		// Replacing the reference of an asset replaces its denom
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::RegisterAsset { asset_id: 1.into(), reference: cw20.clone() },
+		)
+		.unwrap();
+		assert!(lookup(deps.as_ref(), "pica").is_err());
+		assert_eq!(
+			lookup(deps.as_ref(), "cw20:addr1").unwrap(),
+			LookupByDenomResponse { asset_id: 1.into(), reference: cw20 }
+		);
+
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::UnregisterAsset { asset_id: 1.into() },
+		)
+		.unwrap();
+		assert!(lookup(deps.as_ref(), "cw20:addr1").is_err());
+	}
+
+	#[test]
+	fn remote_assets_and_metadata() {
+		let mut deps = mock_dependencies();
+		let info = mock_info("sender", &vec![]);
+		let _ = instantiate(deps.as_mut(), mock_env(), info.clone(), InstantiateMsg {}).unwrap();
+
+		let asset_id = AssetKey::from(1);
+		let metadata = AssetMetadata { decimals: 12, symbol: "PICA".into() };
+		let remote = |network: u32| RemoteAssetReference {
+			reference: format!("ibc/{network}"),
+			decimals: 6,
+		};
+
+		// The asset must be registered first
+		assert!(execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::SetAssetMetadata { asset_id, metadata: metadata.clone() },
+		)
+		.is_err());
+
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::RegisterAsset {
+				asset_id,
+				reference: AssetReference::Native { denom: "pica".into() },
+			},
+		)
+		.unwrap();
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::SetAssetMetadata { asset_id, metadata: metadata.clone() },
+		)
+		.unwrap();
+		for network in [2, 3] {
+			let _ = execute(
+				deps.as_mut(),
+				mock_env(),
+				info.clone(),
+				ExecuteMsg::RegisterRemoteAsset {
+					asset_id,
+					network_id: network.into(),
+					reference: remote(network),
+				},
+			)
+			.unwrap();
+		}
+
+		let res: MetadataResponse = from_binary(
+			&query(deps.as_ref(), mock_env(), QueryMsg::Metadata { asset_id }).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(res, MetadataResponse { metadata });
+
+		let res: RemoteLookupResponse = from_binary(
+			&query(
+				deps.as_ref(),
+				mock_env(),
+				QueryMsg::RemoteLookup { asset_id, network_id: 3.into() },
+			)
+			.unwrap(),
+		)
+		.unwrap();
+		assert_eq!(res, RemoteLookupResponse { reference: remote(3) });
+
+		let res: RemoteAssetsResponse = from_binary(
+			&query(
+				deps.as_ref(),
+				mock_env(),
+				QueryMsg::RemoteAssets { asset_id, start_after: Some(2.into()), limit: None },
+			)
+			.unwrap(),
+		)
+		.unwrap();
+		assert_eq!(res, RemoteAssetsResponse { assets: vec![(3.into(), remote(3))] });
+
+		// Unregistering the asset removes everything related to it
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			info.clone(),
+			ExecuteMsg::UnregisterAsset { asset_id },
+		)
+		.unwrap();
+		assert!(query(deps.as_ref(), mock_env(), QueryMsg::Metadata { asset_id }).is_err());
+		assert!(query(
+			deps.as_ref(),
+			mock_env(),
+			QueryMsg::RemoteLookup { asset_id, network_id: 2.into() }
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn only_admin_manages_metadata_and_remote_assets() {
+		let mut deps = mock_dependencies();
+		let admin = mock_info("admin", &vec![]);
+		let sender = mock_info("sender", &vec![]);
+		let _ = instantiate(deps.as_mut(), mock_env(), admin.clone(), InstantiateMsg {}).unwrap();
+
+		let asset_id = AssetKey::from(1);
+		let _ = execute(
+			deps.as_mut(),
+			mock_env(),
+			sender.clone(),
+			ExecuteMsg::RegisterAsset {
+				asset_id,
+				reference: AssetReference::Native { denom: "pica".into() },
+			},
+		)
+		.unwrap();
+
+		let messages = [
+			ExecuteMsg::SetAssetMetadata {
+				asset_id,
+				metadata: AssetMetadata { decimals: 12, symbol: "PICA".into() },
+			},
+			ExecuteMsg::RegisterRemoteAsset {
+				asset_id,
+				network_id: 2.into(),
+				reference: RemoteAssetReference { reference: "ibc/2".into(), decimals: 6 },
+			},
+			ExecuteMsg::UnregisterRemoteAsset { asset_id, network_id: 2.into() },
+		];
+		for msg in messages {
+			assert!(matches!(
+				execute(deps.as_mut(), mock_env(), sender.clone(), msg.clone()),
+				Err(ContractError::NotAuthorized)
+			));
+			let _ = execute(deps.as_mut(), mock_env(), admin.clone(), msg).unwrap();
+		}
 	}
 }
@@ -8,4 +8,13 @@ pub enum ContractError {
 
 	#[error("Error during parsing asset id")]
 	CannotParseAssetId,
+
+	#[error("The asset is not registered")]
+	UnknownAsset,
+
+	#[error("The denom is already registered for another asset")]
+	DenomAlreadyRegistered,
+
+	#[error("Caller is not authorized to take this action")]
+	NotAuthorized,
 }
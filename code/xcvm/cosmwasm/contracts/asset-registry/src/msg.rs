@@ -1,8 +1,8 @@
 use cosmwasm_std::Addr;
-use cw_storage_plus::{CwIntKey, Key, KeyDeserialize, PrimaryKey};
+use cw_storage_plus::{CwIntKey, Key, KeyDeserialize, Prefixer, PrimaryKey};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::AssetId;
+use xcvm_core::{AssetId, NetworkId};
 
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
 #[repr(transparent)]
@@ -36,6 +36,12 @@ impl<'a> PrimaryKey<'a> for AssetKey {
 	}
 }
 
+impl<'a> Prefixer<'a> for AssetKey {
+	fn prefix(&self) -> Vec<Key> {
+		<u128 as Prefixer<'a>>::prefix(&self.0 .0 .0)
+	}
+}
+
 impl KeyDeserialize for AssetKey {
 	type Output = <u128 as KeyDeserialize>::Output;
 	fn from_vec(value: Vec<u8>) -> cosmwasm_std::StdResult<Self::Output> {
@@ -58,14 +64,49 @@ impl AssetReference {
 	}
 }
 
+/// Metadata of an asset on this network.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetMetadata {
+	pub decimals: u8,
+	pub symbol: String,
+}
+
+/// Representation of an asset on a counterparty network.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoteAssetReference {
+	/// Identifier of the asset on the counterparty network, i.e. a denom or a contract address.
+	pub reference: String,
+	/// Decimals of the asset on the counterparty network.
+	pub decimals: u8,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-	RegisterAsset { asset_id: AssetKey, reference: AssetReference },
-	UnregisterAsset { asset_id: AssetKey },
+	RegisterAsset {
+		asset_id: AssetKey,
+		reference: AssetReference,
+	},
+	/// Unregister an asset, along with its metadata and remote references.
+	UnregisterAsset {
+		asset_id: AssetKey,
+	},
+	SetAssetMetadata {
+		asset_id: AssetKey,
+		metadata: AssetMetadata,
+	},
+	RegisterRemoteAsset {
+		asset_id: AssetKey,
+		network_id: NetworkId,
+		reference: RemoteAssetReference,
+	},
+	UnregisterRemoteAsset {
+		asset_id: AssetKey,
+		network_id: NetworkId,
+	},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -74,10 +115,62 @@ pub struct MigrateMsg {}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-	Lookup { asset_id: AssetKey },
+	Lookup {
+		asset_id: AssetKey,
+	},
+	/// Reverse lookup of an asset by its denom, see [`AssetReference::denom`].
+	LookupByDenom {
+		denom: String,
+	},
+	Metadata {
+		asset_id: AssetKey,
+	},
+	/// Lookup the representation of an asset on a counterparty network.
+	RemoteLookup {
+		asset_id: AssetKey,
+		network_id: NetworkId,
+	},
+	/// List the registered assets, in ascending order of their id.
+	Assets {
+		start_after: Option<AssetKey>,
+		limit: Option<u32>,
+	},
+	/// List the representations of an asset on counterparty networks, in ascending order of the
+	/// network id.
+	RemoteAssets {
+		asset_id: AssetKey,
+		start_after: Option<NetworkId>,
+		limit: Option<u32>,
+	},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct LookupResponse {
 	pub reference: AssetReference,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LookupByDenomResponse {
+	pub asset_id: AssetKey,
+	pub reference: AssetReference,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetadataResponse {
+	pub metadata: AssetMetadata,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoteLookupResponse {
+	pub reference: RemoteAssetReference,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetsResponse {
+	pub assets: Vec<(AssetKey, AssetReference)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoteAssetsResponse {
+	pub assets: Vec<(NetworkId, RemoteAssetReference)>,
+}
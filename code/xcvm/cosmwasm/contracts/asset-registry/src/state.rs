@@ -1,4 +1,13 @@
-use crate::msg::{AssetKey, AssetReference};
-use cw_storage_plus::Map;
+use crate::msg::{AssetKey, AssetMetadata, AssetReference, RemoteAssetReference};
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use xcvm_core::NetworkId;
 
+/// Allowed to manage the metadata and the remote references of the assets.
+pub const ADMIN: Item<Addr> = Item::new("admin");
 pub const ASSETS: Map<AssetKey, AssetReference> = Map::new("assets");
+pub const ASSET_METADATA: Map<AssetKey, AssetMetadata> = Map::new("asset_metadata");
+/// Reverse index of [`ASSETS`], by [`AssetReference::denom`].
+pub const DENOM_ASSETS: Map<String, AssetKey> = Map::new("denom_assets");
+pub const REMOTE_ASSETS: Map<(AssetKey, NetworkId), RemoteAssetReference> =
+	Map::new("remote_assets");
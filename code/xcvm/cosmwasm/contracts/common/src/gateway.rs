@@ -1,13 +1,30 @@
-use crate::shared::BridgeMsg;
+use crate::shared::{BridgeMsg, BridgeQueryMsg};
 use cosmwasm_std::{Addr, CosmosMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::NetworkId;
+use xcvm_core::{AssetId, Displayed, NetworkId};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-	IbcSetNetworkChannel { network_id: NetworkId, channel_id: String },
-	Bridge { interpreter: Addr, msg: BridgeMsg },
-	Batch { msgs: Vec<CosmosMsg> },
+	IbcSetNetworkChannel {
+		network_id: NetworkId,
+		channel_id: String,
+	},
+	Bridge {
+		interpreter: Addr,
+		msg: BridgeMsg,
+	},
+	BridgeQuery {
+		interpreter: Addr,
+		msg: BridgeQueryMsg,
+	},
+	Batch {
+		msgs: Vec<CosmosMsg>,
+	},
+	/// Set the fee required by relayers to deliver a packet when paid in `asset_id`, or remove it.
+	SetRelayerFee {
+		asset_id: AssetId,
+		fee: Option<Displayed<u128>>,
+	},
 }
@@ -1,8 +1,9 @@
-use crate::shared::BridgeMsg;
+use crate::shared::{BridgeMsg, BridgeQueryMsg};
+use cosmwasm_std::Addr;
 use cw_xcvm_utils::DefaultXCVMProgram;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::{BridgeSecurity, CallOrigin, Displayed, Funds, InterpreterOrigin};
+use xcvm_core::{BridgeSecurity, CallOrigin, Displayed, Funds, InterpreterOrigin, QueryResult};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -41,4 +42,17 @@ pub enum ExecuteMsg {
 		/// The message we want to forward to the bridge gateway.
 		msg: BridgeMsg,
 	},
+	/// Forward a query of a remote interpreter to the bridge gateway.
+	BridgeQuery {
+		/// The query we want to forward to the bridge gateway.
+		msg: BridgeQueryMsg,
+	},
+	/// Deliver the result of a query to the interpreter that issued it.
+	/// Only the gateway is allowed to dispatch such operation.
+	DeliverQueryResult {
+		/// The interpreter that issued the query.
+		interpreter: Addr,
+		/// The state of the queried interpreter, or the reason the query failed.
+		result: Result<QueryResult, String>,
+	},
 }
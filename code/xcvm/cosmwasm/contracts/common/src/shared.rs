@@ -2,7 +2,7 @@ use cosmwasm_std::{from_binary, to_binary, Binary, StdResult};
 use cw_xcvm_utils::DefaultXCVMProgram;
 use schemars::JsonSchema;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use xcvm_core::{BridgeSecurity, Displayed, Funds, InterpreterOrigin, NetworkId};
+use xcvm_core::{BridgeSecurity, Deadline, Displayed, Funds, InterpreterOrigin, NetworkId};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BridgeMsg {
@@ -12,6 +12,18 @@ pub struct BridgeMsg {
 	pub salt: Vec<u8>,
 	pub program: DefaultXCVMProgram,
 	pub assets: Funds<Displayed<u128>>,
+	/// The maximum fee paid to the relayer delivering the program.
+	pub fee: Funds<Displayed<u128>>,
+	/// After which the assets are refunded to the interpreter if the program was not executed.
+	pub deadline: Deadline,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BridgeQueryMsg {
+	pub interpreter_origin: InterpreterOrigin,
+	pub network_id: NetworkId,
+	/// The salt of the queried interpreter.
+	pub salt: Vec<u8>,
 }
 
 pub fn encode_base64<T: Serialize>(x: &T) -> StdResult<String> {
@@ -3,42 +3,61 @@ extern crate alloc;
 use crate::{
 	common::{ensure_admin, ensure_router},
 	error::ContractError,
-	msg::{InstantiateMsg, MigrateMsg, QueryMsg},
+	msg::{
+		BridgesResponse, ChannelInfoResponse, ConfigResponse, ExpectedRelayerFeeResponse,
+		InFlightPacket, InFlightPacketsResponse, InstantiateMsg, MigrateMsg, QueryMsg,
+		RelayerFeesResponse,
+	},
 	state::{
-		ChannelInfo, Config, CONFIG, IBC_CHANNEL_INFO, IBC_CHANNEL_NETWORK, IBC_NETWORK_CHANNEL,
-		ROUTER,
+		ChannelId, ChannelInfo, Config, BRIDGES, CONFIG, IBC_CHANNEL_INFO, IBC_CHANNEL_NETWORK,
+		IBC_NETWORK_CHANNEL, IN_FLIGHT_PACKETS, RELAYER_FEES, ROUTER, SENDING_PACKET,
 	},
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-	wasm_execute, wasm_instantiate, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, Event,
-	Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
-	IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg, IbcOrder, IbcPacketAckMsg,
-	IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout, IbcTimeoutBlock,
-	MessageInfo, Reply, Response, StdError, SubMsg, SubMsgResult,
+	to_binary, wasm_execute, wasm_instantiate, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps,
+	DepsMut, Env, Event, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg,
+	IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg, IbcOrder, IbcPacket,
+	IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout,
+	IbcTimeoutBlock, MessageInfo, Order, QueryRequest, Reply, Response, StdError, SubMsg,
+	SubMsgResult, Timestamp, WasmQuery,
 };
 use cw2::set_contract_version;
-use cw20::Cw20ExecuteMsg;
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_storage_plus::Bound;
 use cw_utils::ensure_from_older_version;
-use cw_xcvm_asset_registry::{contract::external_query_lookup_asset, msg::AssetReference};
-use cw_xcvm_common::{gateway::ExecuteMsg, shared::BridgeMsg};
-use cw_xcvm_utils::{DefaultXCVMPacket, DefaultXCVMProgram};
+use cw_xcvm_asset_registry::{
+	contract::{
+		external_query_assets, external_query_lookup_asset, external_query_metadata,
+		external_query_remote_lookup,
+	},
+	msg::AssetReference,
+};
+use cw_xcvm_common::{
+	gateway::ExecuteMsg,
+	shared::{BridgeMsg, BridgeQueryMsg},
+};
+use cw_xcvm_utils::{DefaultXCVMGatewayPacket, DefaultXCVMPacket, DefaultXCVMProgram};
 use xcvm_core::{
-	BridgeProtocol, BridgeSecurity, CallOrigin, Displayed, Funds, InterpreterOrigin, NetworkId,
-	XCVMAck,
+	BridgeId, BridgeProtocol, BridgeSecurity, CallOrigin, Deadline, Displayed, Funds,
+	GatewayPacket, InterpreterOrigin, NetworkId, QueryPacket, QueryResult, XCVMAck,
 };
-use xcvm_proto::{decode_packet, Encodable};
+use xcvm_proto::{decode_gateway_packet, decode_query_result, Encodable};
 
 pub const CONTRACT_NAME: &str = "composable:xcvm-gateway";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const XCVM_GATEWAY_EVENT_PREFIX: &str = "xcvm.gateway";
-pub const XCVM_GATEWAY_IBC_VERSION: &str = "xcvm-gateway-v0";
+pub const XCVM_GATEWAY_IBC_VERSION: &str = "xcvm-gateway-v1";
 pub const XCVM_GATEWAY_IBC_ORDERING: IbcOrder = IbcOrder::Unordered;
 
 pub const XCVM_GATEWAY_INSTANTIATE_ROUTER_REPLY_ID: u64 = 0;
 pub const XCVM_GATEWAY_BATCH_REPLY_ID: u64 = 1;
+pub const XCVM_GATEWAY_SEND_PACKET_REPLY_ID: u64 = 2;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -83,6 +102,7 @@ pub fn execute(
 				.load(deps.storage, channel_id.clone())
 				.map_err(|_| ContractError::UnknownChannel)?;
 			IBC_NETWORK_CHANNEL.save(deps.storage, network_id, &channel_id)?;
+			IBC_CHANNEL_NETWORK.save(deps.storage, channel_id.clone(), &network_id)?;
 			Ok(Response::default().add_event(
 				Event::new(XCVM_GATEWAY_EVENT_PREFIX)
 					.add_attribute("action", "set_network_channel")
@@ -91,11 +111,40 @@ pub fn execute(
 			))
 		},
 
+		ExecuteMsg::SetRelayerFee { asset_id, fee } => {
+			ensure_admin(deps.as_ref(), info.sender.as_ref())?;
+			let asset_id = u128::from(asset_id);
+			match fee {
+				Some(Displayed(fee)) => RELAYER_FEES.save(deps.storage, asset_id, &fee)?,
+				None => RELAYER_FEES.remove(deps.storage, asset_id),
+			}
+			Ok(Response::default().add_event(
+				Event::new(XCVM_GATEWAY_EVENT_PREFIX)
+					.add_attribute("action", "set_relayer_fee")
+					.add_attribute("asset_id", format!("{asset_id}"))
+					.add_attribute(
+						"fee",
+						fee.map(|Displayed(fee)| format!("{fee}")).unwrap_or_default(),
+					),
+			))
+		},
+
 		ExecuteMsg::Bridge {
 			interpreter,
-			msg: BridgeMsg { interpreter_origin, network_id, security, salt, program, assets },
+			msg:
+				BridgeMsg {
+					interpreter_origin,
+					network_id,
+					security,
+					salt,
+					program,
+					assets,
+					fee,
+					deadline,
+				},
 		} => handle_bridge(
 			deps,
+			env,
 			info,
 			interpreter,
 			interpreter_origin,
@@ -104,8 +153,15 @@ pub fn execute(
 			salt,
 			program,
 			assets,
+			fee,
+			deadline,
 		),
 
+		ExecuteMsg::BridgeQuery {
+			interpreter,
+			msg: BridgeQueryMsg { interpreter_origin, network_id, salt },
+		} => handle_bridge_query(deps, info, interpreter, interpreter_origin, network_id, salt),
+
 		ExecuteMsg::Batch { msgs } =>
 			if info.sender != env.contract.address {
 				Err(ContractError::NotAuthorized)
@@ -118,12 +174,124 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
 	let _ = ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	// Older versions only recorded the network to channel side of the mapping.
+	let network_channels = IBC_NETWORK_CHANNEL
+		.range(deps.storage, None, None, Order::Ascending)
+		.collect::<Result<Vec<_>, _>>()?;
+	for (network_id, channel_id) in network_channels {
+		IBC_CHANNEL_NETWORK.save(deps.storage, channel_id, &network_id.into())?;
+	}
 	Ok(Response::default())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> Result<Binary, ContractError> {
-	Err(StdError::generic_err("not implemented").into())
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+	match msg {
+		QueryMsg::Config {} => Ok(to_binary(&ConfigResponse {
+			config: CONFIG.load(deps.storage)?,
+			router: ROUTER.load(deps.storage)?,
+		})?),
+		QueryMsg::Bridges { start_after, limit } =>
+			Ok(to_binary(&query_bridges(deps, start_after, limit)?)?),
+		QueryMsg::ChannelInfo { network_id } => {
+			let channel_id = IBC_NETWORK_CHANNEL.load(deps.storage, network_id)?;
+			let channel_info = IBC_CHANNEL_INFO.load(deps.storage, channel_id)?;
+			Ok(to_binary(&ChannelInfoResponse { channel_info })?)
+		},
+		QueryMsg::InFlightPackets { start_after, limit } =>
+			Ok(to_binary(&query_in_flight_packets(deps, start_after, limit)?)?),
+		QueryMsg::RelayerFees {} =>
+			Ok(to_binary(&RelayerFeesResponse { fees: query_relayer_fees(deps)? })?),
+		QueryMsg::ExpectedRelayerFee { fee } => {
+			let (fee, _) = charge_relayer_fee(deps, fee)?;
+			Ok(to_binary(&ExpectedRelayerFeeResponse { fee })?)
+		},
+	}
+}
+
+fn query_bridges(
+	deps: Deps,
+	start_after: Option<BridgeId>,
+	limit: Option<u32>,
+) -> Result<BridgesResponse, ContractError> {
+	let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+	let bridges = BRIDGES
+		.range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+		.take(limit)
+		.map(|entry| entry.map(|(bridge_id, bridge)| (bridge_id.into(), bridge)))
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(BridgesResponse { bridges })
+}
+
+fn query_in_flight_packets(
+	deps: Deps,
+	start_after: Option<(ChannelId, u64)>,
+	limit: Option<u32>,
+) -> Result<InFlightPacketsResponse, ContractError> {
+	let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+	let packets = IN_FLIGHT_PACKETS
+		.range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+		.take(limit)
+		.map(|entry| {
+			entry.map(|((channel_id, sequence), packet)| InFlightPacket {
+				channel_id,
+				sequence,
+				packet,
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok(InFlightPacketsResponse { packets })
+}
+
+fn query_relayer_fees(deps: Deps) -> Result<Funds<Displayed<u128>>, ContractError> {
+	RELAYER_FEES
+		.range(deps.storage, None, None, Order::Ascending)
+		.map(|entry| {
+			entry
+				.map(|(asset_id, fee)| (asset_id.into(), Displayed(fee)))
+				.map_err(ContractError::from)
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(Funds)
+}
+
+/// Split the `fee` offered for a packet into the part paid to the relayer and the unused part.
+/// The relayer is paid with the first asset of the `fee` that covers its fee in
+/// [`RELAYER_FEES`], the packet is rejected if there is none.
+fn charge_relayer_fee(
+	deps: Deps,
+	fee: Funds<Displayed<u128>>,
+) -> Result<(Funds<Displayed<u128>>, Funds<Displayed<u128>>), ContractError> {
+	if RELAYER_FEES.keys(deps.storage, None, None, Order::Ascending).next().is_none() {
+		return Ok((Funds::empty(), fee))
+	}
+	let mut unused = fee;
+	for (asset_id, Displayed(offered)) in unused.0.iter_mut() {
+		match RELAYER_FEES.may_load(deps.storage, u128::from(*asset_id))? {
+			Some(required) if required <= *offered => {
+				*offered -= required;
+				let charged = Funds(vec![(*asset_id, Displayed(required))]);
+				return Ok((charged, unused))
+			},
+			_ => {},
+		}
+	}
+	Err(ContractError::InsufficientRelayerFee)
+}
+
+/// Add the amounts of `other` to `funds`.
+fn merge_funds(
+	mut funds: Funds<Displayed<u128>>,
+	other: Funds<Displayed<u128>>,
+) -> Result<Funds<Displayed<u128>>, ContractError> {
+	for (asset_id, Displayed(amount)) in other {
+		match funds.0.iter_mut().find(|(id, _)| *id == asset_id) {
+			Some((_, Displayed(total))) =>
+				*total = total.checked_add(amount).ok_or(ContractError::ArithmeticOverflow)?,
+			None => funds.0.push((asset_id, Displayed(amount))),
+		}
+	}
+	Ok(funds)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -131,6 +299,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 	match msg.id {
 		XCVM_GATEWAY_INSTANTIATE_ROUTER_REPLY_ID => handle_instantiate_reply(deps, msg),
 		XCVM_GATEWAY_BATCH_REPLY_ID => handle_batch_reply(msg),
+		XCVM_GATEWAY_SEND_PACKET_REPLY_ID => handle_send_packet_reply(deps, msg),
 		_ => Err(ContractError::UnknownReply),
 	}
 }
@@ -193,8 +362,8 @@ pub fn ibc_channel_close(
 		Err(_) => {},
 	}
 	IBC_CHANNEL_INFO.remove(deps.storage, channel.endpoint.channel_id.clone());
-	// TODO: are all the in flight packets timed out in this case? if not, we need to unescrow
-	// assets
+	// The in flight packets are timed out on close, unescrowing their assets in
+	// `ibc_packet_timeout`.
 	Ok(IbcBasicResponse::new().add_event(
 		Event::new(XCVM_GATEWAY_EVENT_PREFIX)
 			.add_attribute("action", "ibc_close")
@@ -207,31 +376,64 @@ pub fn ibc_packet_receive(
 	deps: DepsMut,
 	env: Env,
 	msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+	let packet: Result<DefaultXCVMGatewayPacket, _> = decode_gateway_packet(&msg.packet.data);
+	match packet {
+		Ok(GatewayPacket::Spawn(packet)) => handle_spawn_packet_receive(deps, env, msg, packet),
+		Ok(GatewayPacket::Query(query)) => handle_query_packet_receive(deps.as_ref(), query),
+		Err(_) => Ok(IbcReceiveResponse::default().set_ack(XCVMAck::KO.into_vec())),
+	}
+}
+
+fn handle_spawn_packet_receive(
+	deps: DepsMut,
+	env: Env,
+	msg: IbcPacketReceiveMsg,
+	packet: DefaultXCVMPacket,
 ) -> Result<IbcReceiveResponse, ContractError> {
 	let batch = (|| -> Result<_, ContractError> {
 		let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
 		let router_address = ROUTER.load(deps.storage)?;
-		let packet: DefaultXCVMPacket =
-			decode_packet(&msg.packet.data).map_err(ContractError::Protobuf)?;
-		// Execute both mints + execution in a single sub-transaction.
+		let network_id = IBC_CHANNEL_NETWORK
+			.load(deps.storage, msg.packet.dest.channel_id.clone())
+			.map_err(|_| ContractError::UnknownChannel)?;
+		let normalize = |assets| {
+			normalize_counterparty_assets(
+				deps.as_ref(),
+				registry_address.as_ref(),
+				network_id,
+				assets,
+			)
+		};
+		let assets = normalize(packet.assets)?;
+		let (fee, unused_fee) = charge_relayer_fee(deps.as_ref(), normalize(packet.fee)?)?;
+		// The unused part of the fee is deposited along the assets.
+		let assets = merge_funds(assets, unused_fee)?;
+		// Execute the mints, the relayer payment and the execution in a single sub-transaction.
 		let mut msgs = mint_counterparty_assets(
 			&deps,
 			router_address.as_ref(),
 			registry_address.as_ref(),
-			packet.assets.clone(),
+			assets.clone(),
 		)?;
+		msgs.extend(mint_counterparty_assets(
+			&deps,
+			msg.relayer.as_ref(),
+			registry_address.as_ref(),
+			fee,
+		)?);
 		msgs.push(
 			wasm_execute(
 				router_address,
 				&cw_xcvm_common::router::ExecuteMsg::ExecuteProgramPrivileged {
 					call_origin: CallOrigin::Remote {
 						protocol: BridgeProtocol::IBC,
-						relayer: msg.relayer,
+						relayer: msg.relayer.clone(),
 						user_origin: packet.user_origin,
 					},
 					salt: packet.salt,
 					program: packet.program,
-					assets: packet.assets,
+					assets,
 				},
 				Default::default(),
 			)?
@@ -250,35 +452,109 @@ pub fn ibc_packet_receive(
 	}
 }
 
+/// Answer a query with the state of the queried interpreter, committed as the acknowledgement
+/// of the packet. Nothing is executed on this network.
+fn handle_query_packet_receive(
+	deps: Deps,
+	query: QueryPacket,
+) -> Result<IbcReceiveResponse, ContractError> {
+	let result = (|| -> Result<_, ContractError> {
+		let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
+		let router_address = ROUTER.load(deps.storage)?;
+		let interpreter = cw_xcvm_router::state::INTERPRETERS
+			.query(
+				&deps.querier,
+				router_address,
+				InterpreterOrigin { user_origin: query.user_origin, salt: query.salt },
+			)?
+			.and_then(|interpreter| interpreter.address);
+		let balances = match &interpreter {
+			Some(interpreter) => query_balances(deps, interpreter, registry_address)?,
+			None => Funds::empty(),
+		};
+		Ok(QueryResult {
+			interpreter: interpreter
+				.map(|interpreter| interpreter.as_bytes().to_vec())
+				.unwrap_or_default(),
+			balances,
+		})
+	})();
+	match result {
+		Ok(result) => Ok(IbcReceiveResponse::default().set_ack(result.encode())),
+		Err(_) => Ok(IbcReceiveResponse::default().set_ack(XCVMAck::KO.into_vec())),
+	}
+}
+
+/// Balances of the registered assets held by `account`.
+fn query_balances(
+	deps: Deps,
+	account: &Addr,
+	registry_address: String,
+) -> Result<Funds<Displayed<u128>>, ContractError> {
+	let mut balances = Funds::empty();
+	for (asset_id, reference) in external_query_assets(deps.querier, registry_address)? {
+		let amount = match reference {
+			AssetReference::Native { denom } =>
+				deps.querier.query_balance(account.clone(), denom)?.amount,
+			AssetReference::Virtual { cw20_address } =>
+				deps.querier
+					.query::<BalanceResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+						contract_addr: cw20_address.into_string(),
+						msg: to_binary(&Cw20QueryMsg::Balance {
+							address: account.clone().into_string(),
+						})?,
+					}))?
+					.balance,
+		};
+		if !amount.is_zero() {
+			balances.0.push((asset_id.into(), Displayed(amount.u128())));
+		}
+	}
+	Ok(balances)
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn ibc_packet_ack(
 	deps: DepsMut,
 	_env: Env,
 	msg: IbcPacketAckMsg,
 ) -> Result<IbcBasicResponse, ContractError> {
+	IN_FLIGHT_PACKETS.remove(
+		deps.storage,
+		(msg.original_packet.src.channel_id.clone(), msg.original_packet.sequence),
+	);
+	let packet: DefaultXCVMGatewayPacket =
+		decode_gateway_packet(&msg.original_packet.data).map_err(ContractError::Protobuf)?;
+	let packet = match packet {
+		GatewayPacket::Spawn(packet) => packet,
+		GatewayPacket::Query(query) => {
+			// A query is acknowledged with the encoded result, or a failure byte.
+			let ack = msg.acknowledgement.data.as_slice();
+			let result = match XCVMAck::try_from(ack) {
+				Ok(XCVMAck::KO) => Err("The query failed on the remote network.".to_string()),
+				_ => decode_query_result(ack).map_err(|e| format!("{}", e)),
+			};
+			return Ok(IbcBasicResponse::default().add_message(deliver_query_result(
+				deps.as_ref(),
+				query,
+				result,
+			)?))
+		},
+	};
 	let ack = XCVMAck::try_from(msg.acknowledgement.data.as_slice())
 		.map_err(|_| ContractError::InvalidAck)?;
-	let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
-	let packet: DefaultXCVMPacket =
-		decode_packet(&msg.original_packet.data).map_err(ContractError::Protobuf)?;
-	let messages = match ack {
+	match ack {
 		XCVMAck::OK => {
 			// We got the ACK
-			burn_escrowed_assets(deps, registry_address.as_str(), packet.assets)
-		},
-		XCVMAck::KO => {
-			// On failure, return the funds
-			unescrow_assets(
-				&deps,
-				// Safe as impossible to tamper.
-				String::from_utf8_lossy(&packet.interpreter).to_string(),
-				registry_address.as_str(),
-				packet.assets,
-			)
+			let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
+			let escrowed = merge_funds(packet.assets, packet.fee)?;
+			let burns = burn_escrowed_assets(deps, registry_address.as_str(), escrowed)?;
+			Ok(IbcBasicResponse::default().add_messages(burns))
 		},
+		// On failure, return the funds
+		XCVMAck::KO => refund_spawn(deps.as_ref(), &msg.original_packet, packet, "failure"),
 		_ => Err(ContractError::InvalidAck),
-	}?;
-	Ok(IbcBasicResponse::default().add_messages(messages))
+	}
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -287,18 +563,98 @@ pub fn ibc_packet_timeout(
 	_env: Env,
 	msg: IbcPacketTimeoutMsg,
 ) -> Result<IbcBasicResponse, ContractError> {
-	let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
-	let packet: DefaultXCVMPacket =
-		decode_packet(&msg.packet.data).map_err(ContractError::Protobuf)?;
+	IN_FLIGHT_PACKETS
+		.remove(deps.storage, (msg.packet.src.channel_id.clone(), msg.packet.sequence));
+	let packet: DefaultXCVMGatewayPacket =
+		decode_gateway_packet(&msg.packet.data).map_err(ContractError::Protobuf)?;
+	let packet = match packet {
+		GatewayPacket::Spawn(packet) => packet,
+		GatewayPacket::Query(query) =>
+			return Ok(IbcBasicResponse::default().add_message(deliver_query_result(
+				deps.as_ref(),
+				query,
+				Err("The query timed out.".to_string()),
+			)?)),
+	};
 	// On timeout, return the funds
-	let burns = unescrow_assets(
-		&deps,
-		// Safe as impossible to tamper.
-		String::from_utf8_lossy(&packet.interpreter).to_string(),
-		registry_address.as_str(),
-		packet.assets,
-	)?;
-	Ok(IbcBasicResponse::default().add_messages(burns))
+	refund_spawn(deps.as_ref(), &msg.packet, packet, "timeout")
+}
+
+/// Return the assets escrowed for a spawned program that has not been executed to the interpreter
+/// that spawned it. An `unescrow` event identifying the packet is emitted for relayers to index.
+fn refund_spawn(
+	deps: Deps,
+	ibc_packet: &IbcPacket,
+	packet: DefaultXCVMPacket,
+	reason: &str,
+) -> Result<IbcBasicResponse, ContractError> {
+	let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
+	// Safe as impossible to tamper.
+	let interpreter = String::from_utf8_lossy(&packet.interpreter).to_string();
+	let event = Event::new(XCVM_GATEWAY_EVENT_PREFIX)
+		.add_attribute("action", "unescrow")
+		.add_attribute("reason", reason)
+		.add_attribute("channel_id", ibc_packet.src.channel_id.clone())
+		.add_attribute("sequence", format!("{}", ibc_packet.sequence))
+		.add_attribute("interpreter", interpreter.clone())
+		.add_attribute(
+			"user_origin",
+			serde_json_wasm::to_string(&packet.user_origin)
+				.map_err(|_| ContractError::FailedToSerialize)?,
+		)
+		.add_attribute("salt", format!("{}", Binary::from(packet.salt)))
+		.add_attribute(
+			"assets",
+			serde_json_wasm::to_string(&packet.assets)
+				.map_err(|_| ContractError::FailedToSerialize)?,
+		)
+		.add_attribute(
+			"fee",
+			serde_json_wasm::to_string(&packet.fee)
+				.map_err(|_| ContractError::FailedToSerialize)?,
+		);
+	// The fee is refunded as well, no relayer has been paid.
+	let escrowed = merge_funds(packet.assets, packet.fee)?;
+	let refunds = unescrow_assets(deps, interpreter, registry_address.as_str(), escrowed)?;
+	Ok(IbcBasicResponse::default().add_messages(refunds).add_event(event))
+}
+
+/// Deliver the result of a query to the interpreter that issued it, through the router.
+fn deliver_query_result(
+	deps: Deps,
+	query: QueryPacket,
+	result: Result<QueryResult, String>,
+) -> Result<CosmosMsg, ContractError> {
+	let router_address = ROUTER.load(deps.storage)?;
+	Ok(wasm_execute(
+		router_address,
+		&cw_xcvm_common::router::ExecuteMsg::DeliverQueryResult {
+			// Safe as impossible to tamper.
+			interpreter: Addr::unchecked(String::from_utf8_lossy(&query.interpreter)),
+			result,
+		},
+		Default::default(),
+	)?
+	.into())
+}
+
+/// Track a packet sent by [`send_packet`] as in flight, under the sequence assigned by the IBC
+/// module.
+pub fn handle_send_packet_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+	let response = msg.result.into_result().map_err(StdError::generic_err)?;
+	let sequence = response
+		.events
+		.iter()
+		.find(|event| event.ty == "send_packet")
+		.and_then(|event| event.attributes.iter().find(|attr| attr.key == "packet_sequence"))
+		.ok_or(StdError::not_found("packet_sequence attribute not found"))?
+		.value
+		.parse::<u64>()
+		.map_err(|_| StdError::generic_err("invalid packet_sequence attribute"))?;
+	let (channel_id, packet) = SENDING_PACKET.load(deps.storage)?;
+	SENDING_PACKET.remove(deps.storage);
+	IN_FLIGHT_PACKETS.save(deps.storage, (channel_id, sequence), &packet)?;
+	Ok(Response::default())
 }
 
 pub fn handle_batch_reply(msg: Reply) -> Result<Response, ContractError> {
@@ -308,6 +664,45 @@ pub fn handle_batch_reply(msg: Reply) -> Result<Response, ContractError> {
 	}
 }
 
+/// Convert the amounts of assets sent by the counterparty `network_id`, expressed with the
+/// decimals of the assets on that network, to the decimals of the assets on this network.
+/// Amounts are unchanged if the decimals of an asset are not registered on either side, and
+/// rejected if they cannot be represented exactly on this network.
+fn normalize_counterparty_assets(
+	deps: Deps,
+	registry_address: &str,
+	network_id: NetworkId,
+	assets: Funds<Displayed<u128>>,
+) -> Result<Funds<Displayed<u128>>, ContractError> {
+	assets
+		.into_iter()
+		.map(|(asset_id, Displayed(amount))| {
+			let local =
+				external_query_metadata(deps.querier, registry_address.to_string(), asset_id)?;
+			let remote = external_query_remote_lookup(
+				deps.querier,
+				registry_address.to_string(),
+				asset_id,
+				network_id,
+			)?;
+			let amount = match (local, remote) {
+				(Some(local), Some(remote)) if local.decimals >= remote.decimals => 10_u128
+					.checked_pow((local.decimals - remote.decimals).into())
+					.and_then(|scale| amount.checked_mul(scale))
+					.ok_or(ContractError::ArithmeticOverflow)?,
+				(Some(local), Some(remote)) => 10_u128
+					.checked_pow((remote.decimals - local.decimals).into())
+					.filter(|scale| amount % scale == 0)
+					.map(|scale| amount / scale)
+					.ok_or(ContractError::UnrepresentableAmount)?,
+				_ => amount,
+			};
+			Ok((asset_id, Displayed(amount)))
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(Into::into)
+}
+
 fn mint_counterparty_assets(
 	deps: &DepsMut,
 	router_address: &str,
@@ -365,7 +760,7 @@ fn burn_escrowed_assets(
 }
 
 fn unescrow_assets(
-	deps: &DepsMut,
+	deps: Deps,
 	sender: String,
 	registry_address: &str,
 	assets: Funds<Displayed<u128>>,
@@ -376,9 +771,13 @@ fn unescrow_assets(
 			let reference =
 				external_query_lookup_asset(deps.querier, registry_address.to_string(), asset_id)?;
 			match &reference {
-				AssetReference::Native { .. } => Err(ContractError::UnsupportedAsset),
+				AssetReference::Native { denom } => Ok(BankMsg::Send {
+					to_address: sender.clone(),
+					amount: vec![Coin::new(amount, denom.clone())],
+				}
+				.into()),
 				AssetReference::Virtual { cw20_address } => {
-					// Transfer from the sender to the gateway
+					// Transfer from the gateway back to the sender
 					Ok(wasm_execute(
 						cw20_address.to_string(),
 						&Cw20ExecuteMsg::Transfer {
@@ -396,6 +795,7 @@ fn unescrow_assets(
 
 pub fn handle_bridge(
 	deps: DepsMut,
+	env: Env,
 	info: MessageInfo,
 	interpreter: Addr,
 	interpreter_origin: InterpreterOrigin,
@@ -404,8 +804,14 @@ pub fn handle_bridge(
 	salt: Vec<u8>,
 	program: DefaultXCVMProgram,
 	assets: Funds<Displayed<u128>>,
+	fee: Funds<Displayed<u128>>,
+	deadline: Deadline,
 ) -> Result<Response, ContractError> {
 	ensure_router(deps.as_ref(), info.sender.as_ref())?;
+	let deadline = deadline.timestamp(env.block.time.seconds());
+	if deadline <= env.block.time.seconds() {
+		return Err(ContractError::DeadlineExpired)
+	}
 	match security {
 		// Only allow deterministic over IBC here
 		BridgeSecurity::Deterministic => {
@@ -416,6 +822,7 @@ pub fn handle_bridge(
 				salt,
 				program,
 				assets,
+				fee,
 			};
 			Ok(Response::default()
 				.add_event(
@@ -432,19 +839,71 @@ pub fn handle_bridge(
 							"assets",
 							serde_json_wasm::to_string(&packet.assets)
 								.map_err(|_| ContractError::FailedToSerialize)?,
-						),
+						)
+						.add_attribute(
+							"fee",
+							serde_json_wasm::to_string(&packet.fee)
+								.map_err(|_| ContractError::FailedToSerialize)?,
+						)
+						.add_attribute("deadline", format!("{deadline}")),
 				)
-				.add_message(IbcMsg::SendPacket {
+				.add_submessage(send_packet(
+					deps,
 					channel_id,
-					data: Binary::from(packet.encode()),
-					// TODO: should be a parameter or configuration
-					timeout: IbcTimeout::with_block(IbcTimeoutBlock { revision: 0, height: 10000 }),
-				}))
+					DefaultXCVMGatewayPacket::Spawn(packet),
+					IbcTimeout::with_timestamp(Timestamp::from_seconds(deadline)),
+				)?))
 		},
 		_ => Err(ContractError::UnsupportedBridgeSecurity),
 	}
 }
 
+pub fn handle_bridge_query(
+	deps: DepsMut,
+	info: MessageInfo,
+	interpreter: Addr,
+	interpreter_origin: InterpreterOrigin,
+	network_id: NetworkId,
+	salt: Vec<u8>,
+) -> Result<Response, ContractError> {
+	ensure_router(deps.as_ref(), info.sender.as_ref())?;
+	let channel_id = IBC_NETWORK_CHANNEL.load(deps.storage, network_id)?;
+	let query = QueryPacket {
+		interpreter: interpreter.as_bytes().to_vec(),
+		user_origin: interpreter_origin.user_origin,
+		salt,
+	};
+	Ok(Response::default()
+		.add_event(
+			Event::new(XCVM_GATEWAY_EVENT_PREFIX)
+				.add_attribute("action", "bridge_query")
+				.add_attribute("network_id", format!("{network_id}"))
+				.add_attribute("salt", format!("{}", Binary::from(query.salt.clone()))),
+		)
+		.add_submessage(send_packet(
+			deps,
+			channel_id,
+			DefaultXCVMGatewayPacket::Query(query),
+			// TODO: should be a parameter or configuration
+			IbcTimeout::with_block(IbcTimeoutBlock { revision: 0, height: 10000 }),
+		)?))
+}
+
+/// Send a packet over `channel_id`, tracking it as in flight once its sequence is known.
+fn send_packet(
+	deps: DepsMut,
+	channel_id: ChannelId,
+	packet: DefaultXCVMGatewayPacket,
+	timeout: IbcTimeout,
+) -> Result<SubMsg, ContractError> {
+	let data = Binary::from(packet.clone().encode());
+	SENDING_PACKET.save(deps.storage, &(channel_id.clone(), packet))?;
+	Ok(SubMsg::reply_on_success(
+		IbcMsg::SendPacket { channel_id, data, timeout },
+		XCVM_GATEWAY_SEND_PACKET_REPLY_ID,
+	))
+}
+
 fn handle_instantiate_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
 	let response = msg.result.into_result().map_err(StdError::generic_err)?;
 	let router_address = {
@@ -465,3 +924,162 @@ fn handle_instantiate_reply(deps: DepsMut, msg: Reply) -> Result<Response, Contr
 	ROUTER.save(deps.storage, &router_address)?;
 	Ok(Response::default())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use cosmwasm_std::Empty;
+	use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+	use xcvm_core::{Asset, Juno, Network, Picasso, PICA};
+
+	fn gateway_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(ContractWrapper::new(execute, instantiate, query).with_reply(reply))
+	}
+
+	fn router_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(
+			ContractWrapper::new(
+				cw_xcvm_router::contract::execute,
+				cw_xcvm_router::contract::instantiate,
+				cw_xcvm_router::contract::query,
+			)
+			.with_reply(cw_xcvm_router::contract::reply),
+		)
+	}
+
+	fn interpreter_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(
+			ContractWrapper::new(
+				cw_xcvm_interpreter::contract::execute,
+				cw_xcvm_interpreter::contract::instantiate,
+				cw_xcvm_interpreter::contract::query,
+			)
+			.with_reply(cw_xcvm_interpreter::contract::reply),
+		)
+	}
+
+	fn registry_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(ContractWrapper::new(
+			cw_xcvm_asset_registry::contract::execute,
+			cw_xcvm_asset_registry::contract::instantiate,
+			cw_xcvm_asset_registry::contract::query,
+		))
+	}
+
+	fn setup() -> (App, Addr) {
+		let mut app = App::default();
+		let gateway_code_id = app.store_code(gateway_contract());
+		let router_code_id = app.store_code(router_contract());
+		let interpreter_code_id = app.store_code(interpreter_contract());
+		let registry_code_id = app.store_code(registry_contract());
+		let registry = app
+			.instantiate_contract(
+				registry_code_id,
+				Addr::unchecked("admin"),
+				&cw_xcvm_asset_registry::msg::InstantiateMsg {},
+				&[],
+				"xcvm-registry",
+				None,
+			)
+			.unwrap();
+		let gateway = app
+			.instantiate_contract(
+				gateway_code_id,
+				Addr::unchecked("admin"),
+				&InstantiateMsg {
+					config: Config {
+						registry_address: registry.into_string(),
+						router_code_id,
+						interpreter_code_id,
+						network_id: Picasso::ID,
+						admin: "admin".into(),
+					},
+				},
+				&[],
+				"xcvm-gateway",
+				None,
+			)
+			.unwrap();
+		(app, gateway)
+	}
+
+	#[test]
+	fn query_config() {
+		let (app, gateway) = setup();
+		let ConfigResponse { config, router } =
+			app.wrap().query_wasm_smart(gateway.clone(), &QueryMsg::Config {}).unwrap();
+		assert_eq!(config.network_id, Picasso::ID);
+		assert_eq!(config.admin, "admin");
+
+		// The router has been instantiated by the gateway.
+		let cw_xcvm_router::msg::ConfigResponse { config: router_config } = app
+			.wrap()
+			.query_wasm_smart(router, &cw_xcvm_router::msg::QueryMsg::Config {})
+			.unwrap();
+		assert_eq!(router_config.gateway_address, gateway);
+	}
+
+	#[test]
+	fn query_bridges_and_packets() {
+		let (app, gateway) = setup();
+
+		let BridgesResponse { bridges } = app
+			.wrap()
+			.query_wasm_smart(
+				gateway.clone(),
+				&QueryMsg::Bridges { start_after: None, limit: None },
+			)
+			.unwrap();
+		assert!(bridges.is_empty());
+
+		let InFlightPacketsResponse { packets } = app
+			.wrap()
+			.query_wasm_smart(
+				gateway.clone(),
+				&QueryMsg::InFlightPackets { start_after: None, limit: None },
+			)
+			.unwrap();
+		assert!(packets.is_empty());
+
+		// No channel has been opened with any network.
+		let result: Result<ChannelInfoResponse, _> = app
+			.wrap()
+			.query_wasm_smart(gateway, &QueryMsg::ChannelInfo { network_id: Juno::ID });
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn query_relayer_fees() {
+		let (mut app, gateway) = setup();
+		let fee = Displayed(1_000_u128);
+
+		let result = app.execute_contract(
+			Addr::unchecked("user"),
+			gateway.clone(),
+			&ExecuteMsg::SetRelayerFee { asset_id: PICA::ID, fee: Some(fee) },
+			&[],
+		);
+		assert!(result.is_err());
+
+		app.execute_contract(
+			Addr::unchecked("admin"),
+			gateway.clone(),
+			&ExecuteMsg::SetRelayerFee { asset_id: PICA::ID, fee: Some(fee) },
+			&[],
+		)
+		.unwrap();
+
+		let RelayerFeesResponse { fees } =
+			app.wrap().query_wasm_smart(gateway.clone(), &QueryMsg::RelayerFees {}).unwrap();
+		assert_eq!(fees, Funds::from([(PICA::ID, 1_000_u128)]));
+
+		let ExpectedRelayerFeeResponse { fee: expected } = app
+			.wrap()
+			.query_wasm_smart(
+				gateway,
+				&QueryMsg::ExpectedRelayerFee { fee: Funds::from([(PICA::ID, 1_500_u128)]) },
+			)
+			.unwrap();
+		assert_eq!(expected, Funds::from([(PICA::ID, 1_000_u128)]));
+	}
+}
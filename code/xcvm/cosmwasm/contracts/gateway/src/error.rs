@@ -38,4 +38,10 @@ pub enum ContractError {
 	UnknownReply,
 	#[error("The provided channel has not been previously opened.")]
 	UnknownChannel,
+	#[error("The deadline of the spawned program has already been reached.")]
+	DeadlineExpired,
+	#[error("The fee offered does not cover the fee required by the relayers.")]
+	InsufficientRelayerFee,
+	#[error("The amount cannot be represented with the decimals of the asset on this network.")]
+	UnrepresentableAmount,
 }
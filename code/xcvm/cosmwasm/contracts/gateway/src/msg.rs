@@ -1,6 +1,9 @@
-use crate::state::Config;
+use crate::state::{Bridge, ChannelId, ChannelInfo, Config};
+use cosmwasm_std::Addr;
+use cw_xcvm_utils::DefaultXCVMGatewayPacket;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use xcvm_core::{BridgeId, Displayed, Funds, NetworkId};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -11,4 +14,67 @@ pub struct InstantiateMsg {
 pub struct MigrateMsg {}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub enum QueryMsg {}
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+	Config {},
+	/// List the registered bridges, in ascending order of their id.
+	Bridges {
+		start_after: Option<BridgeId>,
+		limit: Option<u32>,
+	},
+	/// The IBC channel opened with the given network.
+	ChannelInfo {
+		network_id: NetworkId,
+	},
+	/// List the packets that have been neither acknowledged nor timed out, in ascending order of
+	/// their channel and sequence.
+	InFlightPackets {
+		start_after: Option<(ChannelId, u64)>,
+		limit: Option<u32>,
+	},
+	/// The fees required by relayers, one of which must be covered by the fee of a packet.
+	RelayerFees {},
+	/// The part of the `fee` offered for a packet that is paid to the relayer.
+	ExpectedRelayerFee {
+		fee: Funds<Displayed<u128>>,
+	},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+	pub config: Config,
+	/// Address of the router instantiated by the gateway.
+	pub router: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BridgesResponse {
+	pub bridges: Vec<(BridgeId, Bridge)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChannelInfoResponse {
+	pub channel_info: ChannelInfo,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InFlightPacket {
+	pub channel_id: ChannelId,
+	pub sequence: u64,
+	pub packet: DefaultXCVMGatewayPacket,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InFlightPacketsResponse {
+	pub packets: Vec<InFlightPacket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RelayerFeesResponse {
+	pub fees: Funds<Displayed<u128>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ExpectedRelayerFeeResponse {
+	pub fee: Funds<Displayed<u128>>,
+}
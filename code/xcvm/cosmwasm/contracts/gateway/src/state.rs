@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, IbcEndpoint};
 use cw_storage_plus::{Item, Map};
+use cw_xcvm_utils::DefaultXCVMGatewayPacket;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use xcvm_core::{BridgeId, BridgeSecurity, NetworkId};
@@ -41,9 +42,18 @@ pub struct ChannelInfo {
 pub const ROUTER: Item<Addr> = Item::new("router");
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const BRIDGES: Map<BridgeId, Bridge> = Map::new("bridges");
+/// Fee required by relayers to deliver a packet, per accepted asset. Relaying is free if empty.
+pub const RELAYER_FEES: Map<u128, u128> = Map::new("relayer_fees");
 
 pub const IBC_CHANNEL_INFO: Map<ChannelId, ChannelInfo> = Map::new("ibc_channel_info");
 
 /// According to XCVM protocol, it's always a 1:1 mapping between [`NetworkId`] and [`ChannelId`]
 pub const IBC_NETWORK_CHANNEL: Map<NetworkId, ChannelId> = Map::new("ibc_network_channel");
 pub const IBC_CHANNEL_NETWORK: Map<ChannelId, NetworkId> = Map::new("ibc_channel_network");
+
+/// Packets sent over a channel, indexed by their sequence, that have been neither acknowledged
+/// nor timed out yet.
+pub const IN_FLIGHT_PACKETS: Map<(ChannelId, u64), DefaultXCVMGatewayPacket> =
+	Map::new("in_flight_packets");
+/// Packet being sent, until its sequence is known in the reply of the send.
+pub const SENDING_PACKET: Item<(ChannelId, DefaultXCVMGatewayPacket)> = Item::new("sending_packet");
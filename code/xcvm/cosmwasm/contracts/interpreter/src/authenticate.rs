@@ -1,10 +1,36 @@
-use crate::{error::ContractError, state::OWNERS};
-use cosmwasm_std::{Addr, Deps};
+use crate::{
+	error::ContractError,
+	state::{Config, Spending, CONFIG, EXECUTING_DELEGATE, GRANTS, OWNERS, SPENDINGS},
+};
+use cosmwasm_std::{Addr, Deps, DepsMut, Storage};
+use cw20::Cw20ExecuteMsg;
+use cw_xcvm_asset_registry::{msg::AssetReference, state::DENOM_ASSETS};
+use cw_xcvm_utils::DefaultXCVMProgram;
+use xcvm_core::{
+	cosmwasm::{FlatCosmosMsg, FlatWasmMsg},
+	AssetId, Displayed,
+};
 
 /// Authenticated token, MUST be private and kept in this module.
 /// MUST ONLY be instantiated by [`ensure_owner`].
 pub struct Authenticated(());
 
+/// Token of a delegate allowed to execute a program, MUST be private and kept in this module.
+/// MUST ONLY be instantiated by [`ensure_executor`].
+pub struct Delegated(Addr);
+
+impl Delegated {
+	pub fn delegate(&self) -> &Addr {
+		&self.0
+	}
+}
+
+/// The caller executing a program.
+pub enum Executor {
+	Owner(Authenticated),
+	Delegate(Delegated),
+}
+
 /// Ensure that the caller is either the current interpreter or listed in the owners of the
 /// interpreter.
 /// Any operation executing against the interpreter must pass this check.
@@ -19,3 +45,114 @@ pub fn ensure_owner(
 		Err(ContractError::NotAuthorized)
 	}
 }
+
+/// Ensure that the caller is either an owner, or a delegate whose grant allows it to execute the
+/// `program`.
+/// The calls and spendings of a delegate are further checked during the execution, see
+/// [`ensure_call_permitted`] and [`ensure_spending_permitted`].
+pub fn ensure_executor(
+	deps: Deps,
+	self_addr: &Addr,
+	sender: Addr,
+	program: &DefaultXCVMProgram,
+) -> Result<Executor, ContractError> {
+	if let Ok(token) = ensure_owner(deps, self_addr, sender.clone()) {
+		return Ok(Executor::Owner(token))
+	}
+	let grant = GRANTS
+		.may_load(deps.storage, sender.clone())?
+		.ok_or(ContractError::NotAuthorized)?;
+	match grant.tag {
+		Some(tag) if tag != program.tag => Err(ContractError::ProgramNotPermitted),
+		_ => Ok(Executor::Delegate(Delegated(sender))),
+	}
+}
+
+/// Ensure that the delegate executing the current program, if any, is allowed to dispatch `msg`
+/// at `now`.
+/// A delegate is only allowed to execute contracts other than the interpreter itself, without
+/// funds. Bank messages and wasm messages carrying funds would move native assets out of the
+/// interpreter, and admin messages would change the contracts administered by the interpreter.
+/// The transfers, sends and allowances of the cw20 assets of the interpreter are charged against
+/// the spending limits of the delegate, other executions of the cw20 assets are not permitted.
+pub fn ensure_call_permitted(
+	deps: &mut DepsMut,
+	self_addr: &Addr,
+	now: u64,
+	msg: &FlatCosmosMsg<serde_json::Value>,
+) -> Result<(), ContractError> {
+	let delegate = match EXECUTING_DELEGATE.may_load(deps.storage)? {
+		Some(delegate) => delegate,
+		None => return Ok(()),
+	};
+	let grant = GRANTS.load(deps.storage, delegate)?;
+	let (target, msg) = match msg {
+		FlatCosmosMsg::Wasm(FlatWasmMsg::Execute { contract_addr, msg, funds })
+			if funds.is_empty() =>
+			(contract_addr, msg),
+		_ => return Err(ContractError::CallNotPermitted),
+	};
+	let permitted = target != self_addr.as_str() &&
+		grant
+			.call_targets
+			.map_or(true, |call_targets| call_targets.iter().any(|addr| addr == target));
+	if !permitted {
+		return Err(ContractError::CallNotPermitted)
+	}
+	if let Some(asset) = cw20_asset(deps.as_ref(), target)? {
+		let amount = match serde_json::from_value(msg.clone()) {
+			Ok(Cw20ExecuteMsg::Transfer { amount, .. }) |
+			Ok(Cw20ExecuteMsg::Send { amount, .. }) |
+			Ok(Cw20ExecuteMsg::IncreaseAllowance { amount, .. }) => amount,
+			_ => return Err(ContractError::CallNotPermitted),
+		};
+		ensure_spending_permitted(deps.storage, now, asset, amount.u128())?;
+	}
+	Ok(())
+}
+
+/// The asset registered for the cw20 contract at `contract_addr`, if any.
+fn cw20_asset(deps: Deps, contract_addr: &str) -> Result<Option<AssetId>, ContractError> {
+	let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
+	// Assets are registered with normalized addresses, which the other ones could bypass.
+	let cw20_address = deps
+		.api
+		.addr_validate(contract_addr)
+		.map_err(|_| ContractError::CallNotPermitted)?;
+	let denom = AssetReference::Virtual { cw20_address }.denom();
+	Ok(DENOM_ASSETS.query(&deps.querier, registry_address, denom)?.map(Into::into))
+}
+
+/// Ensure that the delegate executing the current program, if any, is allowed to move `amount`
+/// of `asset` out of the interpreter at `now`, and record the spending.
+pub fn ensure_spending_permitted(
+	storage: &mut dyn Storage,
+	now: u64,
+	asset: AssetId,
+	amount: u128,
+) -> Result<(), ContractError> {
+	let delegate = match EXECUTING_DELEGATE.may_load(storage)? {
+		Some(delegate) => delegate,
+		None => return Ok(()),
+	};
+	let grant = GRANTS.load(storage, delegate.clone())?;
+	let limit = grant
+		.spending_limits
+		.into_iter()
+		.find(|limit| limit.asset == asset)
+		.ok_or(ContractError::SpendingLimitExceeded(asset))?;
+	let key = (delegate, asset.into());
+	let spending = match SPENDINGS.may_load(storage, key.clone())? {
+		// A new period starts with the first spending following the end of the previous one.
+		Some(spending) if now < spending.period_start.saturating_add(limit.period) => spending,
+		_ => Spending { period_start: now, spent: Displayed(0) },
+	};
+	let spent = spending
+		.spent
+		.0
+		.checked_add(amount)
+		.filter(|spent| *spent <= limit.amount.0)
+		.ok_or(ContractError::SpendingLimitExceeded(asset))?;
+	SPENDINGS.save(storage, key, &Spending { spent: Displayed(spent), ..spending })?;
+	Ok(())
+}
@@ -1,28 +1,36 @@
 extern crate alloc;
 
 use crate::{
-	authenticate::{ensure_owner, Authenticated},
+	authenticate::{
+		ensure_call_permitted, ensure_executor, ensure_owner, ensure_spending_permitted,
+		Authenticated, Executor,
+	},
 	error::ContractError,
-	msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-	state::{Config, CONFIG, IP_REGISTER, OWNERS, RELAYER_REGISTER, RESULT_REGISTER},
+	msg::{ExecuteMsg, GrantResponse, GrantsResponse, InstantiateMsg, MigrateMsg, QueryMsg},
+	state::{
+		Config, ExecutionResult, Grant, SuspendedProgram, TryHandler, ABORTED, CONFIG,
+		EXECUTING_DELEGATE, GRANTS, IP_REGISTER, OWNERS, RELAYER_REGISTER, RESULT_REGISTER,
+		SPENDINGS, SUSPENDED_PROGRAM, TRY_HANDLERS,
+	},
 };
 use alloc::{borrow::Cow, collections::VecDeque};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
 	to_binary, wasm_execute, Addr, BankMsg, Binary, CanonicalAddr, Coin, CosmosMsg, Deps, DepsMut,
-	Env, Event, MessageInfo, QueryRequest, Reply, Response, StdError, StdResult, SubMsg, WasmQuery,
+	Env, Event, MessageInfo, Order, QueryRequest, Reply, Response, StdError, StdResult, SubMsg,
+	WasmQuery,
 };
 use cw2::set_contract_version;
 use cw20::{BalanceResponse, Cw20Contract, Cw20ExecuteMsg, Cw20QueryMsg};
 use cw_utils::ensure_from_older_version;
 use cw_xcvm_asset_registry::{contract::external_query_lookup_asset, msg::AssetReference};
-use cw_xcvm_common::shared::{encode_base64, BridgeMsg};
+use cw_xcvm_common::shared::{encode_base64, BridgeMsg, BridgeQueryMsg};
 use cw_xcvm_utils::DefaultXCVMProgram;
 use num::Zero;
 use xcvm_core::{
-	apply_bindings, cosmwasm::*, Amount, BindingValue, BridgeSecurity, Destination, Displayed,
-	Funds, NetworkId, Register,
+	apply_bindings, cosmwasm::*, Amount, BindingValue, BridgeSecurity, Condition, Deadline,
+	Destination, Displayed, Funds, NetworkId, QueryResult, Register,
 };
 
 type XCVMInstruction = xcvm_core::Instruction<NetworkId, Vec<u8>, CanonicalAddr, Funds>;
@@ -32,6 +40,7 @@ const CONTRACT_NAME: &str = "composable:xcvm-interpreter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const CALL_ID: u64 = 1;
 const SELF_CALL_ID: u64 = 2;
+const TRY_ID: u64 = 3;
 pub const XCVM_INTERPRETER_EVENT_PREFIX: &str = "xcvm.interpreter";
 pub const XCVM_INTERPRETER_EVENT_DATA_ORIGIN: &str = "data";
 
@@ -70,11 +79,18 @@ pub fn execute(
 	info: MessageInfo,
 	msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-	// Only owners can execute entrypoints of the interpreter
+	if let ExecuteMsg::Execute { relayer, program } = msg {
+		// Owners, and delegates within the scope of their grant, can execute programs
+		let executor =
+			ensure_executor(deps.as_ref(), &env.contract.address, info.sender, &program)?;
+		return initiate_execution(executor, deps, env, relayer, program)
+	}
+
+	// Only owners can execute the other entrypoints of the interpreter
 	let token = ensure_owner(deps.as_ref(), &env.contract.address, info.sender.clone())?;
 	match msg {
-		ExecuteMsg::Execute { relayer, program } =>
-			initiate_execution(token, deps, env, relayer, program),
+		// Already handled above
+		ExecuteMsg::Execute { .. } => unreachable!(),
 
 		// ExecuteStep should be called by interpreter itself
 		ExecuteMsg::ExecuteStep { relayer, program } =>
@@ -85,9 +101,21 @@ pub fn execute(
 				handle_execute_step(token, deps, env, relayer, program)
 			},
 
+		// ReceiveQueryResult should be called by the router
+		ExecuteMsg::ReceiveQueryResult { result } =>
+			if CONFIG.load(deps.storage)?.router_address != info.sender {
+				Err(ContractError::NotAuthorized)
+			} else {
+				handle_receive_query_result(token, deps, env, result)
+			},
+
 		ExecuteMsg::AddOwners { owners } => add_owners(token, deps, owners),
 
 		ExecuteMsg::RemoveOwners { owners } => Ok(remove_owners(token, deps, owners)),
+
+		ExecuteMsg::Grant { delegate, grant } => add_grant(token, deps, delegate, grant),
+
+		ExecuteMsg::Revoke { delegate } => Ok(revoke_grant(token, deps, delegate)),
 	}
 }
 
@@ -107,20 +135,36 @@ pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, Con
 /// The [`RELAYER_REGISTER`] is updated to hold the current relayer address. Note that the
 /// [`RELAYER_REGISTER`] always contains a value, and the value is equal to the last relayer that
 /// executed a program if any.
+/// The [`EXECUTING_DELEGATE`] holds the delegate executing the program, if any, for its grant to
+/// be enforced by every step of the program.
 fn initiate_execution(
-	_: Authenticated,
+	executor: Executor,
 	deps: DepsMut,
 	env: Env,
 	relayer: Addr,
 	program: DefaultXCVMProgram,
 ) -> Result<Response, ContractError> {
+	// A suspended program must complete before executing another one.
+	if SUSPENDED_PROGRAM.may_load(deps.storage)?.is_some() {
+		return Err(ContractError::ProgramSuspended)
+	}
+
 	// Reset instruction pointer to zero.
 	IP_REGISTER.save(deps.storage, &0)?;
 
+	// The abortion of the previous program does not affect this one.
+	ABORTED.remove(deps.storage);
+
 	// Set the new relayer, note that the relayer that is in the register is always the last relayer
 	// that executed a program.
 	RELAYER_REGISTER.save(deps.storage, &relayer)?;
 
+	match executor {
+		Executor::Owner(_) => EXECUTING_DELEGATE.remove(deps.storage),
+		Executor::Delegate(delegated) =>
+			EXECUTING_DELEGATE.save(deps.storage, delegated.delegate())?,
+	}
+
 	Ok(Response::default().add_submessage(SubMsg::reply_on_error(
 		wasm_execute(
 			env.contract.address,
@@ -158,10 +202,48 @@ fn remove_owners(_: Authenticated, deps: DepsMut, owners: Vec<Addr>) -> Response
 	Response::default().add_event(event)
 }
 
+/// Allow a delegate to execute the programs within the scope of `grant`.
+/// The spendings of the delegate are kept, they are checked against the new limits.
+fn add_grant(
+	_: Authenticated,
+	deps: DepsMut,
+	delegate: Addr,
+	grant: Grant,
+) -> Result<Response, ContractError> {
+	GRANTS.save(deps.storage, delegate.clone(), &grant)?;
+	Ok(Response::default().add_event(
+		Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
+			.add_attribute("action", "grant.added")
+			.add_attribute("delegate", delegate),
+	))
+}
+
+/// Revoke the grant of a delegate, along with its spendings.
+fn revoke_grant(_: Authenticated, deps: DepsMut, delegate: Addr) -> Response {
+	GRANTS.remove(deps.storage, delegate.clone());
+	let assets = SPENDINGS
+		.prefix(delegate.clone())
+		.keys(deps.storage, None, None, Order::Ascending)
+		.collect::<Vec<_>>();
+	for asset in assets.into_iter().flatten() {
+		SPENDINGS.remove(deps.storage, (delegate.clone(), asset));
+	}
+	Response::default().add_event(
+		Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
+			.add_attribute("action", "grant.revoked")
+			.add_attribute("delegate", delegate),
+	)
+}
+
 /// Execute a [`XCVMProgram`].
 /// The function will execute the program instructions one by one.
 /// If the program contains a [`XCVMInstruction::Call`], the execution is suspended and resumed
 /// after having executed the call.
+/// If the program contains a [`XCVMInstruction::Query`], the execution is suspended and resumed
+/// once the result of the query has been received, see [`handle_receive_query_result`].
+/// If the program contains a [`XCVMInstruction::Try`], the execution is suspended and resumed
+/// after having executed the tried program, and its fallback if it failed, see
+/// [`handle_try_result`].
 /// The [`IP_REGISTER`] is updated accordingly.
 /// A final `executed` event is yield whenever a program come to completion (all it's instructions
 /// has been executed).
@@ -172,16 +254,20 @@ pub fn handle_execute_step(
 	relayer: Addr,
 	program: XCVMProgram,
 ) -> Result<Response, ContractError> {
+	// An aborted program does not execute its remaining steps.
+	if ABORTED.may_load(deps.storage)?.unwrap_or(false) {
+		return Ok(Response::default())
+	}
+
 	let mut response = Response::new();
-	let instruction_len = program.instructions.len();
-	let mut instruction_iter = program.instructions.into_iter().enumerate();
+	let mut instructions = program.instructions;
 	let mut ip = IP_REGISTER.load(deps.storage)?;
-	while let Some((index, instruction)) = instruction_iter.next() {
+	while let Some(instruction) = instructions.pop_front() {
 		response = match instruction {
 			XCVMInstruction::Call { bindings, encoded } => {
-				if index >= instruction_len - 1 {
+				if instructions.is_empty() {
 					// If the call is the final instruction, do not yield execution
-					interpret_call(deps.as_ref(), &env, bindings, encoded, ip as usize, response)?
+					interpret_call(&mut deps, &env, bindings, encoded, ip as usize, response)?
 				} else {
 					// If the call is not the final instruction:
 					// 1. interpret the call: this will add the call to the response's
@@ -191,9 +277,7 @@ pub fn handle_execute_step(
 					//    previous call instruction will run first, then the rest of the program
 					//    will run.
 					let response =
-						interpret_call(deps.as_ref(), &env, bindings, encoded, index, response)?;
-					let instructions: VecDeque<XCVMInstruction> =
-						instruction_iter.map(|(_, instr)| instr).collect();
+						interpret_call(&mut deps, &env, bindings, encoded, ip as usize, response)?;
 					let program = XCVMProgram { tag: program.tag, instructions };
 					IP_REGISTER.save(deps.storage, &ip)?;
 					return Ok(response.add_message(wasm_execute(
@@ -203,20 +287,90 @@ pub fn handle_execute_step(
 					)?))
 				}
 			},
-			XCVMInstruction::Spawn { network, bridge_security, salt, assets, program } =>
-				interpret_spawn(
-					&deps,
-					&env,
-					network,
-					bridge_security,
-					salt,
-					assets,
-					program,
-					response,
-				)?,
+			XCVMInstruction::Spawn {
+				network,
+				bridge_security,
+				salt,
+				assets,
+				fee,
+				deadline,
+				program,
+			} => interpret_spawn(
+				&mut deps,
+				&env,
+				network,
+				bridge_security,
+				salt,
+				assets,
+				fee,
+				deadline,
+				program,
+				response,
+			)?,
 			XCVMInstruction::Transfer { to, assets } =>
 				interpret_transfer(&mut deps, &env, relayer.clone(), to, assets, response)?,
-			instr => return Err(ContractError::InstructionNotSupported(format!("{:?}", instr))),
+			XCVMInstruction::Query { network, salt } => {
+				// The query is answered asynchronously by the remote network, the rest of the
+				// program is saved until the result is received.
+				let response = interpret_query(deps.as_ref(), network, salt, response)?;
+				let program = XCVMProgram { tag: program.tag, instructions };
+				SUSPENDED_PROGRAM
+					.save(deps.storage, &SuspendedProgram { relayer: relayer.clone(), program })?;
+				IP_REGISTER.save(deps.storage, &ip)?;
+				return Ok(response)
+			},
+			XCVMInstruction::If { condition, then, otherwise } => {
+				let holds = evaluate_condition(deps.as_ref(), &env, condition, ip)?;
+				// The instructions of the taken branch are executed right after the `If`.
+				let branch = if holds { then } else { otherwise };
+				for instruction in branch.instructions.into_iter().rev() {
+					instructions.push_front(instruction);
+				}
+				response.add_event(
+					Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
+						.add_attribute("instruction", "if")
+						.add_attribute("branch", if holds { "then" } else { "otherwise" }),
+				)
+			},
+			XCVMInstruction::Try { program: tried, on_error } => {
+				// The tried program runs in a submessage so that its effects are reverted if it
+				// fails, the rest of the program is queued after it.
+				if contains_query(&tried.instructions) {
+					return Err(ContractError::QueryInTry)
+				}
+				let mut handlers = TRY_HANDLERS.may_load(deps.storage)?.unwrap_or_default();
+				handlers.push(TryHandler { relayer: relayer.clone(), on_error });
+				TRY_HANDLERS.save(deps.storage, &handlers)?;
+				IP_REGISTER.save(deps.storage, &(ip + 1))?;
+				let response = response
+					.add_event(
+						Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
+							.add_attribute("instruction", "try"),
+					)
+					.add_submessage(SubMsg::reply_always(
+						wasm_execute(
+							env.contract.address.clone(),
+							&ExecuteMsg::ExecuteStep { relayer: relayer.clone(), program: tried },
+							Default::default(),
+						)?,
+						TRY_ID,
+					));
+				let program = XCVMProgram { tag: program.tag, instructions };
+				return Ok(response.add_message(wasm_execute(
+					env.contract.address,
+					&ExecuteMsg::ExecuteStep { relayer, program },
+					Default::default(),
+				)?))
+			},
+			XCVMInstruction::Abort { to, assets } => {
+				let response =
+					interpret_transfer(&mut deps, &env, relayer.clone(), to, assets, response)?;
+				ABORTED.save(deps.storage, &true)?;
+				IP_REGISTER.save(deps.storage, &(ip + 1))?;
+				return Ok(response.add_event(
+					Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("action", "aborted"),
+				))
+			},
 		};
 		ip += 1;
 	}
@@ -234,19 +388,96 @@ pub fn handle_execute_step(
 	Ok(response.add_event(event))
 }
 
+/// Whether a `Query` would be executed by the `instructions`, including the nested ones.
+fn contains_query(instructions: &VecDeque<XCVMInstruction>) -> bool {
+	instructions.iter().any(|instruction| match instruction {
+		XCVMInstruction::Query { .. } => true,
+		XCVMInstruction::If { then, otherwise, .. } =>
+			contains_query(&then.instructions) || contains_query(&otherwise.instructions),
+		XCVMInstruction::Try { program, on_error } =>
+			contains_query(&program.instructions) || contains_query(&on_error.instructions),
+		_ => false,
+	})
+}
+
+/// Evaluate the `condition` of an `If` instruction at the instruction pointer `ip`.
+pub fn evaluate_condition(
+	deps: Deps,
+	env: &Env,
+	condition: Condition,
+	ip: u32,
+) -> Result<bool, ContractError> {
+	Ok(match condition {
+		Condition::Balance { asset, comparison, amount: Displayed(amount) } => {
+			let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
+			let reference =
+				external_query_lookup_asset(deps.querier, registry_address.into_string(), asset)?;
+			let balance = match reference {
+				AssetReference::Native { denom } =>
+					deps.querier.query_balance(env.contract.address.clone(), denom)?.amount,
+				AssetReference::Virtual { cw20_address } =>
+					deps.querier
+						.query::<BalanceResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+							contract_addr: cw20_address.into_string(),
+							msg: to_binary(&Cw20QueryMsg::Balance {
+								address: env.contract.address.clone().into_string(),
+							})?,
+						}))?
+						.balance,
+			};
+			comparison.compare(balance.u128(), amount)
+		},
+		Condition::QueriedBalance { asset, comparison, amount: Displayed(amount) } =>
+			match RESULT_REGISTER.may_load(deps.storage)? {
+				Some(Ok(ExecutionResult::Query(QueryResult { balances, .. }))) => {
+					let balance = balances
+						.0
+						.into_iter()
+						.find_map(|(id, Displayed(balance))| (id == asset).then_some(balance))
+						.unwrap_or(0);
+					comparison.compare(balance, amount)
+				},
+				_ => return Err(ContractError::NoQueryResult),
+			},
+		Condition::Ip { comparison, value } => comparison.compare(ip, value),
+		Condition::Result { ok } => match RESULT_REGISTER.may_load(deps.storage)? {
+			Some(result) => result.is_ok() == ok,
+			None => false,
+		},
+	})
+}
+
 /// Interpret the `Call` instruction
 /// * `encoded`: JSON-encoded `LateCall` as bytes
 ///
-/// Late-bindings are actually done in this function. If our XCVM SDK is not used,
+/// Late-bindings are actually done in [`late_bind_call`]. If our XCVM SDK is not used,
 /// make sure that indices in the `LateCall` is sorted in an ascending order.
 pub fn interpret_call(
-	deps: Deps,
+	deps: &mut DepsMut,
 	env: &Env,
 	bindings: Vec<(u32, BindingValue)>,
 	payload: Vec<u8>,
 	_ip: usize,
 	response: Response,
 ) -> Result<Response, ContractError> {
+	let flat_cosmos_msg = late_bind_call(deps.as_ref(), env, bindings, payload)?;
+
+	ensure_call_permitted(deps, &env.contract.address, env.block.time.seconds(), &flat_cosmos_msg)?;
+
+	let cosmos_msg: CosmosMsg =
+		flat_cosmos_msg.try_into().map_err(|_| ContractError::DataSerializationError)?;
+	Ok(response
+		.add_event(Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("instruction", "call"))
+		.add_submessage(SubMsg::reply_on_success(cosmos_msg, CALL_ID)))
+}
+
+/// Decode the `payload` of a `Call`, with the `bindings` applied.
+fn late_bind_call(
+	deps: Deps,
+	env: &Env,
+	bindings: Vec<(u32, BindingValue)>,
+	payload: Vec<u8>,
+) -> Result<FlatCosmosMsg<serde_json::Value>, ContractError> {
 	// We don't know the type of the payload, so we use `serde_json::Value`
 	let flat_cosmos_msg: FlatCosmosMsg<serde_json::Value> = if !bindings.is_empty() {
 		let Config { registry_address, .. } = CONFIG.load(deps.storage)?;
@@ -288,28 +519,93 @@ pub fn interpret_call(
 		// We don't have any binding, just deserialize the data
 		serde_json_wasm::from_slice(&payload).map_err(|_| ContractError::InvalidCallPayload)?
 	};
-
-	let cosmos_msg: CosmosMsg =
-		flat_cosmos_msg.try_into().map_err(|_| ContractError::DataSerializationError)?;
-	Ok(response
-		.add_event(Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("instruction", "call"))
-		.add_submessage(SubMsg::reply_on_success(cosmos_msg, CALL_ID)))
+	Ok(flat_cosmos_msg)
 }
 
 pub fn interpret_spawn(
-	deps: &DepsMut,
+	deps: &mut DepsMut,
 	env: &Env,
 	network: NetworkId,
 	bridge_security: BridgeSecurity,
 	salt: Vec<u8>,
 	assets: Funds,
+	fee: Funds,
+	deadline: Deadline,
 	program: XCVMProgram,
-	mut response: Response,
+	response: Response,
 ) -> Result<Response, ContractError> {
 	let Config { interpreter_origin, registry_address, router_address, .. } =
 		CONFIG.load(deps.storage)?;
 
 	let registry_address = registry_address.into_string();
+	let (normalized_funds, response) = transfer_to_router(
+		deps,
+		env,
+		&registry_address,
+		&router_address,
+		assets,
+		&Funds::empty(),
+		response,
+	)?;
+	// The fee is taken from what remains once the assets are transferred.
+	let (normalized_fee, response) = transfer_to_router(
+		deps,
+		env,
+		&registry_address,
+		&router_address,
+		fee,
+		&normalized_funds,
+		response,
+	)?;
+	for (asset_id, Displayed(amount)) in normalized_funds.0.iter().chain(normalized_fee.0.iter()) {
+		ensure_spending_permitted(deps.storage, env.block.time.seconds(), *asset_id, *amount)?;
+	}
+
+	Ok(response
+		.add_message(wasm_execute(
+			router_address,
+			&cw_xcvm_common::router::ExecuteMsg::BridgeForward {
+				msg: BridgeMsg {
+					interpreter_origin: interpreter_origin.clone(),
+					network_id: network,
+					security: bridge_security,
+					salt,
+					program,
+					assets: normalized_funds,
+					fee: normalized_fee,
+					deadline,
+				},
+			},
+			Default::default(),
+		)?)
+		.add_event(
+			Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
+				.add_attribute("instruction", "spawn")
+				.add_attribute(
+					"origin_network_id",
+					serde_json_wasm::to_string(&interpreter_origin.user_origin.network_id)
+						.map_err(|_| ContractError::DataSerializationError)?,
+				)
+				.add_attribute(
+					"origin_user_id",
+					serde_json_wasm::to_string(&interpreter_origin.user_origin.user_id)
+						.map_err(|_| ContractError::DataSerializationError)?,
+				),
+		))
+}
+
+/// Transfer `assets` to the router for them to be bridged, returning their absolute amounts.
+/// Amounts are computed from the balances of the interpreter prior to any transfer, minus the
+/// amounts already `transferred` by the response.
+fn transfer_to_router(
+	deps: &DepsMut,
+	env: &Env,
+	registry_address: &str,
+	router_address: &Addr,
+	assets: Funds,
+	transferred: &Funds<Displayed<u128>>,
+	mut response: Response,
+) -> Result<(Funds<Displayed<u128>>, Response), ContractError> {
 	let mut normalized_funds: Funds<Displayed<u128>> = Funds::empty();
 
 	for (asset_id, amount) in assets.0 {
@@ -319,7 +615,7 @@ pub fn interpret_spawn(
 		}
 
 		let reference =
-			external_query_lookup_asset(deps.querier, registry_address.clone(), asset_id)?;
+			external_query_lookup_asset(deps.querier, registry_address.to_string(), asset_id)?;
 		let amount = {
 			let transfer_amount = match &reference {
 				AssetReference::Native { denom } => {
@@ -339,7 +635,14 @@ pub fn interpret_spawn(
 					rsp.balance
 				},
 			};
-			Amount::absolute(amount.apply(transfer_amount.into()).into())
+			let transferred = transferred
+				.0
+				.iter()
+				.find(|(id, _)| *id == asset_id)
+				.map(|(_, Displayed(amount))| *amount)
+				.unwrap_or_default();
+			let available = u128::from(transfer_amount).saturating_sub(transferred);
+			Amount::absolute(amount.apply(available))
 		};
 
 		if !amount.is_zero() {
@@ -361,37 +664,76 @@ pub fn interpret_spawn(
 		}
 	}
 
+	Ok((normalized_funds, response))
+}
+
+/// Interpret the `Query` instruction by forwarding it to the router, which bridges it to the
+/// gateway of the target `network`.
+pub fn interpret_query(
+	deps: Deps,
+	network: NetworkId,
+	salt: Vec<u8>,
+	response: Response,
+) -> Result<Response, ContractError> {
+	let Config { interpreter_origin, router_address, .. } = CONFIG.load(deps.storage)?;
 	Ok(response
 		.add_message(wasm_execute(
 			router_address,
-			&cw_xcvm_common::router::ExecuteMsg::BridgeForward {
-				msg: BridgeMsg {
-					interpreter_origin: interpreter_origin.clone(),
-					network_id: network,
-					security: bridge_security,
-					salt,
-					program,
-					assets: normalized_funds,
-				},
+			&cw_xcvm_common::router::ExecuteMsg::BridgeQuery {
+				msg: BridgeQueryMsg { interpreter_origin, network_id: network, salt },
 			},
 			Default::default(),
 		)?)
 		.add_event(
 			Event::new(XCVM_INTERPRETER_EVENT_PREFIX)
-				.add_attribute("instruction", "spawn")
-				.add_attribute(
-					"origin_network_id",
-					serde_json_wasm::to_string(&interpreter_origin.user_origin.network_id)
-						.map_err(|_| ContractError::DataSerializationError)?,
-				)
-				.add_attribute(
-					"origin_user_id",
-					serde_json_wasm::to_string(&interpreter_origin.user_origin.user_id)
-						.map_err(|_| ContractError::DataSerializationError)?,
-				),
+				.add_attribute("instruction", "query")
+				.add_attribute("network_id", format!("{}", u32::from(network))),
 		))
 }
 
+/// Handle the result of the `Query` that suspended the current program.
+/// The result is stored in the [`RESULT_REGISTER`], making it available to the late-bindings of
+/// the resumed program. If the query failed, the suspended program is aborted.
+fn handle_receive_query_result(
+	_: Authenticated,
+	deps: DepsMut,
+	env: Env,
+	result: Result<QueryResult, String>,
+) -> Result<Response, ContractError> {
+	let SuspendedProgram { relayer, program } = SUSPENDED_PROGRAM
+		.load(deps.storage)
+		.map_err(|_| ContractError::NoSuspendedProgram)?;
+	SUSPENDED_PROGRAM.remove(deps.storage);
+
+	// The query instruction is now executed.
+	let ip = IP_REGISTER.load(deps.storage)?;
+	IP_REGISTER.save(deps.storage, &(ip + 1))?;
+
+	match result {
+		Ok(result) => {
+			RESULT_REGISTER.save(deps.storage, &Ok(ExecutionResult::Query(result)))?;
+			Ok(Response::default()
+				.add_event(
+					Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("action", "resumed"),
+				)
+				.add_submessage(SubMsg::reply_on_error(
+					wasm_execute(
+						env.contract.address,
+						&ExecuteMsg::ExecuteStep { relayer, program },
+						Default::default(),
+					)?,
+					SELF_CALL_ID,
+				)))
+		},
+		Err(e) => {
+			RESULT_REGISTER.save(deps.storage, &Err(e))?;
+			Ok(Response::default().add_event(
+				Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("action", "aborted"),
+			))
+		},
+	}
+}
+
 pub fn interpret_transfer(
 	deps: &mut DepsMut,
 	env: &Env,
@@ -418,6 +760,12 @@ pub fn interpret_transfer(
 			AssetReference::Native { denom } => {
 				let mut coin = deps.querier.query_balance(env.contract.address.clone(), denom)?;
 				coin.amount = amount.apply(coin.amount.into()).into();
+				ensure_spending_permitted(
+					deps.storage,
+					env.block.time.seconds(),
+					asset_id,
+					coin.amount.u128(),
+				)?;
 				response.add_message(BankMsg::Send {
 					to_address: recipient.clone(),
 					amount: vec![coin],
@@ -434,6 +782,12 @@ pub fn interpret_transfer(
 					},
 				))?;
 				let transfer_amount = amount.apply(rsp.balance.into());
+				ensure_spending_permitted(
+					deps.storage,
+					env.block.time.seconds(),
+					asset_id,
+					transfer_amount,
+				)?;
 				response.add_message(contract.call(Cw20ExecuteMsg::Transfer {
 					recipient: recipient.clone(),
 					amount: transfer_amount.into(),
@@ -456,14 +810,34 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 		QueryMsg::Register(Register::This) => Ok(to_binary(&env.contract.address)?),
 		QueryMsg::Register(Register::Relayer) =>
 			Ok(to_binary(&RELAYER_REGISTER.load(deps.storage)?)?),
+		QueryMsg::Grant(delegate) => Ok(to_binary(&query_grant(deps, delegate)?)?),
+		QueryMsg::Grants {} => Ok(to_binary(&query_grants(deps)?)?),
 	}
 }
 
+fn query_grant(deps: Deps, delegate: Addr) -> StdResult<GrantResponse> {
+	let grant = GRANTS.may_load(deps.storage, delegate.clone())?;
+	let spendings = SPENDINGS
+		.prefix(delegate)
+		.range(deps.storage, None, None, Order::Ascending)
+		.map(|entry| entry.map(|(asset, spending)| (asset.into(), spending)))
+		.collect::<StdResult<Vec<_>>>()?;
+	Ok(GrantResponse { grant, spendings })
+}
+
+fn query_grants(deps: Deps) -> StdResult<GrantsResponse> {
+	let grants = GRANTS
+		.range(deps.storage, None, None, Order::Ascending)
+		.collect::<StdResult<Vec<_>>>()?;
+	Ok(GrantsResponse { grants })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
 	match msg.id {
 		CALL_ID => handle_call_result(deps, msg),
 		SELF_CALL_ID => handle_self_call_result(deps, msg),
+		TRY_ID => handle_try_result(deps, env, msg),
 		id => Err(StdError::generic_err(format!("Unknown reply id: {}", id))),
 	}
 }
@@ -485,8 +859,34 @@ fn handle_self_call_result(deps: DepsMut, msg: Reply) -> StdResult<Response> {
 	}
 }
 
+/// Handle the outcome of the program of the innermost `Try`.
+/// If it failed, its effects are already reverted, the error is stored in the [`RESULT_REGISTER`]
+/// and the fallback program is executed before the rest of the program.
+fn handle_try_result(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
+	let mut handlers = TRY_HANDLERS.load(deps.storage)?;
+	let TryHandler { relayer, on_error } = handlers.pop().ok_or_else(|| {
+		StdError::generic_err("No try handler for the reply. This should never happen")
+	})?;
+	TRY_HANDLERS.save(deps.storage, &handlers)?;
+	match msg.result.into_result() {
+		Ok(_) => Ok(Response::default()),
+		Err(e) => {
+			RESULT_REGISTER.save(deps.storage, &Err(e))?;
+			Ok(Response::default()
+				.add_event(
+					Event::new(XCVM_INTERPRETER_EVENT_PREFIX).add_attribute("action", "try.failed"),
+				)
+				.add_message(wasm_execute(
+					env.contract.address,
+					&ExecuteMsg::ExecuteStep { relayer, program: on_error },
+					Default::default(),
+				)?))
+		},
+	}
+}
+
 fn handle_call_result(deps: DepsMut, msg: Reply) -> StdResult<Response> {
 	let response = msg.result.into_result().map_err(StdError::generic_err)?;
-	RESULT_REGISTER.save(deps.storage, &Ok(response.clone()))?;
+	RESULT_REGISTER.save(deps.storage, &Ok(ExecutionResult::Call(response.clone())))?;
 	Ok(Response::default())
 }
@@ -1,6 +1,6 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
-use xcvm_core::{BridgeSecurity, LateBindingError};
+use xcvm_core::{AssetId, BridgeSecurity, LateBindingError};
 
 impl From<()> for ContractError {
 	fn from(_: ()) -> Self {
@@ -46,6 +46,27 @@ pub enum ContractError {
 	#[error("Only the contract is authorized for this action")]
 	NotSelf,
 
+	#[error("The program is not within the scope of the grant of the delegate")]
+	ProgramNotPermitted,
+
+	#[error("The call is not within the scope of the grant of the delegate")]
+	CallNotPermitted,
+
+	#[error("The spending limit of the delegate for the asset {0:?} is exceeded")]
+	SpendingLimitExceeded(AssetId),
+
+	#[error("A program is suspended until the result of its query is received")]
+	ProgramSuspended,
+
+	#[error("No program is waiting for a query result")]
+	NoSuspendedProgram,
+
+	#[error("A query cannot be executed within a try")]
+	QueryInTry,
+
+	#[error("The queried balance condition requires the result of a query")]
+	NoQueryResult,
+
 	#[error("Instruction {0} is not supported")]
 	InstructionNotSupported(String),
 
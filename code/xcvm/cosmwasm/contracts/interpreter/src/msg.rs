@@ -1,11 +1,12 @@
 extern crate alloc;
 
+use crate::state::{Grant, Spending};
 use alloc::{string::String, vec::Vec};
 use cosmwasm_std::Addr;
 use cw_xcvm_utils::DefaultXCVMProgram;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::{InterpreterOrigin, Register};
+use xcvm_core::{AssetId, InterpreterOrigin, QueryResult, Register};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -29,10 +30,18 @@ pub enum ExecuteMsg {
 	/// hit a call, the program queue the call and queue itself after it to ensure that the side
 	/// effect of the call has been executed.
 	ExecuteStep { relayer: Addr, program: DefaultXCVMProgram },
+	/// This is only meant to be used by the router, otherwise it will return an error
+	/// Deliver the result of a `Query`, resuming the program that was suspended by it.
+	ReceiveQueryResult { result: Result<QueryResult, String> },
 	/// Add owners of this contract
 	AddOwners { owners: Vec<Addr> },
 	/// Remove owners from the contract
 	RemoveOwners { owners: Vec<Addr> },
+	/// Allow `delegate` to execute the programs within the scope of the `grant`, replacing its
+	/// previous grant if any.
+	Grant { delegate: Addr, grant: Grant },
+	/// Revoke the grant of `delegate`.
+	Revoke { delegate: Addr },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -46,4 +55,19 @@ pub struct MigrateMsg {
 pub enum QueryMsg {
 	/// Get a specific register
 	Register(Register),
+	/// Get the grant of a delegate and its spendings
+	Grant(Addr),
+	/// Get the grants of all the delegates
+	Grants {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GrantResponse {
+	pub grant: Option<Grant>,
+	pub spendings: Vec<(AssetId, Spending)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GrantsResponse {
+	pub grants: Vec<(Addr, Grant)>,
 }
@@ -1,11 +1,12 @@
 extern crate alloc;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use cosmwasm_std::{Addr, SubMsgResponse};
 use cw_storage_plus::{Item, Map};
+use cw_xcvm_utils::DefaultXCVMProgram;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::InterpreterOrigin;
+use xcvm_core::{AssetId, Displayed, InterpreterOrigin, QueryResult};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
@@ -15,10 +16,74 @@ pub struct Config {
 	pub interpreter_origin: InterpreterOrigin,
 }
 
+/// Successful outcome of the last executed instruction.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionResult {
+	/// Response of a `Call`.
+	Call(SubMsgResponse),
+	/// State of the interpreter targeted by a `Query`.
+	Query(QueryResult),
+}
+
+/// The remaining instructions of a program suspended by a `Query`, executed once the query
+/// result has been received.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SuspendedProgram {
+	pub relayer: Addr,
+	pub program: DefaultXCVMProgram,
+}
+
+/// Fallback of a `Try` whose program is being executed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TryHandler {
+	pub relayer: Addr,
+	pub on_error: DefaultXCVMProgram,
+}
+
+/// Scope of the programs a delegate is allowed to execute on behalf of the owners.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Grant {
+	/// Tag the executed programs must have, any tag is accepted if `None`.
+	pub tag: Option<Vec<u8>>,
+	/// Contracts the `Call` instructions can execute, any contract but the interpreter itself if
+	/// `None`. Calls can neither send native assets, instantiate contracts nor administer them.
+	pub call_targets: Option<Vec<Addr>>,
+	/// Maximum amounts the `Transfer`, `Spawn` and `Abort` instructions, as well as the cw20
+	/// transfers, sends and allowances of the `Call` instructions, can move out of the
+	/// interpreter. The assets that are not listed cannot be moved.
+	pub spending_limits: Vec<SpendingLimit>,
+}
+
+/// Maximum `amount` of `asset` moved out of the interpreter within `period` seconds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SpendingLimit {
+	pub asset: AssetId,
+	pub amount: Displayed<u128>,
+	pub period: u64,
+}
+
+/// Amount spent by a delegate since the start of the current period of a spending limit.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Spending {
+	pub period_start: u64,
+	pub spent: Displayed<u128>,
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const OWNERS: Map<Addr, ()> = Map::new("owners");
+pub const GRANTS: Map<Addr, Grant> = Map::new("grants");
+pub const SPENDINGS: Map<(Addr, u128), Spending> = Map::new("spendings");
+/// The delegate executing the current program, absent if it is executed by an owner.
+pub const EXECUTING_DELEGATE: Item<Addr> = Item::new("executing_delegate");
 
 // Registers
 pub const IP_REGISTER: Item<u32> = Item::new("ip_register");
-pub const RESULT_REGISTER: Item<Result<SubMsgResponse, String>> = Item::new("result_register");
+pub const RESULT_REGISTER: Item<Result<ExecutionResult, String>> = Item::new("result_register");
 pub const RELAYER_REGISTER: Item<Addr> = Item::new("relayer_register");
+
+pub const SUSPENDED_PROGRAM: Item<SuspendedProgram> = Item::new("suspended_program");
+/// Handlers of the nested `Try` being executed, the innermost one being the last.
+pub const TRY_HANDLERS: Item<Vec<TryHandler>> = Item::new("try_handlers");
+/// Set by an `Abort`, the remaining steps of the program are skipped.
+pub const ABORTED: Item<bool> = Item::new("aborted");
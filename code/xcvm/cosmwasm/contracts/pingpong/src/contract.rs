@@ -16,12 +16,14 @@ use cw_utils::ensure_from_older_version;
 use cw_xcvm_utils::DefaultXCVMProgram;
 use xcvm_core::{
 	cosmwasm::{FlatCosmosMsg, FlatWasmMsg},
-	BridgeSecurity, Funds, Juno, Network, Picasso, ProgramBuilder, UserId, UserOrigin,
+	BridgeSecurity, Deadline, Funds, Juno, Network, Picasso, ProgramBuilder, UserId, UserOrigin,
 };
 
 const CONTRACT_NAME: &str = "composable:xcvm-pingpong";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const XCVM_PINGPONG_EVENT_PREFIX: &str = "xcvm.pingpong";
+/// Seconds for the counterparty to execute a ping or a pong.
+const PONG_DEADLINE: u64 = 3600;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -47,6 +49,8 @@ fn make_program<T: Network<EncodedCall = Vec<u8>>, U: Network<EncodedCall = Vec<
 			vec![0x01, 0x02, 0x03],
 			BridgeSecurity::Deterministic,
 			Funds::empty(),
+			Funds::empty(),
+			Deadline::Relative(PONG_DEADLINE),
 			|child| {
 				Ok(child.call_raw(
 					serde_json::to_vec(&FlatCosmosMsg::Wasm(FlatWasmMsg::<ExecuteMsg>::Execute {
@@ -2,33 +2,41 @@ extern crate alloc;
 
 use crate::{
 	error::ContractError,
-	msg::{InstantiateMsg, MigrateMsg, QueryMsg},
+	msg::{
+		ConfigResponse, InstantiateMsg, InterpreterResponse, InterpretersResponse, MigrateMsg,
+		QueryMsg,
+	},
 	state::{Config, Interpreter, CONFIG, INTERPRETERS},
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
 	to_binary, wasm_execute, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Event,
-	MessageInfo, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+	MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{Cw20Contract, Cw20ExecuteMsg};
+use cw_storage_plus::Bound;
 use cw_utils::ensure_from_older_version;
 use cw_xcvm_asset_registry::{contract::external_query_lookup_asset, msg::AssetReference};
 use cw_xcvm_common::{
 	router::ExecuteMsg,
-	shared::{decode_base64, BridgeMsg},
+	shared::{decode_base64, BridgeMsg, BridgeQueryMsg},
 };
 use cw_xcvm_interpreter::contract::{
 	XCVM_INTERPRETER_EVENT_DATA_ORIGIN, XCVM_INTERPRETER_EVENT_PREFIX,
 };
 use cw_xcvm_utils::DefaultXCVMProgram;
-use xcvm_core::{BridgeSecurity, CallOrigin, Displayed, Funds, InterpreterOrigin};
+use xcvm_core::{
+	BridgeSecurity, CallOrigin, Displayed, Funds, InterpreterOrigin, QueryResult, UserOrigin,
+};
 
 const CONTRACT_NAME: &str = "composable:xcvm-router";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const INSTANTIATE_REPLY_ID: u64 = 1;
 pub const XCVM_ROUTER_EVENT_PREFIX: &str = "xcvm.router";
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -82,6 +90,13 @@ pub fn execute(
 			handle_set_interpreter_security(deps, info, interpreter_origin, bridge_security),
 
 		ExecuteMsg::BridgeForward { msg } => handle_bridge_forward(deps, info, msg),
+
+		ExecuteMsg::BridgeQuery { msg } => handle_bridge_query(deps, info, msg),
+
+		ExecuteMsg::DeliverQueryResult { interpreter, result } => {
+			ensure_self_or_gateway(&deps, &env.contract.address, &info.sender)?;
+			handle_deliver_query_result(interpreter, result)
+		},
 	}
 }
 
@@ -166,6 +181,7 @@ fn handle_bridge_forward(
 		.assets
 		.0
 		.iter()
+		.chain(msg.fee.0.iter())
 		.map(|(asset, Displayed(amount))| {
 			let reference = external_query_lookup_asset(
 				deps.querier,
@@ -193,6 +209,35 @@ fn handle_bridge_forward(
 	)?))
 }
 
+/// Handle a request to forward a query to the bridge gateway.
+/// The call must originate from an interpreter.
+fn handle_bridge_query(
+	deps: DepsMut,
+	info: MessageInfo,
+	msg: BridgeQueryMsg,
+) -> Result<Response, ContractError> {
+	ensure_interpreter(&deps, &info.sender, msg.interpreter_origin.clone())?;
+	let config = CONFIG.load(deps.storage)?;
+	Ok(Response::default().add_message(wasm_execute(
+		config.gateway_address,
+		&cw_xcvm_common::gateway::ExecuteMsg::BridgeQuery { interpreter: info.sender, msg },
+		Default::default(),
+	)?))
+}
+
+/// Handle the result of a query bridged by the gateway, resuming the interpreter that issued
+/// it.
+fn handle_deliver_query_result(
+	interpreter: Addr,
+	result: Result<QueryResult, String>,
+) -> Result<Response, ContractError> {
+	Ok(Response::default().add_message(wasm_execute(
+		interpreter,
+		&cw_xcvm_interpreter::msg::ExecuteMsg::ReceiveQueryResult { result },
+		Default::default(),
+	)?))
+}
+
 /// Handle a request to change an interpreter security level.
 /// Only the interpreter instance itself is allowed to change it's security level.
 /// A user is able to change it's interpreter security level by provided an [`XCVMProgram`] that
@@ -364,8 +409,54 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, _msg: QueryMsg) -> StdResult<Binary> {
-	Err(StdError::generic_err("not implemented"))
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+	match msg {
+		QueryMsg::Config {} => to_binary(&query_config(deps)?),
+		QueryMsg::Interpreter { interpreter_origin } =>
+			to_binary(&query_interpreter(deps, interpreter_origin)?),
+		QueryMsg::Interpreters { start_after, limit } =>
+			to_binary(&query_interpreters(deps, start_after, limit)?),
+	}
+}
+
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+	let config = CONFIG.load(deps.storage)?;
+	Ok(ConfigResponse { config })
+}
+
+pub fn query_interpreter(
+	deps: Deps,
+	interpreter_origin: InterpreterOrigin,
+) -> StdResult<InterpreterResponse> {
+	let interpreter = INTERPRETERS.load(deps.storage, interpreter_origin)?;
+	Ok(InterpreterResponse { interpreter })
+}
+
+pub fn query_interpreters(
+	deps: Deps,
+	start_after: Option<InterpreterOrigin>,
+	limit: Option<u32>,
+) -> StdResult<InterpretersResponse> {
+	let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+	let interpreters = INTERPRETERS
+		.range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+		.take(limit)
+		.map(|entry| {
+			entry.map(|((network_id, user_id, salt), interpreter)| {
+				(
+					InterpreterOrigin {
+						user_origin: UserOrigin {
+							network_id: network_id.into(),
+							user_id: user_id.into(),
+						},
+						salt,
+					},
+					interpreter,
+				)
+			})
+		})
+		.collect::<StdResult<Vec<_>>>()?;
+	Ok(InterpretersResponse { interpreters })
 }
 
 fn handle_instantiate_reply(deps: DepsMut, msg: Reply) -> StdResult<Response> {
@@ -426,3 +517,162 @@ fn handle_instantiate_reply(deps: DepsMut, msg: Reply) -> StdResult<Response> {
 
 	Ok(Response::new())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use cosmwasm_std::Empty;
+	use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+	use xcvm_core::{Network, Picasso, Program};
+
+	fn router_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(ContractWrapper::new(execute, instantiate, query).with_reply(reply))
+	}
+
+	fn interpreter_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(
+			ContractWrapper::new(
+				cw_xcvm_interpreter::contract::execute,
+				cw_xcvm_interpreter::contract::instantiate,
+				cw_xcvm_interpreter::contract::query,
+			)
+			.with_reply(cw_xcvm_interpreter::contract::reply),
+		)
+	}
+
+	fn registry_contract() -> Box<dyn Contract<Empty>> {
+		Box::new(ContractWrapper::new(
+			cw_xcvm_asset_registry::contract::execute,
+			cw_xcvm_asset_registry::contract::instantiate,
+			cw_xcvm_asset_registry::contract::query,
+		))
+	}
+
+	fn setup() -> (App, Addr) {
+		let mut app = App::default();
+		let router_code_id = app.store_code(router_contract());
+		let interpreter_code_id = app.store_code(interpreter_contract());
+		let registry_code_id = app.store_code(registry_contract());
+		let registry = app
+			.instantiate_contract(
+				registry_code_id,
+				Addr::unchecked("admin"),
+				&cw_xcvm_asset_registry::msg::InstantiateMsg {},
+				&[],
+				"xcvm-registry",
+				None,
+			)
+			.unwrap();
+		let router = app
+			.instantiate_contract(
+				router_code_id,
+				Addr::unchecked("gateway"),
+				&InstantiateMsg {
+					registry_address: registry.into_string(),
+					interpreter_code_id,
+					network_id: Picasso::ID,
+				},
+				&[],
+				"xcvm-router",
+				None,
+			)
+			.unwrap();
+		(app, router)
+	}
+
+	fn execute_empty_program(app: &mut App, router: &Addr, salt: &[u8]) {
+		app.execute_contract(
+			Addr::unchecked("user"),
+			router.clone(),
+			&ExecuteMsg::ExecuteProgram {
+				salt: salt.to_vec(),
+				program: Program { tag: vec![], instructions: Default::default() },
+				assets: Funds::empty(),
+			},
+			&[],
+		)
+		.unwrap();
+	}
+
+	fn user_interpreter_origin(salt: &[u8]) -> InterpreterOrigin {
+		InterpreterOrigin {
+			user_origin: UserOrigin { network_id: Picasso::ID, user_id: b"user".to_vec().into() },
+			salt: salt.to_vec(),
+		}
+	}
+
+	#[test]
+	fn query_config() {
+		let (app, router) = setup();
+		let ConfigResponse { config } =
+			app.wrap().query_wasm_smart(router, &QueryMsg::Config {}).unwrap();
+		assert_eq!(config.gateway_address, Addr::unchecked("gateway"));
+		assert_eq!(config.network_id, Picasso::ID);
+	}
+
+	#[test]
+	fn query_interpreters() {
+		let (mut app, router) = setup();
+
+		let InterpretersResponse { interpreters } = app
+			.wrap()
+			.query_wasm_smart(
+				router.clone(),
+				&QueryMsg::Interpreters { start_after: None, limit: None },
+			)
+			.unwrap();
+		assert!(interpreters.is_empty());
+
+		let salts: [&[u8]; 3] = [b"a", b"b", b"c"];
+		for salt in salts {
+			execute_empty_program(&mut app, &router, salt);
+		}
+
+		let InterpreterResponse { interpreter } = app
+			.wrap()
+			.query_wasm_smart(
+				router.clone(),
+				&QueryMsg::Interpreter { interpreter_origin: user_interpreter_origin(b"b") },
+			)
+			.unwrap();
+		assert!(interpreter.address.is_some());
+		assert_eq!(interpreter.security, BridgeSecurity::Deterministic);
+
+		let InterpretersResponse { interpreters: first_page } = app
+			.wrap()
+			.query_wasm_smart(
+				router.clone(),
+				&QueryMsg::Interpreters { start_after: None, limit: Some(2) },
+			)
+			.unwrap();
+		assert_eq!(
+			first_page.iter().map(|(origin, _)| origin.clone()).collect::<Vec<_>>(),
+			vec![user_interpreter_origin(b"a"), user_interpreter_origin(b"b")]
+		);
+
+		let InterpretersResponse { interpreters: second_page } = app
+			.wrap()
+			.query_wasm_smart(
+				router,
+				&QueryMsg::Interpreters {
+					start_after: Some(user_interpreter_origin(b"b")),
+					limit: Some(2),
+				},
+			)
+			.unwrap();
+		assert_eq!(
+			second_page.into_iter().map(|(origin, _)| origin).collect::<Vec<_>>(),
+			vec![user_interpreter_origin(b"c")]
+		);
+	}
+
+	#[test]
+	fn query_unknown_interpreter() {
+		let (app, router) = setup();
+		let result: StdResult<InterpreterResponse> = app.wrap().query_wasm_smart(
+			router,
+			&QueryMsg::Interpreter { interpreter_origin: user_interpreter_origin(b"a") },
+		);
+		assert!(result.is_err());
+	}
+}
@@ -1,6 +1,7 @@
+use crate::state::{Config, Interpreter};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use xcvm_core::NetworkId;
+use xcvm_core::{InterpreterOrigin, NetworkId};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {
@@ -16,4 +17,31 @@ pub struct InstantiateMsg {
 pub struct MigrateMsg {}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub enum QueryMsg {}
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+	Config {},
+	/// Lookup the interpreter instantiated for the given origin.
+	Interpreter {
+		interpreter_origin: InterpreterOrigin,
+	},
+	/// List the interpreters, in ascending order of their origin.
+	Interpreters {
+		start_after: Option<InterpreterOrigin>,
+		limit: Option<u32>,
+	},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+	pub config: Config,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterpreterResponse {
+	pub interpreter: Interpreter,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InterpretersResponse {
+	pub interpreters: Vec<(InterpreterOrigin, Interpreter)>,
+}
@@ -0,0 +1,167 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{
+	Addr, Api, Binary, BlockInfo, CustomQuery, DepsMut, Empty, Env, Event, IbcChannelConnectMsg,
+	IbcChannelOpenMsg, IbcMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcQuery,
+	IbcTimeout, Order, Querier, Response, Storage,
+};
+use cw_multi_test::{AppResponse, CosmosRouter, Ibc, Module};
+use cw_storage_plus::Map;
+use cw_xcvm_gateway::{contract, error::ContractError, state::ChannelId};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A packet sent by a contract of a network, waiting to be relayed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OutgoingPacket {
+	pub channel_id: ChannelId,
+	pub sequence: u64,
+	pub data: Binary,
+	pub timeout: IbcTimeout,
+}
+
+/// Packets sent on each channel, indexed by their sequence.
+const OUTGOING_PACKETS: Map<(ChannelId, u64), OutgoingPacket> =
+	Map::new("simulator_ibc_outgoing_packets");
+/// Sequence of the next packet sent on each channel.
+const NEXT_SEQUENCES: Map<ChannelId, u64> = Map::new("simulator_ibc_next_sequences");
+
+/// IBC module of a simulated network.
+/// Sent packets are stored until they are taken by the relayer of the [`crate::Simulator`].
+#[derive(Default)]
+pub struct SimulatedIbc;
+
+impl SimulatedIbc {
+	/// Take all the packets sent since the last call, in order of their channel and sequence.
+	pub fn take_outgoing_packets(storage: &mut dyn Storage) -> AnyResult<Vec<OutgoingPacket>> {
+		let packets = OUTGOING_PACKETS
+			.range(storage, None, None, Order::Ascending)
+			.map(|entry| entry.map(|(_, packet)| packet))
+			.collect::<Result<Vec<_>, _>>()?;
+		for packet in &packets {
+			OUTGOING_PACKETS.remove(storage, (packet.channel_id.clone(), packet.sequence));
+		}
+		Ok(packets)
+	}
+}
+
+impl Module for SimulatedIbc {
+	type ExecT = IbcMsg;
+	type QueryT = IbcQuery;
+	type SudoT = Empty;
+
+	fn execute<ExecC, QueryC>(
+		&self,
+		_api: &dyn Api,
+		storage: &mut dyn Storage,
+		_router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+		_block: &BlockInfo,
+		sender: Addr,
+		msg: Self::ExecT,
+	) -> AnyResult<AppResponse>
+	where
+		ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+		QueryC: CustomQuery + DeserializeOwned + 'static,
+	{
+		match msg {
+			IbcMsg::SendPacket { channel_id, data, timeout } => {
+				let sequence = NEXT_SEQUENCES.may_load(storage, channel_id.clone())?.unwrap_or(1);
+				NEXT_SEQUENCES.save(storage, channel_id.clone(), &(sequence + 1))?;
+				OUTGOING_PACKETS.save(
+					storage,
+					(channel_id.clone(), sequence),
+					&OutgoingPacket { channel_id: channel_id.clone(), sequence, data, timeout },
+				)?;
+				// Mirror the event of the IBC module of wasmd, contracts read the sequence from it.
+				Ok(AppResponse {
+					events: vec![Event::new("send_packet")
+						.add_attribute("packet_src_port", format!("wasm.{sender}"))
+						.add_attribute("packet_src_channel", channel_id)
+						.add_attribute("packet_sequence", format!("{sequence}"))],
+					data: None,
+				})
+			},
+			msg => bail!("Unsupported IBC message {:?} from {}", msg, sender),
+		}
+	}
+
+	fn sudo<ExecC, QueryC>(
+		&self,
+		_api: &dyn Api,
+		_storage: &mut dyn Storage,
+		_router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+		_block: &BlockInfo,
+		msg: Self::SudoT,
+	) -> AnyResult<AppResponse>
+	where
+		ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+		QueryC: CustomQuery + DeserializeOwned + 'static,
+	{
+		bail!("Unsupported IBC sudo {:?}", msg)
+	}
+
+	fn query(
+		&self,
+		_api: &dyn Api,
+		_storage: &dyn Storage,
+		_querier: &dyn Querier,
+		_block: &BlockInfo,
+		request: Self::QueryT,
+	) -> AnyResult<Binary> {
+		bail!("Unsupported IBC query {:?}", request)
+	}
+}
+
+impl Ibc for SimulatedIbc {}
+
+/// The IBC entry points of the gateway.
+/// cw-multi-test is unable to call them, they are dispatched as sudo messages instead.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IbcSudoMsg {
+	ChannelOpen(IbcChannelOpenMsg),
+	ChannelConnect(IbcChannelConnectMsg),
+	PacketReceive(IbcPacketReceiveMsg),
+	PacketAck(IbcPacketAckMsg),
+	PacketTimeout(IbcPacketTimeoutMsg),
+}
+
+/// Sudo entry point of the simulated gateway, the acknowledgement of a received packet is
+/// returned as the data of the response.
+pub fn gateway_sudo(deps: DepsMut, env: Env, msg: IbcSudoMsg) -> Result<Response, ContractError> {
+	match msg {
+		IbcSudoMsg::ChannelOpen(msg) => {
+			contract::ibc_channel_open(deps, env, msg)?;
+			Ok(Response::default())
+		},
+		IbcSudoMsg::ChannelConnect(msg) => {
+			let response = contract::ibc_channel_connect(deps, env, msg)?;
+			Ok(Response::default()
+				.add_submessages(response.messages)
+				.add_attributes(response.attributes)
+				.add_events(response.events))
+		},
+		IbcSudoMsg::PacketReceive(msg) => {
+			let response = contract::ibc_packet_receive(deps, env, msg)?;
+			Ok(Response::default()
+				.add_submessages(response.messages)
+				.add_attributes(response.attributes)
+				.add_events(response.events)
+				.set_data(response.acknowledgement))
+		},
+		IbcSudoMsg::PacketAck(msg) => {
+			let response = contract::ibc_packet_ack(deps, env, msg)?;
+			Ok(Response::default()
+				.add_submessages(response.messages)
+				.add_attributes(response.attributes)
+				.add_events(response.events))
+		},
+		IbcSudoMsg::PacketTimeout(msg) => {
+			let response = contract::ibc_packet_timeout(deps, env, msg)?;
+			Ok(Response::default()
+				.add_submessages(response.messages)
+				.add_attributes(response.attributes)
+				.add_events(response.events))
+		},
+	}
+}
@@ -0,0 +1,374 @@
+//! Simulate XCVM deployments on several networks connected over IBC.
+//!
+//! Every network is a cw-multi-test chain running the asset registry, gateway, router and
+//! interpreter contracts. The gateways are connected by simulated IBC channels, the packets they
+//! send are relayed in-process by [`Simulator::relay`].
+
+mod ibc;
+mod network;
+
+pub use crate::{
+	ibc::{gateway_sudo, IbcSudoMsg, OutgoingPacket, SimulatedIbc},
+	network::{NetworkApp, SimulatedNetwork},
+};
+
+use anyhow::{anyhow, bail, Result as AnyResult};
+use cosmwasm_std::{
+	Addr, Binary, BlockInfo, IbcAcknowledgement, IbcChannel, IbcChannelConnectMsg,
+	IbcChannelOpenMsg, IbcEndpoint, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg,
+	IbcPacketTimeoutMsg, IbcTimeout,
+};
+use cw_multi_test::Executor;
+use cw_xcvm_gateway::{
+	contract::{XCVM_GATEWAY_IBC_ORDERING, XCVM_GATEWAY_IBC_VERSION},
+	state::ChannelId,
+};
+use std::collections::BTreeMap;
+use xcvm_core::{Asset, Network, NetworkId};
+
+/// Relaying rounds after which [`Simulator::relay`] gives up, programs bouncing between networks
+/// forever would otherwise never settle.
+pub const MAX_RELAY_ROUNDS: usize = 32;
+
+/// Outcome of a packet relayed between two networks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RelayedPacket {
+	pub source: NetworkId,
+	pub destination: NetworkId,
+	pub channel_id: ChannelId,
+	pub sequence: u64,
+	/// The acknowledgement written by the destination, `None` if the packet timed out.
+	pub acknowledgement: Option<Binary>,
+}
+
+/// Several networks running XCVM, with an in-process relayer between their gateways.
+pub struct Simulator {
+	networks: BTreeMap<NetworkId, SimulatedNetwork>,
+	/// Counterparty network and channel of each channel.
+	channels: BTreeMap<(NetworkId, ChannelId), (NetworkId, ChannelId)>,
+	relayer: Addr,
+}
+
+impl Default for Simulator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Simulator {
+	pub fn new() -> Self {
+		Simulator {
+			networks: BTreeMap::new(),
+			channels: BTreeMap::new(),
+			relayer: Addr::unchecked("relayer"),
+		}
+	}
+
+	/// The account relaying the packets on every network.
+	pub fn relayer(&self) -> &Addr {
+		&self.relayer
+	}
+
+	/// Deploy XCVM on a new network.
+	pub fn add_network<N: Network>(&mut self) -> AnyResult<&mut SimulatedNetwork> {
+		if self.networks.contains_key(&N::ID) {
+			bail!("Network {:?} already exists", N::ID)
+		}
+		let network = SimulatedNetwork::new(N::ID)?;
+		Ok(self.networks.entry(N::ID).or_insert(network))
+	}
+
+	pub fn network(&self, network_id: NetworkId) -> &SimulatedNetwork {
+		self.networks.get(&network_id).expect("network must have been added")
+	}
+
+	pub fn network_mut(&mut self, network_id: NetworkId) -> &mut SimulatedNetwork {
+		self.networks.get_mut(&network_id).expect("network must have been added")
+	}
+
+	/// Register an asset with 6 decimals on every network.
+	pub fn register_asset<A: Asset>(&mut self, symbol: &str) -> AnyResult<()> {
+		for network in self.networks.values_mut() {
+			network.register_asset(A::ID, symbol, 6)?;
+		}
+		Ok(())
+	}
+
+	/// Open a channel between the gateways of two networks and assign it to the networks.
+	pub fn connect(&mut self, a: NetworkId, b: NetworkId) -> AnyResult<()> {
+		if a == b {
+			bail!("Network {:?} cannot be connected to itself", a)
+		}
+		let endpoint_a = self.next_endpoint(a)?;
+		let endpoint_b = self.next_endpoint(b)?;
+		let channel = |endpoint: &IbcEndpoint, counterparty: &IbcEndpoint| {
+			IbcChannel::new(
+				endpoint.clone(),
+				counterparty.clone(),
+				XCVM_GATEWAY_IBC_ORDERING,
+				XCVM_GATEWAY_IBC_VERSION,
+				format!("connection-{}-{}", a.0, b.0),
+			)
+		};
+		let channel_a = channel(&endpoint_a, &endpoint_b);
+		let channel_b = channel(&endpoint_b, &endpoint_a);
+
+		// Handshake, initiated by `a`.
+		self.network_mut(a)
+			.gateway_sudo(IbcSudoMsg::ChannelOpen(IbcChannelOpenMsg::new_init(
+				channel_a.clone(),
+			)))?;
+		self.network_mut(b)
+			.gateway_sudo(IbcSudoMsg::ChannelOpen(IbcChannelOpenMsg::new_try(
+				channel_b.clone(),
+				XCVM_GATEWAY_IBC_VERSION,
+			)))?;
+		self.network_mut(a).gateway_sudo(IbcSudoMsg::ChannelConnect(
+			IbcChannelConnectMsg::new_ack(channel_a, XCVM_GATEWAY_IBC_VERSION),
+		))?;
+		self.network_mut(b).gateway_sudo(IbcSudoMsg::ChannelConnect(
+			IbcChannelConnectMsg::new_confirm(channel_b),
+		))?;
+
+		for (network_id, endpoint, counterparty_id) in [(a, &endpoint_a, b), (b, &endpoint_b, a)] {
+			let network = self.network_mut(network_id);
+			network.app.execute_contract(
+				network.admin.clone(),
+				network.gateway.clone(),
+				&cw_xcvm_common::gateway::ExecuteMsg::IbcSetNetworkChannel {
+					network_id: counterparty_id,
+					channel_id: endpoint.channel_id.clone(),
+				},
+				&[],
+			)?;
+		}
+		self.channels
+			.insert((a, endpoint_a.channel_id.clone()), (b, endpoint_b.channel_id.clone()));
+		self.channels.insert((b, endpoint_b.channel_id), (a, endpoint_a.channel_id));
+		Ok(())
+	}
+
+	/// Relay the packets sent by every network, then the packets sent while processing them,
+	/// until no packet is left.
+	pub fn relay(&mut self) -> AnyResult<Vec<RelayedPacket>> {
+		let mut relayed = Vec::new();
+		for _ in 0..MAX_RELAY_ROUNDS {
+			let network_ids = self.networks.keys().copied().collect::<Vec<_>>();
+			let mut outgoing = Vec::new();
+			for network_id in network_ids {
+				for packet in self.network_mut(network_id).take_outgoing_packets()? {
+					outgoing.push((network_id, packet));
+				}
+			}
+			if outgoing.is_empty() {
+				return Ok(relayed)
+			}
+			for (source, packet) in outgoing {
+				relayed.push(self.relay_packet(source, packet)?);
+			}
+		}
+		bail!("Packets are still in flight after {} relaying rounds", MAX_RELAY_ROUNDS)
+	}
+
+	/// Deliver a packet to its destination and its acknowledgement back to its source, or time
+	/// it out on its source if the destination is past its timeout.
+	fn relay_packet(
+		&mut self,
+		source: NetworkId,
+		packet: OutgoingPacket,
+	) -> AnyResult<RelayedPacket> {
+		let (destination, destination_channel_id) = self
+			.channels
+			.get(&(source, packet.channel_id.clone()))
+			.cloned()
+			.ok_or_else(|| anyhow!("Unknown channel {} on {:?}", packet.channel_id, source))?;
+		let endpoint = |network: &SimulatedNetwork, channel_id: ChannelId| IbcEndpoint {
+			port_id: format!("wasm.{}", network.gateway),
+			channel_id,
+		};
+		let ibc_packet = IbcPacket::new(
+			packet.data,
+			endpoint(self.network(source), packet.channel_id.clone()),
+			endpoint(self.network(destination), destination_channel_id),
+			packet.sequence,
+			packet.timeout.clone(),
+		);
+		let relayer = self.relayer.clone();
+		let acknowledgement =
+			if is_timed_out(&packet.timeout, &self.network(destination).app.block_info()) {
+				self.network_mut(source).gateway_sudo(IbcSudoMsg::PacketTimeout(
+					IbcPacketTimeoutMsg::new(ibc_packet, relayer),
+				))?;
+				None
+			} else {
+				let acknowledgement = self
+					.network_mut(destination)
+					.gateway_sudo(IbcSudoMsg::PacketReceive(IbcPacketReceiveMsg::new(
+						ibc_packet.clone(),
+						relayer.clone(),
+					)))?
+					.data
+					.unwrap_or_default();
+				self.network_mut(source).gateway_sudo(IbcSudoMsg::PacketAck(
+					IbcPacketAckMsg::new(
+						IbcAcknowledgement::new(acknowledgement.clone()),
+						ibc_packet,
+						relayer,
+					),
+				))?;
+				Some(acknowledgement)
+			};
+		Ok(RelayedPacket {
+			source,
+			destination,
+			channel_id: packet.channel_id,
+			sequence: packet.sequence,
+			acknowledgement,
+		})
+	}
+
+	/// A fresh channel endpoint on the gateway of `network_id`.
+	fn next_endpoint(&self, network_id: NetworkId) -> AnyResult<IbcEndpoint> {
+		let network = self
+			.networks
+			.get(&network_id)
+			.ok_or_else(|| anyhow!("Unknown network {:?}", network_id))?;
+		let channels = self.channels.keys().filter(|(id, _)| *id == network_id).count();
+		Ok(IbcEndpoint {
+			port_id: format!("wasm.{}", network.gateway),
+			channel_id: format!("channel-{channels}"),
+		})
+	}
+}
+
+/// Whether a packet can no longer be received by a network at `block`.
+fn is_timed_out(timeout: &IbcTimeout, block: &BlockInfo) -> bool {
+	let height_reached = timeout.block().map(|timeout| timeout.height <= block.height);
+	let time_reached = timeout.timestamp().map(|timeout| timeout <= block.time);
+	height_reached.unwrap_or(false) || time_reached.unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use cosmwasm_std::{Api, CanonicalAddr};
+	use cw_xcvm_utils::DefaultXCVMProgram;
+	use xcvm_core::{
+		BridgeSecurity, Deadline, Destination, Funds, InterpreterOrigin, Juno, Picasso,
+		ProgramBuilder, UserOrigin, XCVMAck, PICA,
+	};
+
+	const SALT: &[u8] = b"salt";
+
+	fn setup() -> Simulator {
+		let mut simulator = Simulator::new();
+		simulator.add_network::<Picasso>().unwrap();
+		simulator.add_network::<Juno>().unwrap();
+		simulator.register_asset::<PICA>("PICA").unwrap();
+		simulator.connect(Picasso::ID, Juno::ID).unwrap();
+		simulator.network_mut(Picasso::ID).mint(PICA::ID, "alice", 1_000).unwrap();
+		simulator
+	}
+
+	/// Send 400 PICA from Picasso to Juno and transfer them to bob, the spawned program fails
+	/// after the transfer if `fails`.
+	fn spawn_program(simulator: &Simulator, fails: bool) -> DefaultXCVMProgram {
+		let bob = simulator.network(Juno::ID).app.api().addr_canonicalize("bob").unwrap();
+		ProgramBuilder::<Picasso, CanonicalAddr, Funds>::new(b"main".to_vec())
+			.spawn::<Juno, _, (), _>(
+				b"spawned".to_vec(),
+				SALT.to_vec(),
+				BridgeSecurity::Deterministic,
+				Funds::from([(PICA::ID, 400_u128)]),
+				Funds::empty(),
+				Deadline::Relative(3600),
+				|child| {
+					let child = child
+						.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 400_u128)]));
+					// Not a valid cosmos message.
+					Ok(if fails { child.call_raw(b"invalid".to_vec()) } else { child })
+				},
+			)
+			.unwrap()
+			.build()
+	}
+
+	fn alice_interpreter(simulator: &Simulator) -> Addr {
+		simulator
+			.network(Picasso::ID)
+			.interpreter(InterpreterOrigin {
+				user_origin: UserOrigin {
+					network_id: Picasso::ID,
+					user_id: b"alice".to_vec().into(),
+				},
+				salt: SALT.to_vec(),
+			})
+			.expect("interpreter must have been instantiated")
+	}
+
+	#[test]
+	fn spawn_delivers_assets() {
+		let mut simulator = setup();
+		let program = spawn_program(&simulator, false);
+		simulator
+			.network_mut(Picasso::ID)
+			.execute_program("alice", SALT.to_vec(), program, Funds::from([(PICA::ID, 1_000_u128)]))
+			.unwrap();
+
+		let relayed = simulator.relay().unwrap();
+		assert_eq!(relayed.len(), 1);
+		assert_eq!(relayed[0].source, Picasso::ID);
+		assert_eq!(relayed[0].destination, Juno::ID);
+		assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::OK.into_vec().into()));
+
+		let picasso = simulator.network(Picasso::ID);
+		assert_eq!(picasso.balance(PICA::ID, "alice").unwrap(), 0);
+		assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 600);
+		// The escrowed assets are burnt once the packet is acknowledged.
+		assert_eq!(picasso.balance(PICA::ID, picasso.gateway.clone()).unwrap(), 0);
+		assert_eq!(simulator.network(Juno::ID).balance(PICA::ID, "bob").unwrap(), 400);
+	}
+
+	#[test]
+	fn failed_spawn_refunds_assets() {
+		let mut simulator = setup();
+		let program = spawn_program(&simulator, true);
+		simulator
+			.network_mut(Picasso::ID)
+			.execute_program("alice", SALT.to_vec(), program, Funds::from([(PICA::ID, 1_000_u128)]))
+			.unwrap();
+
+		let relayed = simulator.relay().unwrap();
+		assert_eq!(relayed.len(), 1);
+		assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::KO.into_vec().into()));
+
+		let picasso = simulator.network(Picasso::ID);
+		assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 1_000);
+		assert_eq!(picasso.balance(PICA::ID, picasso.gateway.clone()).unwrap(), 0);
+		assert_eq!(simulator.network(Juno::ID).balance(PICA::ID, "bob").unwrap(), 0);
+	}
+
+	#[test]
+	fn expired_spawn_refunds_assets() {
+		let mut simulator = setup();
+		let program = spawn_program(&simulator, false);
+		simulator
+			.network_mut(Picasso::ID)
+			.execute_program("alice", SALT.to_vec(), program, Funds::from([(PICA::ID, 1_000_u128)]))
+			.unwrap();
+
+		// The relayer shows up after the deadline.
+		simulator
+			.network_mut(Juno::ID)
+			.app
+			.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+		let relayed = simulator.relay().unwrap();
+		assert_eq!(relayed.len(), 1);
+		assert_eq!(relayed[0].acknowledgement, None);
+
+		let picasso = simulator.network(Picasso::ID);
+		assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 1_000);
+		assert_eq!(simulator.network(Juno::ID).balance(PICA::ID, "bob").unwrap(), 0);
+	}
+}
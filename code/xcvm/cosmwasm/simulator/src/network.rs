@@ -0,0 +1,264 @@
+use crate::ibc::{gateway_sudo, IbcSudoMsg, OutgoingPacket, SimulatedIbc};
+use anyhow::{anyhow, Result as AnyResult};
+use cosmwasm_std::{
+	testing::{MockApi, MockStorage},
+	Addr, Empty, Uint128,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
+use cw_multi_test::{
+	App, AppBuilder, AppResponse, BankKeeper, Contract, ContractWrapper, DistributionKeeper,
+	Executor, FailingModule, StakeKeeper, WasmKeeper,
+};
+use cw_xcvm_asset_registry::msg::AssetReference;
+use cw_xcvm_utils::DefaultXCVMProgram;
+use std::collections::BTreeMap;
+use xcvm_core::{AssetId, Displayed, Funds, InterpreterOrigin, NetworkId};
+
+/// A cw-multi-test chain using the [`SimulatedIbc`] module.
+pub type NetworkApp = App<
+	BankKeeper,
+	MockApi,
+	MockStorage,
+	FailingModule<Empty, Empty, Empty>,
+	WasmKeeper<Empty, Empty>,
+	StakeKeeper,
+	DistributionKeeper,
+	SimulatedIbc,
+>;
+
+fn gateway_contract() -> Box<dyn Contract<Empty>> {
+	Box::new(
+		ContractWrapper::new(
+			cw_xcvm_gateway::contract::execute,
+			cw_xcvm_gateway::contract::instantiate,
+			cw_xcvm_gateway::contract::query,
+		)
+		.with_reply(cw_xcvm_gateway::contract::reply)
+		.with_sudo(gateway_sudo),
+	)
+}
+
+fn router_contract() -> Box<dyn Contract<Empty>> {
+	Box::new(
+		ContractWrapper::new(
+			cw_xcvm_router::contract::execute,
+			cw_xcvm_router::contract::instantiate,
+			cw_xcvm_router::contract::query,
+		)
+		.with_reply(cw_xcvm_router::contract::reply),
+	)
+}
+
+fn interpreter_contract() -> Box<dyn Contract<Empty>> {
+	Box::new(
+		ContractWrapper::new(
+			cw_xcvm_interpreter::contract::execute,
+			cw_xcvm_interpreter::contract::instantiate,
+			cw_xcvm_interpreter::contract::query,
+		)
+		.with_reply(cw_xcvm_interpreter::contract::reply),
+	)
+}
+
+fn registry_contract() -> Box<dyn Contract<Empty>> {
+	Box::new(ContractWrapper::new(
+		cw_xcvm_asset_registry::contract::execute,
+		cw_xcvm_asset_registry::contract::instantiate,
+		cw_xcvm_asset_registry::contract::query,
+	))
+}
+
+fn cw20_contract() -> Box<dyn Contract<Empty>> {
+	Box::new(ContractWrapper::new(
+		cw20_base::contract::execute,
+		cw20_base::contract::instantiate,
+		cw20_base::contract::query,
+	))
+}
+
+/// A network on which the XCVM contracts are deployed.
+pub struct SimulatedNetwork {
+	pub network_id: NetworkId,
+	pub app: NetworkApp,
+	/// Admin of the gateway, owner of the other contracts.
+	pub admin: Addr,
+	pub registry: Addr,
+	pub gateway: Addr,
+	pub router: Addr,
+	/// The cw20 token of each registered asset.
+	pub assets: BTreeMap<AssetId, Addr>,
+	cw20_code_id: u64,
+}
+
+impl SimulatedNetwork {
+	/// Deploy the asset registry and the gateway, which deploys the router.
+	pub fn new(network_id: NetworkId) -> AnyResult<Self> {
+		let mut app = AppBuilder::new().with_ibc(SimulatedIbc).build(|_, _, _| {});
+		let admin = Addr::unchecked("admin");
+		let gateway_code_id = app.store_code(gateway_contract());
+		let router_code_id = app.store_code(router_contract());
+		let interpreter_code_id = app.store_code(interpreter_contract());
+		let registry_code_id = app.store_code(registry_contract());
+		let cw20_code_id = app.store_code(cw20_contract());
+		let registry = app.instantiate_contract(
+			registry_code_id,
+			admin.clone(),
+			&cw_xcvm_asset_registry::msg::InstantiateMsg {},
+			&[],
+			"xcvm-registry",
+			Some(admin.to_string()),
+		)?;
+		let gateway = app.instantiate_contract(
+			gateway_code_id,
+			admin.clone(),
+			&cw_xcvm_gateway::msg::InstantiateMsg {
+				config: cw_xcvm_gateway::state::Config {
+					registry_address: registry.to_string(),
+					router_code_id,
+					interpreter_code_id,
+					network_id,
+					admin: admin.to_string(),
+				},
+			},
+			&[],
+			"xcvm-gateway",
+			Some(admin.to_string()),
+		)?;
+		let cw_xcvm_gateway::msg::ConfigResponse { router, .. } = app
+			.wrap()
+			.query_wasm_smart(gateway.clone(), &cw_xcvm_gateway::msg::QueryMsg::Config {})?;
+		Ok(SimulatedNetwork {
+			network_id,
+			app,
+			admin,
+			registry,
+			gateway,
+			router,
+			assets: BTreeMap::new(),
+			cw20_code_id,
+		})
+	}
+
+	/// Deploy a cw20 token minted by the gateway and register it as `asset_id`.
+	pub fn register_asset(
+		&mut self,
+		asset_id: AssetId,
+		symbol: &str,
+		decimals: u8,
+	) -> AnyResult<Addr> {
+		let token = self.app.instantiate_contract(
+			self.cw20_code_id,
+			self.admin.clone(),
+			&cw20_base::msg::InstantiateMsg {
+				name: format!("XCVM {symbol}"),
+				symbol: symbol.into(),
+				decimals,
+				initial_balances: vec![],
+				mint: Some(MinterResponse { minter: self.gateway.to_string(), cap: None }),
+				marketing: None,
+			},
+			&[],
+			format!("xcvm-asset-{symbol}"),
+			Some(self.admin.to_string()),
+		)?;
+		self.app.execute_contract(
+			self.admin.clone(),
+			self.registry.clone(),
+			&cw_xcvm_asset_registry::msg::ExecuteMsg::RegisterAsset {
+				asset_id: asset_id.into(),
+				reference: AssetReference::Virtual { cw20_address: token.clone() },
+			},
+			&[],
+		)?;
+		self.assets.insert(asset_id, token.clone());
+		Ok(token)
+	}
+
+	/// The cw20 token of a registered asset.
+	pub fn asset(&self, asset_id: AssetId) -> AnyResult<Addr> {
+		self.assets.get(&asset_id).cloned().ok_or_else(|| {
+			anyhow!("Asset {:?} is not registered on {:?}", asset_id, self.network_id)
+		})
+	}
+
+	/// Mint `amount` of an asset to `recipient`.
+	pub fn mint(
+		&mut self,
+		asset_id: AssetId,
+		recipient: impl Into<String>,
+		amount: u128,
+	) -> AnyResult<AppResponse> {
+		let token = self.asset(asset_id)?;
+		self.app.execute_contract(
+			self.gateway.clone(),
+			token,
+			&Cw20ExecuteMsg::Mint { recipient: recipient.into(), amount: amount.into() },
+			&[],
+		)
+	}
+
+	/// Balance of `account` in an asset.
+	pub fn balance(&self, asset_id: AssetId, account: impl Into<String>) -> AnyResult<u128> {
+		let token = self.asset(asset_id)?;
+		let BalanceResponse { balance } = self
+			.app
+			.wrap()
+			.query_wasm_smart(token, &Cw20QueryMsg::Balance { address: account.into() })?;
+		Ok(balance.u128())
+	}
+
+	/// Submit a program on behalf of `sender`, funding the interpreter with `assets` held by the
+	/// sender.
+	pub fn execute_program(
+		&mut self,
+		sender: impl Into<String>,
+		salt: Vec<u8>,
+		program: DefaultXCVMProgram,
+		assets: Funds<Displayed<u128>>,
+	) -> AnyResult<AppResponse> {
+		let sender = Addr::unchecked(sender);
+		// The router pulls the assets from the sender.
+		for (asset_id, Displayed(amount)) in assets.0.iter() {
+			let token = self.asset(*asset_id)?;
+			self.app.execute_contract(
+				sender.clone(),
+				token,
+				&Cw20ExecuteMsg::IncreaseAllowance {
+					spender: self.router.to_string(),
+					amount: Uint128::from(*amount),
+					expires: None,
+				},
+				&[],
+			)?;
+		}
+		self.app.execute_contract(
+			sender,
+			self.router.clone(),
+			&cw_xcvm_common::router::ExecuteMsg::ExecuteProgram { salt, program, assets },
+			&[],
+		)
+	}
+
+	/// The address of the interpreter instantiated for `interpreter_origin`, if any.
+	pub fn interpreter(&self, interpreter_origin: InterpreterOrigin) -> Option<Addr> {
+		self.app
+			.wrap()
+			.query_wasm_smart::<cw_xcvm_router::msg::InterpreterResponse>(
+				self.router.clone(),
+				&cw_xcvm_router::msg::QueryMsg::Interpreter { interpreter_origin },
+			)
+			.ok()
+			.and_then(|response| response.interpreter.address)
+	}
+
+	/// Dispatch an IBC entry point of the gateway.
+	pub fn gateway_sudo(&mut self, msg: IbcSudoMsg) -> AnyResult<AppResponse> {
+		self.app.wasm_sudo(self.gateway.clone(), &msg)
+	}
+
+	/// Take the packets sent by the gateway since the last call.
+	pub fn take_outgoing_packets(&mut self) -> AnyResult<Vec<OutgoingPacket>> {
+		self.app
+			.init_modules(|_, _, storage| SimulatedIbc::take_outgoing_packets(storage))
+	}
+}
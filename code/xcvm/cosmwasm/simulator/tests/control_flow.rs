@@ -0,0 +1,297 @@
+//! Conditional, fallible and aborted executions of the `If`, `Try` and `Abort` instructions.
+
+use cosmwasm_std::{Addr, Api, CanonicalAddr};
+use cw_xcvm_interpreter::{msg::QueryMsg, state::ExecutionResult};
+use cw_xcvm_simulator::Simulator;
+use cw_xcvm_utils::DefaultXCVMProgram;
+use xcvm_core::{
+	Asset, Comparison, Condition, Destination, Displayed, Funds, InterpreterOrigin, Juno, Network,
+	Picasso, ProgramBuilder, Register, UserOrigin, PICA,
+};
+
+const SALT: &[u8] = b"salt";
+
+type Builder = ProgramBuilder<Picasso, CanonicalAddr, Funds>;
+
+/// Fund the interpreter of alice on Picasso with 1_000 PICA.
+fn setup() -> Simulator {
+	let mut simulator = Simulator::new();
+	simulator.add_network::<Picasso>().unwrap();
+	simulator.register_asset::<PICA>("PICA").unwrap();
+	simulator.network_mut(Picasso::ID).mint(PICA::ID, "alice", 1_000).unwrap();
+	simulator
+		.network_mut(Picasso::ID)
+		.execute_program(
+			"alice",
+			SALT.to_vec(),
+			Builder::new(b"fund".to_vec()).build(),
+			Funds::from([(PICA::ID, 1_000_u128)]),
+		)
+		.unwrap();
+	simulator
+}
+
+fn execute(simulator: &mut Simulator, program: DefaultXCVMProgram) {
+	simulator
+		.network_mut(Picasso::ID)
+		.execute_program("alice", SALT.to_vec(), program, Funds::empty())
+		.unwrap();
+}
+
+fn interpreter(simulator: &Simulator) -> Addr {
+	simulator
+		.network(Picasso::ID)
+		.interpreter(InterpreterOrigin {
+			user_origin: UserOrigin { network_id: Picasso::ID, user_id: b"alice".to_vec().into() },
+			salt: SALT.to_vec(),
+		})
+		.expect("interpreter must have been instantiated")
+}
+
+fn result_register(simulator: &Simulator) -> Option<Result<ExecutionResult, String>> {
+	simulator
+		.network(Picasso::ID)
+		.app
+		.wrap()
+		.query_wasm_smart(interpreter(simulator), &QueryMsg::Register(Register::Result))
+		.ok()
+}
+
+fn account(simulator: &Simulator, name: &str) -> CanonicalAddr {
+	simulator.network(Picasso::ID).app.api().addr_canonicalize(name).unwrap()
+}
+
+fn balance(simulator: &Simulator, account: impl Into<String>) -> u128 {
+	simulator.network(Picasso::ID).balance(PICA::ID, account).unwrap()
+}
+
+/// Transfer `amount` PICA to `to`.
+fn transfer(builder: Builder, to: CanonicalAddr, amount: u128) -> Result<Builder, ()> {
+	Ok(builder.transfer(Destination::Account(to), Funds::from([(PICA::ID, amount)])))
+}
+
+/// Not a valid cosmos message, fails the program calling it.
+fn fail(builder: Builder) -> Result<Builder, ()> {
+	Ok(builder.call_raw(b"invalid".to_vec()))
+}
+
+/// Transfer 100 PICA to bob if `condition` holds, 200 PICA otherwise.
+fn conditional_program(simulator: &Simulator, condition: Condition) -> DefaultXCVMProgram {
+	let bob = account(simulator, "bob");
+	Builder::new(b"if".to_vec())
+		.conditional(
+			condition,
+			|then| transfer(then, bob.clone(), 100),
+			|otherwise| transfer(otherwise, bob.clone(), 200),
+		)
+		.unwrap()
+		.build()
+}
+
+#[test]
+fn if_balance() {
+	let mut simulator = setup();
+	let holds = Condition::Balance {
+		asset: PICA::ID,
+		comparison: Comparison::GreaterOrEqual,
+		amount: Displayed(1_000),
+	};
+	let program = conditional_program(&simulator, holds);
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 100);
+
+	// 900 PICA are left.
+	let fails = Condition::Balance {
+		asset: PICA::ID,
+		comparison: Comparison::GreaterOrEqual,
+		amount: Displayed(1_000),
+	};
+	let program = conditional_program(&simulator, fails);
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 300);
+	assert_eq!(balance(&simulator, interpreter(&simulator)), 700);
+}
+
+#[test]
+fn if_ip() {
+	let mut simulator = setup();
+	// The `If` is the first instruction.
+	let program =
+		conditional_program(&simulator, Condition::Ip { comparison: Comparison::Equal, value: 0 });
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 100);
+
+	let program = conditional_program(
+		&simulator,
+		Condition::Ip { comparison: Comparison::Greater, value: 0 },
+	);
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 300);
+}
+
+#[test]
+fn if_queried_balance_without_query_fails() {
+	let mut simulator = setup();
+	let program = conditional_program(
+		&simulator,
+		Condition::QueriedBalance {
+			asset: PICA::ID,
+			comparison: Comparison::GreaterOrEqual,
+			amount: Displayed(0),
+		},
+	);
+	execute(&mut simulator, program);
+
+	assert!(matches!(result_register(&simulator), Some(Err(_))));
+	assert_eq!(balance(&simulator, "bob"), 0);
+}
+
+#[test]
+fn if_result() {
+	let mut simulator = setup();
+	// No result yet.
+	let program = conditional_program(&simulator, Condition::Result { ok: false });
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 200);
+
+	let bob = account(&simulator, "bob");
+	let program = Builder::new(b"if".to_vec())
+		.try_or_else(fail, Ok)
+		.unwrap()
+		.conditional(
+			Condition::Result { ok: false },
+			|then| transfer(then, bob.clone(), 100),
+			|otherwise| transfer(otherwise, bob.clone(), 200),
+		)
+		.unwrap()
+		.build();
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 300);
+}
+
+#[test]
+fn try_success_skips_handler() {
+	let mut simulator = setup();
+	let (bob, charlie) = (account(&simulator, "bob"), account(&simulator, "charlie"));
+	let program = Builder::new(b"try".to_vec())
+		.try_or_else(
+			|tried| transfer(tried, bob.clone(), 100),
+			|on_error| transfer(on_error, charlie.clone(), 300),
+		)
+		.unwrap()
+		.transfer(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 50_u128)]))
+		.build();
+	execute(&mut simulator, program);
+
+	assert_eq!(balance(&simulator, "bob"), 150);
+	assert_eq!(balance(&simulator, "charlie"), 0);
+}
+
+#[test]
+fn try_failure_reverts_and_runs_handler() {
+	let mut simulator = setup();
+	let (bob, charlie) = (account(&simulator, "bob"), account(&simulator, "charlie"));
+	let program = Builder::new(b"try".to_vec())
+		.try_or_else(
+			|tried| transfer(tried, bob.clone(), 100).and_then(fail),
+			|on_error| transfer(on_error, charlie.clone(), 300),
+		)
+		.unwrap()
+		// Executed after the handler.
+		.transfer(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 50_u128)]))
+		.build();
+	execute(&mut simulator, program);
+
+	// The transfer of the tried program is reverted.
+	assert_eq!(balance(&simulator, "bob"), 50);
+	assert_eq!(balance(&simulator, "charlie"), 300);
+	assert_eq!(balance(&simulator, interpreter(&simulator)), 650);
+}
+
+#[test]
+fn nested_try_failure_reverts_inner_handler() {
+	let mut simulator = setup();
+	let (bob, charlie) = (account(&simulator, "bob"), account(&simulator, "charlie"));
+	let program = Builder::new(b"try".to_vec())
+		.try_or_else(
+			|tried| {
+				tried
+					.try_or_else(fail, |on_error| transfer(on_error, bob.clone(), 10))
+					.and_then(fail)
+			},
+			|on_error| transfer(on_error, charlie.clone(), 20),
+		)
+		.unwrap()
+		.build();
+	execute(&mut simulator, program);
+
+	// The inner handler ran, but its effects are reverted along the outer tried program.
+	assert_eq!(balance(&simulator, "bob"), 0);
+	assert_eq!(balance(&simulator, "charlie"), 20);
+
+	// The handlers of the reverted program are not left behind for the next `Try`.
+	let program = Builder::new(b"try".to_vec())
+		.try_or_else(fail, |on_error| transfer(on_error, bob.clone(), 30))
+		.unwrap()
+		.build();
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 30);
+	assert_eq!(balance(&simulator, "charlie"), 20);
+}
+
+#[test]
+fn query_in_try_is_rejected() {
+	let mut simulator = setup();
+	let bob = account(&simulator, "bob");
+	let program = Builder::new(b"try".to_vec())
+		.try_or_else(|tried| Ok::<_, ()>(tried.query::<Juno>(SALT.to_vec())), Ok)
+		.unwrap()
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 100_u128)]))
+		.build();
+	execute(&mut simulator, program);
+
+	assert!(matches!(result_register(&simulator), Some(Err(_))));
+	assert_eq!(balance(&simulator, "bob"), 0);
+}
+
+#[test]
+fn abort_skips_remaining_instructions() {
+	let mut simulator = setup();
+	let (bob, charlie) = (account(&simulator, "bob"), account(&simulator, "charlie"));
+	let program = Builder::new(b"abort".to_vec())
+		.abort(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 100_u128)]))
+		.transfer(Destination::Account(charlie.clone()), Funds::from([(PICA::ID, 100_u128)]))
+		.build();
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "bob"), 100);
+	assert_eq!(balance(&simulator, "charlie"), 0);
+
+	// The next program is not aborted.
+	let program = Builder::new(b"next".to_vec())
+		.transfer(Destination::Account(charlie), Funds::from([(PICA::ID, 100_u128)]))
+		.build();
+	execute(&mut simulator, program);
+	assert_eq!(balance(&simulator, "charlie"), 100);
+}
+
+#[test]
+fn abort_within_try_aborts_program() {
+	let mut simulator = setup();
+	let (bob, charlie) = (account(&simulator, "bob"), account(&simulator, "charlie"));
+	let program = Builder::new(b"abort".to_vec())
+		.try_or_else(
+			|tried| {
+				Ok(tried
+					.abort(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 100_u128)])))
+			},
+			|on_error| transfer(on_error, charlie.clone(), 300),
+		)
+		.unwrap()
+		.transfer(Destination::Account(charlie.clone()), Funds::from([(PICA::ID, 100_u128)]))
+		.build();
+	execute(&mut simulator, program);
+
+	// The abortion is not a failure, the handler is not executed either.
+	assert_eq!(balance(&simulator, "bob"), 100);
+	assert_eq!(balance(&simulator, "charlie"), 0);
+}
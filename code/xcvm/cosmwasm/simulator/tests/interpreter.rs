@@ -0,0 +1,379 @@
+//! Delegated execution of the programs of an interpreter, see the `Grant` message of the
+//! interpreter.
+
+use cosmwasm_std::{coins, to_vec, Addr, Api, BankMsg, CanonicalAddr, CosmosMsg, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_multi_test::Executor;
+use cw_xcvm_interpreter::{
+	msg::{ExecuteMsg, QueryMsg},
+	state::{ExecutionResult, Grant, SpendingLimit},
+};
+use cw_xcvm_simulator::Simulator;
+use cw_xcvm_utils::DefaultXCVMProgram;
+use serde::Serialize;
+use std::fmt::Debug;
+use xcvm_core::{
+	cosmwasm::{FlatCosmosMsg, FlatWasmMsg},
+	Asset, BridgeSecurity, Deadline, Destination, Displayed, Funds, InterpreterOrigin, Juno,
+	Network, Picasso, ProgramBuilder, Register, UserOrigin, PICA,
+};
+
+const SALT: &[u8] = b"salt";
+const KEEPER: &str = "keeper";
+const SPENDING_LIMIT: u128 = 500;
+
+type Builder = ProgramBuilder<Picasso, CanonicalAddr, Funds>;
+
+/// Fund the interpreter of alice on Picasso with 1_000 PICA, and grant the keeper to move up to
+/// [`SPENDING_LIMIT`] PICA per hour out of it.
+fn setup(call_targets: impl FnOnce(&Simulator) -> Option<Vec<Addr>>) -> (Simulator, Addr) {
+	let mut simulator = Simulator::new();
+	simulator.add_network::<Picasso>().unwrap();
+	simulator.add_network::<Juno>().unwrap();
+	simulator.register_asset::<PICA>("PICA").unwrap();
+	simulator.connect(Picasso::ID, Juno::ID).unwrap();
+	let picasso = simulator.network_mut(Picasso::ID);
+	picasso.mint(PICA::ID, "alice", 1_000).unwrap();
+	picasso
+		.execute_program(
+			"alice",
+			SALT.to_vec(),
+			Builder::new(b"fund".to_vec()).build(),
+			Funds::from([(PICA::ID, 1_000_u128)]),
+		)
+		.unwrap();
+	let interpreter = picasso
+		.interpreter(InterpreterOrigin {
+			user_origin: UserOrigin { network_id: Picasso::ID, user_id: b"alice".to_vec().into() },
+			salt: SALT.to_vec(),
+		})
+		.expect("interpreter must have been instantiated");
+
+	let grant = Grant {
+		tag: None,
+		call_targets: call_targets(&simulator),
+		spending_limits: vec![SpendingLimit {
+			asset: PICA::ID,
+			amount: Displayed(SPENDING_LIMIT),
+			period: 3600,
+		}],
+	};
+	// The router instantiated the interpreter, it is one of its owners.
+	let picasso = simulator.network_mut(Picasso::ID);
+	picasso
+		.app
+		.execute_contract(
+			picasso.router.clone(),
+			interpreter.clone(),
+			&ExecuteMsg::Grant { delegate: Addr::unchecked(KEEPER), grant },
+			&[],
+		)
+		.unwrap();
+	(simulator, interpreter)
+}
+
+fn canonical(simulator: &Simulator, account: &str) -> CanonicalAddr {
+	simulator.network(Picasso::ID).app.api().addr_canonicalize(account).unwrap()
+}
+
+fn encode<T: Serialize + Clone + Debug>(msg: FlatCosmosMsg<T>) -> Vec<u8> {
+	to_vec(&msg).unwrap()
+}
+
+/// Execute `program` on the interpreter on behalf of the keeper. The failures of the program are
+/// stored in the result register rather than returned.
+fn execute_as_keeper(simulator: &mut Simulator, interpreter: &Addr, program: DefaultXCVMProgram) {
+	let relayer = simulator.relayer().clone();
+	simulator
+		.network_mut(Picasso::ID)
+		.app
+		.execute_contract(
+			Addr::unchecked(KEEPER),
+			interpreter.clone(),
+			&ExecuteMsg::Execute { relayer, program },
+			&[],
+		)
+		.unwrap();
+}
+
+fn program_failed(simulator: &Simulator, interpreter: &Addr) -> bool {
+	simulator
+		.network(Picasso::ID)
+		.app
+		.wrap()
+		.query_wasm_smart::<Result<ExecutionResult, String>>(
+			interpreter.clone(),
+			&QueryMsg::Register(Register::Result),
+		)
+		.map(|result| result.is_err())
+		.unwrap_or(false)
+}
+
+fn balance(simulator: &Simulator, account: impl Into<String>) -> u128 {
+	simulator.network(Picasso::ID).balance(PICA::ID, account).unwrap()
+}
+
+#[test]
+fn transfer_within_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 300_u128)]))
+		.transfer(Destination::Relayer, Funds::from([(PICA::ID, SPENDING_LIMIT - 300)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(!program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 300);
+	assert_eq!(balance(&simulator, simulator.relayer().clone()), SPENDING_LIMIT - 300);
+	assert_eq!(balance(&simulator, &interpreter), 1_000 - SPENDING_LIMIT);
+}
+
+#[test]
+fn transfer_over_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.transfer(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 300_u128)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert_eq!(balance(&simulator, "bob"), 300);
+
+	// The spendings of the period add up.
+	let program = Builder::new(b"keeper".to_vec())
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 300_u128)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 300);
+	assert_eq!(balance(&simulator, &interpreter), 700);
+}
+
+#[test]
+fn abort_within_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.abort(Destination::Account(bob.clone()), Funds::from([(PICA::ID, 200_u128)]))
+		// Not executed, the program is aborted.
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 200_u128)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(!program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 200);
+	assert_eq!(balance(&simulator, &interpreter), 800);
+}
+
+#[test]
+fn abort_over_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.abort(Destination::Account(bob), Funds::from([(PICA::ID, SPENDING_LIMIT + 1)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 0);
+	assert_eq!(balance(&simulator, &interpreter), 1_000);
+}
+
+/// Spawn `amount` PICA to Juno, where they are transferred to bob.
+fn spawn_program(simulator: &Simulator, amount: u128) -> DefaultXCVMProgram {
+	let bob = simulator.network(Juno::ID).app.api().addr_canonicalize("bob").unwrap();
+	Builder::new(b"keeper".to_vec())
+		.spawn::<Juno, _, (), _>(
+			b"spawned".to_vec(),
+			SALT.to_vec(),
+			BridgeSecurity::Deterministic,
+			Funds::from([(PICA::ID, amount)]),
+			Funds::empty(),
+			Deadline::Relative(3600),
+			|child| Ok(child.transfer(Destination::Account(bob), Funds::from([(PICA::ID, amount)]))),
+		)
+		.unwrap()
+		.build()
+}
+
+#[test]
+fn spawn_within_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let program = spawn_program(&simulator, 400);
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert!(!program_failed(&simulator, &interpreter));
+
+	assert_eq!(simulator.relay().unwrap().len(), 1);
+	assert_eq!(balance(&simulator, &interpreter), 600);
+	assert_eq!(simulator.network(Juno::ID).balance(PICA::ID, "bob").unwrap(), 400);
+}
+
+#[test]
+fn spawn_over_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let program = spawn_program(&simulator, 600);
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert!(program_failed(&simulator, &interpreter));
+
+	assert!(simulator.relay().unwrap().is_empty());
+	assert_eq!(balance(&simulator, &interpreter), 1_000);
+	assert_eq!(simulator.network(Juno::ID).balance(PICA::ID, "bob").unwrap(), 0);
+}
+
+/// Call the PICA token to transfer `amount` to bob.
+fn cw20_transfer(simulator: &Simulator, amount: u128) -> FlatCosmosMsg<Cw20ExecuteMsg> {
+	FlatCosmosMsg::Wasm(FlatWasmMsg::Execute {
+		contract_addr: simulator.network(Picasso::ID).asset(PICA::ID).unwrap().into_string(),
+		msg: Cw20ExecuteMsg::Transfer { recipient: "bob".into(), amount: Uint128::from(amount) },
+		funds: vec![],
+	})
+}
+
+fn pica_call_target(simulator: &Simulator) -> Option<Vec<Addr>> {
+	Some(vec![simulator.network(Picasso::ID).asset(PICA::ID).unwrap()])
+}
+
+#[test]
+fn call_to_permitted_target() {
+	let (mut simulator, interpreter) = setup(pica_call_target);
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(cw20_transfer(&simulator, 300)))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(!program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 300);
+}
+
+#[test]
+fn cw20_call_over_spending_limit() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, 300_u128)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert_eq!(balance(&simulator, "bob"), 300);
+
+	// The cw20 transfers of the calls add up with the spendings of the period.
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(cw20_transfer(&simulator, 300)))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 300);
+	assert_eq!(balance(&simulator, &interpreter), 700);
+}
+
+#[test]
+fn cw20_call_burning_assets() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(FlatCosmosMsg::Wasm(FlatWasmMsg::Execute {
+			contract_addr: simulator.network(Picasso::ID).asset(PICA::ID).unwrap().into_string(),
+			msg: Cw20ExecuteMsg::Burn { amount: Uint128::from(1_u128) },
+			funds: vec![],
+		})))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, &interpreter), 1_000);
+}
+
+#[test]
+fn admin_call() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let picasso = simulator.network_mut(Picasso::ID);
+	let token = picasso.asset(PICA::ID).unwrap();
+	picasso
+		.app
+		.execute(
+			picasso.admin.clone(),
+			CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+				contract_addr: token.to_string(),
+				admin: interpreter.to_string(),
+			}),
+		)
+		.unwrap();
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(FlatCosmosMsg::<Cw20ExecuteMsg>::Wasm(FlatWasmMsg::ClearAdmin {
+			contract_addr: token.to_string(),
+		})))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	let info = simulator
+		.network(Picasso::ID)
+		.app
+		.wrap()
+		.query_wasm_contract_info(token)
+		.unwrap();
+	assert_eq!(info.admin, Some(interpreter.to_string()));
+}
+
+#[test]
+fn call_to_unlisted_target() {
+	let (mut simulator, interpreter) = setup(pica_call_target);
+	let router = simulator.network(Picasso::ID).router.clone();
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(FlatCosmosMsg::Wasm(FlatWasmMsg::Execute {
+			contract_addr: router.into_string(),
+			msg: Cw20ExecuteMsg::Transfer {
+				recipient: "bob".into(),
+				amount: Uint128::from(1_u128),
+			},
+			funds: vec![],
+		})))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+}
+
+#[test]
+fn call_to_interpreter() {
+	let (mut simulator, interpreter) = setup(|_| None);
+	let program = Builder::new(b"keeper".to_vec())
+		.call_raw(encode(FlatCosmosMsg::Wasm(FlatWasmMsg::Execute {
+			contract_addr: interpreter.to_string(),
+			msg: ExecuteMsg::AddOwners { owners: vec![Addr::unchecked(KEEPER)] },
+			funds: vec![],
+		})))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+
+	assert!(program_failed(&simulator, &interpreter));
+	// Still a delegate, its transfers are limited.
+	let bob = canonical(&simulator, "bob");
+	let program = Builder::new(b"keeper".to_vec())
+		.transfer(Destination::Account(bob), Funds::from([(PICA::ID, SPENDING_LIMIT + 1)]))
+		.build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 0);
+}
+
+#[test]
+fn call_sending_native_assets() {
+	let (mut simulator, interpreter) = setup(pica_call_target);
+	let bank_send = FlatCosmosMsg::<Cw20ExecuteMsg>::Bank(BankMsg::Send {
+		to_address: "bob".into(),
+		amount: coins(1, "upica"),
+	});
+	let program = Builder::new(b"keeper".to_vec()).call_raw(encode(bank_send)).build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert!(program_failed(&simulator, &interpreter));
+
+	let mut with_funds = cw20_transfer(&simulator, 1);
+	if let FlatCosmosMsg::Wasm(FlatWasmMsg::Execute { funds, .. }) = &mut with_funds {
+		*funds = coins(1, "upica");
+	}
+	let program = Builder::new(b"keeper".to_vec()).call_raw(encode(with_funds)).build();
+	execute_as_keeper(&mut simulator, &interpreter, program);
+	assert!(program_failed(&simulator, &interpreter));
+	assert_eq!(balance(&simulator, "bob"), 0);
+}
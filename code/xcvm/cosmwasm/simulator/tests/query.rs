@@ -0,0 +1,195 @@
+//! Programs suspended by a `Query` until the state of a remote interpreter is received.
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Api, CanonicalAddr};
+use cw_multi_test::AppResponse;
+use cw_xcvm_interpreter::{msg::QueryMsg, state::ExecutionResult};
+use cw_xcvm_simulator::Simulator;
+use cw_xcvm_utils::DefaultXCVMProgram;
+use xcvm_core::{
+	Asset, BridgeSecurity, Comparison, Condition, Deadline, Destination, Displayed, Funds,
+	InterpreterOrigin, Juno, Network, NetworkId, Picasso, ProgramBuilder, QueryResult, Register,
+	UserOrigin, PICA,
+};
+
+const SALT: &[u8] = b"salt";
+const REMOTE_SALT: &[u8] = b"remote";
+
+type Builder = ProgramBuilder<Picasso, CanonicalAddr, Funds>;
+
+/// Fund the interpreter of alice on Picasso with 1_000 PICA, 400 of them being spawned to its
+/// interpreter on Juno.
+fn setup() -> Simulator {
+	let mut simulator = Simulator::new();
+	simulator.add_network::<Picasso>().unwrap();
+	simulator.add_network::<Juno>().unwrap();
+	simulator.register_asset::<PICA>("PICA").unwrap();
+	simulator.connect(Picasso::ID, Juno::ID).unwrap();
+	simulator.network_mut(Picasso::ID).mint(PICA::ID, "alice", 1_000).unwrap();
+	let program = Builder::new(b"fund".to_vec())
+		.spawn::<Juno, _, (), _>(
+			b"remote".to_vec(),
+			REMOTE_SALT.to_vec(),
+			BridgeSecurity::Deterministic,
+			Funds::from([(PICA::ID, 400_u128)]),
+			Funds::empty(),
+			Deadline::Relative(3600),
+			Ok,
+		)
+		.unwrap()
+		.build();
+	simulator
+		.network_mut(Picasso::ID)
+		.execute_program("alice", SALT.to_vec(), program, Funds::from([(PICA::ID, 1_000_u128)]))
+		.unwrap();
+	simulator.relay().unwrap();
+	simulator
+}
+
+/// Execute `program` on the interpreter of alice on Picasso, without funding it.
+fn execute(simulator: &mut Simulator, program: DefaultXCVMProgram) -> AnyResult<AppResponse> {
+	simulator.network_mut(Picasso::ID).execute_program(
+		"alice",
+		SALT.to_vec(),
+		program,
+		Funds::empty(),
+	)
+}
+
+fn interpreter(simulator: &Simulator, network_id: NetworkId, salt: &[u8]) -> Addr {
+	simulator
+		.network(network_id)
+		.interpreter(InterpreterOrigin {
+			user_origin: UserOrigin { network_id: Picasso::ID, user_id: b"alice".to_vec().into() },
+			salt: salt.to_vec(),
+		})
+		.expect("interpreter must have been instantiated")
+}
+
+fn result_register(simulator: &Simulator) -> Result<ExecutionResult, String> {
+	simulator
+		.network(Picasso::ID)
+		.app
+		.wrap()
+		.query_wasm_smart(
+			interpreter(simulator, Picasso::ID, SALT),
+			&QueryMsg::Register(Register::Result),
+		)
+		.unwrap()
+}
+
+fn bob_balance(simulator: &Simulator) -> u128 {
+	simulator.network(Picasso::ID).balance(PICA::ID, "bob").unwrap()
+}
+
+/// Query the interpreter of alice identified by `salt` on Juno, and transfer 100 PICA to bob if
+/// it holds at least 400 PICA, 200 PICA otherwise.
+fn query_program(simulator: &Simulator, salt: &[u8]) -> DefaultXCVMProgram {
+	let bob = simulator.network(Picasso::ID).app.api().addr_canonicalize("bob").unwrap();
+	Builder::new(b"query".to_vec())
+		.query::<Juno>(salt.to_vec())
+		.conditional::<(), _, _>(
+			Condition::QueriedBalance {
+				asset: PICA::ID,
+				comparison: Comparison::GreaterOrEqual,
+				amount: Displayed(400),
+			},
+			|then| {
+				Ok(then.transfer(
+					Destination::Account(bob.clone()),
+					Funds::from([(PICA::ID, 100_u128)]),
+				))
+			},
+			|otherwise| {
+				Ok(otherwise.transfer(
+					Destination::Account(bob.clone()),
+					Funds::from([(PICA::ID, 200_u128)]),
+				))
+			},
+		)
+		.unwrap()
+		.build()
+}
+
+#[test]
+fn query_resumes_program_with_remote_state() {
+	let mut simulator = setup();
+	let program = query_program(&simulator, REMOTE_SALT);
+	execute(&mut simulator, program).unwrap();
+	// Suspended until the result is received.
+	assert_eq!(bob_balance(&simulator), 0);
+
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed.len(), 1);
+	assert_eq!(relayed[0].destination, Juno::ID);
+
+	assert_eq!(bob_balance(&simulator), 100);
+	let remote = interpreter(&simulator, Juno::ID, REMOTE_SALT);
+	assert_eq!(
+		result_register(&simulator),
+		Ok(ExecutionResult::Query(QueryResult {
+			interpreter: remote.as_bytes().to_vec(),
+			balances: Funds::from([(PICA::ID, 400_u128)]),
+		}))
+	);
+}
+
+#[test]
+fn query_of_unknown_interpreter() {
+	let mut simulator = setup();
+	let program = query_program(&simulator, b"unknown");
+	execute(&mut simulator, program).unwrap();
+	simulator.relay().unwrap();
+
+	assert_eq!(bob_balance(&simulator), 200);
+	assert_eq!(
+		result_register(&simulator),
+		Ok(ExecutionResult::Query(QueryResult { interpreter: vec![], balances: Funds::empty() }))
+	);
+}
+
+#[test]
+fn suspended_program_blocks_execution() {
+	let mut simulator = setup();
+	let program = query_program(&simulator, REMOTE_SALT);
+	execute(&mut simulator, program).unwrap();
+
+	assert!(execute(&mut simulator, Builder::new(b"other".to_vec()).build()).is_err());
+
+	simulator.relay().unwrap();
+	execute(&mut simulator, Builder::new(b"other".to_vec()).build()).unwrap();
+}
+
+#[test]
+fn timed_out_query_aborts_program() {
+	let mut simulator = setup();
+	let program = query_program(&simulator, REMOTE_SALT);
+	execute(&mut simulator, program).unwrap();
+
+	// The relayer shows up after the timeout of the query.
+	simulator.network_mut(Juno::ID).app.update_block(|block| block.height = 10_000);
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed.len(), 1);
+	assert_eq!(relayed[0].acknowledgement, None);
+
+	// Neither branch is executed.
+	assert_eq!(bob_balance(&simulator), 0);
+	assert_eq!(result_register(&simulator), Err("The query timed out.".to_string()));
+	// The program is no longer suspended.
+	execute(&mut simulator, Builder::new(b"other".to_vec()).build()).unwrap();
+}
+
+#[test]
+fn failure_of_resumed_program_is_stored() {
+	let mut simulator = setup();
+	let program = Builder::new(b"query".to_vec())
+		.query::<Juno>(REMOTE_SALT.to_vec())
+		// Not a valid cosmos message.
+		.call_raw(b"invalid".to_vec())
+		.build();
+	execute(&mut simulator, program).unwrap();
+	simulator.relay().unwrap();
+
+	assert!(result_register(&simulator).is_err());
+	execute(&mut simulator, Builder::new(b"other".to_vec()).build()).unwrap();
+}
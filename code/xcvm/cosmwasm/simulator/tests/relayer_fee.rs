@@ -0,0 +1,146 @@
+//! Fees offered by spawned programs to the relayers delivering them.
+
+use cosmwasm_std::{Addr, Api, CanonicalAddr};
+use cw_multi_test::Executor;
+use cw_xcvm_simulator::Simulator;
+use cw_xcvm_utils::DefaultXCVMProgram;
+use xcvm_core::{
+	Amount, Asset, BridgeSecurity, Deadline, Destination, Displayed, Funds, InterpreterOrigin,
+	Juno, Network, Picasso, ProgramBuilder, UserOrigin, XCVMAck, MAX_PARTS, PICA,
+};
+
+const SALT: &[u8] = b"salt";
+
+/// Fund alice with 1_000 PICA on Picasso, and require a fee of `relayer_fee` PICA to relay
+/// packets to Juno.
+fn setup(relayer_fee: Option<u128>) -> Simulator {
+	let mut simulator = Simulator::new();
+	simulator.add_network::<Picasso>().unwrap();
+	simulator.add_network::<Juno>().unwrap();
+	simulator.register_asset::<PICA>("PICA").unwrap();
+	simulator.connect(Picasso::ID, Juno::ID).unwrap();
+	simulator.network_mut(Picasso::ID).mint(PICA::ID, "alice", 1_000).unwrap();
+	if let Some(fee) = relayer_fee {
+		let juno = simulator.network_mut(Juno::ID);
+		juno.app
+			.execute_contract(
+				juno.admin.clone(),
+				juno.gateway.clone(),
+				&cw_xcvm_common::gateway::ExecuteMsg::SetRelayerFee {
+					asset_id: PICA::ID,
+					fee: Some(Displayed(fee)),
+				},
+				&[],
+			)
+			.unwrap();
+	}
+	simulator
+}
+
+/// Spawn `assets` PICA to Juno offering `fee` PICA to the relayer, the spawned program
+/// transfers all its PICA to bob.
+fn spawn_program(simulator: &Simulator, assets: Amount, fee: Amount) -> DefaultXCVMProgram {
+	let bob = simulator.network(Juno::ID).app.api().addr_canonicalize("bob").unwrap();
+	ProgramBuilder::<Picasso, CanonicalAddr, Funds>::new(b"main".to_vec())
+		.spawn::<Juno, _, (), _>(
+			b"spawned".to_vec(),
+			SALT.to_vec(),
+			BridgeSecurity::Deterministic,
+			Funds::from([(PICA::ID, assets)]),
+			Funds::from([(PICA::ID, fee)]),
+			Deadline::Relative(3600),
+			|child| {
+				Ok(child.transfer(
+					Destination::Account(bob),
+					Funds::from([(PICA::ID, Amount::ratio(MAX_PARTS))]),
+				))
+			},
+		)
+		.unwrap()
+		.build()
+}
+
+fn execute(simulator: &mut Simulator, program: DefaultXCVMProgram) {
+	simulator
+		.network_mut(Picasso::ID)
+		.execute_program("alice", SALT.to_vec(), program, Funds::from([(PICA::ID, 1_000_u128)]))
+		.unwrap();
+}
+
+fn alice_interpreter(simulator: &Simulator) -> Addr {
+	simulator
+		.network(Picasso::ID)
+		.interpreter(InterpreterOrigin {
+			user_origin: UserOrigin { network_id: Picasso::ID, user_id: b"alice".to_vec().into() },
+			salt: SALT.to_vec(),
+		})
+		.expect("interpreter must have been instantiated")
+}
+
+fn juno_balance(simulator: &Simulator, account: impl Into<String>) -> u128 {
+	simulator.network(Juno::ID).balance(PICA::ID, account).unwrap()
+}
+
+#[test]
+fn relayer_is_paid_and_unused_fee_is_deposited() {
+	let mut simulator = setup(Some(100));
+	let program = spawn_program(&simulator, Amount::absolute(400), Amount::absolute(300));
+	execute(&mut simulator, program);
+
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed.len(), 1);
+	assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::OK.into_vec().into()));
+
+	let picasso = simulator.network(Picasso::ID);
+	assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 300);
+	assert_eq!(picasso.balance(PICA::ID, picasso.gateway.clone()).unwrap(), 0);
+	assert_eq!(juno_balance(&simulator, simulator.relayer().clone()), 100);
+	assert_eq!(juno_balance(&simulator, "bob"), 600);
+}
+
+#[test]
+fn fee_is_deposited_without_relayer_fee() {
+	let mut simulator = setup(None);
+	let program = spawn_program(&simulator, Amount::absolute(400), Amount::absolute(300));
+	execute(&mut simulator, program);
+
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::OK.into_vec().into()));
+	assert_eq!(juno_balance(&simulator, simulator.relayer().clone()), 0);
+	assert_eq!(juno_balance(&simulator, "bob"), 700);
+}
+
+#[test]
+fn insufficient_fee_is_refunded() {
+	let mut simulator = setup(Some(500));
+	let program = spawn_program(&simulator, Amount::absolute(400), Amount::absolute(300));
+	execute(&mut simulator, program);
+
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed.len(), 1);
+	assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::KO.into_vec().into()));
+
+	// Both the assets and the fee are refunded.
+	let picasso = simulator.network(Picasso::ID);
+	assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 1_000);
+	assert_eq!(picasso.balance(PICA::ID, picasso.gateway.clone()).unwrap(), 0);
+	assert_eq!(juno_balance(&simulator, simulator.relayer().clone()), 0);
+	assert_eq!(juno_balance(&simulator, "bob"), 0);
+}
+
+#[test]
+fn fee_ratio_applies_to_remaining_assets() {
+	let mut simulator = setup(Some(100));
+	// Half of the assets are spawned, the other half is offered as fee.
+	let program = spawn_program(&simulator, Amount::ratio(MAX_PARTS / 2), Amount::ratio(MAX_PARTS));
+	execute(&mut simulator, program);
+
+	let relayed = simulator.relay().unwrap();
+	assert_eq!(relayed.len(), 1);
+	assert_eq!(relayed[0].acknowledgement, Some(XCVMAck::OK.into_vec().into()));
+
+	let picasso = simulator.network(Picasso::ID);
+	assert_eq!(picasso.balance(PICA::ID, alice_interpreter(&simulator)).unwrap(), 0);
+	assert_eq!(juno_balance(&simulator, simulator.relayer().clone()), 100);
+	assert_eq!(juno_balance(&simulator, "bob"), 900);
+}
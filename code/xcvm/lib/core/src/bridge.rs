@@ -50,6 +50,18 @@ impl PartialOrd for BridgeSecurity {
 )]
 pub struct BridgeId(Vec<u8>);
 
+impl From<Vec<u8>> for BridgeId {
+	fn from(x: Vec<u8>) -> Self {
+		Self(x)
+	}
+}
+
+impl AsRef<[u8]> for BridgeId {
+	fn as_ref(&self) -> &[u8] {
+		self.0.as_ref()
+	}
+}
+
 /// Protocol used to bridge call/funds.
 #[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
 #[derive(
@@ -64,7 +64,7 @@
 //! variants of both of them.
 
 use super::{BindingValue, Bindings};
-use crate::{InterpreterOrigin, NetworkId, OrderedBindings, UserId, UserOrigin};
+use crate::{BridgeId, InterpreterOrigin, NetworkId, OrderedBindings, UserId, UserOrigin};
 use alloc::{fmt::Debug, string::String, vec, vec::Vec};
 use cosmwasm_std::{BankMsg, Coin, CosmosMsg, StdResult};
 use cw_storage_plus::{CwIntKey, Key, KeyDeserialize, Prefixer, PrimaryKey};
@@ -147,6 +147,29 @@ impl KeyDeserialize for UserId {
 	}
 }
 
+impl<'a> PrimaryKey<'a> for BridgeId {
+	type Prefix = ();
+	type SubPrefix = ();
+	type Suffix = u128;
+	type SuperSuffix = u128;
+	fn key(&self) -> Vec<Key> {
+		vec![Key::Ref(self.as_ref())]
+	}
+}
+
+impl<'a> Prefixer<'a> for BridgeId {
+	fn prefix(&self) -> Vec<Key> {
+		vec![Key::Ref(self.as_ref())]
+	}
+}
+
+impl KeyDeserialize for BridgeId {
+	type Output = <Vec<u8> as KeyDeserialize>::Output;
+	fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+		<Vec<u8> as KeyDeserialize>::from_vec(value)
+	}
+}
+
 impl<'a> PrimaryKey<'a> for NetworkId {
 	type Prefix = ();
 	type SubPrefix = ();
@@ -252,6 +275,7 @@ where
 /// following payload and want to put the interpreter's address:
 /// `{"from":"helloworld","to":""}`
 /// Then the binding is `(26, BindingValue::Register(Register::This))`
+#[derive(Clone, Debug)]
 pub enum IndexedBinding<T> {
 	None(T),
 	Some((OrderedBindings, T)),
@@ -259,13 +283,14 @@ pub enum IndexedBinding<T> {
 
 /// Static bindings that are used for typed and fixed types.
 /// Eg. `contract_addr` in `ExecuteMsg`
+#[derive(Clone, Debug)]
 pub enum StaticBinding<T> {
 	None(T),
 	Some(BindingValue),
 }
 
 #[inline]
-fn find_key_offset(key: &str, data: &str) -> Option<u32> {
+pub(crate) fn find_key_offset(key: &str, data: &str) -> Option<u32> {
 	data.find(key).map(|index| index as u32 + key.len() as u32 + 1)
 }
 
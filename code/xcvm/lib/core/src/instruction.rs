@@ -1,4 +1,4 @@
-use crate::{AssetId, BridgeSecurity, Program};
+use crate::{AssetId, BridgeSecurity, Displayed, Program};
 use alloc::{
 	borrow::Cow,
 	collections::{BTreeMap, VecDeque},
@@ -45,6 +45,74 @@ pub enum Destination<Account> {
 	Relayer,
 }
 
+/// Deadline of an [`Instruction::Spawn`]. Once reached, the spawned program is no longer executed
+/// and its assets are refunded to the spawning interpreter.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Deadline {
+	/// Unix timestamp, in seconds.
+	Timestamp(u64),
+	/// Number of seconds after the execution of the `Spawn`.
+	Relative(u64),
+}
+
+impl Deadline {
+	/// The unix timestamp, in seconds, of the deadline of a `Spawn` executed at `now`.
+	pub fn timestamp(&self, now: u64) -> u64 {
+		match self {
+			Deadline::Timestamp(timestamp) => *timestamp,
+			Deadline::Relative(seconds) => now.saturating_add(*seconds),
+		}
+	}
+}
+
+/// Comparison of a value, the left operand, against an expected one, the right operand.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+	Equal,
+	NotEqual,
+	Less,
+	LessOrEqual,
+	Greater,
+	GreaterOrEqual,
+}
+
+impl Comparison {
+	pub fn compare<T: Ord>(&self, lhs: T, rhs: T) -> bool {
+		match self {
+			Comparison::Equal => lhs == rhs,
+			Comparison::NotEqual => lhs != rhs,
+			Comparison::Less => lhs < rhs,
+			Comparison::LessOrEqual => lhs <= rhs,
+			Comparison::Greater => lhs > rhs,
+			Comparison::GreaterOrEqual => lhs >= rhs,
+		}
+	}
+}
+
+/// Condition evaluated by an [`Instruction::If`].
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+	/// Compare the balance of `asset` held by the interpreter to `amount`.
+	#[serde(rename_all = "snake_case")]
+	Balance { asset: AssetId, comparison: Comparison, amount: Displayed<u128> },
+	/// Compare the balance of `asset` held by the interpreter targeted by the last
+	/// [`Instruction::Query`] to `amount`.
+	#[serde(rename_all = "snake_case")]
+	QueriedBalance { asset: AssetId, comparison: Comparison, amount: Displayed<u128> },
+	/// Compare the [`Register::Ip`] to `value`.
+	#[serde(rename_all = "snake_case")]
+	Ip { comparison: Comparison, value: u32 },
+	/// Whether the [`Register::Result`] holds a success or an error.
+	#[serde(rename_all = "snake_case")]
+	Result { ok: bool },
+}
+
 /// Base XCVM instructions.
 /// This set will remain as small as possible, expressiveness must come on `top` of the base
 /// instructions.
@@ -70,17 +138,38 @@ pub enum Instruction<Network, Payload, Account, Assets> {
 	///
 	/// The program will be spawned with the desired [`Assets`].
 	/// The salt is used to track the program when events are dispatched in the network.
+	/// If the program is not executed before the `deadline`, the assets are refunded.
+	/// The `fee` is the maximum offered to the relayer delivering the program, on top of the
+	/// `assets`, its ratios apply to what remains once the `assets` are taken. The unused part of
+	/// it is deposited along the assets.
 	#[serde(rename_all = "snake_case")]
 	Spawn {
 		network: Network,
 		bridge_security: BridgeSecurity,
 		salt: Vec<u8>,
 		assets: Assets,
+		fee: Assets,
+		deadline: Deadline,
 		program: Program<VecDeque<Self>>,
 	},
-	/// Query the state of a contract
+	/// Query the state of the interpreter identified by `salt` on the target `network`.
+	///
+	/// The execution is suspended until the [`crate::QueryResult`] is received, which is then
+	/// available through the [`crate::Register::Result`].
 	#[serde(rename_all = "snake_case")]
 	Query { network: Network, salt: Vec<u8> },
+	/// Execute the instructions of `then` if the `condition` holds, the ones of `otherwise` if
+	/// not, then continue with the rest of the program.
+	#[serde(rename_all = "snake_case")]
+	If { condition: Condition, then: Program<VecDeque<Self>>, otherwise: Program<VecDeque<Self>> },
+	/// Execute `program`. If it fails, its effects are reverted, the [`Register::Result`] holds
+	/// the error and `on_error` is executed instead. The rest of the program continues in both
+	/// cases.
+	#[serde(rename_all = "snake_case")]
+	Try { program: Program<VecDeque<Self>>, on_error: Program<VecDeque<Self>> },
+	/// Stop the execution of the program, refunding `assets` to the `to` account.
+	#[serde(rename_all = "snake_case")]
+	Abort { to: Destination<Account>, assets: Assets },
 }
 
 /// Error types for late binding operation
@@ -12,9 +12,12 @@ mod network;
 mod packet;
 mod program;
 mod protocol;
+pub mod protocols;
+mod validation;
 
 pub use crate::{
 	asset::*, bridge::*, instruction::*, network::*, packet::*, program::*, protocol::*,
+	validation::*,
 };
 use alloc::{collections::VecDeque, vec::Vec};
 use core::marker::PhantomData;
@@ -44,12 +47,15 @@ where
 	}
 
 	#[inline]
+	#[allow(clippy::too_many_arguments)]
 	pub fn spawn<SpawningNetwork, FinalNetwork, E, F>(
 		self,
 		tag: Vec<u8>,
 		salt: Vec<u8>,
 		bridge_security: BridgeSecurity,
 		assets: Assets,
+		fee: Assets,
+		deadline: Deadline,
 		f: F,
 	) -> Result<ProgramBuilder<FinalNetwork, Account, Assets>, E>
 	where
@@ -68,6 +74,8 @@ where
 			bridge_security,
 			salt,
 			assets,
+			fee,
+			deadline,
 			network: SpawningNetwork::ID,
 			program: f(ProgramBuilder::<SpawningNetwork, Account, Assets>::new(tag))?.build(),
 		});
@@ -75,9 +83,62 @@ where
 	}
 
 	#[inline]
-	pub fn call_raw(mut self, encoded: CurrentNetwork::EncodedCall) -> Self {
+	pub fn query<QueriedNetwork>(mut self, salt: Vec<u8>) -> Self
+	where
+		QueriedNetwork: Network,
+	{
+		self.instructions
+			.push_back(Instruction::Query { network: QueriedNetwork::ID, salt });
+		self
+	}
+
+	#[inline]
+	pub fn conditional<E, F, G>(
+		mut self,
+		condition: Condition,
+		then: F,
+		otherwise: G,
+	) -> Result<Self, E>
+	where
+		F: FnOnce(Self) -> Result<Self, E>,
+		G: FnOnce(Self) -> Result<Self, E>,
+	{
+		self.instructions.push_back(Instruction::If {
+			condition,
+			then: then(Self::new(Vec::new()))?.build(),
+			otherwise: otherwise(Self::new(Vec::new()))?.build(),
+		});
+		Ok(self)
+	}
+
+	#[inline]
+	pub fn try_or_else<E, F, G>(mut self, f: F, on_error: G) -> Result<Self, E>
+	where
+		F: FnOnce(Self) -> Result<Self, E>,
+		G: FnOnce(Self) -> Result<Self, E>,
+	{
+		self.instructions.push_back(Instruction::Try {
+			program: f(Self::new(Vec::new()))?.build(),
+			on_error: on_error(Self::new(Vec::new()))?.build(),
+		});
+		Ok(self)
+	}
+
+	#[inline]
+	pub fn abort(mut self, to: Destination<Account>, assets: Assets) -> Self {
+		self.instructions.push_back(Instruction::Abort { to, assets });
+		self
+	}
+
+	#[inline]
+	pub fn call_raw(self, encoded: CurrentNetwork::EncodedCall) -> Self {
+		self.call_bound(Bindings::new(), encoded)
+	}
+
+	#[inline]
+	pub fn call_bound(mut self, bindings: Bindings, encoded: CurrentNetwork::EncodedCall) -> Self {
 		self.instructions
-			.push_back(Instruction::Call { bindings: Vec::new(), encoded: encoded.into() });
+			.push_back(Instruction::Call { bindings, encoded: encoded.into() });
 		self
 	}
 
@@ -86,7 +147,9 @@ where
 	where
 		T: Protocol<CurrentNetwork>,
 	{
-		protocol.serialize().map(|encoded_call| self.call_raw(encoded_call))
+		protocol
+			.serialize_with_bindings()
+			.map(|(bindings, encoded_call)| self.call_bound(bindings, encoded_call))
 	}
 
 	#[inline]
@@ -153,11 +216,14 @@ mod tests {
 		let program = || -> Result<_, ProgramBuildError> {
 			Ok(ProgramBuilder::<Picasso, (), Funds>::new("Main program".as_bytes().to_vec())
 				.call(DummyProtocol1)?
+				.query::<Ethereum>(vec![0x01])
 				.spawn::<Ethereum, _, ProgramBuildError, _>(
 					Default::default(),
 					Default::default(),
 					BridgeSecurity::Deterministic,
 					Funds::empty(),
+					Funds::from([(PICA::ID, 1_000_u128)]),
+					Deadline::Relative(3600),
 					|child| {
 						Ok(child
 							.call(DummyProtocol2)?
@@ -176,12 +242,16 @@ mod tests {
 				instructions: VecDeque::from([
 					// Protocol 1 on picasso
 					Instruction::Call { bindings: vec![], encoded: vec![202, 254, 190, 239] },
+					// Check the state of an interpreter on ethereum
+					Instruction::Query { network: Ethereum::ID, salt: vec![0x01] },
 					// Move to ethereum
 					Instruction::Spawn {
 						network: Ethereum::ID,
 						bridge_security: BridgeSecurity::Deterministic,
 						salt: Vec::new(),
 						assets: Funds::empty(),
+						fee: Funds::from([(PICA::ID, 1_000_u128)]),
+						deadline: Deadline::Relative(3600),
 						program: Program {
 							tag: Default::default(),
 							instructions: VecDeque::from([
@@ -206,4 +276,85 @@ mod tests {
 			},
 		);
 	}
+
+	#[test]
+	fn can_build_control_flow() {
+		let program = || -> Result<_, ProgramBuildError> {
+			ProgramBuilder::<Picasso, (), Funds>::new("Main program".as_bytes().to_vec())
+				.conditional::<ProgramBuildError, _, _>(
+					Condition::Balance {
+						asset: PICA::ID,
+						comparison: Comparison::GreaterOrEqual,
+						amount: Displayed(1000),
+					},
+					|then| Ok(then.call(DummyProtocol1)?),
+					|otherwise| {
+						Ok(otherwise.abort(Destination::Relayer, Funds::from([(PICA::ID, 1_u128)])))
+					},
+				)?
+				.try_or_else::<ProgramBuildError, _, _>(
+					|program| Ok(program.call(DummyProtocol2)?),
+					|on_error| Ok(on_error.transfer(Destination::Relayer, Funds::empty())),
+				)
+				.map(ProgramBuilder::build)
+		}()
+		.expect("valid program");
+
+		assert_eq!(
+			program,
+			Program {
+				tag: "Main program".as_bytes().to_vec(),
+				instructions: VecDeque::from([
+					Instruction::If {
+						condition: Condition::Balance {
+							asset: PICA::ID,
+							comparison: Comparison::GreaterOrEqual,
+							amount: Displayed(1000),
+						},
+						then: Program {
+							tag: Vec::new(),
+							instructions: VecDeque::from([Instruction::Call {
+								bindings: vec![],
+								encoded: vec![202, 254, 190, 239]
+							}])
+						},
+						otherwise: Program {
+							tag: Vec::new(),
+							instructions: VecDeque::from([Instruction::Abort {
+								to: Destination::Relayer,
+								assets: Funds::from(vec![(PICA::ID, 1_u128)])
+							}])
+						},
+					},
+					Instruction::Try {
+						program: Program {
+							tag: Vec::new(),
+							instructions: VecDeque::from([Instruction::Call {
+								bindings: vec![],
+								encoded: vec![202, 254, 186, 190]
+							}])
+						},
+						on_error: Program {
+							tag: Vec::new(),
+							instructions: VecDeque::from([Instruction::Transfer {
+								to: Destination::Relayer,
+								assets: Funds::empty()
+							}])
+						},
+					},
+				])
+			},
+		);
+	}
+
+	#[test]
+	fn comparison_works() {
+		assert!(Comparison::Equal.compare(1, 1));
+		assert!(Comparison::NotEqual.compare(1, 2));
+		assert!(Comparison::Less.compare(1, 2));
+		assert!(Comparison::LessOrEqual.compare(2, 2));
+		assert!(Comparison::Greater.compare(3, 2));
+		assert!(Comparison::GreaterOrEqual.compare(2, 2));
+		assert!(!Comparison::Greater.compare(2, 2));
+	}
 }
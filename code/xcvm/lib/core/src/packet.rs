@@ -47,4 +47,41 @@ pub struct Packet<Program> {
 	pub program: Program,
 	/// The assets that were attached to the program.
 	pub assets: Funds<Displayed<u128>>,
+	/// The maximum fee paid to the relayer delivering the packet.
+	pub fee: Funds<Displayed<u128>>,
+}
+
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// A query of the state of an interpreter living on another network.
+pub struct QueryPacket {
+	/// The interpreter that was the origin of this query.
+	pub interpreter: Vec<u8>,
+	/// The user that originated the first XCVM call, owning the queried interpreter.
+	pub user_origin: UserOrigin,
+	/// The salt of the queried interpreter.
+	pub salt: Vec<u8>,
+}
+
+/// State of a remote interpreter, answered to a [`QueryPacket`].
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryResult {
+	/// The queried interpreter, empty if it has not been instantiated yet.
+	pub interpreter: Vec<u8>,
+	/// The assets held by the queried interpreter.
+	pub balances: Funds<Displayed<u128>>,
+}
+
+/// Data exchanged between two gateways.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GatewayPacket<Program> {
+	/// A program to execute on the counterparty network.
+	Spawn(Packet<Program>),
+	/// A query of an interpreter living on the counterparty network.
+	Query(QueryPacket),
 }
@@ -1,6 +1,11 @@
-use crate::network::Network;
+use crate::{network::Network, Bindings};
 
 pub trait Protocol<N: Network> {
 	type Error;
 	fn serialize(&self) -> Result<N::EncodedCall, Self::Error>;
+	/// Serialize the call along with the late bindings to apply before executing it.
+	/// Protocols that don't rely on late bindings can keep the default implementation.
+	fn serialize_with_bindings(&self) -> Result<(Bindings, N::EncodedCall), Self::Error> {
+		self.serialize().map(|encoded| (Bindings::new(), encoded))
+	}
 }
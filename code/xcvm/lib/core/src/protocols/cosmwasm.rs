@@ -0,0 +1,290 @@
+//! Adapters for CosmWasm contracts, serialized as [`LateCall`]s executed by the interpreter.
+
+use crate::{
+	cosmwasm::{find_key_offset, IndexedBinding, LateCall, StaticBinding},
+	AssetId, BindingValue, Bindings, Juno, Network, Picasso, Protocol,
+};
+use alloc::{string::String, vec, vec::Vec};
+use cosmwasm_std::{to_binary, Binary, Coin, Uint128};
+use serde::Serialize;
+
+/// Networks on which the XCVM interpreter is a CosmWasm contract.
+pub trait CosmWasmNetwork: Network<EncodedCall = Vec<u8>> {}
+
+impl CosmWasmNetwork for Picasso {}
+impl CosmWasmNetwork for Juno {}
+
+macro_rules! impl_late_call_protocol {
+	($($adapter:ty),*) => {
+		$(
+			impl<N: CosmWasmNetwork> Protocol<N> for $adapter {
+				type Error = ();
+				fn serialize(&self) -> Result<Vec<u8>, Self::Error> {
+					self.late_call().map(|call| call.encoded_call)
+				}
+				fn serialize_with_bindings(&self) -> Result<(Bindings, Vec<u8>), Self::Error> {
+					self.late_call().map(|call| (call.bindings, call.encoded_call))
+				}
+			}
+		)*
+	};
+}
+
+impl_late_call_protocol!(Cw20Transfer, PoolSwap, Stake, LendingDeposit);
+
+/// Subset of the cw20 execute messages.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+enum Cw20ExecuteMsg {
+	Transfer { recipient: String, amount: Uint128 },
+	Send { contract: String, amount: Uint128, msg: Binary },
+}
+
+/// The value to put in a field that is possibly late-bound.
+fn static_value(value: &StaticBinding<String>) -> String {
+	match value {
+		StaticBinding::Some(_) => String::new(),
+		StaticBinding::None(data) => data.clone(),
+	}
+}
+
+/// Bind the value of the field `key` of `msg` if required.
+fn bind_field<T: Serialize>(
+	msg: T,
+	key: &str,
+	value: &StaticBinding<String>,
+) -> Result<IndexedBinding<T>, ()> {
+	match value {
+		StaticBinding::None(_) => Ok(IndexedBinding::None(msg)),
+		StaticBinding::Some(binding) => {
+			let serialized_msg = serde_json::to_string(&msg).map_err(|_| ())?;
+			let offset = find_key_offset(key, &serialized_msg).ok_or(())?;
+			Ok(IndexedBinding::Some(([(offset, *binding)].into(), msg)))
+		},
+	}
+}
+
+/// Transfer `amount` of the cw20 token of `asset` to `recipient`.
+#[derive(Clone, Debug)]
+pub struct Cw20Transfer {
+	pub asset: AssetId,
+	pub recipient: StaticBinding<String>,
+	pub amount: u128,
+}
+
+impl Cw20Transfer {
+	fn late_call(&self) -> Result<LateCall, ()> {
+		let msg = Cw20ExecuteMsg::Transfer {
+			recipient: static_value(&self.recipient),
+			amount: self.amount.into(),
+		};
+		LateCall::wasm_execute(
+			StaticBinding::Some(BindingValue::Asset(self.asset)),
+			bind_field(msg, "\"recipient\"", &self.recipient)?,
+			Vec::new(),
+		)
+	}
+}
+
+/// Swap message of an Osmosis-style CosmWasm pool.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+enum PoolExecuteMsg {
+	SwapExactAmountIn { token_in: Coin, token_out_denom: String, token_out_min_amount: Uint128 },
+}
+
+/// Swap `token_in` for at least `token_out_min_amount` of `token_out` on an Osmosis-style pool.
+/// The denom of `token_out` is resolved by the interpreter, it must be a native asset.
+#[derive(Clone, Debug)]
+pub struct PoolSwap {
+	pub pool: String,
+	pub token_in: Coin,
+	pub token_out: AssetId,
+	pub token_out_min_amount: u128,
+}
+
+impl PoolSwap {
+	fn late_call(&self) -> Result<LateCall, ()> {
+		let token_out = StaticBinding::Some(BindingValue::Asset(self.token_out));
+		let msg = PoolExecuteMsg::SwapExactAmountIn {
+			token_in: self.token_in.clone(),
+			token_out_denom: static_value(&token_out),
+			token_out_min_amount: self.token_out_min_amount.into(),
+		};
+		LateCall::wasm_execute(
+			StaticBinding::None(self.pool.clone()),
+			bind_field(msg, "\"token_out_denom\"", &token_out)?,
+			vec![self.token_in.clone()],
+		)
+	}
+}
+
+/// Hook message of a cw20-stake-style staking contract.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+enum StakingReceiveMsg {
+	Stake {},
+}
+
+/// Stake `amount` of the cw20 token of `asset` in a cw20-stake-style `staking_contract`, on
+/// behalf of the interpreter.
+#[derive(Clone, Debug)]
+pub struct Stake {
+	pub asset: AssetId,
+	pub staking_contract: String,
+	pub amount: u128,
+}
+
+impl Stake {
+	fn late_call(&self) -> Result<LateCall, ()> {
+		let msg = Cw20ExecuteMsg::Send {
+			contract: self.staking_contract.clone(),
+			amount: self.amount.into(),
+			msg: to_binary(&StakingReceiveMsg::Stake {}).map_err(|_| ())?,
+		};
+		LateCall::wasm_execute(
+			StaticBinding::Some(BindingValue::Asset(self.asset)),
+			IndexedBinding::None(msg),
+			Vec::new(),
+		)
+	}
+}
+
+/// Deposit message of a Mars-style lending market.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+enum MarketExecuteMsg {
+	Deposit { on_behalf_of: Option<String> },
+}
+
+/// Deposit `asset` in a lending `market`, crediting `on_behalf_of` or the interpreter if `None`.
+#[derive(Clone, Debug)]
+pub struct LendingDeposit {
+	pub market: String,
+	pub asset: Coin,
+	pub on_behalf_of: Option<StaticBinding<String>>,
+}
+
+impl LendingDeposit {
+	fn late_call(&self) -> Result<LateCall, ()> {
+		let msg = MarketExecuteMsg::Deposit {
+			on_behalf_of: self.on_behalf_of.as_ref().map(static_value),
+		};
+		let msg = match &self.on_behalf_of {
+			Some(on_behalf_of) => bind_field(msg, "\"on_behalf_of\"", on_behalf_of)?,
+			None => IndexedBinding::None(msg),
+		};
+		LateCall::wasm_execute(
+			StaticBinding::None(self.market.clone()),
+			msg,
+			vec![self.asset.clone()],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{apply_bindings, Funds, Instruction, ProgramBuilder, Register};
+	use alloc::{borrow::Cow, string::ToString};
+
+	/// Apply the bindings as the interpreter would.
+	fn bind<T: Protocol<Juno, Error = ()>>(protocol: T) -> String {
+		let (bindings, encoded) = protocol.serialize_with_bindings().unwrap();
+		let mut formatted = vec![0; 64 * bindings.len() + encoded.len()];
+		apply_bindings(encoded, bindings, &mut formatted, |binding| {
+			Ok::<_, ()>(Cow::Owned(
+				match binding {
+					BindingValue::Register(Register::This) => "interpreter".to_string(),
+					BindingValue::Asset(asset) => alloc::format!("asset{}", asset.0 .0),
+					_ => unreachable!(),
+				}
+				.into_bytes(),
+			))
+		})
+		.ok()
+		.expect("valid bindings");
+		String::from_utf8(formatted).unwrap()
+	}
+
+	#[test]
+	fn cw20_transfer() {
+		assert_eq!(
+			bind(Cw20Transfer {
+				asset: AssetId::from(1),
+				recipient: StaticBinding::Some(BindingValue::Register(Register::This)),
+				amount: 100,
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"asset1","msg":{"transfer":{"recipient":"interpreter","amount":"100"}},"funds":[]}}}"#
+		);
+		assert_eq!(
+			bind(Cw20Transfer {
+				asset: AssetId::from(1),
+				recipient: StaticBinding::None("alice".into()),
+				amount: 100,
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"asset1","msg":{"transfer":{"recipient":"alice","amount":"100"}},"funds":[]}}}"#
+		);
+	}
+
+	#[test]
+	fn pool_swap() {
+		assert_eq!(
+			bind(PoolSwap {
+				pool: "pool".into(),
+				token_in: Coin::new(100, "uosmo"),
+				token_out: AssetId::from(2),
+				token_out_min_amount: 90,
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"pool","msg":{"swap_exact_amount_in":{"token_in":{"denom":"uosmo","amount":"100"},"token_out_denom":"asset2","token_out_min_amount":"90"}},"funds":[{"denom":"uosmo","amount":"100"}]}}}"#
+		);
+	}
+
+	#[test]
+	fn stake() {
+		assert_eq!(
+			bind(Stake {
+				asset: AssetId::from(1),
+				staking_contract: "staking".into(),
+				amount: 100
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"asset1","msg":{"send":{"contract":"staking","amount":"100","msg":"eyJzdGFrZSI6e319"}},"funds":[]}}}"#
+		);
+	}
+
+	#[test]
+	fn lending_deposit() {
+		assert_eq!(
+			bind(LendingDeposit {
+				market: "market".into(),
+				asset: Coin::new(100, "uatom"),
+				on_behalf_of: Some(StaticBinding::Some(BindingValue::Register(Register::This))),
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"market","msg":{"deposit":{"on_behalf_of":"interpreter"}},"funds":[{"denom":"uatom","amount":"100"}]}}}"#
+		);
+		assert_eq!(
+			bind(LendingDeposit {
+				market: "market".into(),
+				asset: Coin::new(100, "uatom"),
+				on_behalf_of: None,
+			}),
+			r#"{"wasm":{"execute":{"contract_addr":"market","msg":{"deposit":{"on_behalf_of":null}},"funds":[{"denom":"uatom","amount":"100"}]}}}"#
+		);
+	}
+
+	#[test]
+	fn builder_keeps_bindings() {
+		let transfer = Cw20Transfer {
+			asset: AssetId::from(1),
+			recipient: StaticBinding::Some(BindingValue::Register(Register::This)),
+			amount: 100,
+		};
+		let (bindings, encoded) =
+			<Cw20Transfer as Protocol<Juno>>::serialize_with_bindings(&transfer).unwrap();
+		let program = ProgramBuilder::<Juno, (), Funds>::new(Vec::new())
+			.call(transfer)
+			.unwrap()
+			.build();
+		assert_eq!(program.instructions, [Instruction::Call { bindings, encoded }]);
+	}
+}
@@ -0,0 +1,9 @@
+//! Typed adapters for common DeFi actions, to be used with [`crate::ProgramBuilder::call`].
+//!
+//! Adapters targeting CosmWasm contracts produce payloads that can be late-bound by the
+//! interpreter, for instance to use the address of the cw20 contract of an asset or the address
+//! of the interpreter itself.
+
+#[cfg(feature = "cosmwasm")]
+pub mod cosmwasm;
+pub mod pablo;
@@ -0,0 +1,61 @@
+//! Adapters for the Pablo pallet of Picasso.
+
+use crate::{Picasso, Protocol};
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use core::convert::Infallible;
+use scale_info::TypeInfo;
+
+/// Index of the Pablo pallet in the Picasso runtime.
+pub const PABLO_PALLET_INDEX: u8 = 60;
+/// Index of the `swap` extrinsic of the Pablo pallet.
+pub const PABLO_SWAP_CALL_INDEX: u8 = 2;
+
+/// An amount of a local Picasso currency.
+/// Note that the asset is identified by its `CurrencyId` on Picasso, not by its XCVM `AssetId`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct AssetAmount {
+	pub asset_id: u128,
+	pub amount: u128,
+}
+
+/// Swap `in_asset` on the Pablo pool `pool_id`, failing if less than `min_receive` is received.
+/// The SCALE-encoded `Pablo::swap` extrinsic is dispatched on behalf of the interpreter.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct Swap {
+	pub pool_id: u128,
+	pub in_asset: AssetAmount,
+	pub min_receive: AssetAmount,
+	pub keep_alive: bool,
+}
+
+impl Protocol<Picasso> for Swap {
+	type Error = Infallible;
+	fn serialize(&self) -> Result<Vec<u8>, Self::Error> {
+		Ok((PABLO_PALLET_INDEX, PABLO_SWAP_CALL_INDEX, self).encode())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	#[test]
+	fn swap_is_scale_encoded() {
+		let swap = Swap {
+			pool_id: 1,
+			in_asset: AssetAmount { asset_id: 1, amount: 1_000 },
+			min_receive: AssetAmount { asset_id: 4, amount: 900 },
+			keep_alive: true,
+		};
+		let encoded = <Swap as Protocol<Picasso>>::serialize(&swap).unwrap();
+		let mut expected = vec![PABLO_PALLET_INDEX, PABLO_SWAP_CALL_INDEX];
+		for value in [1_u128, 1, 1_000, 4, 900] {
+			expected.extend(value.to_le_bytes());
+		}
+		expected.push(1);
+		assert_eq!(encoded, expected);
+		assert_eq!(<Swap as Protocol<Picasso>>::serialize_with_bindings(&swap).unwrap().0, vec![]);
+	}
+}
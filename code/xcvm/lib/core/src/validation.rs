@@ -0,0 +1,465 @@
+//! Static validation and cost estimation of XCVM programs, before they are submitted.
+
+use crate::{
+	AssetId, BindingValue, Bindings, BridgeSecurity, Condition, Displayed, Funds, Instruction,
+	NetworkId, Program, MAX_PARTS,
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+
+/// Format of the payloads of the `Call` instructions executed on a network.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFormat {
+	/// JSON messages executed by a CosmWasm interpreter, late-bound fields are empty strings.
+	#[default]
+	Json,
+	/// Payloads that are not inspected.
+	Opaque,
+}
+
+/// What is known about a network a program is validated against.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct NetworkInfo {
+	/// Security of the bridges able to spawn a program on this network.
+	pub bridge_securities: Vec<BridgeSecurity>,
+	/// Assets registered in the asset registry of this network.
+	pub assets: BTreeSet<AssetId>,
+	#[serde(default)]
+	pub payload_format: PayloadFormat,
+}
+
+/// The networks a program is validated against, any other network is unknown.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ValidationContext {
+	pub networks: BTreeMap<NetworkId, NetworkInfo>,
+}
+
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationErrorKind {
+	/// The network is not part of the [`ValidationContext`].
+	UnknownNetwork(NetworkId),
+	/// No bridge of this security is able to reach the network.
+	UnsupportedBridgeSecurity { network: NetworkId, bridge_security: BridgeSecurity },
+	/// The asset is not registered on the network it is used on.
+	UnregisteredAsset { network: NetworkId, asset: AssetId },
+	/// The ratio of an amount is above [`MAX_PARTS`].
+	InvalidRatio { asset: AssetId },
+	/// The bindings are not sorted and unique, or are out of the bounds of the payload.
+	InvalidBindings,
+	/// The binding doesn't target an empty string of the payload.
+	InvalidBindingIndex(u32),
+	/// The payload is not in the format expected by the network.
+	InvalidPayload,
+}
+
+/// An error found in a program.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ValidationError {
+	/// Network on which the invalid instruction is executed.
+	pub network: NetworkId,
+	/// Index of the invalid instruction, preceded by the indices of the instructions nesting it.
+	/// The branch taken in an `If` or a `Try` is also part of the path, `0` for `then` and
+	/// `program`, `1` for `otherwise` and `on_error`.
+	pub path: Vec<u32>,
+	pub kind: ValidationErrorKind,
+}
+
+/// What a program requires on a network.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct NetworkEstimate {
+	/// Number of programs spawned on this network.
+	pub hops: u32,
+	/// Absolute amounts of the assets leaving the interpreters of this network, either
+	/// transferred, spawned or paid as fees.
+	pub funds: BTreeMap<AssetId, Displayed<u128>>,
+	/// Assets of which a ratio of the balance leaves the interpreters, on top of `funds`.
+	pub ratios: BTreeSet<AssetId>,
+}
+
+/// What a program requires on each network it is executed on.
+/// Both branches of the conditional instructions are accounted for, this is an upper bound.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Estimate {
+	pub networks: BTreeMap<NetworkId, NetworkEstimate>,
+}
+
+type XCVMProgram<Account> = Program<VecDeque<Instruction<NetworkId, Vec<u8>, Account, Funds>>>;
+
+/// Validate a program executed on `network`, including the programs it spawns.
+/// All the errors found are returned.
+pub fn validate<Account>(
+	context: &ValidationContext,
+	network: NetworkId,
+	program: &XCVMProgram<Account>,
+) -> Result<(), Vec<ValidationError>> {
+	let mut validator = Validator { context, path: Vec::new(), errors: Vec::new() };
+	match context.networks.get(&network) {
+		Some(info) => validator.validate_program(network, info, program),
+		None => validator.error(network, ValidationErrorKind::UnknownNetwork(network)),
+	}
+	if validator.errors.is_empty() {
+		Ok(())
+	} else {
+		Err(validator.errors)
+	}
+}
+
+/// Estimate the funds and hops required by a program executed on `network`, including the
+/// programs it spawns.
+pub fn estimate<Account>(network: NetworkId, program: &XCVMProgram<Account>) -> Estimate {
+	let mut estimate = Estimate::default();
+	estimate.networks.entry(network).or_default();
+	estimate_program(&mut estimate, network, program);
+	estimate
+}
+
+struct Validator<'a> {
+	context: &'a ValidationContext,
+	path: Vec<u32>,
+	errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+	fn error(&mut self, network: NetworkId, kind: ValidationErrorKind) {
+		self.errors.push(ValidationError { network, path: self.path.clone(), kind });
+	}
+
+	fn nested<F: FnOnce(&mut Self)>(&mut self, index: u32, f: F) {
+		self.path.push(index);
+		f(self);
+		self.path.pop();
+	}
+
+	fn validate_program<Account>(
+		&mut self,
+		network: NetworkId,
+		info: &NetworkInfo,
+		program: &XCVMProgram<Account>,
+	) {
+		for (index, instruction) in program.instructions.iter().enumerate() {
+			self.nested(index as u32, |validator| {
+				validator.validate_instruction(network, info, instruction)
+			});
+		}
+	}
+
+	fn validate_instruction<Account>(
+		&mut self,
+		network: NetworkId,
+		info: &NetworkInfo,
+		instruction: &Instruction<NetworkId, Vec<u8>, Account, Funds>,
+	) {
+		match instruction {
+			Instruction::Transfer { assets, .. } | Instruction::Abort { assets, .. } =>
+				self.validate_funds(network, info, assets),
+			Instruction::Call { bindings, encoded } =>
+				self.validate_call(network, info, bindings, encoded),
+			Instruction::Spawn {
+				network: spawned_network,
+				bridge_security,
+				assets,
+				fee,
+				program,
+				..
+			} => {
+				self.validate_funds(network, info, assets);
+				self.validate_funds(network, info, fee);
+				match self.context.networks.get(spawned_network) {
+					Some(spawned_info) => {
+						if !spawned_info.bridge_securities.contains(bridge_security) {
+							self.error(
+								network,
+								ValidationErrorKind::UnsupportedBridgeSecurity {
+									network: *spawned_network,
+									bridge_security: *bridge_security,
+								},
+							);
+						}
+						// The spawned assets are minted on the spawned network.
+						for (asset, _) in assets.0.iter().chain(fee.0.iter()) {
+							if !spawned_info.assets.contains(asset) {
+								self.error(
+									network,
+									ValidationErrorKind::UnregisteredAsset {
+										network: *spawned_network,
+										asset: *asset,
+									},
+								);
+							}
+						}
+						self.validate_program(*spawned_network, spawned_info, program);
+					},
+					None =>
+						self.error(network, ValidationErrorKind::UnknownNetwork(*spawned_network)),
+				}
+			},
+			Instruction::Query { network: queried_network, .. } =>
+				if !self.context.networks.contains_key(queried_network) {
+					self.error(network, ValidationErrorKind::UnknownNetwork(*queried_network));
+				},
+			Instruction::If { condition, then, otherwise } => {
+				if let Condition::Balance { asset, .. } = condition {
+					self.validate_asset(network, info, *asset);
+				}
+				self.nested(0, |validator| validator.validate_program(network, info, then));
+				self.nested(1, |validator| validator.validate_program(network, info, otherwise));
+			},
+			Instruction::Try { program, on_error } => {
+				self.nested(0, |validator| validator.validate_program(network, info, program));
+				self.nested(1, |validator| validator.validate_program(network, info, on_error));
+			},
+		}
+	}
+
+	fn validate_asset(&mut self, network: NetworkId, info: &NetworkInfo, asset: AssetId) {
+		if !info.assets.contains(&asset) {
+			self.error(network, ValidationErrorKind::UnregisteredAsset { network, asset });
+		}
+	}
+
+	fn validate_funds(&mut self, network: NetworkId, info: &NetworkInfo, funds: &Funds) {
+		for (asset, amount) in funds.0.iter() {
+			self.validate_asset(network, info, *asset);
+			if amount.slope.0 > MAX_PARTS {
+				self.error(network, ValidationErrorKind::InvalidRatio { asset: *asset });
+			}
+		}
+	}
+
+	fn validate_call(
+		&mut self,
+		network: NetworkId,
+		info: &NetworkInfo,
+		bindings: &Bindings,
+		encoded: &[u8],
+	) {
+		// Mirror the requirements of `apply_bindings`.
+		let sorted = bindings.windows(2).all(|pair| pair[0].0 < pair[1].0);
+		let in_bounds = bindings.iter().all(|(index, _)| (*index as usize) + 1 < encoded.len());
+		if !sorted || !in_bounds {
+			self.error(network, ValidationErrorKind::InvalidBindings);
+		}
+		for (_, binding) in bindings {
+			if let BindingValue::Asset(asset) = binding {
+				self.validate_asset(network, info, *asset);
+			}
+		}
+		if info.payload_format == PayloadFormat::Json {
+			if in_bounds {
+				for (index, _) in bindings {
+					let index = *index as usize;
+					if &encoded[index..index + 2] != b"\"\"" {
+						self.error(network, ValidationErrorKind::InvalidBindingIndex(index as u32));
+					}
+				}
+			}
+			if serde_json::from_slice::<serde_json::Value>(encoded).is_err() {
+				self.error(network, ValidationErrorKind::InvalidPayload);
+			}
+		}
+	}
+}
+
+fn estimate_funds(estimate: &mut NetworkEstimate, funds: &Funds) {
+	for (asset, amount) in funds.0.iter() {
+		let Displayed(total) = estimate.funds.entry(*asset).or_insert(Displayed(0));
+		*total = total.saturating_add(amount.intercept.0);
+		if amount.slope.0 != 0 {
+			estimate.ratios.insert(*asset);
+		}
+	}
+}
+
+fn estimate_program<Account>(
+	estimate: &mut Estimate,
+	network: NetworkId,
+	program: &XCVMProgram<Account>,
+) {
+	for instruction in program.instructions.iter() {
+		match instruction {
+			Instruction::Transfer { assets, .. } | Instruction::Abort { assets, .. } =>
+				estimate_funds(estimate.networks.entry(network).or_default(), assets),
+			Instruction::Spawn { network: spawned_network, assets, fee, program, .. } => {
+				let network_estimate = estimate.networks.entry(network).or_default();
+				estimate_funds(network_estimate, assets);
+				estimate_funds(network_estimate, fee);
+				estimate.networks.entry(*spawned_network).or_default().hops += 1;
+				estimate_program(estimate, *spawned_network, program);
+			},
+			Instruction::If { then, otherwise, .. } => {
+				estimate_program(estimate, network, then);
+				estimate_program(estimate, network, otherwise);
+			},
+			Instruction::Try { program, on_error } => {
+				estimate_program(estimate, network, program);
+				estimate_program(estimate, network, on_error);
+			},
+			Instruction::Call { .. } | Instruction::Query { .. } => {},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Amount, Asset, Deadline, Destination, Ethereum, Juno, Network, Picasso, ProgramBuilder,
+		PICA, USDT,
+	};
+	use alloc::vec;
+
+	fn context() -> ValidationContext {
+		let info = NetworkInfo {
+			bridge_securities: vec![BridgeSecurity::Deterministic],
+			assets: [PICA::ID, USDT::ID].into(),
+			payload_format: PayloadFormat::Json,
+		};
+		ValidationContext { networks: [(Picasso::ID, info.clone()), (Juno::ID, info)].into() }
+	}
+
+	fn spawn_program(
+		network_assets: Funds,
+		bridge_security: BridgeSecurity,
+		call: (Bindings, Vec<u8>),
+	) -> XCVMProgram<()> {
+		ProgramBuilder::<Picasso, (), Funds>::new(Vec::new())
+			.transfer(Destination::Relayer, Funds::from([(PICA::ID, 10_u128)]))
+			.spawn::<Juno, Juno, (), _>(
+				Vec::new(),
+				Vec::new(),
+				bridge_security,
+				network_assets,
+				Funds::from([(PICA::ID, 5_u128)]),
+				Deadline::Relative(3600),
+				|child| Ok::<_, ()>(child.call_bound(call.0, call.1)),
+			)
+			.unwrap()
+			.build()
+	}
+
+	#[test]
+	fn valid_program() {
+		let program = spawn_program(
+			Funds::from([(USDT::ID, Amount::new(100, MAX_PARTS / 2))]),
+			BridgeSecurity::Deterministic,
+			(
+				vec![(13, BindingValue::Register(crate::Register::This))],
+				br#"{"recipient":"","amount":"10"}"#.to_vec(),
+			),
+		);
+		assert_eq!(validate(&context(), Picasso::ID, &program), Ok(()));
+		assert_eq!(
+			estimate(Picasso::ID, &program),
+			Estimate {
+				networks: [
+					(
+						Picasso::ID,
+						NetworkEstimate {
+							hops: 0,
+							funds: [(PICA::ID, Displayed(15)), (USDT::ID, Displayed(100))].into(),
+							ratios: [USDT::ID].into(),
+						}
+					),
+					(Juno::ID, NetworkEstimate { hops: 1, ..Default::default() }),
+				]
+				.into()
+			}
+		);
+	}
+
+	#[test]
+	fn invalid_program() {
+		let program = spawn_program(
+			Funds::from([(AssetId::from(42), Amount::ratio(MAX_PARTS + 1))]),
+			BridgeSecurity::Optimistic,
+			(
+				vec![(2, BindingValue::Asset(PICA::ID)), (1, BindingValue::Asset(PICA::ID))],
+				br#"{"recipient":"","amount":"10"}"#.to_vec(),
+			),
+		);
+		let errors = validate(&context(), Picasso::ID, &program).unwrap_err();
+		let error =
+			|network: NetworkId, path: Vec<u32>, kind| ValidationError { network, path, kind };
+		assert_eq!(
+			errors,
+			vec![
+				error(
+					Picasso::ID,
+					vec![1],
+					ValidationErrorKind::UnregisteredAsset {
+						network: Picasso::ID,
+						asset: AssetId::from(42)
+					}
+				),
+				error(
+					Picasso::ID,
+					vec![1],
+					ValidationErrorKind::InvalidRatio { asset: AssetId::from(42) }
+				),
+				error(
+					Picasso::ID,
+					vec![1],
+					ValidationErrorKind::UnsupportedBridgeSecurity {
+						network: Juno::ID,
+						bridge_security: BridgeSecurity::Optimistic
+					}
+				),
+				error(
+					Picasso::ID,
+					vec![1],
+					ValidationErrorKind::UnregisteredAsset {
+						network: Juno::ID,
+						asset: AssetId::from(42)
+					}
+				),
+				error(Juno::ID, vec![1, 0], ValidationErrorKind::InvalidBindings),
+				error(Juno::ID, vec![1, 0], ValidationErrorKind::InvalidBindingIndex(2)),
+				error(Juno::ID, vec![1, 0], ValidationErrorKind::InvalidBindingIndex(1)),
+			]
+		);
+	}
+
+	#[test]
+	fn unknown_network() {
+		let program = ProgramBuilder::<Picasso, (), Funds>::new(Vec::new())
+			.query::<Ethereum>(Vec::new())
+			.build();
+		assert_eq!(
+			validate(&context(), Picasso::ID, &program),
+			Err(vec![ValidationError {
+				network: Picasso::ID,
+				path: vec![0],
+				kind: ValidationErrorKind::UnknownNetwork(Ethereum::ID),
+			}])
+		);
+		assert!(validate(&context(), Ethereum::ID, &program).is_err());
+	}
+
+	#[test]
+	fn invalid_payload() {
+		let program = ProgramBuilder::<Picasso, (), Funds>::new(Vec::new())
+			.call_raw(b"invalid".to_vec())
+			.build();
+		assert_eq!(
+			validate(&context(), Picasso::ID, &program).unwrap_err()[0].kind,
+			ValidationErrorKind::InvalidPayload
+		);
+	}
+}
@@ -14,6 +14,9 @@ include!(concat!(env!("OUT_DIR"), "/interpreter.rs"));
 pub type XCVMPacket<TNetwork, TAbiEncoded, TAccount, TAssets> =
 	xcvm_core::Packet<XCVMProgram<TNetwork, TAbiEncoded, TAccount, TAssets>>;
 
+pub type XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets> =
+	xcvm_core::GatewayPacket<XCVMProgram<TNetwork, TAbiEncoded, TAccount, TAssets>>;
+
 pub type XCVMProgram<TNetwork, TAbiEncoded, TAccount, TAssets> =
 	xcvm_core::Program<VecDeque<xcvm_core::Instruction<TNetwork, TAbiEncoded, TAccount, TAssets>>>;
 
@@ -50,6 +53,31 @@ where
 		.and_then(|x| TryInto::try_into(x).map_err(|_| DecodingFailure::Isomorphism))
 }
 
+pub fn decode_gateway_packet<TNetwork, TAbiEncoded, TAccount, TAssets>(
+	buffer: &[u8],
+) -> core::result::Result<
+	XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets>,
+	DecodingFailure,
+>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	GatewayPacket::decode(buffer)
+		.map_err(DecodingFailure::Protobuf)
+		.and_then(|x| TryInto::try_into(x).map_err(|_| DecodingFailure::Isomorphism))
+}
+
+pub fn decode_query_result(
+	buffer: &[u8],
+) -> core::result::Result<xcvm_core::QueryResult, DecodingFailure> {
+	QueryResult::decode(buffer)
+		.map_err(DecodingFailure::Protobuf)
+		.and_then(|x| TryInto::try_into(x).map_err(|_| DecodingFailure::Isomorphism))
+}
+
 pub fn decode<TNetwork, TAbiEncoded, TAccount, TAssets>(
 	buffer: &[u8],
 ) -> core::result::Result<XCVMProgram<TNetwork, TAbiEncoded, TAccount, TAssets>, DecodingFailure>
@@ -77,6 +105,25 @@ where
 	}
 }
 
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> Encodable
+	for XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: Into<u32>,
+	TAbiEncoded: Into<Vec<u8>>,
+	TAccount: Into<Vec<u8>>,
+	TAssets: Into<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	fn encode(self) -> Vec<u8> {
+		GatewayPacket::encode_to_vec(&self.into())
+	}
+}
+
+impl Encodable for xcvm_core::QueryResult {
+	fn encode(self) -> Vec<u8> {
+		QueryResult::encode_to_vec(&self.into())
+	}
+}
+
 impl From<Vec<u8>> for Salt {
 	fn from(value: Vec<u8>) -> Self {
 		Salt { salt: value }
@@ -116,6 +163,46 @@ where
 			salt: Some(value.salt.into()),
 			program: Some(value.program.into()),
 			assets: value.assets.0.into_iter().map(PacketAsset::from).collect::<Vec<_>>(),
+			fee: value.fee.0.into_iter().map(PacketAsset::from).collect::<Vec<_>>(),
+		}
+	}
+}
+
+impl From<xcvm_core::QueryPacket> for QueryPacket {
+	fn from(value: xcvm_core::QueryPacket) -> Self {
+		QueryPacket {
+			interpreter: Some(Account { account: value.interpreter }),
+			user_origin: Some(value.user_origin.into()),
+			salt: Some(value.salt.into()),
+		}
+	}
+}
+
+impl From<xcvm_core::QueryResult> for QueryResult {
+	fn from(value: xcvm_core::QueryResult) -> Self {
+		QueryResult {
+			interpreter: Some(Account { account: value.interpreter }),
+			balances: value.balances.0.into_iter().map(PacketAsset::from).collect::<Vec<_>>(),
+		}
+	}
+}
+
+impl<TNetwork, TAbiEncoded, TAccount, TAssets>
+	From<XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets>> for GatewayPacket
+where
+	TNetwork: Into<u32>,
+	TAbiEncoded: Into<Vec<u8>>,
+	TAccount: Into<Vec<u8>>,
+	TAssets: Into<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	fn from(value: XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets>) -> Self {
+		GatewayPacket {
+			packet: Some(match value {
+				xcvm_core::GatewayPacket::Spawn(packet) =>
+					gateway_packet::Packet::Spawn(packet.into()),
+				xcvm_core::GatewayPacket::Query(query) =>
+					gateway_packet::Packet::Query(query.into()),
+			}),
 		}
 	}
 }
@@ -188,6 +275,62 @@ where
 					.map(|asset| <(xcvm_core::AssetId, Displayed<u128>)>::try_from(asset))
 					.collect::<core::result::Result<Vec<_>, _>>()?,
 			),
+			fee: Funds(
+				packet
+					.fee
+					.into_iter()
+					.map(|asset| <(xcvm_core::AssetId, Displayed<u128>)>::try_from(asset))
+					.collect::<core::result::Result<Vec<_>, _>>()?,
+			),
+		})
+	}
+}
+
+impl TryFrom<QueryPacket> for xcvm_core::QueryPacket {
+	type Error = ();
+
+	fn try_from(query: QueryPacket) -> core::result::Result<Self, Self::Error> {
+		Ok(xcvm_core::QueryPacket {
+			interpreter: query.interpreter.ok_or(())?.account,
+			user_origin: query.user_origin.ok_or(())?.try_into()?,
+			salt: query.salt.map(|s| s.salt).ok_or(())?,
+		})
+	}
+}
+
+impl TryFrom<QueryResult> for xcvm_core::QueryResult {
+	type Error = ();
+
+	fn try_from(result: QueryResult) -> core::result::Result<Self, Self::Error> {
+		Ok(xcvm_core::QueryResult {
+			interpreter: result.interpreter.ok_or(())?.account,
+			balances: Funds(
+				result
+					.balances
+					.into_iter()
+					.map(|asset| <(xcvm_core::AssetId, Displayed<u128>)>::try_from(asset))
+					.collect::<core::result::Result<Vec<_>, _>>()?,
+			),
+		})
+	}
+}
+
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> TryFrom<GatewayPacket>
+	for XCVMGatewayPacket<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(packet: GatewayPacket) -> core::result::Result<Self, Self::Error> {
+		Ok(match packet.packet.ok_or(())? {
+			gateway_packet::Packet::Spawn(packet) =>
+				xcvm_core::GatewayPacket::Spawn(packet.try_into()?),
+			gateway_packet::Packet::Query(query) =>
+				xcvm_core::GatewayPacket::Query(query.try_into()?),
 		})
 	}
 }
@@ -259,9 +402,10 @@ where
 			instruction::Instruction::Transfer(t) => t.try_into(),
 			instruction::Instruction::Spawn(s) => s.try_into(),
 			instruction::Instruction::Call(c) => c.try_into(),
-			// TODO(aeryz): Query needs to be implemented
-			// instruction::Instruction::Query(q) => q.try_into(),
-			_ => Err(()),
+			instruction::Instruction::Query(q) => q.try_into(),
+			instruction::Instruction::If(i) => i.try_into(),
+			instruction::Instruction::Try(t) => t.try_into(),
+			instruction::Instruction::Abort(a) => a.try_into(),
 		}
 	}
 }
@@ -357,6 +501,13 @@ where
 				.map(|asset| asset.try_into())
 				.collect::<core::result::Result<Vec<_>, _>>()?
 				.into(),
+			fee: spawn
+				.fee
+				.into_iter()
+				.map(|asset| asset.try_into())
+				.collect::<core::result::Result<Vec<_>, _>>()?
+				.into(),
+			deadline: spawn.deadline.ok_or(())?.try_into()?,
 			program: XCVMProgram {
 				tag: Vec::new(),
 				instructions: spawn.program.ok_or(())?.instructions.ok_or(())?.try_into()?,
@@ -365,6 +516,154 @@ where
 	}
 }
 
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> TryFrom<Query>
+	for xcvm_core::Instruction<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(query: Query) -> core::result::Result<Self, Self::Error> {
+		Ok(xcvm_core::Instruction::Query {
+			network: query.network.ok_or(())?.network_id.into(),
+			salt: query.salt.ok_or(())?.salt,
+		})
+	}
+}
+
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> TryFrom<If>
+	for xcvm_core::Instruction<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(if_: If) -> core::result::Result<Self, Self::Error> {
+		Ok(xcvm_core::Instruction::If {
+			condition: if_.condition.ok_or(())?.try_into()?,
+			then: if_.then.ok_or(())?.try_into()?,
+			otherwise: if_.otherwise.ok_or(())?.try_into()?,
+		})
+	}
+}
+
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> TryFrom<Try>
+	for xcvm_core::Instruction<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(try_: Try) -> core::result::Result<Self, Self::Error> {
+		Ok(xcvm_core::Instruction::Try {
+			program: try_.program.ok_or(())?.try_into()?,
+			on_error: try_.on_error.ok_or(())?.try_into()?,
+		})
+	}
+}
+
+impl<TNetwork, TAbiEncoded, TAccount, TAssets> TryFrom<Abort>
+	for xcvm_core::Instruction<TNetwork, TAbiEncoded, TAccount, TAssets>
+where
+	TNetwork: From<u32>,
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+	TAssets: From<Vec<(xcvm_core::AssetId, xcvm_core::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(abort: Abort) -> core::result::Result<Self, Self::Error> {
+		let account_type = abort.account_type.ok_or(())?;
+		Ok(xcvm_core::Instruction::Abort {
+			to: account_type.try_into()?,
+			assets: abort
+				.assets
+				.into_iter()
+				.map(|asset| asset.try_into())
+				.collect::<core::result::Result<Vec<_>, _>>()?
+				.into(),
+		})
+	}
+}
+
+impl TryFrom<Condition> for xcvm_core::Condition {
+	type Error = ();
+
+	fn try_from(condition: Condition) -> core::result::Result<Self, Self::Error> {
+		let balance = |condition: BalanceCondition| -> core::result::Result<_, ()> {
+			Ok((
+				condition.asset_id.ok_or(())?.try_into()?,
+				Comparison::from_i32(condition.comparison).ok_or(())?.into(),
+				Displayed(condition.amount.ok_or(())?.into()),
+			))
+		};
+		Ok(match condition.condition.ok_or(())? {
+			condition::Condition::Balance(condition) => {
+				let (asset, comparison, amount) = balance(condition)?;
+				xcvm_core::Condition::Balance { asset, comparison, amount }
+			},
+			condition::Condition::QueriedBalance(condition) => {
+				let (asset, comparison, amount) = balance(condition)?;
+				xcvm_core::Condition::QueriedBalance { asset, comparison, amount }
+			},
+			condition::Condition::Ip(IpCondition { comparison, ip }) => xcvm_core::Condition::Ip {
+				comparison: Comparison::from_i32(comparison).ok_or(())?.into(),
+				value: ip,
+			},
+			condition::Condition::Result(ResultCondition { ok }) =>
+				xcvm_core::Condition::Result { ok },
+		})
+	}
+}
+
+impl From<Comparison> for xcvm_core::Comparison {
+	fn from(comparison: Comparison) -> Self {
+		match comparison {
+			Comparison::Equal => xcvm_core::Comparison::Equal,
+			Comparison::NotEqual => xcvm_core::Comparison::NotEqual,
+			Comparison::Less => xcvm_core::Comparison::Less,
+			Comparison::LessOrEqual => xcvm_core::Comparison::LessOrEqual,
+			Comparison::Greater => xcvm_core::Comparison::Greater,
+			Comparison::GreaterOrEqual => xcvm_core::Comparison::GreaterOrEqual,
+		}
+	}
+}
+
+impl<TAccount> TryFrom<abort::AccountType> for Destination<TAccount>
+where
+	TAccount: for<'a> TryFrom<&'a [u8]>,
+{
+	type Error = ();
+
+	fn try_from(account_type: abort::AccountType) -> core::result::Result<Self, Self::Error> {
+		Ok(match account_type {
+			abort::AccountType::Account(Account { account }) =>
+				Destination::Account(account.as_slice().try_into().map_err(|_| ())?),
+			abort::AccountType::Relayer(_) => Destination::Relayer,
+		})
+	}
+}
+
+impl TryFrom<Deadline> for xcvm_core::Deadline {
+	type Error = ();
+
+	fn try_from(deadline: Deadline) -> core::result::Result<Self, Self::Error> {
+		Ok(match deadline.deadline.ok_or(())? {
+			deadline::Deadline::Timestamp(timestamp) => xcvm_core::Deadline::Timestamp(timestamp),
+			deadline::Deadline::Relative(seconds) => xcvm_core::Deadline::Relative(seconds),
+		})
+	}
+}
+
 impl From<Network> for NetworkId {
 	fn from(network: Network) -> Self {
 		network.network_id.into()
@@ -553,6 +852,72 @@ where
 	}
 }
 
+impl<TAccount> From<xcvm_core::Destination<TAccount>> for abort::AccountType
+where
+	TAccount: Into<Vec<u8>>,
+{
+	fn from(destination: xcvm_core::Destination<TAccount>) -> Self {
+		match destination {
+			Destination::Account(account) =>
+				abort::AccountType::Account(Account { account: account.into() }),
+			Destination::Relayer => abort::AccountType::Relayer(Relayer { id: 0 }),
+		}
+	}
+}
+
+impl From<xcvm_core::Deadline> for Deadline {
+	fn from(deadline: xcvm_core::Deadline) -> Self {
+		let deadline = match deadline {
+			xcvm_core::Deadline::Timestamp(timestamp) => deadline::Deadline::Timestamp(timestamp),
+			xcvm_core::Deadline::Relative(seconds) => deadline::Deadline::Relative(seconds),
+		};
+		Deadline { deadline: Some(deadline) }
+	}
+}
+
+impl From<xcvm_core::Comparison> for Comparison {
+	fn from(comparison: xcvm_core::Comparison) -> Self {
+		match comparison {
+			xcvm_core::Comparison::Equal => Comparison::Equal,
+			xcvm_core::Comparison::NotEqual => Comparison::NotEqual,
+			xcvm_core::Comparison::Less => Comparison::Less,
+			xcvm_core::Comparison::LessOrEqual => Comparison::LessOrEqual,
+			xcvm_core::Comparison::Greater => Comparison::Greater,
+			xcvm_core::Comparison::GreaterOrEqual => Comparison::GreaterOrEqual,
+		}
+	}
+}
+
+impl From<xcvm_core::Condition> for Condition {
+	fn from(condition: xcvm_core::Condition) -> Self {
+		let condition = match condition {
+			xcvm_core::Condition::Balance { asset, comparison, amount: Displayed(amount) } =>
+				condition::Condition::Balance(BalanceCondition {
+					asset_id: Some(asset.into()),
+					comparison: Comparison::from(comparison) as i32,
+					amount: Some(amount.into()),
+				}),
+			xcvm_core::Condition::QueriedBalance {
+				asset,
+				comparison,
+				amount: Displayed(amount),
+			} => condition::Condition::QueriedBalance(BalanceCondition {
+				asset_id: Some(asset.into()),
+				comparison: Comparison::from(comparison) as i32,
+				amount: Some(amount.into()),
+			}),
+			xcvm_core::Condition::Ip { comparison, value } =>
+				condition::Condition::Ip(IpCondition {
+					comparison: Comparison::from(comparison) as i32,
+					ip: value,
+				}),
+			xcvm_core::Condition::Result { ok } =>
+				condition::Condition::Result(ResultCondition { ok }),
+		};
+		Condition { condition: Some(condition) }
+	}
+}
+
 impl From<(u32, xcvm_core::BindingValue)> for Binding {
 	fn from((position, binding_value): (u32, xcvm_core::BindingValue)) -> Self {
 		Binding { position, binding_value: Some(binding_value.into()) }
@@ -581,19 +946,44 @@ where
 						bindings: bindings.into_iter().map(|binding| binding.into()).collect(),
 					}),
 				}),
-			xcvm_core::Instruction::Spawn { network, bridge_security, salt, assets, program } =>
-				instruction::Instruction::Spawn(Spawn {
-					network: Some(Network { network_id: network.into() }),
-					security: bridge_security as i32,
-					salt: Some(Salt { salt }),
-					program: Some(program.into()),
-					assets: assets.into().into_iter().map(|asset| asset.into()).collect(),
-				}),
+			xcvm_core::Instruction::Spawn {
+				network,
+				bridge_security,
+				salt,
+				assets,
+				fee,
+				deadline,
+				program,
+			} => instruction::Instruction::Spawn(Spawn {
+				network: Some(Network { network_id: network.into() }),
+				security: bridge_security as i32,
+				salt: Some(Salt { salt }),
+				program: Some(program.into()),
+				assets: assets.into().into_iter().map(|asset| asset.into()).collect(),
+				fee: fee.into().into_iter().map(|asset| asset.into()).collect(),
+				deadline: Some(deadline.into()),
+			}),
 			xcvm_core::Instruction::Query { network, salt } =>
 				instruction::Instruction::Query(Query {
 					network: Some(Network { network_id: network.into() }),
 					salt: Some(Salt { salt }),
 				}),
+			xcvm_core::Instruction::If { condition, then, otherwise } =>
+				instruction::Instruction::If(If {
+					condition: Some(condition.into()),
+					then: Some(then.into()),
+					otherwise: Some(otherwise.into()),
+				}),
+			xcvm_core::Instruction::Try { program, on_error } =>
+				instruction::Instruction::Try(Try {
+					program: Some(program.into()),
+					on_error: Some(on_error.into()),
+				}),
+			xcvm_core::Instruction::Abort { to, assets } =>
+				instruction::Instruction::Abort(Abort {
+					assets: assets.into().into_iter().map(|asset| asset.into()).collect(),
+					account_type: Some(to.into()),
+				}),
 		}
 	}
 }
@@ -652,6 +1042,102 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn query_instruction_roundtrip_works() {
+		let instruction = xcvm_core::Instruction::<NetworkId, Vec<u8>, Vec<u8>, Funds>::Query {
+			network: <xcvm_core::Ethereum as xcvm_core::Network>::ID,
+			salt: alloc::vec![0xCA, 0xFE],
+		};
+		let decoded: xcvm_core::Instruction<NetworkId, Vec<u8>, Vec<u8>, Funds> =
+			Instruction::from(instruction.clone()).try_into().unwrap();
+		assert_eq!(decoded, instruction);
+	}
+
+	#[test]
+	fn spawn_instruction_roundtrip_works() {
+		type XCVMInstruction = xcvm_core::Instruction<NetworkId, Vec<u8>, Vec<u8>, Funds>;
+		for deadline in
+			[xcvm_core::Deadline::Timestamp(1_700_000_000), xcvm_core::Deadline::Relative(600)]
+		{
+			let instruction = XCVMInstruction::Spawn {
+				network: <xcvm_core::Ethereum as xcvm_core::Network>::ID,
+				bridge_security: xcvm_core::BridgeSecurity::Deterministic,
+				salt: alloc::vec![0xCA, 0xFE],
+				assets: Funds::from(alloc::vec![(
+					xcvm_core::AssetId::from(1_u128),
+					Amount::absolute(10)
+				)]),
+				fee: Funds::from(alloc::vec![(
+					xcvm_core::AssetId::from(2_u128),
+					Amount::absolute(1)
+				)]),
+				deadline,
+				program: xcvm_core::Program { tag: Vec::new(), instructions: VecDeque::new() },
+			};
+			let decoded: XCVMInstruction =
+				Instruction::from(instruction.clone()).try_into().unwrap();
+			assert_eq!(decoded, instruction);
+		}
+	}
+
+	#[test]
+	fn control_flow_instructions_roundtrip_works() {
+		type XCVMInstruction = xcvm_core::Instruction<NetworkId, Vec<u8>, Vec<u8>, Funds>;
+		let program = |instructions: Vec<XCVMInstruction>| xcvm_core::Program {
+			tag: Vec::new(),
+			instructions: VecDeque::from(instructions),
+		};
+		let instructions = [
+			XCVMInstruction::If {
+				condition: xcvm_core::Condition::QueriedBalance {
+					asset: xcvm_core::AssetId::from(1_u128),
+					comparison: xcvm_core::Comparison::GreaterOrEqual,
+					amount: Displayed(1000),
+				},
+				then: program(alloc::vec![XCVMInstruction::If {
+					condition: xcvm_core::Condition::Result { ok: false },
+					then: program(alloc::vec![]),
+					otherwise: program(alloc::vec![]),
+				}]),
+				otherwise: program(alloc::vec![XCVMInstruction::Abort {
+					to: Destination::Relayer,
+					assets: Funds::from(alloc::vec![(
+						xcvm_core::AssetId::from(1_u128),
+						Amount::absolute(10)
+					)]),
+				}]),
+			},
+			XCVMInstruction::Try {
+				program: program(alloc::vec![XCVMInstruction::If {
+					condition: xcvm_core::Condition::Ip {
+						comparison: xcvm_core::Comparison::Less,
+						value: 3,
+					},
+					then: program(alloc::vec![]),
+					otherwise: program(alloc::vec![]),
+				}]),
+				on_error: program(alloc::vec![XCVMInstruction::Abort {
+					to: Destination::Account(b"user".to_vec()),
+					assets: Funds::empty(),
+				}]),
+			},
+		];
+		for instruction in instructions {
+			let decoded: XCVMInstruction =
+				Instruction::from(instruction.clone()).try_into().unwrap();
+			assert_eq!(decoded, instruction);
+		}
+	}
+
+	#[test]
+	fn query_result_roundtrip_works() {
+		let result = xcvm_core::QueryResult {
+			interpreter: b"interpreter".to_vec(),
+			balances: Funds(alloc::vec![(xcvm_core::AssetId::from(1_u128), Displayed(42_u128))]),
+		};
+		assert_eq!(decode_query_result(&result.clone().encode()).unwrap(), result);
+	}
+
 	#[test]
 	fn u128_from_uint128_works() {
 		let real_value = 1231231231231231233123123123123123_u128;